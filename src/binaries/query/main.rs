@@ -39,6 +39,7 @@ use databend_query::servers::FlightSQLServer;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
+use databend_query::servers::PostgresHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::GlobalServices;
@@ -144,6 +145,22 @@ async fn main_entrypoint() -> Result<()> {
         );
     }
 
+    // PostgreSQL handler.
+    {
+        let hostname = conf.query.postgres_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.postgres_handler_port);
+        let mut handler = PostgresHandler::create()?;
+        let listening = handler.start(listening.parse()?).await?;
+        shutdown_handle.add_service(handler);
+
+        info!(
+            "Listening for PostgreSQL compatibility protocol: {}, Usage: psql -h{} -p{} -Uroot",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // ClickHouse HTTP handler.
     {
         let hostname = conf.query.clickhouse_http_handler_host.clone();
@@ -289,6 +306,15 @@ async fn main_entrypoint() -> Result<()> {
         "    connect via: mysql -uroot -h{} -P{}",
         conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
+    println!("PostgreSQL");
+    println!(
+        "    listened at {}:{}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
+    println!(
+        "    connect via: psql -h{} -p{} -Uroot",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
     println!("Clickhouse(http)");
     println!(
         "    listened at {}:{}",
@@ -370,12 +370,14 @@ impl MemStat {
         })
     }
 
-    pub fn set_limit(&self, mut size: i64) {
-        // It may cause the process unable to run if memory limit is too low.
-        const LOWEST: i64 = 256 * 1024 * 1024;
+    /// The lowest memory limit, the limits below it are raised to it.
+    ///
+    /// It may cause the process unable to run if memory limit is too low.
+    pub const LOWEST_LIMIT: i64 = 256 * 1024 * 1024;
 
-        if size > 0 && size < LOWEST {
-            size = LOWEST;
+    pub fn set_limit(&self, mut size: i64) {
+        if size > 0 && size < Self::LOWEST_LIMIT {
+            size = Self::LOWEST_LIMIT;
         }
 
         self.limit.store(size, Ordering::Relaxed);
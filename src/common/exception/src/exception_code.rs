@@ -138,6 +138,8 @@ build_exceptions! {
     // create table or alter table add column with internal column name
     TableWithInternalColumnName(1110),
     EmptyShareEndpointConfig(1111),
+    // alter table modify column with a type the existing data can not be cast to
+    ModifyColumnTypeError(1112),
 
     // Data Related Errors
 
@@ -210,6 +212,10 @@ build_exceptions! {
     /// - not exists.
     /// - and without `IF EXISTS`
     CatalogNotFound(2320),
+    /// `IndexAlreadyExists` should be raised when creating an index with the name of an existing one on the same table.
+    IndexAlreadyExists(2321),
+    /// `UnknownIndex` should be raised when dropping an index that does not exist.
+    UnknownIndex(2322),
 
 
     // Cluster error codes.
@@ -234,6 +240,16 @@ build_exceptions! {
     UnknownUDF(2602),
     UdfAlreadyExists(2603),
 
+    // Row access policy error codes.
+    IllegalRowAccessPolicy(2604),
+    UnknownRowAccessPolicy(2605),
+    RowAccessPolicyAlreadyExists(2606),
+
+    // Masking policy error codes.
+    IllegalMaskingPolicy(2607),
+    UnknownMaskingPolicy(2608),
+    MaskingPolicyAlreadyExists(2609),
+
     // Database error codes.
     UnknownDatabaseEngine(2701),
     UnknownTableEngine(2702),
@@ -262,6 +278,7 @@ build_exceptions! {
     IllegalTenantQuotaFormat(2901),
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
+    UserQuotaExceeded(2904),
 
 }
 
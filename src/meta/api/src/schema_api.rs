@@ -35,6 +35,8 @@ use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SetDatabaseOwnerReply;
+use common_meta_app::schema::SetDatabaseOwnerReq;
 use common_meta_app::schema::TableId;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
@@ -86,6 +88,11 @@ pub trait SchemaApi: Send + Sync {
         req: RenameDatabaseReq,
     ) -> Result<RenameDatabaseReply, KVAppError>;
 
+    async fn set_database_owner(
+        &self,
+        req: SetDatabaseOwnerReq,
+    ) -> Result<SetDatabaseOwnerReply, KVAppError>;
+
     async fn get_database_history(
         &self,
         req: ListDatabaseReq,
@@ -72,6 +72,8 @@ use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SetDatabaseOwnerReply;
+use common_meta_app::schema::SetDatabaseOwnerReq;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
@@ -712,6 +714,54 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
         )))
     }
 
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn set_database_owner(
+        &self,
+        req: SetDatabaseOwnerReq,
+    ) -> Result<SetDatabaseOwnerReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let name_key = &req.name_ident;
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+            let (_, db_id, db_meta_seq, mut db_meta) =
+                get_db_or_err(self, name_key, "set_database_owner").await?;
+
+            db_meta.owner = Some(req.owner.clone());
+
+            let dbid = DatabaseId { db_id };
+            let txn_req = TxnRequest {
+                condition: vec![
+                    // db meta is not changed
+                    txn_cond_seq(&dbid, Eq, db_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&dbid, serialize_struct(&db_meta)?), // (db_id) -> db_meta
+                ],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(&name_key),
+                owner = display(&req.owner),
+                succ = display(succ),
+                "set_database_owner"
+            );
+
+            if succ {
+                return Ok(SetDatabaseOwnerReply {});
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("set_database_owner", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn get_database(&self, req: GetDatabaseReq) -> Result<Arc<DatabaseInfo>, KVAppError> {
         debug!(req = debug(&req), "SchemaApi: {}", func_name!());
@@ -45,6 +45,7 @@ use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListTableReq;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SetDatabaseOwnerReq;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
@@ -229,6 +230,7 @@ impl SchemaApiTestSuite {
         suite.database_list(&b.build().await).await?;
         suite.database_list_in_diff_tenant(&b.build().await).await?;
         suite.database_rename(&b.build().await).await?;
+        suite.database_set_owner(&b.build().await).await?;
         suite
             .database_drop_undrop_list_history(&b.build().await)
             .await?;
@@ -1171,6 +1173,52 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn database_set_owner<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+
+        info!("--- set owner of not exists db1");
+        {
+            let req = SetDatabaseOwnerReq {
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                owner: "role1".to_string(),
+            };
+
+            let res = mt.set_database_owner(req).await;
+            info!("set database owner res: {:?}", res);
+            assert!(res.is_err());
+            assert_eq!(
+                ErrorCode::UnknownDatabase("").code(),
+                ErrorCode::from(res.unwrap_err()).code()
+            );
+        }
+
+        info!("--- set owner of exists db1");
+        {
+            self.create_database(mt, tenant, db_name, "eng1").await?;
+
+            let req = SetDatabaseOwnerReq {
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                owner: "role1".to_string(),
+            };
+            mt.set_database_owner(req).await?;
+
+            let res = mt
+                .get_database(GetDatabaseReq::new(tenant, db_name))
+                .await?;
+            assert_eq!(Some("role1".to_string()), res.meta.owner);
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn database_drop_undrop_list_history<MT: SchemaApi>(
         &self,
@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A masking policy decides what the values of a column look like to a query.
+///
+/// The body is an expression over the argument, which is bound to the masked column,
+/// and returns a value of the same type as the column.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct MaskingPolicy {
+    pub name: String,
+    /// The name and data type of the argument.
+    pub arg: (String, TableDataType),
+    pub return_type: TableDataType,
+    pub body: String,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl MaskingPolicy {
+    pub fn new(
+        name: &str,
+        arg: (String, TableDataType),
+        return_type: TableDataType,
+        body: &str,
+        comment: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            arg,
+            return_type,
+            body: body.to_string(),
+            comment: comment.to_string(),
+            created_on: Utc::now(),
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for MaskingPolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(serialize_error) => Err(ErrorCode::IllegalMaskingPolicy(format!(
+                "Cannot deserialize masking policy from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -14,8 +14,10 @@
 
 //! Principal is a user or role that accesses an entity.
 
+mod masking_policy;
 mod principal_identity;
 mod role_info;
+mod row_access_policy;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -27,9 +29,11 @@ mod user_quota;
 mod user_setting;
 mod user_stage;
 
+pub use masking_policy::MaskingPolicy;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use row_access_policy::RowAccessPolicy;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
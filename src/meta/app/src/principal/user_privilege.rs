@@ -62,6 +62,9 @@ pub enum UserPrivilegeType {
     DropRole = 1 << 14,
     // Privilege to Drop user.
     DropUser = 1 << 15,
+    // Privilege held by the owner of a database or table, it contains all the privileges on the
+    // object and can only be transferred by GRANT OWNERSHIP.
+    Ownership = 1 << 16,
     // TODO: remove this later
     Set = 1 << 4,
 }
@@ -105,6 +108,7 @@ impl std::fmt::Display for UserPrivilegeType {
             UserPrivilegeType::CreateStage => "CREATE STAGE",
             UserPrivilegeType::Grant => "GRANT",
             UserPrivilegeType::Set => "SET",
+            UserPrivilegeType::Ownership => "OWNERSHIP",
         })
     }
 }
@@ -145,6 +149,11 @@ impl UserPrivilegeSet {
         make_bitflags!(UserPrivilegeType::{ Create | Update | Select | Insert | Delete | Drop | Alter | Grant }).into()
     }
 
+    /// The privileges available to a column of a table, only SELECT is supported for now
+    pub fn available_privileges_on_column() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Select }).into()
+    }
+
     // TODO: remove this, as ALL has different meanings on different objects
     pub fn all_privileges() -> Self {
         ALL_PRIVILEGES.into()
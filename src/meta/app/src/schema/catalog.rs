@@ -24,6 +24,7 @@ pub enum CatalogType {
     Default = 1,
     Hive = 2,
     Iceberg = 3,
+    Delta = 4,
 }
 
 impl Display for CatalogType {
@@ -32,6 +33,7 @@ impl Display for CatalogType {
             CatalogType::Default => write!(f, "DEFAULT"),
             CatalogType::Hive => write!(f, "HIVE"),
             CatalogType::Iceberg => write!(f, "ICEBERG"),
+            CatalogType::Delta => write!(f, "DELTA"),
         }
     }
 }
@@ -45,6 +47,12 @@ pub struct IcebergCatalogOption {
     pub flatten: bool,
 }
 
+/// Option for creating a delta lake catalog
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeltaCatalogOption {
+    pub storage_params: Box<StorageParams>,
+}
+
 /// different options for creating catalogs
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CatalogOption {
@@ -52,6 +60,8 @@ pub enum CatalogOption {
     Hive(String),
     // Uri location for iceberg
     Iceberg(IcebergCatalogOption),
+    // Uri location for delta lake
+    Delta(DeltaCatalogOption),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -97,6 +97,8 @@ pub struct DatabaseMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub from_share: Option<ShareNameIdent>,
+    // The role owning the database, None if the database is created before ownership is introduced.
+    pub owner: Option<String>,
 }
 
 impl Default for DatabaseMeta {
@@ -111,6 +113,7 @@ impl Default for DatabaseMeta {
             drop_on: None,
             shared_by: BTreeSet::new(),
             from_share: None,
+            owner: None,
         }
     }
 }
@@ -215,6 +218,25 @@ impl Display for RenameDatabaseReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RenameDatabaseReply {}
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SetDatabaseOwnerReq {
+    pub name_ident: DatabaseNameIdent,
+    pub owner: String,
+}
+
+impl Display for SetDatabaseOwnerReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "set_database_owner:{}/{}=>{}",
+            self.name_ident.tenant, self.name_ident.db_name, self.owner
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SetDatabaseOwnerReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct DropDatabaseReq {
     pub if_exists: bool,
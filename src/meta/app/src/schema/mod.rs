@@ -23,6 +23,7 @@ pub use catalog::CatalogNameIdent;
 pub use catalog::CatalogOption;
 pub use catalog::CatalogType;
 pub use catalog::CreateCatalogReq;
+pub use catalog::DeltaCatalogOption;
 pub use catalog::DropCatalogReq;
 pub use catalog::IcebergCatalogOption;
 pub use database::CreateDatabaseReply;
@@ -41,6 +42,8 @@ pub use database::GetDatabaseReq;
 pub use database::ListDatabaseReq;
 pub use database::RenameDatabaseReply;
 pub use database::RenameDatabaseReq;
+pub use database::SetDatabaseOwnerReply;
+pub use database::SetDatabaseOwnerReq;
 pub use database::UndropDatabaseReply;
 pub use database::UndropDatabaseReq;
 pub use table::CountTablesKey;
@@ -67,9 +70,11 @@ pub use table::TableIdList;
 pub use table::TableIdListKey;
 pub use table::TableIdToName;
 pub use table::TableIdent;
+pub use table::TableIndex;
 pub use table::TableInfo;
 pub use table::TableMeta;
 pub use table::TableNameIdent;
+pub use table::TableRowAccessPolicy;
 pub use table::TableStatistics;
 pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
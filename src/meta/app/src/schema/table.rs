@@ -22,7 +22,10 @@ use std::sync::Arc;
 
 use chrono::DateTime;
 use chrono::Utc;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_meta_types::MatchSeq;
@@ -199,6 +202,22 @@ pub struct TableStatistics {
     pub index_data_bytes: u64,
 }
 
+/// An inverted index defined on some string columns of a table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableIndex {
+    pub name: String,
+    /// Id of the indexed columns, they are kept across column renaming.
+    pub column_ids: Vec<ColumnId>,
+}
+
+/// A row access policy attached to a table, the arguments of the policy are bound to the columns.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableRowAccessPolicy {
+    pub name: String,
+    /// Id of the columns bound to the arguments of the policy, in the order of the arguments.
+    pub column_ids: Vec<ColumnId>,
+}
+
 /// The essential state that defines what a table is.
 ///
 /// It is what a meta store just needs to save.
@@ -221,6 +240,14 @@ pub struct TableMeta {
     pub updated_on: DateTime<Utc>,
     pub comment: String,
     pub field_comments: Vec<String>,
+    // The inverted indexes of the table, keyed by index name.
+    pub indexes: BTreeMap<String, TableIndex>,
+    // The row access policy filtering the rows of the table.
+    pub row_access_policy: Option<TableRowAccessPolicy>,
+    // The masking policies of the columns, keyed by column id.
+    pub column_mask_policy: BTreeMap<ColumnId, String>,
+    // The role owning the table, None if the table is created before ownership is introduced.
+    pub owner: Option<String>,
 
     // if used in CreateTableReq, this field MUST set to None.
     pub drop_on: Option<DateTime<Utc>>,
@@ -239,9 +266,125 @@ impl TableMeta {
     }
 
     pub fn drop_column(&mut self, column: &str) -> Result<()> {
+        if let Some(policy) = &self.row_access_policy {
+            let column_id = self.schema.field_with_name(column)?.column_id();
+            if policy.column_ids.contains(&column_id) {
+                return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                    "column {} is referenced by row access policy {}",
+                    column, policy.name
+                )));
+            }
+        }
+        let column_id = self.schema.column_id_of(column);
         let mut new_schema = self.schema.as_ref().to_owned();
         new_schema.drop_column(column)?;
         self.schema = Arc::new(new_schema);
+        if let Ok(column_id) = column_id {
+            self.column_mask_policy.remove(&column_id);
+        }
+        Ok(())
+    }
+
+    pub fn rename_column(&mut self, column: &str, new_column: &str) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.rename_column(column, new_column)?;
+        self.schema = Arc::new(new_schema);
+        Ok(())
+    }
+
+    pub fn modify_column_type(&mut self, column: &str, data_type: TableDataType) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.modify_column_type(column, data_type)?;
+        self.schema = Arc::new(new_schema);
+        Ok(())
+    }
+
+    pub fn set_column_default_expr(
+        &mut self,
+        column: &str,
+        default_expr: Option<String>,
+    ) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.set_column_default_expr(column, default_expr)?;
+        self.schema = Arc::new(new_schema);
+        Ok(())
+    }
+
+    pub fn set_column_comment(&mut self, column: &str, comment: String) -> Result<()> {
+        let i = self.schema.index_of(column)?;
+        // Tables created without comments have no entry for each field.
+        if self.field_comments.len() < self.schema.num_fields() {
+            self.field_comments
+                .resize(self.schema.num_fields(), "".to_string());
+        }
+        self.field_comments[i] = comment;
+        Ok(())
+    }
+
+    pub fn add_index(&mut self, index: TableIndex, if_not_exists: bool) -> Result<()> {
+        if self.indexes.contains_key(&index.name) {
+            return if if_not_exists {
+                Ok(())
+            } else {
+                Err(ErrorCode::IndexAlreadyExists(format!(
+                    "index '{}' already exists",
+                    index.name
+                )))
+            };
+        }
+        self.indexes.insert(index.name.clone(), index);
+        Ok(())
+    }
+
+    pub fn drop_index(&mut self, name: &str, if_exists: bool) -> Result<()> {
+        if self.indexes.remove(name).is_none() && !if_exists {
+            return Err(ErrorCode::UnknownIndex(format!(
+                "index '{}' does not exist",
+                name
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn add_row_access_policy(&mut self, policy: TableRowAccessPolicy) -> Result<()> {
+        if let Some(exists) = &self.row_access_policy {
+            return Err(ErrorCode::RowAccessPolicyAlreadyExists(format!(
+                "table already has row access policy '{}'",
+                exists.name
+            )));
+        }
+        self.row_access_policy = Some(policy);
+        Ok(())
+    }
+
+    pub fn drop_row_access_policy(&mut self, name: &str) -> Result<()> {
+        match &self.row_access_policy {
+            Some(policy) if policy.name == name => {
+                self.row_access_policy = None;
+                Ok(())
+            }
+            _ => Err(ErrorCode::UnknownRowAccessPolicy(format!(
+                "row access policy '{}' is not attached to the table",
+                name
+            ))),
+        }
+    }
+
+    pub fn set_column_mask_policy(&mut self, column: &str, policy: &str) -> Result<()> {
+        let column_id = self.schema.column_id_of(column)?;
+        self.column_mask_policy
+            .insert(column_id, policy.to_string());
+        Ok(())
+    }
+
+    pub fn unset_column_mask_policy(&mut self, column: &str) -> Result<()> {
+        let column_id = self.schema.column_id_of(column)?;
+        if self.column_mask_policy.remove(&column_id).is_none() {
+            return Err(ErrorCode::UnknownMaskingPolicy(format!(
+                "column '{}' has no masking policy",
+                column
+            )));
+        }
         Ok(())
     }
 }
@@ -318,6 +461,10 @@ impl Default for TableMeta {
             updated_on: Utc::now(),
             comment: "".to_string(),
             field_comments: vec![],
+            indexes: BTreeMap::new(),
+            row_access_policy: None,
+            column_mask_policy: BTreeMap::new(),
+            owner: None,
             drop_on: None,
             statistics: Default::default(),
         }
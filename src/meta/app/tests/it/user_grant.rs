@@ -77,6 +77,36 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Database("default".into(), "db1".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::Database("default".into(), "db1".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "x".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "x".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "d".into(), "x".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "x".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "x".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "x".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "y".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "x".into()),
+            rhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            expect: false,
+        },
     ];
     for t in tests {
         assert_eq!(
@@ -200,3 +230,49 @@ fn test_user_grant_set() -> Result<()> {
     ));
     Ok(())
 }
+
+#[test]
+fn test_user_grant_set_columns() -> Result<()> {
+    let mut grants = UserGrantSet::empty();
+
+    for column in ["a", "b"] {
+        grants.grant_privileges(
+            &GrantObject::Column("default".into(), "db1".into(), "t1".into(), column.into()),
+            make_bitflags!(UserPrivilegeType::{Select}).into(),
+        );
+    }
+    assert_eq!(2, grants.entries().len());
+    assert_eq!(
+        "GRANT SELECT(a) ON 'default'.'db1'.'t1'",
+        grants.entries()[0].to_string()
+    );
+
+    assert!(!grants.verify_privilege(
+        &GrantObject::Table("default".into(), "db1".into(), "t1".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+    assert!(grants.verify_privilege(
+        &GrantObject::Column("default".into(), "db1".into(), "t1".into(), "a".into()),
+        vec![UserPrivilegeType::Select]
+    ));
+    assert_eq!(
+        vec!["a".to_string(), "b".to_string()],
+        grants.granted_columns("default", "db1", "t1", UserPrivilegeType::Select)
+    );
+    assert!(
+        grants
+            .granted_columns("default", "db1", "t2", UserPrivilegeType::Select)
+            .is_empty()
+    );
+
+    grants.revoke_privileges(
+        &GrantObject::Column("default".into(), "db1".into(), "t1".into(), "a".into()),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    assert_eq!(
+        vec!["b".to_string()],
+        grants.granted_columns("default", "db1", "t1", UserPrivilegeType::Select)
+    );
+
+    Ok(())
+}
@@ -71,6 +71,18 @@ impl FromToProto for ex::TableField {
     fn from_pb(p: pb::DataField) -> Result<Self, Incompatible> {
         reader_check_msg(p.ver, p.min_reader_ver)?;
 
+        let mut legacy_columns = Vec::with_capacity(p.legacy_columns.len());
+        for legacy_column in p.legacy_columns {
+            reader_check_msg(legacy_column.ver, legacy_column.min_reader_ver)?;
+            let data_type = legacy_column.data_type.ok_or_else(|| Incompatible {
+                reason: "LegacyColumn.data_type can not be None".to_string(),
+            })?;
+            legacy_columns.push((
+                legacy_column.column_id,
+                ex::TableDataType::from_pb(data_type)?,
+            ));
+        }
+
         let v = ex::TableField::new_from_column_id(
             &p.name,
             ex::TableDataType::from_pb(p.data_type.ok_or_else(|| Incompatible {
@@ -78,11 +90,22 @@ impl FromToProto for ex::TableField {
             })?)?,
             p.column_id,
         )
-        .with_default_expr(p.default_expr);
+        .with_default_expr(p.default_expr)
+        .with_legacy_columns(legacy_columns);
         Ok(v)
     }
 
     fn to_pb(&self) -> Result<pb::DataField, Incompatible> {
+        let mut legacy_columns = Vec::with_capacity(self.legacy_columns().len());
+        for (column_id, data_type) in self.legacy_columns() {
+            legacy_columns.push(pb::LegacyColumn {
+                ver: VER,
+                min_reader_ver: MIN_READER_VER,
+                column_id: *column_id,
+                data_type: Some(data_type.to_pb()?),
+            });
+        }
+
         let p = pb::DataField {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
@@ -90,6 +113,7 @@ impl FromToProto for ex::TableField {
             default_expr: self.default_expr().cloned(),
             data_type: Some(self.data_type().to_pb()?),
             column_id: self.column_id(),
+            legacy_columns,
         };
         Ok(p)
     }
@@ -210,6 +234,9 @@ impl FromToProto for ex::TableDataType {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
                     Dt24::EmptyMapT(_) => ex::TableDataType::EmptyMap,
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
+                    Dt24::BitmapT(_) => ex::TableDataType::Bitmap,
+                    Dt24::BinaryT(_) => ex::TableDataType::Binary,
                 };
                 Ok(x)
             }
@@ -237,6 +264,7 @@ impl FromToProto for ex::TableDataType {
             }
             TableDataType::Timestamp => new_pb_dt24(Dt24::TimestampT(pb::Empty {})),
             TableDataType::Date => new_pb_dt24(Dt24::DateT(pb::Empty {})),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
             TableDataType::Nullable(v) => {
                 let x = v.to_pb()?;
                 new_pb_dt24(Dt24::NullableT(Box::new(x)))
@@ -269,6 +297,8 @@ impl FromToProto for ex::TableDataType {
                 new_pb_dt24(Dt24::TupleT(x))
             }
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
+            TableDataType::Bitmap => new_pb_dt24(Dt24::BitmapT(pb::Empty {})),
+            TableDataType::Binary => new_pb_dt24(Dt24::BinaryT(pb::Empty {})),
         };
         Ok(x)
     }
@@ -214,6 +214,17 @@ impl FromToProto for mt::TableMeta {
             },
             comment: p.comment,
             field_comments: p.field_comments,
+            indexes: p
+                .indexes
+                .into_iter()
+                .map(|(name, index)| Ok((name, mt::TableIndex::from_pb(index)?)))
+                .collect::<Result<_, Incompatible>>()?,
+            row_access_policy: p
+                .row_access_policy
+                .map(mt::TableRowAccessPolicy::from_pb)
+                .transpose()?,
+            column_mask_policy: p.column_mask_policy,
+            owner: p.owner,
             statistics: p
                 .statistics
                 .map(mt::TableStatistics::from_pb)
@@ -252,12 +263,76 @@ impl FromToProto for mt::TableMeta {
             },
             comment: self.comment.clone(),
             field_comments: self.field_comments.clone(),
+            indexes: self
+                .indexes
+                .iter()
+                .map(|(name, index)| Ok((name.clone(), index.to_pb()?)))
+                .collect::<Result<_, Incompatible>>()?,
+            row_access_policy: self
+                .row_access_policy
+                .as_ref()
+                .map(|policy| policy.to_pb())
+                .transpose()?,
+            column_mask_policy: self.column_mask_policy.clone(),
+            owner: self.owner.clone(),
             statistics: Some(self.statistics.to_pb()?),
         };
         Ok(p)
     }
 }
 
+impl FromToProto for mt::TableIndex {
+    type PB = pb::TableIndex;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableIndex) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            name: p.name,
+            column_ids: p.column_ids,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableIndex, Incompatible> {
+        let p = pb::TableIndex {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            column_ids: self.column_ids.clone(),
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableRowAccessPolicy {
+    type PB = pb::TableRowAccessPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableRowAccessPolicy) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            name: p.name,
+            column_ids: p.column_ids,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableRowAccessPolicy, Incompatible> {
+        let p = pb::TableRowAccessPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            column_ids: self.column_ids.clone(),
+        };
+        Ok(p)
+    }
+}
+
 impl FromToProto for mt::TableStatistics {
     type PB = pb::TableStatistics;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -161,6 +161,14 @@ impl FromToProto for mt::principal::GrantObject {
                 db,
                 table,
             })) => Ok(mt::principal::GrantObject::Table(catalog, db, table)),
+            Some(pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                catalog,
+                db,
+                table,
+                column,
+            })) => Ok(mt::principal::GrantObject::Column(
+                catalog, db, table, column,
+            )),
             _ => Err(Incompatible {
                 reason: "GrantObject cannot be None".to_string(),
             }),
@@ -185,6 +193,14 @@ impl FromToProto for mt::principal::GrantObject {
                     table: table.clone(),
                 }),
             ),
+            mt::principal::GrantObject::Column(catalog, db, table, column) => Some(
+                pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                    catalog: catalog.clone(),
+                    db: db.clone(),
+                    table: table.clone(),
+                    column: column.clone(),
+                }),
+            ),
         };
         Ok(pb::GrantObject {
             ver: VER,
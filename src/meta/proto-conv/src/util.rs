@@ -61,6 +61,15 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (29, "2023-02-23: Add: metadata.proto/DataType EmptyMap types", ),
     (30, "2023-02-21: Add: config.proto/WebhdfsStorageConfig; Modify: user.proto/UserStageInfo::StageStorage", ),
     (31, "2023-02-21: Add: CopyOptions::max_files", ),
+    (32, "2023-03-06: Add: metadata.proto/DataType Interval type", ),
+    (33, "2023-03-08: Add: metadata.proto/DataType Bitmap type", ),
+    (34, "2023-03-10: Add: metadata.proto/DataType Binary type", ),
+    (35, "2023-03-13: Add: metadata.proto/DataField::legacy_columns", ),
+    (36, "2023-03-15: Add: table.proto/TableMeta::indexes, table.proto/TableIndex", ),
+    (37, "2023-03-20: Add: user.proto/GrantObject::GrantColumnObject", ),
+    (38, "2023-03-22: Add: table.proto/TableMeta::row_access_policy, table.proto/TableRowAccessPolicy", ),
+    (39, "2023-03-24: Add: table.proto/TableMeta::column_mask_policy", ),
+    (40, "2023-03-27: Add: table.proto/TableMeta::owner, database.proto/DatabaseMeta::owner", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
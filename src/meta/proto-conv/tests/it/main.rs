@@ -38,3 +38,12 @@ mod v028_schema;
 mod v029_schema;
 mod v030_user_stage;
 mod v031_copy_max_file;
+mod v032_interval_schema;
+mod v033_bitmap_schema;
+mod v034_binary_schema;
+mod v035_legacy_columns;
+mod v036_table_index;
+mod v037_grant_column_object;
+mod v038_table_row_access_policy;
+mod v039_table_column_mask_policy;
+mod v040_object_owner;
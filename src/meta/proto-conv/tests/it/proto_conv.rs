@@ -51,6 +51,7 @@ fn new_db_meta_share() -> mt::DatabaseMeta {
             tenant: "tenant".to_string(),
             share_name: "share".to_string(),
         }),
+        owner: None,
     }
 }
 
@@ -65,6 +66,7 @@ fn new_db_meta() -> mt::DatabaseMeta {
         drop_on: None,
         shared_by: BTreeSet::from_iter(vec![1].into_iter()),
         from_share: None,
+        owner: Some(s("role1")),
     }
 }
 
@@ -193,6 +195,16 @@ fn new_table_meta() -> mt::TableMeta {
         updated_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 10).unwrap(),
         comment: s("table_comment"),
         field_comments: vec!["c".to_string(); 21],
+        indexes: btreemap! {s("idx1") => mt::TableIndex {
+            name: s("idx1"),
+            column_ids: vec![1, 2],
+        }},
+        row_access_policy: Some(mt::TableRowAccessPolicy {
+            name: s("p1"),
+            column_ids: vec![1],
+        }),
+        column_mask_policy: btreemap! {2 => s("m1")},
+        owner: Some(s("role1")),
         drop_on: None,
         statistics: Default::default(),
     }
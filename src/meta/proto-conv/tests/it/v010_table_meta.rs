@@ -139,6 +139,10 @@ fn test_decode_v10_table_meta() -> anyhow::Result<()> {
         updated_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 10).unwrap(),
         comment: s("table_comment"),
         field_comments: vec!["c".to_string(); 21],
+        indexes: btreemap! {},
+        row_access_policy: None,
+        column_mask_policy: btreemap! {},
+        owner: None,
         drop_on: None,
         statistics: Default::default(),
     };
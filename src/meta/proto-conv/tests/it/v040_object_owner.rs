@@ -0,0 +1,73 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_expression::TableSchema;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::TableMeta;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v40_table_owner() -> anyhow::Result<()> {
+    let table_meta_v40 = vec![
+        10, 6, 160, 6, 40, 168, 6, 24, 162, 1, 23, 50, 48, 50, 51, 45, 48, 51, 45, 50, 55, 32, 49,
+        48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 170, 1, 23, 50, 48, 50, 51, 45, 48, 51, 45, 50,
+        55, 32, 49, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 250, 1, 5, 114, 111, 108, 101, 49,
+        160, 6, 40, 168, 6, 24,
+    ];
+
+    let want = || TableMeta {
+        schema: Arc::new(TableSchema::empty()),
+        created_on: Utc.with_ymd_and_hms(2023, 3, 27, 10, 0, 0).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2023, 3, 27, 10, 0, 0).unwrap(),
+        owner: Some("role1".to_string()),
+        ..Default::default()
+    };
+    common::test_load_old(func_name!(), table_meta_v40.as_slice(), 40, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
+
+#[test]
+fn test_decode_v40_database_owner() -> anyhow::Result<()> {
+    let database_meta_v40 = vec![
+        162, 1, 23, 50, 48, 50, 51, 45, 48, 51, 45, 50, 55, 32, 49, 48, 58, 48, 48, 58, 48, 48, 32,
+        85, 84, 67, 170, 1, 23, 50, 48, 50, 51, 45, 48, 51, 45, 50, 55, 32, 49, 48, 58, 48, 48, 58,
+        48, 48, 32, 85, 84, 67, 210, 1, 5, 114, 111, 108, 101, 49, 160, 6, 40, 168, 6, 24,
+    ];
+
+    let want = || DatabaseMeta {
+        created_on: Utc.with_ymd_and_hms(2023, 3, 27, 10, 0, 0).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2023, 3, 27, 10, 0, 0).unwrap(),
+        owner: Some("role1".to_string()),
+        ..Default::default()
+    };
+    common::test_load_old(func_name!(), database_meta_v40.as_slice(), 40, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
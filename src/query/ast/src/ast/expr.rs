@@ -427,6 +427,7 @@ pub enum TypeName {
     },
     Date,
     Timestamp,
+    Interval,
     String,
     Array(Box<TypeName>),
     Map {
@@ -438,6 +439,8 @@ pub enum TypeName {
         fields_type: Vec<TypeName>,
     },
     Variant,
+    Bitmap,
+    Binary,
     Nullable(Box<TypeName>),
 }
 
@@ -460,6 +463,8 @@ pub enum TrimWhere {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct WindowSpec {
+    /// `IGNORE NULLS` or `RESPECT NULLS` placed between the function call and `OVER`
+    pub ignore_nulls: Option<bool>,
     pub partition_by: Vec<Expr>,
     pub order_by: Vec<OrderByExpr>,
     pub window_frame: Option<WindowFrame>,
@@ -816,6 +821,9 @@ impl Display for TypeName {
             TypeName::Timestamp => {
                 write!(f, "TIMESTAMP")?;
             }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
             TypeName::String => {
                 write!(f, "STRING")?;
             }
@@ -856,6 +864,12 @@ impl Display for TypeName {
             TypeName::Variant => {
                 write!(f, "VARIANT")?;
             }
+            TypeName::Bitmap => {
+                write!(f, "BITMAP")?;
+            }
+            TypeName::Binary => {
+                write!(f, "BINARY")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -1152,6 +1166,11 @@ impl Display for Expr {
                 write!(f, ")")?;
 
                 if let Some(window) = window {
+                    match window.ignore_nulls {
+                        Some(true) => write!(f, " IGNORE NULLS")?,
+                        Some(false) => write!(f, " RESPECT NULLS")?,
+                        None => {}
+                    }
                     write!(f, " OVER ({window})")?;
                 }
             }
@@ -915,6 +915,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         let mut children = Vec::new();
         self.visit_table_ref(&insert.catalog, &insert.database, &insert.table);
         children.push(self.children.pop().unwrap());
+        if !insert.partition.is_empty() {
+            let mut partition_children = Vec::with_capacity(insert.partition.len());
+            for (column, value) in insert.partition.iter() {
+                let value_name = format!("PartitionValue {} = {}", column, value);
+                let value_format_ctx = AstFormatContext::new(value_name);
+                partition_children.push(FormatTreeNode::new(value_format_ctx));
+            }
+            let partition_name = "Partition".to_string();
+            let partition_format_ctx =
+                AstFormatContext::with_children(partition_name, partition_children.len());
+            let partition_node =
+                FormatTreeNode::with_children(partition_format_ctx, partition_children);
+            children.push(partition_node);
+        }
         if !insert.columns.is_empty() {
             let mut columns_children = Vec::with_capacity(insert.columns.len());
             for column in insert.columns.iter() {
@@ -1323,6 +1337,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                let action_name = format!("Action Rename column {} to {}", old_column, new_column);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::ModifyColumn { column, action } => {
+                let action_name = format!("Action Modify column {} {}", column, action);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
             AlterTableAction::DropColumn { column } => {
                 let action_name = format!("Action Drop column {}", column);
                 let action_format_ctx = AstFormatContext::new(action_name);
@@ -1362,6 +1389,22 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::with_children(action_name, 1);
                 FormatTreeNode::with_children(action_format_ctx, vec![point_node])
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let mut columns_children = Vec::with_capacity(columns.len());
+                for column in columns.iter() {
+                    self.visit_identifier(column);
+                    columns_children.push(self.children.pop().unwrap());
+                }
+                let action_name = format!("Action Add row access policy {}", policy);
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, columns_children.len());
+                FormatTreeNode::with_children(action_format_ctx, columns_children)
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                let action_name = format!("Action Drop row access policy {}", policy);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1461,6 +1504,111 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        let mut children = Vec::new();
+        let index_name = format!("Index {}", stmt.index_name);
+        let index_format_ctx = AstFormatContext::new(index_name);
+        children.push(FormatTreeNode::new(index_format_ctx));
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        children.push(self.children.pop().unwrap());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            children.push(self.children.pop().unwrap());
+        }
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt) {
+        let index_name = format!("Index {}", stmt.index_name);
+        let index_format_ctx = AstFormatContext::new(index_name);
+        let index_child = FormatTreeNode::new(index_format_ctx);
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let mut children = Vec::new();
+        let policy_name = format!("Policy {}", stmt.name);
+        let policy_format_ctx = AstFormatContext::new(policy_name);
+        children.push(FormatTreeNode::new(policy_format_ctx));
+        for arg in stmt.args.iter() {
+            let arg_name = format!("Argument {}", arg);
+            let arg_format_ctx = AstFormatContext::new(arg_name);
+            children.push(FormatTreeNode::new(arg_format_ctx));
+        }
+        let return_type_name = format!("ReturnType {}", stmt.return_type);
+        let return_type_format_ctx = AstFormatContext::new(return_type_name);
+        children.push(FormatTreeNode::new(return_type_format_ctx));
+        self.visit_expr(&stmt.body);
+        let body_child = self.children.pop().unwrap();
+        let body_name = "Body".to_string();
+        let body_format_ctx = AstFormatContext::with_children(body_name, 1);
+        children.push(FormatTreeNode::with_children(body_format_ctx, vec![
+            body_child,
+        ]));
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let policy_name = format!("Policy {}", stmt.name);
+        let policy_format_ctx = AstFormatContext::new(policy_name);
+        let child = FormatTreeNode::new(policy_format_ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_masking_policy(&mut self, stmt: &'ast CreateMaskingPolicyStmt) {
+        let mut children = Vec::new();
+        let policy_name = format!("Policy {}", stmt.name);
+        let policy_format_ctx = AstFormatContext::new(policy_name);
+        children.push(FormatTreeNode::new(policy_format_ctx));
+        let arg_name = format!("Argument {}", stmt.arg);
+        let arg_format_ctx = AstFormatContext::new(arg_name);
+        children.push(FormatTreeNode::new(arg_format_ctx));
+        let return_type_name = format!("ReturnType {}", stmt.return_type);
+        let return_type_format_ctx = AstFormatContext::new(return_type_name);
+        children.push(FormatTreeNode::new(return_type_format_ctx));
+        self.visit_expr(&stmt.body);
+        let body_child = self.children.pop().unwrap();
+        let body_name = "Body".to_string();
+        let body_format_ctx = AstFormatContext::with_children(body_name, 1);
+        children.push(FormatTreeNode::with_children(body_format_ctx, vec![
+            body_child,
+        ]));
+
+        let name = "CreateMaskingPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_masking_policy(&mut self, stmt: &'ast DropMaskingPolicyStmt) {
+        let policy_name = format!("Policy {}", stmt.name);
+        let policy_format_ctx = AstFormatContext::new(policy_name);
+        let child = FormatTreeNode::new(policy_format_ctx);
+
+        let name = "DropMaskingPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_show_users(&mut self) {
         let name = "ShowUsers".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -1607,6 +1755,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::with_children(privileges_name, privileges_children.len());
                 FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
             }
+            AccountMgrSource::ColumnPrivs {
+                privilege, columns, ..
+            } => {
+                let mut columns_children = Vec::with_capacity(columns.len());
+                for column in columns.iter() {
+                    let column_name = format!("Column {}", column);
+                    let column_format_ctx = AstFormatContext::new(column_name);
+                    columns_children.push(FormatTreeNode::new(column_format_ctx));
+                }
+                let privilege_name = format!("Privilege {}", privilege);
+                let privilege_format_ctx =
+                    AstFormatContext::with_children(privilege_name, columns_children.len());
+                FormatTreeNode::with_children(privilege_format_ctx, columns_children)
+            }
             AccountMgrSource::ALL { .. } => {
                 let all_name = "All".to_string();
                 let all_format_ctx = AstFormatContext::new(all_name);
@@ -1661,6 +1823,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::with_children(privileges_name, privileges_children.len());
                 FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
             }
+            AccountMgrSource::ColumnPrivs {
+                privilege, columns, ..
+            } => {
+                let mut columns_children = Vec::with_capacity(columns.len());
+                for column in columns.iter() {
+                    let column_name = format!("Column {}", column);
+                    let column_format_ctx = AstFormatContext::new(column_name);
+                    columns_children.push(FormatTreeNode::new(column_format_ctx));
+                }
+                let privilege_name = format!("Privilege {}", privilege);
+                let privilege_format_ctx =
+                    AstFormatContext::with_children(privilege_name, columns_children.len());
+                FormatTreeNode::with_children(privilege_format_ctx, columns_children)
+            }
             AccountMgrSource::ALL { .. } => {
                 let all_name = "All".to_string();
                 let all_format_ctx = AstFormatContext::new(all_name);
@@ -1680,6 +1856,29 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_grant_ownership(&mut self, stmt: &'ast GrantOwnershipStmt) {
+        let level_name = match &stmt.level {
+            AccountMgrLevel::Global => "Global".to_string(),
+            AccountMgrLevel::Database(database) => {
+                format!("Database {}", database.as_deref().unwrap_or("*"))
+            }
+            AccountMgrLevel::Table(database, table) => match database {
+                Some(database) => format!("Table {}.{}", database, table),
+                None => format!("Table {}", table),
+            },
+        };
+        let level_format_ctx = AstFormatContext::new(level_name);
+        let level_child = FormatTreeNode::new(level_format_ctx);
+        let role_name = format!("Role {}", stmt.role);
+        let role_format_ctx = AstFormatContext::new(role_name);
+        let role_child = FormatTreeNode::new(role_format_ctx);
+
+        let name = "GrantOwnership".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![level_child, role_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_udf(
         &mut self,
         _if_not_exists: bool,
@@ -2215,6 +2414,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             children.push(having_node);
         }
 
+        if let Some(qualify) = &stmt.qualify {
+            self.visit_expr(qualify);
+            let qualify_child = self.children.pop().unwrap();
+            let qualify_name = "Qualify".to_string();
+            let qualify_format_ctx = AstFormatContext::with_children(qualify_name, 1);
+            let qualify_node =
+                FormatTreeNode::with_children(qualify_format_ctx, vec![qualify_child]);
+            children.push(qualify_node);
+        }
+
         let name = "SelectQuery".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
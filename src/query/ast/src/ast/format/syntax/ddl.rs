@@ -157,6 +157,19 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::AddColumn { column } => RcDoc::line()
             .append(RcDoc::text("ADD COLUMN "))
             .append(RcDoc::text(column.to_string())),
+        AlterTableAction::RenameColumn {
+            old_column,
+            new_column,
+        } => RcDoc::line()
+            .append(RcDoc::text("RENAME COLUMN "))
+            .append(RcDoc::text(old_column.to_string()))
+            .append(RcDoc::text(" TO "))
+            .append(RcDoc::text(new_column.to_string())),
+        AlterTableAction::ModifyColumn { column, action } => RcDoc::line()
+            .append(RcDoc::text("MODIFY COLUMN "))
+            .append(RcDoc::text(column.to_string()))
+            .append(RcDoc::space())
+            .append(RcDoc::text(action.to_string())),
         AlterTableAction::DropColumn { column } => RcDoc::line()
             .append(RcDoc::text("DROP COLUMN "))
             .append(RcDoc::text(column.to_string())),
@@ -52,6 +52,20 @@ pub(crate) fn pretty_insert(insert_stmt: InsertStmt) -> RcDoc<'static> {
                     RcDoc::nil()
                 })
                 .append(RcDoc::text(insert_stmt.table.to_string()))
+                .append(if !insert_stmt.partition.is_empty() {
+                    RcDoc::space()
+                        .append(RcDoc::text("PARTITION ("))
+                        .append(inline_comma(insert_stmt.partition.into_iter().map(
+                            |(column, value)| {
+                                RcDoc::text(column.to_string())
+                                    .append(RcDoc::text(" = "))
+                                    .append(pretty_expr(value))
+                            },
+                        )))
+                        .append(RcDoc::text(")"))
+                } else {
+                    RcDoc::nil()
+                })
                 .append(if !insert_stmt.columns.is_empty() {
                     RcDoc::space()
                         .append(RcDoc::text("("))
@@ -85,6 +85,8 @@ pub struct SelectStmt {
     pub group_by: Option<GroupBy>,
     // `HAVING` clause
     pub having: Option<Expr>,
+    // `QUALIFY` clause
+    pub qualify: Option<Expr>,
 }
 
 /// Group by Clause.
@@ -586,6 +588,11 @@ impl Display for SelectStmt {
             write!(f, " HAVING {having}")?;
         }
 
+        // QUALIFY clause
+        if let Some(qualify) = &self.qualify {
+            write!(f, " QUALIFY {qualify}")?;
+        }
+
         Ok(())
     }
 }
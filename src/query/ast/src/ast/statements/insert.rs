@@ -18,6 +18,7 @@ use std::fmt::Formatter;
 
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_period_separated_list;
+use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Query;
 
@@ -26,6 +27,8 @@ pub struct InsertStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub table: Identifier,
+    /// static partition values, like `PARTITION (p_date = '20230101')`
+    pub partition: Vec<(Identifier, Expr)>,
     pub columns: Vec<Identifier>,
     pub source: InsertSource,
     pub overwrite: bool,
@@ -46,6 +49,16 @@ impl Display for InsertStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if !self.partition.is_empty() {
+            write!(f, " PARTITION (")?;
+            for (i, (column, value)) in self.partition.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{column} = {value}")?;
+            }
+            write!(f, ")")?;
+        }
         if !self.columns.is_empty() {
             write!(f, " (")?;
             write_comma_separated_list(f, &self.columns)?;
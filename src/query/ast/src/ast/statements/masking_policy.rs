@@ -0,0 +1,65 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::Expr;
+use crate::ast::Identifier;
+use crate::ast::PolicyArg;
+use crate::ast::TypeName;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateMaskingPolicyStmt {
+    pub if_not_exists: bool,
+    pub name: Identifier,
+    pub arg: PolicyArg,
+    pub return_type: TypeName,
+    pub body: Expr,
+    pub comment: Option<String>,
+}
+
+impl Display for CreateMaskingPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE MASKING POLICY ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(
+            f,
+            "{} AS ({}) RETURNS {} -> {}",
+            self.name, self.arg, self.return_type, self.body
+        )?;
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropMaskingPolicyStmt {
+    pub if_exists: bool,
+    pub name: Identifier,
+}
+
+impl Display for DropMaskingPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP MASKING POLICY ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
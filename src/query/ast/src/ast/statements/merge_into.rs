@@ -0,0 +1,138 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::write_period_separated_list;
+use crate::ast::Expr;
+use crate::ast::Identifier;
+use crate::ast::TableReference;
+use crate::ast::UpdateExpr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeIntoStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub target_alias: Option<Identifier>,
+    pub source: TableReference,
+    pub join_expr: Expr,
+    pub matched_clauses: Vec<MatchedClause>,
+    pub unmatched_clauses: Vec<UnmatchedClause>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MergeOption {
+    Match(MatchedClause),
+    Unmatch(UnmatchedClause),
+}
+
+/// `WHEN MATCHED [AND <selection>] THEN { UPDATE SET ... | DELETE }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchedClause {
+    pub selection: Option<Expr>,
+    pub operation: MatchOperation,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchOperation {
+    Update { update_list: Vec<UpdateExpr> },
+    Delete,
+}
+
+/// `WHEN NOT MATCHED [AND <selection>] THEN INSERT [(<column>, ...)] VALUES (<expr>, ...)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnmatchedClause {
+    pub selection: Option<Expr>,
+    pub columns: Vec<Identifier>,
+    pub values: Vec<Expr>,
+}
+
+impl MergeIntoStmt {
+    pub fn split_merge_options(
+        options: Vec<MergeOption>,
+    ) -> (Vec<MatchedClause>, Vec<UnmatchedClause>) {
+        let mut matched_clauses = vec![];
+        let mut unmatched_clauses = vec![];
+        for option in options {
+            match option {
+                MergeOption::Match(clause) => matched_clauses.push(clause),
+                MergeOption::Unmatch(clause) => unmatched_clauses.push(clause),
+            }
+        }
+        (matched_clauses, unmatched_clauses)
+    }
+}
+
+impl Display for MergeIntoStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "MERGE INTO ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        if let Some(alias) = &self.target_alias {
+            write!(f, " AS {alias}")?;
+        }
+        write!(f, " USING {} ON {}", self.source, self.join_expr)?;
+        for clause in &self.matched_clauses {
+            write!(f, " {clause}")?;
+        }
+        for clause in &self.unmatched_clauses {
+            write!(f, " {clause}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for MatchedClause {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "WHEN MATCHED")?;
+        if let Some(selection) = &self.selection {
+            write!(f, " AND {selection}")?;
+        }
+        write!(f, " THEN ")?;
+        match &self.operation {
+            MatchOperation::Update { update_list } => {
+                write!(f, "UPDATE SET ")?;
+                write_comma_separated_list(f, update_list)?;
+            }
+            MatchOperation::Delete => write!(f, "DELETE")?,
+        }
+        Ok(())
+    }
+}
+
+impl Display for UnmatchedClause {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "WHEN NOT MATCHED")?;
+        if let Some(selection) = &self.selection {
+            write!(f, " AND {selection}")?;
+        }
+        write!(f, " THEN INSERT")?;
+        if !self.columns.is_empty() {
+            write!(f, " (")?;
+            write_comma_separated_list(f, &self.columns)?;
+            write!(f, ")")?;
+        }
+        write!(f, " VALUES (")?;
+        write_comma_separated_list(f, &self.values)?;
+        write!(f, ")")
+    }
+}
@@ -18,10 +18,14 @@ mod columns;
 mod copy;
 mod database;
 mod explain;
+mod index;
 mod insert;
 mod kill;
+mod masking_policy;
+mod merge_into;
 mod presign;
 mod replace;
+mod row_access_policy;
 mod share;
 mod show;
 mod stage;
@@ -38,10 +42,14 @@ pub use columns::*;
 pub use copy::*;
 pub use database::*;
 pub use explain::*;
+pub use index::*;
 pub use insert::*;
 pub use kill::*;
+pub use masking_policy::*;
+pub use merge_into::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
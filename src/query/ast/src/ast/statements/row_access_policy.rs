@@ -0,0 +1,76 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::Expr;
+use crate::ast::Identifier;
+use crate::ast::TypeName;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateRowAccessPolicyStmt {
+    pub if_not_exists: bool,
+    pub name: Identifier,
+    pub args: Vec<PolicyArg>,
+    pub return_type: TypeName,
+    pub body: Expr,
+    pub comment: Option<String>,
+}
+
+impl Display for CreateRowAccessPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE ROW ACCESS POLICY ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{} AS (", self.name)?;
+        write_comma_separated_list(f, &self.args)?;
+        write!(f, ") RETURNS {} -> {}", self.return_type, self.body)?;
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+        Ok(())
+    }
+}
+
+/// An argument of a data policy, like `region STRING`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyArg {
+    pub name: Identifier,
+    pub data_type: TypeName,
+}
+
+impl Display for PolicyArg {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropRowAccessPolicyStmt {
+    pub if_exists: bool,
+    pub name: Identifier,
+}
+
+impl Display for DropRowAccessPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP ROW ACCESS POLICY ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
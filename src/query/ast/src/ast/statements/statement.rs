@@ -82,6 +82,7 @@ pub enum Statement {
     },
 
     Update(UpdateStmt),
+    MergeInto(MergeIntoStmt),
 
     // Catalogs
     ShowCatalogs(ShowCatalogsStmt),
@@ -122,6 +123,18 @@ pub enum Statement {
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
 
+    // Indexes
+    CreateInvertedIndex(CreateInvertedIndexStmt),
+    DropInvertedIndex(DropInvertedIndexStmt),
+
+    // Row access policies
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+
+    // Masking policies
+    CreateMaskingPolicy(CreateMaskingPolicyStmt),
+    DropMaskingPolicy(DropMaskingPolicyStmt),
+
     // User
     ShowUsers,
     CreateUser(CreateUserStmt),
@@ -144,6 +157,7 @@ pub enum Statement {
         principal: Option<PrincipalIdentity>,
     },
     Revoke(RevokeStmt),
+    GrantOwnership(GrantOwnershipStmt),
 
     // UDF
     CreateUDF {
@@ -280,6 +294,7 @@ impl Display for Statement {
                 }
             }
             Statement::Update(update) => write!(f, "{update}")?,
+            Statement::MergeInto(merge_into) => write!(f, "{merge_into}")?,
             Statement::Copy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowSettings { like } => {
                 write!(f, "SHOW SETTINGS")?;
@@ -364,6 +379,12 @@ impl Display for Statement {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaskingPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaskingPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowUsers => write!(f, "SHOW USERS")?,
             Statement::ShowRoles => write!(f, "SHOW ROLES")?,
             Statement::CreateUser(stmt) => write!(f, "{stmt}")?,
@@ -404,6 +425,7 @@ impl Display for Statement {
                 }
             }
             Statement::Revoke(stmt) => write!(f, "{stmt}")?,
+            Statement::GrantOwnership(stmt) => write!(f, "{stmt}")?,
             Statement::CreateUDF {
                 if_not_exists,
                 udf_name,
@@ -285,6 +285,14 @@ pub enum AlterTableAction {
     AddColumn {
         column: ColumnDefinition,
     },
+    RenameColumn {
+        old_column: Identifier,
+        new_column: Identifier,
+    },
+    ModifyColumn {
+        column: Identifier,
+        action: ModifyColumnAction,
+    },
     DropColumn {
         column: Identifier,
     },
@@ -299,6 +307,13 @@ pub enum AlterTableAction {
     RevertTo {
         point: TimeTravelPoint,
     },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -310,6 +325,15 @@ impl Display for AlterTableAction {
             AlterTableAction::AddColumn { column } => {
                 write!(f, "ADD COLUMN {column}")
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                write!(f, "RENAME COLUMN {old_column} TO {new_column}")
+            }
+            AlterTableAction::ModifyColumn { column, action } => {
+                write!(f, "MODIFY COLUMN {column} {action}")
+            }
             AlterTableAction::DropColumn { column } => {
                 write!(f, "DROP COLUMN {column}")
             }
@@ -337,6 +361,48 @@ impl Display for AlterTableAction {
                 write!(f, "REVERT TO {}", point)?;
                 Ok(())
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModifyColumnAction {
+    /// Change the data type (and nullability) of the column.
+    SetDataType(TypeName),
+    SetDefault(Box<Expr>),
+    DropDefault,
+    Comment(String),
+    SetMaskingPolicy(Identifier),
+    UnsetMaskingPolicy,
+}
+
+impl Display for ModifyColumnAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ModifyColumnAction::SetDataType(data_type) => {
+                write!(f, "{data_type}")?;
+                if !matches!(data_type, TypeName::Nullable(_)) {
+                    write!(f, " NOT NULL")?;
+                }
+                Ok(())
+            }
+            ModifyColumnAction::SetDefault(default_expr) => {
+                write!(f, "SET DEFAULT {default_expr}")
+            }
+            ModifyColumnAction::DropDefault => write!(f, "DROP DEFAULT"),
+            ModifyColumnAction::Comment(comment) => write!(f, "COMMENT '{comment}'"),
+            ModifyColumnAction::SetMaskingPolicy(policy) => {
+                write!(f, "SET MASKING POLICY {policy}")
+            }
+            ModifyColumnAction::UnsetMaskingPolicy => write!(f, "UNSET MASKING POLICY"),
         }
     }
 }
@@ -21,8 +21,10 @@ use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserOptionFlag;
 use common_meta_app::principal::UserPrivilegeType;
+use common_meta_app::principal::UserQuota;
 
 use crate::ast::write_comma_separated_list;
+use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateUserStmt {
@@ -133,6 +135,36 @@ impl Display for RevokeStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrantOwnershipStmt {
+    pub level: AccountMgrLevel,
+    pub role: String,
+}
+
+impl Display for GrantOwnershipStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GRANT OWNERSHIP ON")?;
+        match &self.level {
+            AccountMgrLevel::Global => write!(f, " *.*")?,
+            AccountMgrLevel::Database(database_name) => {
+                if let Some(database_name) = database_name {
+                    write!(f, " {database_name}.*")?;
+                } else {
+                    write!(f, " *")?;
+                }
+            }
+            AccountMgrLevel::Table(database_name, table_name) => {
+                if let Some(database_name) = database_name {
+                    write!(f, " {database_name}.{table_name}")?;
+                } else {
+                    write!(f, " {table_name}")?;
+                }
+            }
+        }
+        write!(f, " TO ROLE {}", self.role)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AccountMgrSource {
     Role {
@@ -142,6 +174,11 @@ pub enum AccountMgrSource {
         privileges: Vec<UserPrivilegeType>,
         level: AccountMgrLevel,
     },
+    ColumnPrivs {
+        privilege: UserPrivilegeType,
+        columns: Vec<Identifier>,
+        level: AccountMgrLevel,
+    },
     ALL {
         level: AccountMgrLevel,
     },
@@ -158,6 +195,14 @@ pub enum AccountMgrLevel {
 pub enum UserOptionItem {
     TenantSetting(bool),
     DefaultRole(String),
+    Quota(Vec<UserQuotaItem>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserQuotaItem {
+    MaxCpu(u64),
+    MaxMemoryInBytes(u64),
+    MaxStorageInBytes(u64),
 }
 
 impl UserOptionItem {
@@ -167,6 +212,19 @@ impl UserOptionItem {
                 option.switch_option_flag(UserOptionFlag::TenantSetting, *enabled);
             }
             Self::DefaultRole(v) => option.set_default_role(Some(v.clone())),
+            Self::Quota(_) => {}
+        }
+    }
+
+    pub fn apply_quota(&self, quota: &mut UserQuota) {
+        if let Self::Quota(items) = self {
+            for item in items {
+                match item {
+                    UserQuotaItem::MaxCpu(v) => quota.max_cpu = *v,
+                    UserQuotaItem::MaxMemoryInBytes(v) => quota.max_memory_in_bytes = *v,
+                    UserQuotaItem::MaxStorageInBytes(v) => quota.max_storage_in_bytes = *v,
+                }
+            }
         }
     }
 }
@@ -197,6 +255,32 @@ impl Display for AccountMgrSource {
                     }
                 }
             }
+            AccountMgrSource::ColumnPrivs {
+                privilege,
+                columns,
+                level,
+            } => {
+                write!(f, " {privilege}(")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") ON")?;
+                match level {
+                    AccountMgrLevel::Global => write!(f, " *.*")?,
+                    AccountMgrLevel::Database(database_name) => {
+                        if let Some(database_name) = database_name {
+                            write!(f, " {database_name}.*")?;
+                        } else {
+                            write!(f, " *")?;
+                        }
+                    }
+                    AccountMgrLevel::Table(database_name, table_name) => {
+                        if let Some(database_name) = database_name {
+                            write!(f, " {database_name}.{table_name}")?;
+                        } else {
+                            write!(f, " {table_name}")?;
+                        }
+                    }
+                }
+            }
             AccountMgrSource::ALL { level, .. } => {
                 write!(f, " ALL PRIVILEGES")?;
                 write!(f, " ON")?;
@@ -229,6 +313,21 @@ impl Display for UserOptionItem {
             UserOptionItem::TenantSetting(true) => write!(f, "TENANTSETTING"),
             UserOptionItem::TenantSetting(false) => write!(f, "NOTENANTSETTING"),
             UserOptionItem::DefaultRole(v) => write!(f, "DEFAULT_ROLE = '{}'", v),
+            UserOptionItem::Quota(items) => {
+                write!(f, "QUOTA (")?;
+                write_comma_separated_list(f, items)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl Display for UserQuotaItem {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            UserQuotaItem::MaxCpu(v) => write!(f, "MAX_CPU = {v}"),
+            UserQuotaItem::MaxMemoryInBytes(v) => write!(f, "MAX_MEMORY_IN_BYTES = {v}"),
+            UserQuotaItem::MaxStorageInBytes(v) => write!(f, "MAX_STORAGE_IN_BYTES = {v}"),
         }
     }
 }
@@ -865,6 +865,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             ~ ((ROWS | RANGE) ~ #window_frame_between)?
         },
         |(opt_partition, opt_order, between)| WindowSpec {
+            ignore_nulls: None,
             partition_by: opt_partition.map(|x| x.2).unwrap_or_default(),
             order_by: opt_order.map(|x| x.2).unwrap_or_default(),
             window_frame: between.map(|x| {
@@ -901,14 +902,18 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         rule! {
             #function_name
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ ((IGNORE | RESPECT) ~ NULLS)?
             ~ (OVER ~ "(" ~ #window_spec ~ ")")
         },
-        |(name, _, opt_distinct, opt_args, _, window)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, _, opt_nulls, window)| ExprElement::FunctionCall {
             distinct: opt_distinct.is_some(),
             name,
             args: opt_args.unwrap_or_default(),
             params: vec![],
-            window: Some(window.2),
+            window: Some(WindowSpec {
+                ignore_nulls: opt_nulls.map(|(null_treatment, _)| null_treatment.kind == IGNORE),
+                ..window.2
+            }),
         },
     );
 
@@ -1074,6 +1079,19 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
+    let interval_expr = map(
+        rule! {
+            INTERVAL ~ #consumed(literal_string)
+        },
+        |(_, (span, interval))| ExprElement::Cast {
+            expr: Box::new(Expr::Literal {
+                span: transform_span(span.0),
+                lit: Literal::String(interval),
+            }),
+            target_type: TypeName::Interval,
+        },
+    );
+
     let is_distinct_from = map(
         rule! {
             IS ~ NOT? ~ DISTINCT ~ FROM
@@ -1097,6 +1115,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #date_expr: "`DATE <str_literal>`"
             | #timestamp_expr: "`TIMESTAMP <str_literal>`"
             | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
+            | #interval_expr: "`INTERVAL <str_literal>`"
             | #pg_cast : "`::<type_name>`"
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND) FROM ...)`"
         ),
@@ -1438,7 +1457,13 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         TypeName::String,
         rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT  ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
     );
+    let ty_interval = value(TypeName::Interval, rule! { INTERVAL });
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_bitmap = value(TypeName::Bitmap, rule! { BITMAP });
+    let ty_binary = value(
+        TypeName::Binary,
+        rule! { ( BINARY | VARBINARY ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
+    );
     map(
         rule! {
             ( #ty_boolean
@@ -1458,9 +1483,9 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_tuple : "TUPLE(<type>, ...)"
             | #ty_named_tuple : "TUPLE(<name> <type>, ...)"
             | #ty_date
-            | #ty_datetime
+            | ( #ty_datetime | #ty_interval )
             | #ty_string
-            | #ty_variant
+            | ( #ty_variant | #ty_bitmap | #ty_binary )
             | #ty_nullable
             ) ~ NULL? : "type name"
         },
@@ -579,6 +579,7 @@ pub enum SetOperationElement {
         selection: Box<Option<Expr>>,
         group_by: Option<GroupBy>,
         having: Box<Option<Expr>>,
+        qualify: Box<Option<Expr>>,
     },
     SetOperation {
         op: SetOperator,
@@ -639,6 +640,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                 ~ ( WHERE ~ ^#expr )?
                 ~ ( GROUP ~ ^BY ~ ^#group_by_items )?
                 ~ ( HAVING ~ ^#expr )?
+                ~ ( QUALIFY ~ ^#expr )?
         },
         |(
             _select,
@@ -648,6 +650,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             opt_where_block,
             opt_group_by_block,
             opt_having_block,
+            opt_qualify_block,
         )| {
             SetOperationElement::SelectStmt {
                 distinct: opt_distinct.is_some(),
@@ -660,6 +663,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                 selection: Box::new(opt_where_block.map(|(_, selection)| selection)),
                 group_by: opt_group_by_block.map(|(_, _, group_by)| group_by),
                 having: Box::new(opt_having_block.map(|(_, having)| having)),
+                qualify: Box::new(opt_qualify_block.map(|(_, qualify)| qualify)),
             }
         },
     );
@@ -706,6 +710,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 selection,
                 group_by,
                 having,
+                qualify,
             } => SetExpr::Select(Box::new(SelectStmt {
                 span: transform_span(input.span.0),
                 distinct,
@@ -714,6 +719,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 selection: *selection,
                 group_by,
                 having: *having,
+                qualify: *qualify,
             })),
             _ => unreachable!(),
         };
@@ -98,14 +98,18 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! {
             INSERT ~ ( INTO | OVERWRITE ) ~ TABLE?
             ~ #period_separated_idents_1_to_3
+            ~ ( PARTITION ~ ^"(" ~ ^#comma_separated_list1(partition_value) ~ ^")" )?
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ #insert_source
         },
-        |(_, overwrite, _, (catalog, database, table), opt_columns, source)| {
+        |(_, overwrite, _, (catalog, database, table), opt_partition, opt_columns, source)| {
             Statement::Insert(InsertStmt {
                 catalog,
                 database,
                 table,
+                partition: opt_partition
+                    .map(|(_, _, partition, _)| partition)
+                    .unwrap_or_default(),
                 columns: opt_columns
                     .map(|(_, columns, _)| columns)
                     .unwrap_or_default(),
@@ -170,6 +174,39 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let merge = map(
+        rule! {
+            MERGE ~ INTO ~ #period_separated_idents_1_to_3 ~ #alias_name?
+            ~ USING ~ #table_reference
+            ~ ON ~ ^#expr
+            ~ #merge_option+
+        },
+        |(
+            _,
+            _,
+            (catalog, database, table),
+            target_alias,
+            _,
+            source,
+            _,
+            join_expr,
+            merge_options,
+        )| {
+            let (matched_clauses, unmatched_clauses) =
+                MergeIntoStmt::split_merge_options(merge_options);
+            Statement::MergeInto(MergeIntoStmt {
+                catalog,
+                database,
+                table,
+                target_alias,
+                source,
+                join_expr,
+                matched_clauses,
+                unmatched_clauses,
+            })
+        },
+    );
+
     let show_settings = map(
         rule! {
             SHOW ~ SETTINGS ~ (LIKE ~ #literal_string)?
@@ -652,6 +689,112 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+            ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, _, _, opt_if_not_exists, index_name, _, (catalog, database, table), _, columns, _)| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+            })
+        },
+    );
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ EXISTS )?
+            ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ AS ~ "(" ~ #comma_separated_list1(policy_arg) ~ ")"
+            ~ RETURNS ~ #type_name ~ "->" ~ #expr
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            args,
+            _,
+            _,
+            return_type,
+            _,
+            body,
+            opt_comment,
+        )| {
+            Statement::CreateRowAccessPolicy(CreateRowAccessPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                args,
+                return_type,
+                body,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            Statement::DropRowAccessPolicy(DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let create_masking_policy = map(
+        rule! {
+            CREATE ~ MASKING ~ POLICY ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ AS ~ "(" ~ #policy_arg ~ ")"
+            ~ RETURNS ~ #type_name ~ "->" ~ #expr
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(_, _, _, opt_if_not_exists, name, _, _, arg, _, _, return_type, _, body, opt_comment)| {
+            Statement::CreateMaskingPolicy(CreateMaskingPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                arg,
+                return_type,
+                body,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_masking_policy = map(
+        rule! {
+            DROP ~ MASKING ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropMaskingPolicy(DropMaskingPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
     let alter_view = map(
         rule! {
             ALTER ~ VIEW
@@ -751,6 +894,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let grant_ownership = map(
+        rule! {
+            GRANT ~ OWNERSHIP ~ ON ~ #grant_level ~ TO ~ ROLE ~ #literal_string
+        },
+        |(_, _, _, level, _, _, role)| {
+            Statement::GrantOwnership(GrantOwnershipStmt { level, role })
+        },
+    );
     let show_grants = map(
         rule! {
             SHOW ~ GRANTS ~ #show_grant_option?
@@ -1151,6 +1302,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
+            | #merge : "`MERGE INTO <table> [AS <alias>] USING <source> ON <expr> { WHEN [NOT] MATCHED [AND <expr>] THEN <operation> } ...`"
         ),
         rule!(
             #set_variable : "`SET <variable> = <value>`"
@@ -1220,7 +1372,8 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #call: "`CALL <procedure_name>(<parameter>, ...)`"
         ),
         rule!(
-            #grant : "`GRANT { ROLE <role_name> | schemaObjectPrivileges | ALL [ PRIVILEGES ] ON <privileges_level> } TO { [ROLE <role_name>] | [USER] <user> }`"
+            #grant_ownership : "`GRANT OWNERSHIP ON <privileges_level> TO ROLE <role_name>`"
+            | #grant : "`GRANT { ROLE <role_name> | schemaObjectPrivileges | ALL [ PRIVILEGES ] ON <privileges_level> } TO { [ROLE <role_name>] | [USER] <user> }`"
             | #show_grants : "`SHOW GRANTS {FOR  { ROLE <role_name> | USER <user> }] | ON {DATABASE <db_name> | TABLE <db_name>.<table_name>} }`"
             | #revoke : "`REVOKE { ROLE <role_name> | schemaObjectPrivileges | ALL [ PRIVILEGES ] ON <privileges_level> } FROM { [ROLE <role_name>] | [USER] <user> }`"
         ),
@@ -1247,6 +1400,21 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         | #create_catalog: "`CREATE CATALOG [IF NOT EXISTS] <catalog> TYPE=<catalog_type> CONNECTION=<catalog_options>`"
         | #drop_catalog: "`DROP CATALOG [IF EXISTS] <catalog>`"
         ),
+        // index
+        rule!(
+            #create_inverted_index : "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>, ...)`"
+            | #drop_inverted_index : "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+        ),
+        // row access policy
+        rule!(
+            #create_row_access_policy : "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] <policy> AS (<arg> <type>, ...) RETURNS BOOLEAN -> <body expr> [COMMENT = '<string_literal>']`"
+            | #drop_row_access_policy : "`DROP ROW ACCESS POLICY [IF EXISTS] <policy>`"
+        ),
+        // masking policy
+        rule!(
+            #create_masking_policy : "`CREATE MASKING POLICY [IF NOT EXISTS] <policy> AS (<arg> <type>) RETURNS <type> -> <body expr> [COMMENT = '<string_literal>']`"
+            | #drop_masking_policy : "`DROP MASKING POLICY [IF EXISTS] <policy>`"
+        ),
     ));
 
     map(
@@ -1260,6 +1428,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     )(i)
 }
 
+pub fn partition_value(i: Input) -> IResult<(Identifier, Expr)> {
+    map(rule! { #ident ~ "=" ~ ^#expr }, |(name, _, value)| {
+        (name, value)
+    })(i)
+}
+
 // `INSERT INTO ... FORMAT ...` and `INSERT INTO ... VALUES` statements will
 // stop the parser immediately and return the rest tokens by `InsertSource`.
 //
@@ -1409,6 +1583,16 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
             level,
         },
     );
+    let column_privs = map(
+        rule! {
+            #priv_type ~ "(" ~ #comma_separated_list1(ident) ~ ")" ~ ON ~ #grant_level
+        },
+        |(privilege, _, columns, _, _, level)| AccountMgrSource::ColumnPrivs {
+            privilege,
+            columns,
+            level,
+        },
+    );
     let all = map(
         rule! { ALL ~ PRIVILEGES? ~ ON ~ #grant_level },
         |(_, _, _, level)| AccountMgrSource::ALL { level },
@@ -1416,6 +1600,7 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
 
     rule!(
         #role : "ROLE <role_name>"
+        | #column_privs : "<privilege>(<column>, ...) ON <database>.<table>"
         | #privs : "<privileges> ON <privileges_level>"
         | #all : "ALL [ PRIVILEGES ] ON <privileges_level>"
     )(i)
@@ -1610,6 +1795,21 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
         |(_, _, column)| AlterTableAction::AddColumn { column },
     );
+    let rename_column = map(
+        rule! {
+            RENAME ~ COLUMN ~ #ident ~ TO ~ #ident
+        },
+        |(_, _, old_column, _, new_column)| AlterTableAction::RenameColumn {
+            old_column,
+            new_column,
+        },
+    );
+    let modify_column = map(
+        rule! {
+            MODIFY ~ COLUMN ~ #ident ~ #modify_column_action
+        },
+        |(_, _, column, action)| AlterTableAction::ModifyColumn { column, action },
+    );
     let drop_column = map(
         rule! {
             DROP ~ COLUMN ~ #ident
@@ -1647,14 +1847,95 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, point)| AlterTableAction::RevertTo { point },
     );
 
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ #ident ~ ON ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
     rule!(
         #rename_table
+        | #rename_column
+        | #modify_column
         | #add_column
         | #drop_column
         | #alter_table_cluster_key
         | #drop_table_cluster_key
         | #recluster_table
         | #revert_table
+        | #add_row_access_policy
+        | #drop_row_access_policy
+    )(i)
+}
+
+pub fn policy_arg(i: Input) -> IResult<PolicyArg> {
+    map(
+        rule! {
+            #ident ~ #type_name
+        },
+        |(name, data_type)| PolicyArg { name, data_type },
+    )(i)
+}
+
+pub fn modify_column_action(i: Input) -> IResult<ModifyColumnAction> {
+    let set_default = map(
+        rule! {
+            SET ~ DEFAULT ~ ^#subexpr(NOT_PREC)
+        },
+        |(_, _, default_expr)| ModifyColumnAction::SetDefault(Box::new(default_expr)),
+    );
+    let drop_default = value(ModifyColumnAction::DropDefault, rule! { DROP ~ DEFAULT });
+    let set_masking_policy = map(
+        rule! {
+            SET ~ MASKING ~ ^POLICY ~ ^#ident
+        },
+        |(_, _, _, policy)| ModifyColumnAction::SetMaskingPolicy(policy),
+    );
+    let unset_masking_policy = value(
+        ModifyColumnAction::UnsetMaskingPolicy,
+        rule! { UNSET ~ ^MASKING ~ ^POLICY },
+    );
+    let comment = map(
+        rule! {
+            COMMENT ~ ^#literal_string
+        },
+        |(_, comment)| ModifyColumnAction::Comment(comment),
+    );
+    let nullable = alt((
+        value(true, rule! { NULL }),
+        value(false, rule! { NOT ~ ^NULL }),
+    ));
+    let set_data_type = map(
+        rule! {
+            #type_name ~ #nullable?
+            : "`<type> [NULL | NOT NULL]`"
+        },
+        |(data_type, nullable)| {
+            if nullable == Some(true) {
+                ModifyColumnAction::SetDataType(data_type.wrap_nullable())
+            } else {
+                ModifyColumnAction::SetDataType(data_type)
+            }
+        },
+    );
+
+    rule!(
+        #set_default
+        | #drop_default
+        | #set_masking_policy
+        | #unset_masking_policy
+        | #comment
+        | #set_data_type
     )(i)
 }
 
@@ -1830,6 +2111,7 @@ pub fn catalog_type(i: Input) -> IResult<CatalogType> {
         value(CatalogType::Default, rule! {DEFAULT}),
         value(CatalogType::Hive, rule! {HIVE}),
         value(CatalogType::Iceberg, rule! {ICEBERG}),
+        value(CatalogType::Delta, rule! {DELTA}),
     ));
     map(rule! { ^#catalog_type }, |catalog_type| catalog_type)(i)
 }
@@ -1841,6 +2123,12 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, role)| UserOptionItem::DefaultRole(role),
     );
+    let quota_option = map(
+        rule! {
+            "QUOTA" ~ "(" ~ ^#comma_separated_list1(user_quota_item) ~ ^")"
+        },
+        |(_, _, items, _)| UserOptionItem::Quota(items),
+    );
     alt((
         value(UserOptionItem::TenantSetting(true), rule! { TENANTSETTING }),
         value(
@@ -1848,6 +2136,23 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
             rule! { NOTENANTSETTING },
         ),
         default_role_option,
+        quota_option,
+    ))(i)
+}
+
+pub fn user_quota_item(i: Input) -> IResult<UserQuotaItem> {
+    alt((
+        map(rule! { "MAX_CPU" ~ "=" ~ #literal_u64 }, |(_, _, v)| {
+            UserQuotaItem::MaxCpu(v)
+        }),
+        map(
+            rule! { "MAX_MEMORY_IN_BYTES" ~ "=" ~ #literal_u64 },
+            |(_, _, v)| UserQuotaItem::MaxMemoryInBytes(v),
+        ),
+        map(
+            rule! { "MAX_STORAGE_IN_BYTES" ~ "=" ~ #literal_u64 },
+            |(_, _, v)| UserQuotaItem::MaxStorageInBytes(v),
+        ),
     ))(i)
 }
 
@@ -1973,3 +2278,53 @@ pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
         UpdateExpr { name, expr }
     })(i)
 }
+
+pub fn merge_option(i: Input) -> IResult<MergeOption> {
+    let matched = map(
+        rule! {
+            WHEN ~ MATCHED ~ ( AND ~ ^#expr )? ~ THEN ~ #match_operation
+        },
+        |(_, _, opt_selection, _, operation)| {
+            MergeOption::Match(MatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                operation,
+            })
+        },
+    );
+    let unmatched = map(
+        rule! {
+            WHEN ~ NOT ~ MATCHED ~ ( AND ~ ^#expr )? ~ THEN ~ INSERT
+            ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
+            ~ VALUES ~ "(" ~ ^#comma_separated_list1(expr) ~ ")"
+        },
+        |(_, _, _, opt_selection, _, _, opt_columns, _, _, values, _)| {
+            MergeOption::Unmatch(UnmatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                columns: opt_columns
+                    .map(|(_, columns, _)| columns)
+                    .unwrap_or_default(),
+                values,
+            })
+        },
+    );
+
+    rule!(
+        #matched
+        | #unmatched
+    )(i)
+}
+
+pub fn match_operation(i: Input) -> IResult<MatchOperation> {
+    let update = map(
+        rule! {
+            UPDATE ~ SET ~ ^#comma_separated_list1(update_expr)
+        },
+        |(_, _, update_list)| MatchOperation::Update { update_list },
+    );
+    let delete = value(MatchOperation::Delete, rule! { DELETE });
+
+    rule!(
+        #update
+        | #delete
+    )(i)
+}
@@ -243,6 +243,8 @@ pub enum TokenKind {
     //    reserved list.
     #[token("ALL", ignore(ascii_case))]
     ALL,
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
     #[token("ADD", ignore(ascii_case))]
     ADD,
     #[token("ANY", ignore(ascii_case))]
@@ -277,6 +279,10 @@ pub enum TokenKind {
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
     BIGINT,
+    #[token("BINARY", ignore(ascii_case))]
+    BINARY,
+    #[token("BITMAP", ignore(ascii_case))]
+    BITMAP,
     #[token("BOOL", ignore(ascii_case))]
     BOOL,
     #[token("BOOLEAN", ignore(ascii_case))]
@@ -369,6 +375,8 @@ pub enum TokenKind {
     DEFLATE,
     #[token("DELETE", ignore(ascii_case))]
     DELETE,
+    #[token("DELTA", ignore(ascii_case))]
+    DELTA,
     #[token("DESC", ignore(ascii_case))]
     DESC,
     #[token("DESCRIBE", ignore(ascii_case))]
@@ -491,8 +499,12 @@ pub enum TokenKind {
     IDENTIFIED,
     #[token("IF", ignore(ascii_case))]
     IF,
+    #[token("IGNORE", ignore(ascii_case))]
+    IGNORE,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("INDEX", ignore(ascii_case))]
+    INDEX,
     #[token("INNER", ignore(ascii_case))]
     INNER,
     #[token("INSERT", ignore(ascii_case))]
@@ -513,6 +525,8 @@ pub enum TokenKind {
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
     INTO,
+    #[token("INVERTED", ignore(ascii_case))]
+    INVERTED,
     #[token("IS", ignore(ascii_case))]
     IS,
     #[token("ISODOW", ignore(ascii_case))]
@@ -551,12 +565,18 @@ pub enum TokenKind {
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MASKING", ignore(ascii_case))]
+    MASKING,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
+    #[token("MATCHED", ignore(ascii_case))]
+    MATCHED,
     #[token("MEMO", ignore(ascii_case))]
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("MERGE", ignore(ascii_case))]
+    MERGE,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -567,6 +587,8 @@ pub enum TokenKind {
     MILLISECONDS,
     #[token("MINUTE", ignore(ascii_case))]
     MINUTE,
+    #[token("MODIFY", ignore(ascii_case))]
+    MODIFY,
     #[token("MONTH", ignore(ascii_case))]
     MONTH,
     #[token("NON_DISPLAY", ignore(ascii_case))]
@@ -599,6 +621,8 @@ pub enum TokenKind {
     OPTIMIZE,
     #[token("OR", ignore(ascii_case))]
     OR,
+    #[token("ORC", ignore(ascii_case))]
+    ORC,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
     #[token("OUTER", ignore(ascii_case))]
@@ -609,6 +633,8 @@ pub enum TokenKind {
     OVER,
     #[token("OVERWRITE", ignore(ascii_case))]
     OVERWRITE,
+    #[token("OWNERSHIP", ignore(ascii_case))]
+    OWNERSHIP,
     #[token("PARTITION", ignore(ascii_case))]
     PARTITION,
     #[token("PARQUET", ignore(ascii_case))]
@@ -619,12 +645,16 @@ pub enum TokenKind {
     PIPELINE,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
     PLAINTEXT_PASSWORD,
+    #[token("POLICY", ignore(ascii_case))]
+    POLICY,
     #[token("POSITION", ignore(ascii_case))]
     POSITION,
     #[token("PROCESSLIST", ignore(ascii_case))]
     PROCESSLIST,
     #[token("PURGE", ignore(ascii_case))]
     PURGE,
+    #[token("QUALIFY", ignore(ascii_case))]
+    QUALIFY,
     #[token("QUARTER", ignore(ascii_case))]
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
@@ -647,6 +677,10 @@ pub enum TokenKind {
     RENAME,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
+    #[token("RESPECT", ignore(ascii_case))]
+    RESPECT,
+    #[token("RETURNS", ignore(ascii_case))]
+    RETURNS,
     #[token("ROW", ignore(ascii_case))]
     ROW,
     #[token("ROWS", ignore(ascii_case))]
@@ -829,6 +863,8 @@ pub enum TokenKind {
     VALUES,
     #[token("VALIDATION_MODE", ignore(ascii_case))]
     VALIDATION_MODE,
+    #[token("VARBINARY", ignore(ascii_case))]
+    VARBINARY,
     #[token("VARCHAR", ignore(ascii_case))]
     VARCHAR,
     #[token("VARIANT", ignore(ascii_case))]
@@ -1071,6 +1107,7 @@ impl TokenKind {
             | TokenKind::OF
             | TokenKind::ORDER
             | TokenKind::OVER
+            | TokenKind::QUALIFY
             | TokenKind::ROWS
             // | TokenKind::PRECISION
             // | TokenKind::RETURNING
@@ -1187,6 +1224,7 @@ impl TokenKind {
             | TokenKind::OF
             | TokenKind::ORDER
             | TokenKind::OVER
+            | TokenKind::QUALIFY
             | TokenKind::ROWS
             | TokenKind::RANGE
             // | TokenKind::OVERLAPS
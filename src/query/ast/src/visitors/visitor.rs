@@ -380,6 +380,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_update(&mut self, _update: &'ast UpdateStmt) {}
 
+    fn visit_merge_into(&mut self, _merge_into: &'ast MergeIntoStmt) {}
+
     fn visit_show_catalogs(&mut self, _stmt: &'ast ShowCatalogsStmt) {}
 
     fn visit_show_create_catalog(&mut self, _stmt: &'ast ShowCreateCatalogStmt) {}
@@ -440,6 +442,18 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_inverted_index(&mut self, _stmt: &'ast CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &'ast DropInvertedIndexStmt) {}
+
+    fn visit_create_row_access_policy(&mut self, _stmt: &'ast CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &'ast DropRowAccessPolicyStmt) {}
+
+    fn visit_create_masking_policy(&mut self, _stmt: &'ast CreateMaskingPolicyStmt) {}
+
+    fn visit_drop_masking_policy(&mut self, _stmt: &'ast DropMaskingPolicyStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &'ast CreateUserStmt) {}
@@ -460,6 +474,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_revoke(&mut self, _revoke: &'ast RevokeStmt) {}
 
+    fn visit_grant_ownership(&mut self, _stmt: &'ast GrantOwnershipStmt) {}
+
     fn visit_create_udf(
         &mut self,
         _if_not_exists: bool,
@@ -561,6 +577,7 @@ pub trait Visitor<'ast>: Sized {
             selection,
             group_by,
             having,
+            qualify,
             ..
         } = stmt;
 
@@ -595,6 +612,10 @@ pub trait Visitor<'ast>: Sized {
         if let Some(having) = having {
             walk_expr(self, having);
         }
+
+        if let Some(qualify) = qualify {
+            walk_expr(self, qualify);
+        }
     }
 
     fn visit_select_target(&mut self, target: &'ast SelectTarget) {
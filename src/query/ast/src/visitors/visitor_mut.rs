@@ -386,6 +386,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_update(&mut self, _update: &mut UpdateStmt) {}
 
+    fn visit_merge_into(&mut self, _merge_into: &mut MergeIntoStmt) {}
+
     fn visit_show_catalogs(&mut self, _stmt: &mut ShowCatalogsStmt) {}
 
     fn visit_show_create_catalog(&mut self, _stmt: &mut ShowCreateCatalogStmt) {}
@@ -446,6 +448,18 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_inverted_index(&mut self, _stmt: &mut CreateInvertedIndexStmt) {}
+
+    fn visit_drop_inverted_index(&mut self, _stmt: &mut DropInvertedIndexStmt) {}
+
+    fn visit_create_row_access_policy(&mut self, _stmt: &mut CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &mut DropRowAccessPolicyStmt) {}
+
+    fn visit_create_masking_policy(&mut self, _stmt: &mut CreateMaskingPolicyStmt) {}
+
+    fn visit_drop_masking_policy(&mut self, _stmt: &mut DropMaskingPolicyStmt) {}
+
     fn visit_show_users(&mut self) {}
 
     fn visit_create_user(&mut self, _stmt: &mut CreateUserStmt) {}
@@ -466,6 +480,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_revoke(&mut self, _revoke: &mut RevokeStmt) {}
 
+    fn visit_grant_ownership(&mut self, _stmt: &mut GrantOwnershipStmt) {}
+
     fn visit_create_udf(
         &mut self,
         _if_not_exists: bool,
@@ -567,6 +583,7 @@ pub trait VisitorMut: Sized {
             selection,
             group_by,
             having,
+            qualify,
             ..
         } = stmt;
 
@@ -601,6 +618,10 @@ pub trait VisitorMut: Sized {
         if let Some(having) = having {
             walk_expr_mut(self, having);
         }
+
+        if let Some(qualify) = qualify {
+            walk_expr_mut(self, qualify);
+        }
     }
 
     fn visit_select_target(&mut self, target: &mut SelectTarget) {
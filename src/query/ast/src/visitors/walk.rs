@@ -311,6 +311,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
             ..
         } => visitor.visit_delete(table_reference, selection),
         Statement::Update(update) => visitor.visit_update(update),
+        Statement::MergeInto(merge_into) => visitor.visit_merge_into(merge_into),
         Statement::Copy(stmt) => visitor.visit_copy(stmt),
         Statement::ShowSettings { like } => visitor.visit_show_settings(like),
         Statement::ShowProcessList => visitor.visit_show_process_list(),
@@ -360,6 +361,12 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::CreateView(stmt) => visitor.visit_create_view(stmt),
         Statement::AlterView(stmt) => visitor.visit_alter_view(stmt),
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
+        Statement::CreateInvertedIndex(stmt) => visitor.visit_create_inverted_index(stmt),
+        Statement::DropInvertedIndex(stmt) => visitor.visit_drop_inverted_index(stmt),
+        Statement::CreateRowAccessPolicy(stmt) => visitor.visit_create_row_access_policy(stmt),
+        Statement::DropRowAccessPolicy(stmt) => visitor.visit_drop_row_access_policy(stmt),
+        Statement::CreateMaskingPolicy(stmt) => visitor.visit_create_masking_policy(stmt),
+        Statement::DropMaskingPolicy(stmt) => visitor.visit_drop_masking_policy(stmt),
         Statement::ShowUsers => visitor.visit_show_users(),
         Statement::ShowRoles => visitor.visit_show_roles(),
         Statement::CreateUser(stmt) => visitor.visit_create_user(stmt),
@@ -376,6 +383,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::Grant(stmt) => visitor.visit_grant(stmt),
         Statement::ShowGrants { principal } => visitor.visit_show_grant(principal),
         Statement::Revoke(stmt) => visitor.visit_revoke(stmt),
+        Statement::GrantOwnership(stmt) => visitor.visit_grant_ownership(stmt),
         Statement::CreateUDF {
             if_not_exists,
             udf_name,
@@ -107,6 +107,12 @@ fn test_statement() {
         r#"drop view v;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
+        r#"create inverted index if not exists idx on db.t(title, body);"#,
+        r#"drop inverted index idx on t;"#,
+        r#"create row access policy p as (region string) returns boolean -> region = 'us';"#,
+        r#"drop row access policy if exists p;"#,
+        r#"create masking policy m as (val string) returns string -> '***';"#,
+        r#"drop masking policy if exists m;"#,
         r#"rename table d.t to e.s;"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
@@ -118,6 +124,7 @@ fn test_statement() {
         r#"ALTER USER u1 IDENTIFIED BY '123456';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1', TENANTSETTING;"#,
+        r#"ALTER USER u1 WITH QUOTA (MAX_CPU = 4, MAX_MEMORY_IN_BYTES = 1073741824);"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"DROP database if exists db1;"#,
         r#"select distinct a, count(*) from t where a = 1 and b - 1 < a group by a having a = 1;"#,
@@ -149,6 +156,7 @@ fn test_statement() {
         r#"insert into t (c1, c2) values (1, 2), (3, 4);"#,
         r#"insert into table t format json;"#,
         r#"insert into table t select * from t2;"#,
+        r#"insert into table t partition (p = 'a') select * from t2;"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
         r#"CREATE STAGE ~"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z') file_format=(type = CSV compression = GZIP record_delimiter=',')"#,
@@ -167,6 +175,12 @@ fn test_statement() {
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0;"#,
         r#"ALTER TABLE t ADD COLUMN a float default 101 COMMENT 'hello';"#,
         r#"ALTER TABLE t DROP COLUMN b;"#,
+        r#"alter table t add row access policy p on (region);"#,
+        r#"ALTER TABLE t RENAME COLUMN a TO b;"#,
+        r#"ALTER TABLE t MODIFY COLUMN a bigint NULL;"#,
+        r#"ALTER TABLE t MODIFY COLUMN a SET DEFAULT 1;"#,
+        r#"ALTER TABLE t MODIFY COLUMN a SET MASKING POLICY m;"#,
+        r#"ALTER TABLE t MODIFY COLUMN a UNSET MASKING POLICY;"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
@@ -190,6 +204,7 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO USER 'test-grant'@'localhost';"#,
         r#"GRANT SELECT ON db01.tb1 TO ROLE 'role1';"#,
         r#"GRANT SELECT ON tb1 TO ROLE 'role1';"#,
+        r#"GRANT SELECT(a, b) ON db01.tb1 TO ROLE 'role1';"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant'@'localhost';"#,
@@ -198,6 +213,8 @@ fn test_statement() {
         r#"REVOKE SELECT, CREATE ON * FROM 'test-grant'@'localhost';"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE 'role1';"#,
         r#"REVOKE ALL ON tb1 FROM 'u1';"#,
+        r#"GRANT OWNERSHIP ON db01.tb1 TO ROLE 'role1';"#,
+        r#"GRANT OWNERSHIP ON db01.* TO ROLE 'role1';"#,
         r#"COPY INTO mytable
                 FROM @~/mybucket/data.csv
                 FILE_FORMAT = (
@@ -494,6 +511,7 @@ fn test_query() {
         r#"select * from monthly_sales pivot(sum(amount) for month in ('JAN', 'FEB', 'MAR', 'APR')) order by empid"#,
         r#"select * from monthly_sales_1 unpivot(sales for month in (jan, feb, mar, april)) order by empid"#,
         r#"select * from range(1, 2)"#,
+        r#"select a from t qualify a > 1"#,
     ];
 
     for case in cases {
@@ -605,6 +623,7 @@ fn test_expr() {
         r#"COUNT() OVER (ORDER BY hire_date ROWS UNBOUNDED PRECEDING)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS CURRENT ROW)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS 3 PRECEDING)"#,
+        r#"LAG(salary, 1) IGNORE NULLS OVER (ORDER BY hire_date)"#,
     ];
 
     for case in cases {
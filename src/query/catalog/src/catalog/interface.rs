@@ -30,6 +30,8 @@ use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SetDatabaseOwnerReply;
+use common_meta_app::schema::SetDatabaseOwnerReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -91,6 +93,8 @@ pub trait Catalog: DynClone + Send + Sync {
 
     async fn rename_database(&self, req: RenameDatabaseReq) -> Result<RenameDatabaseReply>;
 
+    async fn set_database_owner(&self, req: SetDatabaseOwnerReq) -> Result<SetDatabaseOwnerReply>;
+
     /// Table.
 
     // Build a `Arc<dyn Table>` from `TableInfo`.
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_expression::Scalar;
 use common_expression::TableSchema;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableInfo;
@@ -32,6 +34,11 @@ pub struct ParquetTableInfo {
     pub table_info: TableInfo,
     pub arrow_schema: ArrowSchema,
     pub files_to_read: Option<Vec<StageFileInfo>>,
+    /// Values of the partition columns by the path of data file.
+    ///
+    /// The partition columns (e.g. of delta tables) are not stored in the data files,
+    /// they are the fields of the table schema after the columns of `arrow_schema`.
+    pub partition_values: BTreeMap<String, Vec<Scalar>>,
 }
 
 impl ParquetTableInfo {
@@ -202,6 +202,24 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// Assembly the pipeline of MERGE INTO, the input blocks consist of the columns of the table
+    /// followed by a UInt8 action column (update, delete or insert) for each row.
+    #[async_backtrace::framed]
+    async fn merge_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        on_conflict_fields: Vec<TableField>,
+    ) -> Result<()> {
+        let (_, _, _) = (ctx, pipeline, on_conflict_fields);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "merge_into operation for table {} is not implemented. table engine : {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
     #[async_backtrace::framed]
     async fn commit_insertion(
         &self,
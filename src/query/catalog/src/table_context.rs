@@ -136,6 +136,15 @@ pub trait TableContext: Send + Sync {
     async fn get_table(&self, catalog: &str, database: &str, table: &str)
     -> Result<Arc<dyn Table>>;
 
+    /// Get the columns of the table that the current user is granted to select by column level
+    /// privileges, None means all the columns of the table are not restricted.
+    async fn get_granted_columns(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Option<HashSet<String>>>;
+
     async fn filter_out_copied_files(
         &self,
         catalog_name: &str,
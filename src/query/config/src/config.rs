@@ -1157,6 +1157,12 @@ pub struct QueryConfig {
     #[clap(long, default_value = "8900")]
     pub flight_sql_handler_port: u16,
 
+    #[clap(long, default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, default_value = "15432")]
+    pub postgres_handler_port: u16,
+
     #[clap(long, default_value = "127.0.0.1:9090")]
     pub flight_api_address: String,
 
@@ -1341,6 +1347,8 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             flight_api_address: self.flight_api_address,
             flight_sql_handler_host: self.flight_sql_handler_host,
             flight_sql_handler_port: self.flight_sql_handler_port,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
             admin_api_address: self.admin_api_address,
             metric_api_address: self.metric_api_address,
             http_handler_tls_server_cert: self.http_handler_tls_server_cert,
@@ -1404,6 +1412,8 @@ impl From<InnerQueryConfig> for QueryConfig {
             flight_api_address: inner.flight_api_address,
             flight_sql_handler_host: inner.flight_sql_handler_host,
             flight_sql_handler_port: inner.flight_sql_handler_port,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
             admin_api_address: inner.admin_api_address,
             metric_api_address: inner.metric_api_address,
             http_handler_tls_server_cert: inner.http_handler_tls_server_cert,
@@ -139,6 +139,8 @@ pub struct QueryConfig {
     pub flight_api_address: String,
     pub flight_sql_handler_host: String,
     pub flight_sql_handler_port: u16,
+    pub postgres_handler_host: String,
+    pub postgres_handler_port: u16,
     pub admin_api_address: String,
     pub metric_api_address: String,
     pub http_handler_tls_server_cert: String,
@@ -197,6 +199,8 @@ impl Default for QueryConfig {
             flight_api_address: "127.0.0.1:9090".to_string(),
             flight_sql_handler_host: "127.0.0.1".to_string(),
             flight_sql_handler_port: 8900,
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 15432,
             admin_api_address: "127.0.0.1:8080".to_string(),
             metric_api_address: "127.0.0.1:7070".to_string(),
             api_tls_server_cert: "".to_string(),
@@ -43,6 +43,8 @@ use crate::types::string::StringColumn;
 use crate::types::F32;
 use crate::types::F64;
 use crate::Column;
+use crate::ARROW_EXT_TYPE_BINARY;
+use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
 use crate::ARROW_EXT_TYPE_VARIANT;
@@ -88,7 +90,7 @@ impl Column {
                 let array_data = unsafe { array_data.build_unchecked() };
                 Arc::new(LargeBinaryArray::from(array_data))
             }
-            Column::Variant(col) => {
+            Column::Variant(col) | Column::Bitmap(col) | Column::Binary(col) => {
                 let len = col.len();
                 let values = Buffer::from(col.data);
                 let offsets = Buffer::from(col.offsets);
@@ -165,6 +167,10 @@ impl Column {
             Column::Date(buf) => {
                 numbers_into::<i32, arrow_array::types::Date32Type>(buf, DataType::Date32)?
             }
+            Column::Interval(buf) => numbers_into::<
+                i64,
+                arrow_array::types::DurationMicrosecondType,
+            >(buf, DataType::Duration(TimeUnit::Microsecond))?,
             Column::Nullable(col) => {
                 let null_buffer = NullBuffer::from(col.validity);
                 let inner = col.column.into_arrow_rs()?;
@@ -221,6 +227,14 @@ impl Column {
                         offsets,
                         data: values,
                     }),
+                    Some(ARROW_EXT_TYPE_BITMAP) => Column::Bitmap(StringColumn {
+                        offsets,
+                        data: values,
+                    }),
+                    Some(ARROW_EXT_TYPE_BINARY) => Column::Binary(StringColumn {
+                        offsets,
+                        data: values,
+                    }),
                     _ => Column::String(StringColumn {
                         offsets,
                         data: values,
@@ -27,6 +27,8 @@ use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::DataField;
 use crate::DataSchema;
+use crate::ARROW_EXT_TYPE_BINARY;
+use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
 use crate::ARROW_EXT_TYPE_VARIANT;
@@ -49,6 +51,7 @@ impl From<&DataType> for ArrowDataType {
             }
             DataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             DataType::Date => ArrowDataType::Date32,
+            DataType::Interval => ArrowDataType::Duration(TimeUnit::Microsecond),
             DataType::Nullable(ty) => ty.as_ref().into(),
             DataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -89,7 +92,7 @@ impl From<&DataType> for ArrowDataType {
 
             DataType::EmptyArray => ArrowDataType::Null,
             DataType::EmptyMap => ArrowDataType::Null,
-            DataType::Variant => ArrowDataType::LargeBinary,
+            DataType::Variant | DataType::Bitmap | DataType::Binary => ArrowDataType::LargeBinary,
 
             _ => unreachable!(),
         }
@@ -138,6 +141,12 @@ impl From<&DataField> for ArrowField {
                     ARROW_EXT_TYPE_VARIANT.to_string(),
                 );
             }
+            DataType::Bitmap => {
+                metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BITMAP.to_string());
+            }
+            DataType::Binary => {
+                metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BINARY.to_string());
+            }
             _ => Default::default(),
         };
         match ty {
@@ -191,6 +200,8 @@ impl TryFrom<&ArrowField> for DataType {
             Some(ARROW_EXT_TYPE_EMPTY_ARRAY) => return Ok(DataType::EmptyArray),
             Some(ARROW_EXT_TYPE_EMPTY_MAP) => return Ok(DataType::EmptyMap),
             Some(ARROW_EXT_TYPE_VARIANT) => return Ok(DataType::Variant),
+            Some(ARROW_EXT_TYPE_BITMAP) => return Ok(DataType::Bitmap),
+            Some(ARROW_EXT_TYPE_BINARY) => return Ok(DataType::Binary),
             _ => {}
         }
 
@@ -212,6 +223,7 @@ impl TryFrom<&ArrowField> for DataType {
             ArrowDataType::Float64 => DataType::Number(NumberDataType::Float64),
             ArrowDataType::Timestamp(_unit, _tz) => DataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => DataType::Date,
+            ArrowDataType::Duration(_unit) => DataType::Interval,
             ArrowDataType::Utf8
             | ArrowDataType::LargeUtf8
             | ArrowDataType::Binary
@@ -125,6 +125,7 @@ pub fn from_scalar(datavalue: &DataValue, datatype: &DataType) -> Scalar {
             (*datavalue.as_float64().unwrap()).into(),
         )),
         DataType::Timestamp => Scalar::Timestamp(*datavalue.as_int64().unwrap()),
+        DataType::Interval => Scalar::Interval(*datavalue.as_int64().unwrap()),
         DataType::Date => Scalar::Date(*datavalue.as_int64().unwrap() as i32),
         DataType::String => Scalar::String(datavalue.as_string().unwrap().to_vec()),
         DataType::Variant => match datavalue {
@@ -135,6 +136,8 @@ pub fn from_scalar(datavalue: &DataValue, datatype: &DataType) -> Scalar {
             }
             _ => unreachable!(),
         },
+        DataType::Bitmap => Scalar::Bitmap(datavalue.as_string().unwrap().to_vec()),
+        DataType::Binary => Scalar::Binary(datavalue.as_string().unwrap().to_vec()),
         DataType::Tuple(types) => {
             let values = match datavalue {
                 DataValue::Struct(x) => x,
@@ -25,10 +25,13 @@ use crate::types::string::StringColumnBuilder;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::DateType;
 use crate::types::EmptyArrayType;
 use crate::types::EmptyMapType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NullType;
 use crate::types::NullableType;
@@ -138,6 +141,10 @@ impl Column {
                 let builder = Vec::with_capacity(capacity);
                 Self::concat_value_types::<DateType>(builder, columns)
             }
+            Column::Interval(_) => {
+                let builder = Vec::with_capacity(capacity);
+                Self::concat_value_types::<IntervalType>(builder, columns)
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
@@ -194,6 +201,16 @@ impl Column {
                 let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
                 Self::concat_value_types::<VariantType>(builder, columns)
             }
+            Column::Bitmap(_) => {
+                let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
+                let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
+                Self::concat_value_types::<BitmapType>(builder, columns)
+            }
+            Column::Binary(_) => {
+                let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
+                let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
+                Self::concat_value_types::<BinaryType>(builder, columns)
+            }
         }
     }
 
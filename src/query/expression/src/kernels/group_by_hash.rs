@@ -171,6 +171,8 @@ impl HashMethod for HashMethodSingleString {
         match key_state {
             KeysState::Column(Column::String(col)) => Ok(col.iter()),
             KeysState::Column(Column::Variant(col)) => Ok(col.iter()),
+            KeysState::Column(Column::Bitmap(col)) => Ok(col.iter()),
+            KeysState::Column(Column::Binary(col)) => Ok(col.iter()),
             _ => unreachable!(),
         }
     }
@@ -536,7 +538,9 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
                 _ => unreachable!(),
             })
         }
-        Column::Timestamp(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
+        Column::Timestamp(v) | Column::Interval(v) => {
+            vec.extend_from_slice(v[row].to_le_bytes().as_ref())
+        }
         Column::Date(v) => vec.extend_from_slice(v[row].to_le_bytes().as_ref()),
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
@@ -557,7 +561,7 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
                 serialize_column_binary(inner_col, row, vec);
             }
         }
-        Column::Variant(v) => {
+        Column::Variant(v) | Column::Bitmap(v) | Column::Binary(v) => {
             BinaryWrite::write_binary(vec, unsafe { v.index_unchecked(row) }).unwrap()
         }
     }
@@ -691,7 +695,7 @@ pub fn fixed_hash(
                 }
             }
         }
-        Column::Timestamp(c) => {
+        Column::Timestamp(c) | Column::Interval(c) => {
             let mut ptr = ptr;
             match nulls {
                 Some((offsize, Some(bitmap))) => {
@@ -32,6 +32,10 @@ use crate::types::DataType;
 use crate::utils::arrow::column_to_arrow_array;
 use crate::Column;
 use crate::DataBlock;
+use crate::ARROW_EXT_TYPE_BINARY;
+use crate::ARROW_EXT_TYPE_BITMAP;
+use crate::ARROW_EXT_TYPE_INTERVAL;
+use crate::ARROW_EXT_TYPE_VARIANT;
 
 pub type Aborting = Arc<Box<dyn Fn() -> bool + Send + Sync + 'static>>;
 
@@ -200,11 +204,47 @@ fn compare_variant(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynCompar
     }))
 }
 
+fn compare_interval(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = Column::from_arrow(left, &DataType::Interval)
+        .into_interval()
+        .unwrap();
+    let right = Column::from_arrow(right, &DataType::Interval)
+        .into_interval()
+        .unwrap();
+    Ok(Box::new(move |i, j| left[i].cmp(&right[j])))
+}
+
+fn compare_binary(
+    left: &dyn Array,
+    right: &dyn Array,
+    data_type: &DataType,
+) -> ArrowResult<DynComparator> {
+    let (left, right) = match (
+        Column::from_arrow(left, data_type),
+        Column::from_arrow(right, data_type),
+    ) {
+        (Column::Binary(left), Column::Binary(right))
+        | (Column::Bitmap(left), Column::Bitmap(right)) => (left, right),
+        _ => unreachable!(),
+    };
+    Ok(Box::new(move |i, j| {
+        let l = unsafe { left.index_unchecked(i) };
+        let r = unsafe { right.index_unchecked(j) };
+        l.cmp(r)
+    }))
+}
+
 fn build_compare(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
     match left.data_type() {
         ArrowType::Extension(name, _, _) => {
-            if name == "Variant" {
+            if name == ARROW_EXT_TYPE_VARIANT {
                 compare_variant(left, right)
+            } else if name == ARROW_EXT_TYPE_INTERVAL {
+                compare_interval(left, right)
+            } else if name == ARROW_EXT_TYPE_BINARY {
+                compare_binary(left, right, &DataType::Binary)
+            } else if name == ARROW_EXT_TYPE_BITMAP {
+                compare_binary(left, right, &DataType::Bitmap)
             } else {
                 Err(ArrowError::NotYetImplemented(format!(
                     "Sort not supported for data type {:?}",
@@ -24,6 +24,8 @@ use crate::types::number::NumberColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
+use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::MapType;
 use crate::types::NumberType;
@@ -103,6 +105,14 @@ impl Column {
                     .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::take_arg_types::<NumberType<i64>, _>(column, indices)
+                    .into_number()
+                    .unwrap()
+                    .into_int64()
+                    .unwrap();
+                Column::Interval(i)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -141,6 +151,8 @@ impl Column {
                 Column::Tuple(fields)
             }
             Column::Variant(column) => Self::take_arg_types::<VariantType, _>(column, indices),
+            Column::Bitmap(column) => Self::take_arg_types::<BitmapType, _>(column, indices),
+            Column::Binary(column) => Self::take_arg_types::<BinaryType, _>(column, indices),
         }
     }
 
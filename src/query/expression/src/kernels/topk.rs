@@ -50,6 +50,7 @@ impl TopKSorter {
             DataType::String => self.push_column_internal::<StringType>(col, bitmap),
             DataType::Timestamp => self.push_column_internal::<TimestampType>(col, bitmap),
             DataType::Date => self.push_column_internal::<DateType>(col, bitmap),
+            DataType::Interval => self.push_column_internal::<IntervalType>(col, bitmap),
             _ => {}
         });
     }
@@ -122,6 +123,7 @@ impl TopKSorter {
             DataType::String => self.never_match_any_internal::<StringType>(col),
             DataType::Timestamp => self.never_match_any_internal::<TimestampType>(col),
             DataType::Date => self.never_match_any_internal::<DateType>(col),
+            DataType::Interval => self.never_match_any_internal::<IntervalType>(col),
             _ => false,
         })
     }
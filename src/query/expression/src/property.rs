@@ -30,6 +30,7 @@ use crate::types::ArgType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -97,6 +98,7 @@ pub enum Domain {
     String(StringDomain),
     Timestamp(SimpleDomain<i64>),
     Date(SimpleDomain<i32>),
+    Interval(SimpleDomain<i64>),
     Nullable(NullableDomain<AnyType>),
     /// `Array(None)` means that the array is empty, thus there is no inner domain information.
     Array(Option<Box<Domain>>),
@@ -176,6 +178,7 @@ impl Domain {
             },
             DataType::Timestamp => Domain::Timestamp(TimestampType::full_domain()),
             DataType::Date => Domain::Date(DateType::full_domain()),
+            DataType::Interval => Domain::Interval(IntervalType::full_domain()),
             DataType::Null => Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
@@ -201,7 +204,7 @@ impl Domain {
                 };
                 Domain::Map(Some(inner_domain))
             }
-            DataType::Variant => Domain::Undefined,
+            DataType::Variant | DataType::Bitmap | DataType::Binary => Domain::Undefined,
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -253,6 +256,10 @@ impl Domain {
                 min: this.min.min(other.min),
                 max: this.max.max(other.max),
             }),
+            (Domain::Interval(this), Domain::Interval(other)) => Domain::Interval(SimpleDomain {
+                min: this.min.min(other.min),
+                max: this.max.max(other.max),
+            }),
             (
                 Domain::Nullable(NullableDomain {
                     has_null: true,
@@ -384,6 +391,9 @@ impl Domain {
                 Some(Scalar::Timestamp(*min))
             }
             Domain::Date(SimpleDomain { min, max }) if min == max => Some(Scalar::Date(*min)),
+            Domain::Interval(SimpleDomain { min, max }) if min == max => {
+                Some(Scalar::Interval(*min))
+            }
             Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
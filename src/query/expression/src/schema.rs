@@ -34,8 +34,11 @@ use crate::types::DataType;
 use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::Scalar;
+use crate::ARROW_EXT_TYPE_BINARY;
+use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_INTERVAL;
 use crate::ARROW_EXT_TYPE_VARIANT;
 
 // Column id of TableField
@@ -76,6 +79,11 @@ pub struct TableField {
     data_type: TableDataType,
     #[serde(default = "uninit_column_id")]
     column_id: ColumnId,
+    /// Column ids and data types this field was stored with before its data type
+    /// was modified, most recent first. Blocks written before the modification are
+    /// read through these and cast to `data_type`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    legacy_columns: Vec<(ColumnId, TableDataType)>,
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -91,6 +99,7 @@ pub enum TableDataType {
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Interval,
     Nullable(Box<TableDataType>),
     Array(Box<TableDataType>),
     Map(Box<TableDataType>),
@@ -99,6 +108,8 @@ pub enum TableDataType {
         fields_type: Vec<TableDataType>,
     },
     Variant,
+    Bitmap,
+    Binary,
 }
 
 impl DataSchema {
@@ -319,7 +330,7 @@ impl TableSchema {
 
     pub fn is_column_deleted(&self, column_id: ColumnId) -> bool {
         for field in &self.fields {
-            if field.contain_column_id(column_id) {
+            if field.contain_column_id(column_id) || field.contain_legacy_column_id(column_id) {
                 return false;
             }
         }
@@ -364,6 +375,63 @@ impl TableSchema {
         Ok(())
     }
 
+    /// Rename a column, the column id is kept so the existing data is still readable.
+    pub fn rename_column(&mut self, column: &str, new_column: &str) -> Result<()> {
+        let i = self.index_of(column)?;
+        if column != new_column && self.index_of(new_column).is_ok() {
+            return Err(ErrorCode::AddColumnExistError(format!(
+                "rename column {} to {}, column {} already exist",
+                column, new_column, new_column,
+            )));
+        }
+        self.fields[i].name = new_column.to_string();
+
+        Ok(())
+    }
+
+    /// Change the data type of a column.
+    ///
+    /// Only widening changes are allowed, e.g. `Int32` to `Int64`, increasing the
+    /// precision of a decimal, or `NOT NULL` to `NULL`. The column gets new column ids,
+    /// and the previous ones are kept as legacy columns, so that blocks written before
+    /// the change can be read and cast to the new type.
+    pub fn modify_column_type(&mut self, column: &str, data_type: TableDataType) -> Result<()> {
+        let i = self.index_of(column)?;
+        let field = &self.fields[i];
+        if field.data_type == data_type {
+            return Ok(());
+        }
+        if !can_widen_data_type(&field.data_type, &data_type) {
+            return Err(ErrorCode::ModifyColumnTypeError(format!(
+                "cannot modify column {} from {} to {}",
+                column, field.data_type, data_type,
+            )));
+        }
+
+        let mut legacy_columns = Vec::with_capacity(field.legacy_columns.len() + 1);
+        legacy_columns.push((field.column_id, field.data_type.clone()));
+        legacy_columns.extend(field.legacy_columns.iter().cloned());
+
+        let new_field = TableField::new(column, data_type)
+            .with_default_expr(field.default_expr.clone())
+            .build_column_id(&mut self.next_column_id)
+            .with_legacy_columns(legacy_columns);
+        self.fields[i] = new_field;
+
+        Ok(())
+    }
+
+    pub fn set_column_default_expr(
+        &mut self,
+        column: &str,
+        default_expr: Option<String>,
+    ) -> Result<()> {
+        let i = self.index_of(column)?;
+        self.fields[i].default_expr = default_expr;
+
+        Ok(())
+    }
+
     pub fn to_leaf_column_id_set(&self) -> HashSet<ColumnId> {
         HashSet::from_iter(self.to_leaf_column_ids().iter().cloned())
     }
@@ -826,6 +894,7 @@ impl TableField {
             default_expr: None,
             data_type,
             column_id: 0,
+            legacy_columns: vec![],
         }
     }
 
@@ -835,6 +904,7 @@ impl TableField {
             default_expr: None,
             data_type,
             column_id,
+            legacy_columns: vec![],
         }
     }
 
@@ -880,6 +950,7 @@ impl TableField {
             default_expr: self.default_expr.clone(),
             data_type: self.data_type.clone(),
             column_id,
+            legacy_columns: self.legacy_columns.clone(),
         }
     }
 
@@ -887,6 +958,21 @@ impl TableField {
         self.column_ids().contains(&column_id)
     }
 
+    pub fn contain_legacy_column_id(&self, column_id: ColumnId) -> bool {
+        self.legacy_columns.iter().any(|(id, _)| *id == column_id)
+    }
+
+    /// Column ids and data types of the previous versions of this field, most recent first.
+    pub fn legacy_columns(&self) -> &[(ColumnId, TableDataType)] {
+        &self.legacy_columns
+    }
+
+    #[must_use]
+    pub fn with_legacy_columns(mut self, legacy_columns: Vec<(ColumnId, TableDataType)>) -> Self {
+        self.legacy_columns = legacy_columns;
+        self
+    }
+
     // `leaf_column_ids` return only the child column id.
     // if field is Tuple(t1, t2), it will return a column id vector of 2 column id.
     pub fn leaf_column_ids(&self) -> Vec<ColumnId> {
@@ -953,6 +1039,7 @@ impl From<&TableDataType> for DataType {
             TableDataType::Decimal(ty) => DataType::Decimal(*ty),
             TableDataType::Timestamp => DataType::Timestamp,
             TableDataType::Date => DataType::Date,
+            TableDataType::Interval => DataType::Interval,
             TableDataType::Nullable(ty) => DataType::Nullable(Box::new((&**ty).into())),
             TableDataType::Array(ty) => DataType::Array(Box::new((&**ty).into())),
             TableDataType::Map(ty) => DataType::Map(Box::new((&**ty).into())),
@@ -960,6 +1047,8 @@ impl From<&TableDataType> for DataType {
                 DataType::Tuple(fields_type.iter().map(Into::into).collect())
             }
             TableDataType::Variant => DataType::Variant,
+            TableDataType::Bitmap => DataType::Bitmap,
+            TableDataType::Binary => DataType::Binary,
         }
     }
 }
@@ -1150,6 +1239,7 @@ impl From<&ArrowField> for TableField {
             data_type: f.into(),
             default_expr: None,
             column_id: 0,
+            legacy_columns: vec![],
         }
     }
 }
@@ -1189,10 +1279,11 @@ impl From<&ArrowField> for TableDataType {
             | ArrowDataType::FixedSizeList(f, _) =>
                 TableDataType::Array(Box::new(f.as_ref().into())),
 
-            ArrowDataType::Binary
-            | ArrowDataType::LargeBinary
-            | ArrowDataType::Utf8
-            | ArrowDataType::LargeUtf8 => TableDataType::String,
+            // Databend writes `String` columns as `LargeBinary`, so only the
+            // 32-bit offset `Binary` coming from external files maps to `Binary`.
+            ArrowDataType::Binary => TableDataType::Binary,
+            ArrowDataType::LargeBinary | ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 =>
+                TableDataType::String,
 
             ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
@@ -1210,6 +1301,9 @@ impl From<&ArrowField> for TableDataType {
             }
             ArrowDataType::Extension(custom_name, _, _) => match custom_name.as_str() {
                 ARROW_EXT_TYPE_VARIANT => TableDataType::Variant,
+                ARROW_EXT_TYPE_INTERVAL => TableDataType::Interval,
+                ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
+                ARROW_EXT_TYPE_BINARY => TableDataType::Binary,
                 ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
                 ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
                 _ => unimplemented!("data_type: {:?}", f.data_type()),
@@ -1298,6 +1392,11 @@ impl From<&DataType> for ArrowDataType {
             }
             DataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             DataType::Date => ArrowDataType::Date32,
+            DataType::Interval => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_INTERVAL.to_string(),
+                Box::new(ArrowDataType::Int64),
+                None,
+            ),
             DataType::Nullable(ty) => ty.as_ref().into(),
             DataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1340,6 +1439,16 @@ impl From<&DataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            DataType::Bitmap => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BITMAP.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            DataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
 
             _ => unreachable!(),
         }
@@ -1373,6 +1482,11 @@ impl From<&TableDataType> for ArrowDataType {
             }
             TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             TableDataType::Date => ArrowDataType::Date32,
+            TableDataType::Interval => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_INTERVAL.to_string(),
+                Box::new(ArrowDataType::Int64),
+                None,
+            ),
             TableDataType::Nullable(ty) => ty.as_ref().into(),
             TableDataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1419,7 +1533,35 @@ impl From<&TableDataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            TableDataType::Bitmap => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BITMAP.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            TableDataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+        }
+    }
+}
+
+/// Whether all values of type `from` can be cast to type `to` without loss,
+/// which is required for changing the data type of an existing column.
+fn can_widen_data_type(from: &TableDataType, to: &TableDataType) -> bool {
+    match (from, to) {
+        (TableDataType::Nullable(from), TableDataType::Nullable(to)) => {
+            can_widen_data_type(from, to)
+        }
+        (from, TableDataType::Nullable(to)) => from == to.as_ref() || can_widen_data_type(from, to),
+        (TableDataType::Nullable(_), _) => false,
+        (TableDataType::Number(from), TableDataType::Number(to)) => from.can_lossless_cast_to(*to),
+        (TableDataType::Decimal(from), TableDataType::Decimal(to)) => {
+            let (from, to) = (from.size(), to.size());
+            to.scale >= from.scale && to.precision - to.scale >= from.precision - from.scale
         }
+        _ => false,
     }
 }
 
@@ -1441,6 +1583,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Timestamp => Ok(TableDataType::Timestamp),
         DataType::Decimal(x) => Ok(TableDataType::Decimal(*x)),
         DataType::Date => Ok(TableDataType::Date),
+        DataType::Interval => Ok(TableDataType::Interval),
         DataType::Nullable(inner_type) => Ok(TableDataType::Nullable(Box::new(infer_schema_type(
             inner_type,
         )?))),
@@ -1451,6 +1594,8 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
             Ok(TableDataType::Map(Box::new(infer_schema_type(inner_type)?)))
         }
         DataType::Variant => Ok(TableDataType::Variant),
+        DataType::Bitmap => Ok(TableDataType::Bitmap),
+        DataType::Binary => Ok(TableDataType::Binary),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
@@ -585,7 +585,10 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_float64",
     "to_timestamp",
     "to_date",
+    "to_interval",
     "to_variant",
+    "to_bitmap",
+    "to_binary",
     "to_boolean",
     "to_decimal",
 ];
@@ -0,0 +1,307 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use common_arrow::arrow::buffer::Buffer;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_IN_A_MINUTE: i64 = 60 * MICROS_IN_A_SEC;
+pub const MICROS_IN_AN_HOUR: i64 = 60 * MICROS_IN_A_MINUTE;
+pub const MICROS_IN_A_DAY: i64 = 24 * MICROS_IN_AN_HOUR;
+
+/// Interval is a fixed length of time, represented by the number of microseconds.
+///
+/// A day is always 24 hours, calendar units like month and year are not supported
+/// because their lengths vary, `add_months` and `add_years` should be used instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = i64;
+    type ScalarRef<'a> = i64;
+    type Column = Buffer<i64>;
+    type Domain = SimpleDomain<i64>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, i64>>;
+    type ColumnBuilder = Vec<i64>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: i64) -> i64 {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<i64>> {
+        domain.as_interval().map(SimpleDomain::clone)
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<i64>) -> Domain {
+        Domain::Interval(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: i64::MIN,
+            max: i64::MAX,
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Parse an interval string like `3 days 2 hours`, `-90 minutes` or `1 day 02:00:00.5`.
+pub fn string_to_interval(interval_str: impl AsRef<[u8]>) -> Result<i64, String> {
+    let interval_str = std::str::from_utf8(interval_str.as_ref())
+        .map_err(|_| "interval string is not valid UTF-8".to_string())?;
+    let invalid = || format!("invalid interval string '{interval_str}'");
+
+    let mut micros: i64 = 0;
+    let mut has_part = false;
+    let mut tokens = interval_str.split_whitespace().peekable();
+    while let Some(token) = tokens.next() {
+        let part = if token.contains(':') {
+            parse_time_part(token).ok_or_else(invalid)?
+        } else {
+            let (number, unit) = match token.find(|c: char| c.is_ascii_alphabetic()) {
+                // The unit is attached to the number, e.g. `3d`.
+                Some(pos) if pos > 0 => (&token[..pos], &token[pos..]),
+                Some(_) => return Err(invalid()),
+                None => (token, tokens.next().ok_or_else(invalid)?),
+            };
+            let number: f64 = number.parse().map_err(|_| invalid())?;
+            let unit = interval_unit_micros(unit)?;
+            let part = number * unit as f64;
+            if !part.is_finite() || part.abs() >= i64::MAX as f64 {
+                return Err("interval is out of range".to_string());
+            }
+            part.round() as i64
+        };
+        micros = micros
+            .checked_add(part)
+            .ok_or_else(|| "interval is out of range".to_string())?;
+        has_part = true;
+    }
+
+    if !has_part {
+        return Err(invalid());
+    }
+    Ok(micros)
+}
+
+// Parse the `[-]HH:MM[:SS[.ffffff]]` part of an interval.
+fn parse_time_part(token: &str) -> Option<i64> {
+    let (negative, token) = match token.strip_prefix('-') {
+        Some(token) => (true, token),
+        None => (false, token.strip_prefix('+').unwrap_or(token)),
+    };
+
+    let mut fields = token.split(':');
+    let hours: i64 = fields.next()?.parse().ok()?;
+    let minutes: i64 = fields.next()?.parse().ok()?;
+    let seconds: f64 = match fields.next() {
+        Some(seconds) => seconds.parse().ok()?,
+        None => 0.0,
+    };
+    if fields.next().is_some() || !(0..60).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+
+    let micros = hours
+        .checked_mul(MICROS_IN_AN_HOUR)?
+        .checked_add(minutes * MICROS_IN_A_MINUTE)?
+        .checked_add((seconds * MICROS_IN_A_SEC as f64).round() as i64)?;
+    Some(if negative { -micros } else { micros })
+}
+
+fn interval_unit_micros(unit: &str) -> Result<i64, String> {
+    match unit.to_ascii_lowercase().as_str() {
+        "us" | "usec" | "usecs" | "microsecond" | "microseconds" => Ok(1),
+        "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => Ok(1_000),
+        "s" | "sec" | "secs" | "second" | "seconds" => Ok(MICROS_IN_A_SEC),
+        "m" | "min" | "mins" | "minute" | "minutes" => Ok(MICROS_IN_A_MINUTE),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Ok(MICROS_IN_AN_HOUR),
+        "d" | "day" | "days" => Ok(MICROS_IN_A_DAY),
+        "w" | "week" | "weeks" => Ok(7 * MICROS_IN_A_DAY),
+        "mon" | "mons" | "month" | "months" | "y" | "year" | "years" => Err(format!(
+            "interval unit '{unit}' is not supported, use add_months or add_years instead"
+        )),
+        _ => Err(format!("unknown interval unit '{unit}'")),
+    }
+}
+
+/// Display an interval like `3 days 02:00:00` or `-00:00:01.500000`.
+pub struct IntervalDisplay(pub i64);
+
+impl Display for IntervalDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0 < 0 {
+            write!(f, "-")?;
+        }
+        let micros = self.0.unsigned_abs();
+        let day_micros = MICROS_IN_A_DAY as u64;
+        let days = micros / day_micros;
+        let micros = micros % day_micros;
+        match days {
+            0 => {}
+            1 => write!(f, "1 day ")?,
+            _ => write!(f, "{days} days ")?,
+        }
+
+        let secs = micros / MICROS_IN_A_SEC as u64;
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        )?;
+        let fraction = micros % MICROS_IN_A_SEC as u64;
+        if fraction != 0 {
+            write!(f, ".{fraction:06}")?;
+        }
+        Ok(())
+    }
+}
+
+#[inline]
+pub fn interval_to_string(micros: i64) -> impl Display {
+    IntervalDisplay(micros)
+}
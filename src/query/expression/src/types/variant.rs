@@ -14,7 +14,9 @@
 
 use std::ops::Range;
 
+use super::bitmap::bitmap_to_string;
 use super::date::date_to_string;
+use super::interval::interval_to_string;
 use super::number::NumberScalar;
 use super::timestamp::timestamp_to_string;
 use crate::date_helper::TzLUT;
@@ -194,6 +196,9 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
         ScalarRef::String(s) => jsonb::Value::String(String::from_utf8_lossy(s)),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Interval(i) => interval_to_string(i).to_string().into(),
+        ScalarRef::Bitmap(b) => bitmap_to_string(b).to_string().into(),
+        ScalarRef::Binary(b) => hex::encode_upper(b).into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -211,6 +216,7 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
                         ScalarRef::Boolean(v) => v.to_string(),
                         ScalarRef::Timestamp(v) => timestamp_to_string(v, inner_tz).to_string(),
                         ScalarRef::Date(v) => date_to_string(v, inner_tz).to_string(),
+                        ScalarRef::Interval(v) => interval_to_string(v).to_string(),
                         _ => unreachable!(),
                     };
                     let mut val = vec![];
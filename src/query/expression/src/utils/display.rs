@@ -33,12 +33,14 @@ use crate::function::Function;
 use crate::function::FunctionSignature;
 use crate::property::Domain;
 use crate::property::FunctionProperty;
+use crate::types::bitmap::bitmap_to_string;
 use crate::types::boolean::BooleanDomain;
 use crate::types::date::date_to_string;
 use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::interval::interval_to_string;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -124,6 +126,7 @@ impl<'a> Debug for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "{t:?}"),
             ScalarRef::Date(d) => write!(f, "{d:?}"),
+            ScalarRef::Interval(i) => write!(f, "{i:?}"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -152,6 +155,8 @@ impl<'a> Debug for ScalarRef<'a> {
                 write!(f, ")")
             }
             ScalarRef::Variant(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Bitmap(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Binary(s) => write!(f, "0x{}", &hex::encode(s)),
         }
     }
 }
@@ -168,11 +173,14 @@ impl Debug for Column {
             Column::String(col) => write!(f, "{col:?}"),
             Column::Timestamp(col) => write!(f, "{col:?}"),
             Column::Date(col) => write!(f, "{col:?}"),
+            Column::Interval(col) => write!(f, "{col:?}"),
             Column::Array(col) => write!(f, "{col:?}"),
             Column::Map(col) => write!(f, "{col:?}"),
             Column::Nullable(col) => write!(f, "{col:?}"),
             Column::Tuple(fields) => f.debug_tuple("Tuple").field(fields).finish(),
             Column::Variant(col) => write!(f, "{col:?}"),
+            Column::Bitmap(col) => write!(f, "{col:?}"),
+            Column::Binary(col) => write!(f, "{col:?}"),
         }
     }
 }
@@ -198,6 +206,7 @@ impl<'a> Display for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "{}", timestamp_to_string(*t, Tz::UTC)),
             ScalarRef::Date(d) => write!(f, "{}", date_to_string(*d as i64, Tz::UTC)),
+            ScalarRef::Interval(i) => write!(f, "{}", interval_to_string(*i)),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -229,6 +238,8 @@ impl<'a> Display for ScalarRef<'a> {
                 let value = jsonb::to_string(s);
                 write!(f, "{value}")
             }
+            ScalarRef::Bitmap(s) => write!(f, "'{}'", bitmap_to_string(s)),
+            ScalarRef::Binary(s) => write!(f, "X'{}'", &hex::encode_upper(s)),
         }
     }
 }
@@ -433,6 +444,7 @@ impl Display for DataType {
             DataType::Decimal(decimal) => write!(f, "{decimal}"),
             DataType::Timestamp => write!(f, "Timestamp"),
             DataType::Date => write!(f, "Date"),
+            DataType::Interval => write!(f, "Interval"),
             DataType::Null => write!(f, "NULL"),
             DataType::Nullable(inner) => write!(f, "{inner} NULL"),
             DataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -458,6 +470,8 @@ impl Display for DataType {
                 write!(f, ")")
             }
             DataType::Variant => write!(f, "Variant"),
+            DataType::Bitmap => write!(f, "Bitmap"),
+            DataType::Binary => write!(f, "Binary"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -472,6 +486,7 @@ impl Display for TableDataType {
             TableDataType::Decimal(decimal) => write!(f, "{decimal}"),
             TableDataType::Timestamp => write!(f, "Timestamp"),
             TableDataType::Date => write!(f, "Date"),
+            TableDataType::Interval => write!(f, "Interval"),
             TableDataType::Null => write!(f, "NULL"),
             TableDataType::Nullable(inner) => write!(f, "{inner} NULL"),
             TableDataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -503,6 +518,8 @@ impl Display for TableDataType {
                 write!(f, ")")
             }
             TableDataType::Variant => write!(f, "Variant"),
+            TableDataType::Bitmap => write!(f, "Bitmap"),
+            TableDataType::Binary => write!(f, "Binary"),
         }
     }
 }
@@ -863,6 +880,7 @@ impl Display for Domain {
             Domain::String(domain) => write!(f, "{domain}"),
             Domain::Timestamp(domain) => write!(f, "{domain}"),
             Domain::Date(domain) => write!(f, "{domain}"),
+            Domain::Interval(domain) => write!(f, "{domain}"),
             Domain::Nullable(domain) => write!(f, "{domain}"),
             Domain::Array(None) => write!(f, "[]"),
             Domain::Array(Some(domain)) => write!(f, "[{domain}]"),
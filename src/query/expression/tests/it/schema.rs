@@ -527,6 +527,52 @@ fn test_schema_modify_field() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_schema_rename_and_modify_column_type() -> Result<()> {
+    let field1 = TableField::new("a", TableDataType::Number(NumberDataType::Int32));
+    let field2 = TableField::new("b", TableDataType::Number(NumberDataType::UInt64));
+    let mut schema = TableSchema::new(vec![field1, field2]);
+
+    // rename keeps the column id
+    schema.rename_column("a", "c")?;
+    assert_eq!(schema.column_id_of("c").unwrap(), 0);
+    assert!(schema.index_of("a").is_err());
+    assert!(schema.rename_column("c", "b").is_err());
+
+    // widening assigns new column ids and keeps the old ones as legacy columns
+    let int64 = TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int64)));
+    schema.modify_column_type("c", int64.clone())?;
+    let field = schema.field_with_name("c")?;
+    assert_eq!(field.data_type(), &int64);
+    assert_eq!(field.column_id(), 2);
+    assert_eq!(field.legacy_columns(), &[(
+        0,
+        TableDataType::Number(NumberDataType::Int32)
+    )]);
+    assert_eq!(schema.next_column_id(), 3);
+    assert_eq!(schema.is_column_deleted(0), false);
+    assert_eq!(schema.to_leaf_column_ids(), vec![2, 1]);
+
+    // narrowing and NULL to NOT NULL are rejected
+    assert!(
+        schema
+            .modify_column_type("c", TableDataType::Number(NumberDataType::Int64))
+            .is_err()
+    );
+    assert!(
+        schema
+            .modify_column_type("b", TableDataType::Number(NumberDataType::Int64))
+            .is_err()
+    );
+    assert!(
+        schema
+            .modify_column_type("b", TableDataType::Number(NumberDataType::UInt32))
+            .is_err()
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_leaf_columns_of() -> Result<()> {
     let fields = vec![
@@ -22,10 +22,13 @@ use common_exception::Result;
 use common_expression::serialize::read_decimal_from_json;
 use common_expression::serialize::uniform_date;
 use common_expression::types::array::ArrayColumnBuilder;
+use common_expression::types::bitmap::serialize_bitmap;
+use common_expression::types::bitmap::string_to_bitmap;
 use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::interval::string_to_interval;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
@@ -39,6 +42,7 @@ use common_io::cursor_ext::BufferReadDateTimeExt;
 use common_io::cursor_ext::ReadNumberExt;
 use lexical_core::FromLexical;
 use num::cast::AsPrimitive;
+use roaring::RoaringTreemap;
 use serde_json::Value;
 
 use crate::FieldDecoder;
@@ -82,11 +86,14 @@ impl FieldJsonAstDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::Interval(c) => self.read_interval(c, value),
             ColumnBuilder::String(c) => self.read_string(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
             ColumnBuilder::Map(c) => self.read_map(c, value),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
             ColumnBuilder::Variant(c) => self.read_variant(c, value),
+            ColumnBuilder::Bitmap(c) => self.read_bitmap(c, value),
+            ColumnBuilder::Binary(c) => self.read_binary(c, value),
             _ => unimplemented!(),
         }
     }
@@ -208,6 +215,26 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_interval(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let micros = string_to_interval(v).map_err(ErrorCode::BadBytes)?;
+                column.push(micros);
+                Ok(())
+            }
+            Value::Number(number) => match number.as_i64() {
+                Some(n) => {
+                    column.push(n);
+                    Ok(())
+                }
+                None => Err(ErrorCode::BadArguments(
+                    "Incorrect interval value, must be i64",
+                )),
+            },
+            _ => Err(ErrorCode::BadBytes("Incorrect interval value")),
+        }
+    }
+
     fn read_timestamp(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
@@ -241,6 +268,36 @@ impl FieldJsonAstDecoder {
         Ok(())
     }
 
+    fn read_bitmap(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        let bitmap = match value {
+            Value::String(v) => string_to_bitmap(v).map_err(ErrorCode::BadBytes)?,
+            Value::Array(vals) => vals
+                .iter()
+                .map(|v| {
+                    v.as_u64()
+                        .ok_or_else(|| ErrorCode::BadBytes("Incorrect bitmap value, must be u64"))
+                })
+                .collect::<Result<RoaringTreemap>>()?,
+            _ => return Err(ErrorCode::BadBytes("Incorrect bitmap value")),
+        };
+        serialize_bitmap(&bitmap, &mut column.data);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_binary(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let data = hex::decode(v)
+                    .map_err(|e| ErrorCode::BadBytes(format!("invalid hex binary value: {e}")))?;
+                column.put_slice(&data);
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect binary value")),
+        }
+    }
+
     fn read_array(&self, column: &mut ArrayColumnBuilder<AnyType>, value: &Value) -> Result<()> {
         match value {
             Value::Array(vals) => {
@@ -24,10 +24,13 @@ use common_exception::Result;
 use common_expression::serialize::read_decimal_with_size;
 use common_expression::serialize::uniform_date;
 use common_expression::types::array::ArrayColumnBuilder;
+use common_expression::types::bitmap::serialize_bitmap;
+use common_expression::types::bitmap::string_to_bitmap;
 use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::interval::string_to_interval;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
@@ -86,11 +89,14 @@ pub trait FieldDecoderRowBased: FieldDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, reader, raw),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, raw),
+            ColumnBuilder::Interval(c) => self.read_interval(c, reader, raw),
             ColumnBuilder::String(c) => self.read_string(c, reader, raw),
             ColumnBuilder::Array(c) => self.read_array(c, reader, raw),
             ColumnBuilder::Map(c) => self.read_map(c, reader, raw),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader, raw),
             ColumnBuilder::Variant(c) => self.read_variant(c, reader, raw),
+            ColumnBuilder::Bitmap(c) => self.read_bitmap(c, reader, raw),
+            ColumnBuilder::Binary(c) => self.read_binary(c, reader, raw),
             _ => unimplemented!(),
         }
     }
@@ -221,6 +227,19 @@ pub trait FieldDecoderRowBased: FieldDecoder {
         Ok(())
     }
 
+    fn read_interval<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<i64>,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let micros = string_to_interval(&buf).map_err(ErrorCode::BadBytes)?;
+        column.push(micros);
+        Ok(())
+    }
+
     fn read_timestamp<R: AsRef<[u8]>>(
         &self,
         column: &mut Vec<i64>,
@@ -267,6 +286,35 @@ pub trait FieldDecoderRowBased: FieldDecoder {
         Ok(())
     }
 
+    fn read_bitmap<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let bitmap = string_to_bitmap(&buf).map_err(ErrorCode::BadBytes)?;
+        serialize_bitmap(&bitmap, &mut column.data);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_binary<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let data = hex::decode(&buf)
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid hex binary value: {e}")))?;
+        column.put_slice(&data);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_array<R: AsRef<[u8]>>(
         &self,
         column: &mut ArrayColumnBuilder<AnyType>,
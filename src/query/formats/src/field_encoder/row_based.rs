@@ -15,8 +15,10 @@
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::buffer::Buffer;
 use common_expression::types::array::ArrayColumn;
+use common_expression::types::bitmap::bitmap_to_string;
 use common_expression::types::date::date_to_string;
 use common_expression::types::decimal::DecimalColumn;
+use common_expression::types::interval::interval_to_string;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::NumberColumn;
 use common_expression::types::string::StringColumn;
@@ -59,12 +61,15 @@ pub trait FieldEncoderRowBased {
             Column::Decimal(c) => self.write_decimal(c, row_index, out_buf),
             Column::Date(c) => self.write_date(c, row_index, out_buf, raw),
             Column::Timestamp(c) => self.write_timestamp(c, row_index, out_buf, raw),
+            Column::Interval(c) => self.write_interval(c, row_index, out_buf, raw),
             Column::String(c) => self.write_string(c, row_index, out_buf, raw),
             Column::Nullable(box c) => self.write_nullable(c, row_index, out_buf, raw),
             Column::Array(box c) => self.write_array(c, row_index, out_buf, raw),
             Column::Map(box c) => self.write_map(c, row_index, out_buf, raw),
             Column::Tuple(fields) => self.write_tuple(fields, row_index, out_buf, raw),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, raw),
+            Column::Bitmap(c) => self.write_bitmap(c, row_index, out_buf, raw),
+            Column::Binary(c) => self.write_binary(c, row_index, out_buf, raw),
         }
     }
 
@@ -164,6 +169,18 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(s.as_bytes(), out_buf, raw);
     }
 
+    fn write_interval(
+        &self,
+        column: &Buffer<i64>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = interval_to_string(*v).to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
     fn write_variant(
         &self,
         column: &StringColumn,
@@ -176,6 +193,30 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(s.as_bytes(), out_buf, raw);
     }
 
+    fn write_bitmap(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        let s = bitmap_to_string(v).to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
+    fn write_binary(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        let s = hex::encode_upper(v);
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
     fn write_array<T: ValueType>(
         &self,
         column: &ArrayColumn<T>,
@@ -80,6 +80,22 @@ impl FieldEncoderValues {
             quote_char: b'\'',
         }
     }
+
+    // PostgreSQL renders the special float values as "NaN" and "Infinity", booleans and
+    // binaries are written by the postgres handler itself.
+    pub fn create_for_postgres_handler(timezone: Tz) -> Self {
+        FieldEncoderValues {
+            common_settings: CommonSettings {
+                true_bytes: TRUE_BYTES_NUM.as_bytes().to_vec(),
+                false_bytes: FALSE_BYTES_NUM.as_bytes().to_vec(),
+                null_bytes: NULL_BYTES_UPPER.as_bytes().to_vec(),
+                nan_bytes: NAN_BYTES_SNAKE.as_bytes().to_vec(),
+                inf_bytes: INF_BYTES_LONG.as_bytes().to_vec(),
+                timezone,
+            },
+            quote_char: b'\'',
+        }
+    }
 }
 
 impl FieldEncoderRowBased for FieldEncoderValues {
@@ -29,6 +29,7 @@ pub fn get_format_option_checker(
         StageFileFormatType::Tsv => Ok(Box::new(TSVFormatOptionChecker {})),
         StageFileFormatType::NdJson => Ok(Box::new(NDJsonFormatOptionChecker {})),
         StageFileFormatType::Parquet => Ok(Box::new(ParquetFormatOptionChecker {})),
+        StageFileFormatType::Orc => Ok(Box::new(OrcFormatOptionChecker {})),
         StageFileFormatType::Xml => Ok(Box::new(XMLFormatOptionChecker {})),
         StageFileFormatType::Json => Ok(Box::new(JsonFormatOptionChecker {})),
         _ => Err(ErrorCode::Internal(format!(
@@ -212,6 +213,13 @@ impl FormatOptionChecker for ParquetFormatOptionChecker {
     }
 }
 
+pub struct OrcFormatOptionChecker {}
+impl FormatOptionChecker for OrcFormatOptionChecker {
+    fn name(&self) -> String {
+        "ORC".to_string()
+    }
+}
+
 pub fn check_escape(option: &mut String, default: &str) -> Result<()> {
     if option.is_empty() {
         *option = default.to_string()
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use common_expression::date_helper::DateConverter;
+use common_expression::types::bitmap::bitmap_to_string;
+use common_expression::types::interval::interval_to_string;
 use common_expression::types::number::NumberScalar;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
@@ -95,6 +97,7 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(v) => serde_json::to_value(interval_to_string(v).to_string()).unwrap(),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::String(x) => JsonValue::String(String::from_utf8_lossy(x).to_string()),
@@ -131,6 +134,8 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let b = jsonb::from_slice(x).unwrap();
             b.into()
         }
+        ScalarRef::Bitmap(x) => serde_json::to_value(bitmap_to_string(x).to_string()).unwrap(),
+        ScalarRef::Binary(x) => JsonValue::String(hex::encode_upper(x)),
     }
 }
 
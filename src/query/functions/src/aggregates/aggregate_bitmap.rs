@@ -0,0 +1,234 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::bitmap::deserialize_bitmap;
+use common_expression::types::bitmap::serialize_bitmap;
+use common_expression::types::BitmapType;
+use common_expression::types::DataType;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use roaring::RoaringTreemap;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+
+pub trait BitmapOperation: Send + Sync + 'static {
+    fn operate(lhs: &mut RoaringTreemap, rhs: RoaringTreemap);
+}
+
+pub struct BitmapUnionOperation;
+
+impl BitmapOperation for BitmapUnionOperation {
+    fn operate(lhs: &mut RoaringTreemap, rhs: RoaringTreemap) {
+        *lhs |= rhs;
+    }
+}
+
+pub struct BitmapIntersectOperation;
+
+impl BitmapOperation for BitmapIntersectOperation {
+    fn operate(lhs: &mut RoaringTreemap, rhs: RoaringTreemap) {
+        *lhs &= rhs;
+    }
+}
+
+/// The state is `None` until the first bitmap is added, so that the intersection
+/// does not start from an empty bitmap.
+struct AggregateBitmapState {
+    value: Option<RoaringTreemap>,
+}
+
+impl AggregateBitmapState {
+    fn add<OP: BitmapOperation>(&mut self, other: RoaringTreemap) {
+        match &mut self.value {
+            Some(value) => OP::operate(value, other),
+            None => self.value = Some(other),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateBitmapFunction<OP> {
+    display_name: String,
+    _op: PhantomData<OP>,
+}
+
+impl<OP: BitmapOperation> AggregateBitmapFunction<OP> {
+    pub fn try_create(display_name: &str) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _op: PhantomData,
+        }))
+    }
+
+    fn add_row(state: &mut AggregateBitmapState, data: &[u8]) -> Result<()> {
+        let bitmap = deserialize_bitmap(data).map_err(ErrorCode::BadBytes)?;
+        state.add::<OP>(bitmap);
+        Ok(())
+    }
+}
+
+impl<OP: BitmapOperation> AggregateFunction for AggregateBitmapFunction<OP> {
+    fn name(&self) -> &str {
+        "AggregateBitmapFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Bitmap)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateBitmapState { value: None });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateBitmapState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let column = BitmapType::try_downcast_column(&columns[0]).unwrap();
+
+        match validity {
+            Some(validity) => {
+                for (data, valid) in column.iter().zip(validity.iter()) {
+                    if valid {
+                        Self::add_row(state, data)?;
+                    }
+                }
+            }
+            None => {
+                for data in column.iter() {
+                    Self::add_row(state, data)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let column = BitmapType::try_downcast_column(&columns[0]).unwrap();
+        Self::add_row(state, unsafe { column.index_unchecked(row) })
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        match &state.value {
+            Some(value) => {
+                writer.push(1);
+                serialize_bitmap(value, writer);
+            }
+            None => writer.push(0),
+        }
+        Ok(())
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let (flag, rest) = reader
+            .split_first()
+            .ok_or_else(|| ErrorCode::BadBytes("bitmap state is empty"))?;
+        *reader = rest;
+        state.value = match flag {
+            0 => None,
+            _ => Some(
+                RoaringTreemap::deserialize_from(&mut *reader)
+                    .map_err(|e| ErrorCode::BadBytes(format!("invalid bitmap state: {e}")))?,
+            ),
+        };
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let rhs = rhs.get::<AggregateBitmapState>();
+        if let Some(value) = &rhs.value {
+            state.add::<OP>(value.clone());
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateBitmapState>();
+        let builder = BitmapType::try_downcast_builder(builder).unwrap();
+        if let Some(value) = &state.value {
+            serialize_bitmap(value, &mut builder.data);
+        }
+        builder.commit_row();
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateBitmapState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl<OP> fmt::Display for AggregateBitmapFunction<OP> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_bitmap_function<OP: BitmapOperation>(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    match &arguments[0] {
+        DataType::Bitmap => AggregateBitmapFunction::<OP>::try_create(display_name),
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
+        ))),
+    }
+}
+
+pub fn aggregate_bitmap_union_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_bitmap_function::<BitmapUnionOperation>,
+    ))
+}
+
+pub fn aggregate_bitmap_intersect_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_bitmap_function::<BitmapIntersectOperation>,
+    ))
+}
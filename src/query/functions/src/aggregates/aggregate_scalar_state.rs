@@ -41,6 +41,7 @@ macro_rules! with_simple_no_number_mapped_type {
                 EmptyArray => EmptyArrayType,
                 EmptyMap => EmptyMapType,
                 Date => DateType,
+                Interval => IntervalType,
             ],
             $($tail)*
         }
@@ -250,7 +251,7 @@ where
 
 pub fn need_manual_drop_state(data_type: &DataType) -> bool {
     match data_type {
-        DataType::String | DataType::Variant => true,
+        DataType::String | DataType::Variant | DataType::Bitmap | DataType::Binary => true,
         DataType::Nullable(t) | DataType::Array(t) | DataType::Map(t) => need_manual_drop_state(t),
         DataType::Tuple(ts) => ts.iter().any(need_manual_drop_state),
         _ => false,
@@ -22,6 +22,7 @@ mod adaptors;
 mod aggregate_approx_count_distinct;
 mod aggregate_arg_min_max;
 mod aggregate_avg;
+mod aggregate_bitmap;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
 mod aggregate_covariance;
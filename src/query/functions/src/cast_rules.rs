@@ -70,6 +70,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 pub const GENERAL_CAST_RULES: AutoCastRules = &[
     (DataType::String, DataType::Timestamp),
     (DataType::String, DataType::Date),
+    (DataType::String, DataType::Interval),
     (DataType::String, DataType::Boolean),
     (DataType::Date, DataType::Timestamp),
     (
@@ -0,0 +1,125 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_expression::error_to_null;
+use common_expression::types::BinaryType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("hex", &["to_hex"]);
+    registry.register_aliases("unhex", &["from_hex"]);
+
+    // cast(string AS binary)
+    // to_binary(string)
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "to_binary",
+        |_| FunctionDomain::Full,
+        eval_string_to_binary,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_to_binary",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_string_to_binary),
+    );
+
+    // cast(binary AS string)
+    // to_string(binary)
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::MayThrow,
+        eval_binary_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "try_to_string",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_binary_to_string),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "hex",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            let old_len = output.data.len();
+            output.data.resize(old_len + val.len() * 2, 0);
+            hex::encode_to_slice(val, &mut output.data[old_len..]).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, BinaryType, _, _>(
+        "unhex",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, BinaryType>(|val, output, ctx| {
+            let old_len = output.data.len();
+            output.data.resize(old_len + val.len() / 2, 0);
+            if let Err(err) = hex::decode_to_slice(val, &mut output.data[old_len..]) {
+                ctx.set_error(output.len(), err.to_string());
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_base64",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            base64::write::EncoderWriter::new(&mut output.data, &general_purpose::STANDARD)
+                .write_all(val)
+                .unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, BinaryType, _, _>(
+        "from_base64",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, BinaryType>(|val, output, ctx| {
+            if let Err(err) = general_purpose::STANDARD.decode_vec(val, &mut output.data) {
+                ctx.set_error(output.len(), err.to_string());
+            }
+            output.commit_row();
+        }),
+    );
+}
+
+fn eval_string_to_binary(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<BinaryType> {
+    vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, _| {
+        output.put_slice(val);
+        output.commit_row();
+    })(val, ctx)
+}
+
+/// The bytes of a binary value are copied as is, so they must be valid UTF-8 to become a string.
+fn eval_binary_to_string(val: ValueRef<BinaryType>, ctx: &mut EvalContext) -> Value<StringType> {
+    vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, ctx| {
+        match std::str::from_utf8(val) {
+            Ok(s) => output.put_str(s),
+            Err(e) => ctx.set_error(
+                output.len(),
+                format!("cannot parse to type `STRING`, invalid utf-8 sequence: {e}"),
+            ),
+        }
+        output.commit_row();
+    })(val, ctx)
+}
@@ -0,0 +1,191 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_expression::error_to_null;
+use common_expression::types::bitmap::bitmap_to_string;
+use common_expression::types::bitmap::deserialize_bitmap;
+use common_expression::types::bitmap::serialize_bitmap;
+use common_expression::types::bitmap::string_to_bitmap;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::string::StringDomain;
+use common_expression::types::ArrayType;
+use common_expression::types::BitmapType;
+use common_expression::types::BooleanType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+use roaring::RoaringTreemap;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS bitmap)
+    // to_bitmap(xx)
+    register_to_bitmap(registry);
+
+    // cast(bitmap AS string)
+    // to_string(bitmap)
+    register_bitmap_to_string(registry);
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<UInt64Type>, BitmapType, _, _>(
+        "build_bitmap",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<ArrayType<UInt64Type>, BitmapType>(|arr, output, _| {
+            let bitmap: RoaringTreemap = arr.iter().copied().collect();
+            serialize_bitmap(&bitmap, &mut output.data);
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BitmapType, UInt64Type, _, _>(
+        "bitmap_count",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BitmapType, UInt64Type>(|buf, output, ctx| {
+            match deserialize_bitmap(buf) {
+                Ok(bitmap) => output.push(bitmap.len()),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<BitmapType, UInt64Type, BooleanType, _, _>(
+        "bitmap_contains",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BitmapType, UInt64Type, BooleanType>(
+            |buf, value, output, ctx| match deserialize_bitmap(buf) {
+                Ok(bitmap) => output.push(bitmap.contains(value)),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    register_bitmap_logic(registry, "bitmap_and", |lhs, rhs| lhs & rhs);
+    register_bitmap_logic(registry, "bitmap_or", |lhs, rhs| lhs | rhs);
+    register_bitmap_logic(registry, "bitmap_xor", |lhs, rhs| lhs ^ rhs);
+}
+
+fn register_to_bitmap(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, BitmapType, _, _>(
+        "to_bitmap",
+        |_| FunctionDomain::MayThrow,
+        eval_string_to_bitmap,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, BitmapType, _, _>(
+        "try_to_bitmap",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_string_to_bitmap),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<UInt64Type, BitmapType, _, _>(
+        "to_bitmap",
+        |_| FunctionDomain::Full,
+        eval_number_to_bitmap,
+    );
+    registry.register_combine_nullable_1_arg::<UInt64Type, BitmapType, _, _>(
+        "try_to_bitmap",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_number_to_bitmap),
+    );
+
+    fn eval_string_to_bitmap(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<BitmapType> {
+        vectorize_with_builder_1_arg::<StringType, BitmapType>(|val, output, ctx| {
+            match string_to_bitmap(val) {
+                Ok(bitmap) => serialize_bitmap(&bitmap, &mut output.data),
+                Err(e) => {
+                    ctx.set_error(output.len(), format!("cannot parse to type `BITMAP`, {e}"))
+                }
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+
+    fn eval_number_to_bitmap(
+        val: ValueRef<UInt64Type>,
+        ctx: &mut EvalContext,
+    ) -> Value<BitmapType> {
+        vectorize_with_builder_1_arg::<UInt64Type, BitmapType>(|val, output, _| {
+            let mut bitmap = RoaringTreemap::new();
+            bitmap.insert(val);
+            serialize_bitmap(&bitmap, &mut output.data);
+            output.commit_row();
+        })(val, ctx)
+    }
+}
+
+fn register_bitmap_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BitmapType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BitmapType, StringType>(|val, output, _| {
+            write!(output.data, "{}", bitmap_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<BitmapType, StringType, _, _>(
+        "try_to_string",
+        |_| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: vec![],
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<BitmapType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.data, "{}", bitmap_to_string(val)).unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_bitmap_logic(
+    registry: &mut FunctionRegistry,
+    name: &'static str,
+    op: fn(RoaringTreemap, RoaringTreemap) -> RoaringTreemap,
+) {
+    registry.register_passthrough_nullable_2_arg::<BitmapType, BitmapType, BitmapType, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BitmapType, BitmapType, BitmapType>(
+            move |lhs, rhs, output, ctx| {
+                let result = deserialize_bitmap(lhs)
+                    .and_then(|lhs| deserialize_bitmap(rhs).map(|rhs| op(lhs, rhs)));
+                match result {
+                    Ok(bitmap) => serialize_bitmap(&bitmap, &mut output.data),
+                    Err(e) => ctx.set_error(output.len(), e),
+                }
+                output.commit_row();
+            },
+        ),
+    );
+}
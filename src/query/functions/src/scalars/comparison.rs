@@ -26,6 +26,7 @@ use common_expression::types::DataType;
 use common_expression::types::DateType;
 use common_expression::types::EmptyArrayType;
 use common_expression::types::GenericType;
+use common_expression::types::IntervalType;
 use common_expression::types::NumberClass;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
@@ -56,6 +57,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_string_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
+    register_interval_cmp(registry);
     register_number_cmp(registry);
     register_boolean_cmp(registry);
     register_array_cmp(registry);
@@ -167,6 +169,10 @@ fn register_timestamp_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, TimestampType);
 }
 
+fn register_interval_cmp(registry: &mut FunctionRegistry) {
+    register_simple_domain_type_cmp!(registry, IntervalType);
+}
+
 fn register_boolean_cmp(registry: &mut FunctionRegistry) {
     registry.register_2_arg::<BooleanType, BooleanType, BooleanType, _, _>(
         "eq",
@@ -1019,25 +1019,6 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
         },
         |a, b, _| a - b,
     );
-
-    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
-        "minus",
-        |lhs, rhs| {
-            (|| {
-                let lm = lhs.max;
-                let ln = lhs.min;
-                let rm = rhs.max;
-                let rn = rhs.min;
-
-                Some(FunctionDomain::Domain(SimpleDomain::<i64> {
-                    min: ln.checked_sub(rm)?,
-                    max: lm.checked_sub(rn)?,
-                }))
-            })()
-            .unwrap_or(FunctionDomain::Full)
-        },
-        |a, b, _| a - b,
-    );
 }
 
 fn register_rounder_functions(registry: &mut FunctionRegistry) {
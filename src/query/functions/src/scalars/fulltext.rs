@@ -0,0 +1,139 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use common_expression::types::number::F64;
+use common_expression::types::BooleanType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+// Term frequency saturation of the relevance score, as in BM25.
+const SCORE_K1: f64 = 1.2;
+// Document length normalization of the relevance score, as in BM25.
+const SCORE_B: f64 = 0.75;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // match(text, query)
+    // True if every term of the query appears in the text.
+    registry.register_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _| FunctionDomain::Full,
+        |text, query, _| {
+            let terms = tokenize(query);
+            if terms.is_empty() {
+                return false;
+            }
+            let tokens = tokenize(text);
+            terms.iter().all(|term| tokens.contains(term))
+        },
+    );
+
+    // match_score(text, query)
+    // Relevance of the text to the query, it is what `score()` is rewritten to.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, NumberType<F64>, _, _>(
+        "match_score",
+        |_, _| FunctionDomain::Full,
+        |text, query, ctx| {
+            let scorer = match &text {
+                ValueRef::Scalar(text) => Scorer::new(vec![tokenize(text)]),
+                ValueRef::Column(texts) => Scorer::new(texts.iter().map(tokenize).collect()),
+            };
+            match (&text, &query) {
+                (ValueRef::Scalar(_), ValueRef::Scalar(query)) => {
+                    Value::Scalar(F64::from(scorer.score(0, query)))
+                }
+                _ => {
+                    let scores: Vec<F64> = (0..ctx.num_rows)
+                        .map(|row| {
+                            let doc = if text.as_scalar().is_some() { 0 } else { row };
+                            F64::from(scorer.score(doc, query.index(row).unwrap()))
+                        })
+                        .collect();
+                    Value::Column(scores.into())
+                }
+            }
+        },
+    );
+}
+
+/// Split the text into lowercase alphanumeric terms.
+///
+/// This is the tokenizer shared by the `match` functions and the inverted index,
+/// so that a term found in the index is always matched by the predicate.
+pub fn tokenize(text: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+        .collect()
+}
+
+/// BM25 relevance of the texts evaluated together to a query.
+///
+/// The texts of one evaluation (usually a block) are the collection: a term
+/// shared by fewer of them weighs more, and a match in a text shorter than
+/// the average weighs more than the same match in a longer one.
+struct Scorer {
+    docs: Vec<Vec<String>>,
+    avg_len: f64,
+    doc_freqs: HashMap<String, usize>,
+}
+
+impl Scorer {
+    fn new(docs: Vec<Vec<String>>) -> Self {
+        let total_len: usize = docs.iter().map(|doc| doc.len()).sum();
+        let avg_len = total_len as f64 / docs.len().max(1) as f64;
+        let mut doc_freqs = HashMap::new();
+        for doc in &docs {
+            for term in doc.iter().collect::<HashSet<_>>() {
+                *doc_freqs.entry(term.clone()).or_insert(0) += 1;
+            }
+        }
+        Scorer {
+            docs,
+            avg_len,
+            doc_freqs,
+        }
+    }
+
+    fn score(&self, doc: usize, query: &[u8]) -> f64 {
+        let mut terms = tokenize(query);
+        terms.sort();
+        terms.dedup();
+
+        let tokens = &self.docs[doc];
+        let num_docs = self.docs.len() as f64;
+        // an empty collection has no length to normalize with
+        let len_norm = if self.avg_len > 0.0 {
+            1.0 - SCORE_B + SCORE_B * tokens.len() as f64 / self.avg_len
+        } else {
+            1.0
+        };
+        terms
+            .iter()
+            .map(|term| {
+                let tf = tokens.iter().filter(|token| *token == term).count() as f64;
+                let df = self.doc_freqs.get(term).copied().unwrap_or(0) as f64;
+                let idf = (1.0 + (num_docs - df + 0.5) / (df + 0.5)).ln();
+                idf * tf * (SCORE_K1 + 1.0) / (tf + SCORE_K1 * len_norm)
+            })
+            .sum()
+    }
+}
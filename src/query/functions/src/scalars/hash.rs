@@ -22,7 +22,9 @@ use common_expression::types::decimal::DecimalType;
 use common_expression::types::number::NumberScalar;
 use common_expression::types::number::F32;
 use common_expression::types::number::F64;
+use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::ArgType;
+use common_expression::types::BinaryType;
 use common_expression::types::BooleanType;
 use common_expression::types::DateType;
 use common_expression::types::NumberClass;
@@ -37,6 +39,7 @@ use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::with_integer_mapped_type;
 use common_expression::with_number_mapped_type;
+use common_expression::EvalContext;
 use common_expression::FunctionDomain;
 use common_expression::FunctionRegistry;
 use common_expression::Scalar;
@@ -44,7 +47,6 @@ use ethnum::i256;
 use md5::Digest;
 use md5::Md5 as Md5Hasher;
 use naive_cityhash::cityhash64_with_seed;
-use num_traits::AsPrimitive;
 use twox_hash::XxHash32;
 use twox_hash::XxHash64;
 
@@ -56,6 +58,7 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     register_simple_domain_type_hash::<VariantType>(registry);
     register_simple_domain_type_hash::<StringType>(registry);
+    register_simple_domain_type_hash::<BinaryType>(registry);
     register_simple_domain_type_hash::<DateType>(registry);
     register_simple_domain_type_hash::<TimestampType>(registry);
     register_simple_domain_type_hash::<BooleanType>(registry);
@@ -77,106 +80,120 @@ pub fn register(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "md5",
         |_| FunctionDomain::MayThrow,
-        vectorize_string_to_string(
-            |col| col.data.len() * 32,
-            |val, output, ctx| {
-                // TODO md5 lib doesn't allow encode into buffer...
-                let old_len = output.data.len();
-                output.data.resize(old_len + 32, 0);
-                if let Err(err) = hex::encode_to_slice(
-                    Md5Hasher::digest(val).as_slice(),
-                    &mut output.data[old_len..],
-                ) {
-                    ctx.set_error(output.len(), err.to_string());
-                }
-                output.commit_row();
-            },
-        ),
+        vectorize_string_to_string(|col| col.data.len() * 32, md5_hex),
+    );
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "md5",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(md5_hex),
     );
 
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "sha",
         |_| FunctionDomain::MayThrow,
-        vectorize_string_to_string(
-            |col| col.data.len() * 40,
-            |val, output, ctx| {
-                let old_len = output.data.len();
-                output.data.resize(old_len + 40, 0);
-                // TODO sha1 lib doesn't allow encode into buffer...
-                let mut m = ::sha1::Sha1::new();
-                sha1::digest::Update::update(&mut m, val);
-
-                if let Err(err) =
-                    hex::encode_to_slice(m.finalize().as_slice(), &mut output.data[old_len..])
-                {
-                    ctx.set_error(output.len(), err.to_string());
-                }
-                output.commit_row();
-            },
-        ),
+        vectorize_string_to_string(|col| col.data.len() * 40, sha1_hex),
+    );
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "sha",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(sha1_hex),
     );
 
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "blake3",
         |_| FunctionDomain::MayThrow,
-        vectorize_string_to_string(
-            |col| col.data.len() * 64,
-            |val, output, ctx| {
-                let old_len = output.data.len();
-                output.data.resize(old_len + 64, 0);
-                if let Err(err) =
-                    hex::encode_to_slice(blake3::hash(val).as_bytes(), &mut output.data[old_len..])
-                {
-                    ctx.set_error(output.len(), err.to_string());
-                }
-                output.commit_row();
-            },
-        ),
+        vectorize_string_to_string(|col| col.data.len() * 64, blake3_hex),
+    );
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "blake3",
+        |_| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(blake3_hex),
     );
 
     registry.register_passthrough_nullable_2_arg::<StringType, NumberType<u64>, StringType, _, _>(
         "sha2",
         |_, _| FunctionDomain::MayThrow,
-        vectorize_with_builder_2_arg::<StringType, NumberType<u64>, StringType>(
-            |val, l, output, ctx| {
-                let l: u64 = l.as_();
-                let res = match l {
-                    224 => {
-                        let mut h = sha2::Sha224::new();
-                        sha2::digest::Update::update(&mut h, val);
-                        format!("{:x}", h.finalize())
-                    }
-                    256 | 0 => {
-                        let mut h = sha2::Sha256::new();
-                        sha2::digest::Update::update(&mut h, val);
-                        format!("{:x}", h.finalize())
-                    }
-                    384 => {
-                        let mut h = sha2::Sha384::new();
-                        sha2::digest::Update::update(&mut h, val);
-                        format!("{:x}", h.finalize())
-                    }
-                    512 => {
-                        let mut h = sha2::Sha512::new();
-                        sha2::digest::Update::update(&mut h, val);
-                        format!("{:x}", h.finalize())
-                    }
-                    v => {
-                        ctx.set_error(
-                            output.len(),
-                            format!(
-                                "Expected [0, 224, 256, 384, 512] as sha2 encode options, but got {}",
-                                v
-                            ),
-                        );
-                        String::new()
-                    },
-                };
-                output.put_slice(res.as_bytes());
-                output.commit_row();
-            },
-        ),
+        vectorize_with_builder_2_arg::<StringType, NumberType<u64>, StringType>(sha2_hex),
     );
+    registry.register_passthrough_nullable_2_arg::<BinaryType, NumberType<u64>, StringType, _, _>(
+        "sha2",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BinaryType, NumberType<u64>, StringType>(sha2_hex),
+    );
+}
+
+fn md5_hex(val: &[u8], output: &mut StringColumnBuilder, ctx: &mut EvalContext) {
+    // TODO md5 lib doesn't allow encode into buffer...
+    let old_len = output.data.len();
+    output.data.resize(old_len + 32, 0);
+    if let Err(err) = hex::encode_to_slice(
+        Md5Hasher::digest(val).as_slice(),
+        &mut output.data[old_len..],
+    ) {
+        ctx.set_error(output.len(), err.to_string());
+    }
+    output.commit_row();
+}
+
+fn sha1_hex(val: &[u8], output: &mut StringColumnBuilder, ctx: &mut EvalContext) {
+    let old_len = output.data.len();
+    output.data.resize(old_len + 40, 0);
+    // TODO sha1 lib doesn't allow encode into buffer...
+    let mut m = ::sha1::Sha1::new();
+    sha1::digest::Update::update(&mut m, val);
+
+    if let Err(err) = hex::encode_to_slice(m.finalize().as_slice(), &mut output.data[old_len..]) {
+        ctx.set_error(output.len(), err.to_string());
+    }
+    output.commit_row();
+}
+
+fn blake3_hex(val: &[u8], output: &mut StringColumnBuilder, ctx: &mut EvalContext) {
+    let old_len = output.data.len();
+    output.data.resize(old_len + 64, 0);
+    if let Err(err) =
+        hex::encode_to_slice(blake3::hash(val).as_bytes(), &mut output.data[old_len..])
+    {
+        ctx.set_error(output.len(), err.to_string());
+    }
+    output.commit_row();
+}
+
+fn sha2_hex(val: &[u8], l: u64, output: &mut StringColumnBuilder, ctx: &mut EvalContext) {
+    let res = match l {
+        224 => {
+            let mut h = sha2::Sha224::new();
+            sha2::digest::Update::update(&mut h, val);
+            format!("{:x}", h.finalize())
+        }
+        256 | 0 => {
+            let mut h = sha2::Sha256::new();
+            sha2::digest::Update::update(&mut h, val);
+            format!("{:x}", h.finalize())
+        }
+        384 => {
+            let mut h = sha2::Sha384::new();
+            sha2::digest::Update::update(&mut h, val);
+            format!("{:x}", h.finalize())
+        }
+        512 => {
+            let mut h = sha2::Sha512::new();
+            sha2::digest::Update::update(&mut h, val);
+            format!("{:x}", h.finalize())
+        }
+        v => {
+            ctx.set_error(
+                output.len(),
+                format!(
+                    "Expected [0, 224, 256, 384, 512] as sha2 encode options, but got {}",
+                    v
+                ),
+            );
+            String::new()
+        }
+    };
+    output.put_slice(res.as_bytes());
+    output.commit_row();
 }
 
 fn register_simple_domain_type_hash<T: ArgType>(registry: &mut FunctionRegistry)
@@ -357,7 +374,10 @@ impl DFHash for Scalar {
                     DFHash::hash(v, state);
                 }
             }),
-            Scalar::String(vals) | Scalar::Variant(vals) => {
+            Scalar::String(vals)
+            | Scalar::Variant(vals)
+            | Scalar::Bitmap(vals)
+            | Scalar::Binary(vals) => {
                 for v in vals {
                     DFHash::hash(v, state);
                 }
@@ -0,0 +1,346 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_expression::error_to_null;
+use common_expression::types::interval::interval_to_string;
+use common_expression::types::interval::string_to_interval;
+use common_expression::types::interval::MICROS_IN_AN_HOUR;
+use common_expression::types::interval::MICROS_IN_A_DAY;
+use common_expression::types::interval::MICROS_IN_A_MINUTE;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::Int64Type;
+use common_expression::types::number::SimpleDomain;
+use common_expression::types::string::StringDomain;
+use common_expression::types::timestamp::check_timestamp;
+use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::DateType;
+use common_expression::types::IntervalType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS interval)
+    // to_interval(xx)
+    register_string_to_interval(registry);
+
+    // cast(interval AS [string | int64])
+    // to_[string | int64](interval)
+    register_interval_to_string(registry);
+    register_interval_to_number(registry);
+
+    // interval n [day | hour | minute | second]
+    register_number_to_interval(registry);
+
+    // [date | timestamp] [+ | -] interval
+    // interval [+ | -] interval
+    register_interval_arithmetic(registry);
+}
+
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(micros) => output.push(micros),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `INTERVAL`, {e}"),
+                    );
+                    output.push(0);
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_interval_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{}", interval_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "try_to_string",
+        |_| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: vec![],
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<IntervalType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.data, "{}", interval_to_string(val)).unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_interval_to_number(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, Int64Type, _, _>(
+        "to_int64",
+        |domain| FunctionDomain::Domain(*domain),
+        |val, _| match val {
+            ValueRef::Scalar(scalar) => Value::Scalar(scalar),
+            ValueRef::Column(col) => Value::Column(col),
+        },
+    );
+}
+
+fn register_number_to_interval(registry: &mut FunctionRegistry) {
+    fn register_unit(registry: &mut FunctionRegistry, name: &'static str, factor: i64) {
+        registry.register_passthrough_nullable_1_arg::<Int64Type, IntervalType, _, _>(
+            name,
+            move |domain| {
+                (|| {
+                    let min = domain.min.checked_mul(factor)?;
+                    let max = domain.max.checked_mul(factor)?;
+                    Some(FunctionDomain::Domain(SimpleDomain { min, max }))
+                })()
+                .unwrap_or(FunctionDomain::MayThrow)
+            },
+            move |val, ctx| {
+                vectorize_with_builder_1_arg::<Int64Type, IntervalType>(move |val, output, ctx| {
+                    match val.checked_mul(factor) {
+                        Some(micros) => output.push(micros),
+                        None => {
+                            ctx.set_error(output.len(), "interval is out of range");
+                            output.push(0);
+                        }
+                    }
+                })(val, ctx)
+            },
+        );
+    }
+
+    register_unit(registry, "to_interval_days", MICROS_IN_A_DAY);
+    register_unit(registry, "to_interval_hours", MICROS_IN_AN_HOUR);
+    register_unit(registry, "to_interval_minutes", MICROS_IN_A_MINUTE);
+    register_unit(registry, "to_interval_seconds", MICROS_IN_A_SEC);
+}
+
+fn register_interval_arithmetic(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    push_timestamp(ts.checked_add(interval), output, ctx);
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, output, ctx| {
+                    push_timestamp(ts.checked_add(interval), output, ctx);
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    push_timestamp(ts.checked_sub(interval), output, ctx);
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, TimestampType, IntervalType, _, _>(
+            "minus",
+            |lhs, rhs| {
+                (|| {
+                    Some(FunctionDomain::Domain(SimpleDomain {
+                        min: lhs.min.checked_sub(rhs.max)?,
+                        max: lhs.max.checked_sub(rhs.min)?,
+                    }))
+                })()
+                .unwrap_or(FunctionDomain::MayThrow)
+            },
+            vectorize_with_builder_2_arg::<TimestampType, TimestampType, IntervalType>(
+                |a, b, output, ctx| {
+                    push_interval(a.checked_sub(b), output, ctx);
+                },
+            ),
+        );
+
+    // A date is treated as the midnight of that day, so the result is always a timestamp.
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                push_timestamp(ts.checked_add(interval), output, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, DateType, TimestampType, _, _>(
+        "plus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, DateType, TimestampType>(
+            |interval, date, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                push_timestamp(ts.checked_add(interval), output, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                push_timestamp(ts.checked_sub(interval), output, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |lhs, rhs| {
+            (|| {
+                Some(FunctionDomain::Domain(SimpleDomain {
+                    min: lhs.min.checked_add(rhs.min)?,
+                    max: lhs.max.checked_add(rhs.max)?,
+                }))
+            })()
+            .unwrap_or(FunctionDomain::MayThrow)
+        },
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| {
+                push_interval(a.checked_add(b), output, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |lhs, rhs| {
+            (|| {
+                Some(FunctionDomain::Domain(SimpleDomain {
+                    min: lhs.min.checked_sub(rhs.max)?,
+                    max: lhs.max.checked_sub(rhs.min)?,
+                }))
+            })()
+            .unwrap_or(FunctionDomain::MayThrow)
+        },
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| {
+                push_interval(a.checked_sub(b), output, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, Int64Type, IntervalType, _, _>(
+        "multiply",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, Int64Type, IntervalType>(
+            |a, b, output, ctx| {
+                push_interval(a.checked_mul(b), output, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Int64Type, IntervalType, IntervalType, _, _>(
+        "multiply",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Int64Type, IntervalType, IntervalType>(
+            |a, b, output, ctx| {
+                push_interval(b.checked_mul(a), output, ctx);
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |domain| {
+            (|| {
+                Some(FunctionDomain::Domain(SimpleDomain {
+                    min: domain.max.checked_neg()?,
+                    max: domain.min.checked_neg()?,
+                }))
+            })()
+            .unwrap_or(FunctionDomain::MayThrow)
+        },
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|val, output, ctx| {
+            push_interval(val.checked_neg(), output, ctx);
+        }),
+    );
+}
+
+#[inline]
+fn push_timestamp(ts: Option<i64>, output: &mut Vec<i64>, ctx: &mut EvalContext) {
+    match ts
+        .ok_or_else(|| "timestamp is out of range".to_string())
+        .and_then(check_timestamp)
+    {
+        Ok(ts) => output.push(ts),
+        Err(e) => {
+            ctx.set_error(output.len(), e);
+            output.push(0);
+        }
+    }
+}
+
+#[inline]
+fn push_interval(interval: Option<i64>, output: &mut Vec<i64>, ctx: &mut EvalContext) {
+    match interval {
+        Some(interval) => output.push(interval),
+        None => {
+            ctx.set_error(output.len(), "interval is out of range");
+            output.push(0);
+        }
+    }
+}
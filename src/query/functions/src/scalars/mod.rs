@@ -17,10 +17,14 @@ use common_expression::FunctionRegistry;
 mod arithmetic;
 mod arithmetic_modulo;
 mod array;
+mod binary;
+mod bitmap;
 mod boolean;
 mod control;
 mod datetime;
+mod fulltext;
 mod geo;
+mod interval;
 mod map;
 mod math;
 mod tuple;
@@ -38,6 +42,7 @@ pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use fulltext::tokenize;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -47,6 +52,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     control::register(registry);
     comparison::register(registry);
     datetime::register(registry);
+    interval::register(registry);
     math::register(registry);
     map::register(registry);
     string::register(registry);
@@ -57,4 +63,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     other::register(registry);
     decimal::register(registry);
     vector::register(registry);
+    bitmap::register(registry);
+    binary::register(registry);
+    fulltext::register(registry);
 }
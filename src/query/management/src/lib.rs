@@ -16,8 +16,10 @@
 
 mod cluster;
 mod file_format;
+mod masking_policy;
 mod quota;
 mod role;
+mod row_access_policy;
 mod serde;
 mod setting;
 mod stage;
@@ -28,10 +30,14 @@ pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
 pub use file_format::FileFormatApi;
 pub use file_format::FileFormatMgr;
+pub use masking_policy::MaskingPolicyApi;
+pub use masking_policy::MaskingPolicyMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
+pub use row_access_policy::RowAccessPolicyApi;
+pub use row_access_policy::RowAccessPolicyMgr;
 pub use serde::deserialize_struct;
 pub use serde::serialize_struct;
 pub use setting::SettingApi;
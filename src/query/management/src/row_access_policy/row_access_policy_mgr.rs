@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::RowAccessPolicy;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::row_access_policy::RowAccessPolicyApi;
+
+static ROW_ACCESS_POLICY_API_KEY_PREFIX: &str = "__fd_row_access_policies";
+
+pub struct RowAccessPolicyMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    policy_prefix: String,
+}
+
+impl RowAccessPolicyMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while row access policy mgr create)",
+            ));
+        }
+
+        Ok(RowAccessPolicyMgr {
+            kv_api,
+            policy_prefix: format!(
+                "{}/{}",
+                ROW_ACCESS_POLICY_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RowAccessPolicyApi for RowAccessPolicyMgr {
+    #[async_backtrace::framed]
+    async fn add_policy(&self, policy: RowAccessPolicy) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&policy)?);
+        let key = format!("{}/{}", self.policy_prefix, escape_for_key(&policy.name)?);
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::RowAccessPolicyAlreadyExists(format!(
+                "Row access policy already exists, seq [{}]",
+                v.seq
+            ))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_policy(&self, name: &str, seq: MatchSeq) -> Result<SeqV<RowAccessPolicy>> {
+        let key = format!("{}/{}", self.policy_prefix, escape_for_key(name)?);
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownRowAccessPolicy(format!("Unknown row access policy {}", name))
+        })?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownRowAccessPolicy(format!(
+                "Unknown row access policy {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_policies(&self) -> Result<Vec<RowAccessPolicy>> {
+        let values = self.kv_api.prefix_list_kv(&self.policy_prefix).await?;
+
+        let mut policies = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let policy = serde_json::from_slice::<RowAccessPolicy>(&value.data)?;
+            policies.push(policy);
+        }
+        Ok(policies)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_policy(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = format!("{}/{}", self.policy_prefix, escape_for_key(name)?);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownRowAccessPolicy(format!(
+                "Unknown row access policy {}",
+                name
+            )))
+        }
+    }
+}
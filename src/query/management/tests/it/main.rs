@@ -15,6 +15,8 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod cluster;
+mod masking_policy;
+mod row_access_policy;
 mod setting;
 mod stage;
 mod udf;
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_management::*;
+use common_meta_app::principal::MaskingPolicy;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_masking_policy() -> Result<()> {
+    let (kv_api, policy_api) = new_masking_policy_api().await?;
+
+    let policy = create_test_policy();
+    policy_api.add_policy(policy.clone()).await?;
+    let value = kv_api
+        .get_kv("__fd_masking_policies/admin/mask_email")
+        .await?;
+    assert_eq!(value.unwrap().data, serde_json::to_vec(&policy)?);
+
+    match policy_api.add_policy(policy.clone()).await {
+        Ok(_) => panic!("Already exists add masking policy must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2609),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drop_masking_policy() -> Result<()> {
+    let (_, policy_api) = new_masking_policy_api().await?;
+
+    let policy = create_test_policy();
+    policy_api.add_policy(policy.clone()).await?;
+
+    let got = policy_api.get_policy(&policy.name, MatchSeq::GE(0)).await?;
+    assert_eq!(got.data, policy);
+    let policies = policy_api.get_policies().await?;
+    assert_eq!(policies, vec![policy.clone()]);
+
+    policy_api
+        .drop_policy(&policy.name, MatchSeq::GE(1))
+        .await?;
+
+    let policies = policy_api.get_policies().await?;
+    assert_eq!(policies, vec![]);
+
+    match policy_api.drop_policy(&policy.name, MatchSeq::GE(1)).await {
+        Ok(_) => panic!("Unknown masking policy drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2608),
+    }
+    Ok(())
+}
+
+fn create_test_policy() -> MaskingPolicy {
+    MaskingPolicy::new(
+        "mask_email",
+        ("val".to_string(), TableDataType::String),
+        TableDataType::String,
+        "CASE WHEN current_role() = 'etl' THEN val ELSE '***' END",
+        "This is a comment",
+    )
+}
+
+async fn new_masking_policy_api() -> Result<(Arc<MetaEmbedded>, MaskingPolicyMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = MaskingPolicyMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -0,0 +1,453 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::mem;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::io::orc;
+use common_arrow::arrow::io::orc::format::read::read_metadata;
+use common_arrow::arrow::io::orc::format::read::read_stripe_column;
+use common_arrow::arrow::io::orc::format::read::read_stripe_footer;
+use common_arrow::arrow::io::orc::format::read::FileMetadata;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use opendal::Operator;
+use serde::Deserializer;
+use serde::Serializer;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::DynData;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+// the footer of orc file is usually small, try to get it by reading the tail only.
+const ORC_TAIL_SIZE: u64 = 64 * 1024;
+
+pub struct InputFormatOrc;
+
+impl InputFormatOrc {
+    fn make_splits(
+        file_infos: Vec<StageFileInfo>,
+        metas: Vec<FileMetadata>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let mut infos = vec![];
+        for (info, metadata) in file_infos.into_iter().zip(metas.into_iter()) {
+            let schema = orc::read::infer_schema(&metadata.footer)?;
+            let num_file_splits = metadata.footer.stripes.len();
+            let file_info = Arc::new(FileInfo {
+                path: info.path.clone(),
+                size: info.size as usize,
+                num_splits: num_file_splits,
+                compress_alg: None,
+            });
+            let file_meta = Arc::new(FileMeta {
+                metadata,
+                fields: schema.fields,
+            });
+
+            for (i, stripe) in file_meta.metadata.footer.stripes.iter().enumerate() {
+                let meta = Arc::new(SplitMeta {
+                    file: file_meta.clone(),
+                    stripe: i,
+                });
+                infos.push(Arc::new(SplitInfo {
+                    file: file_info.clone(),
+                    seq_in_file: i,
+                    offset: stripe.offset() as usize,
+                    size: stripe_size(&file_meta.metadata, i),
+                    num_file_splits,
+                    format_info: Some(meta),
+                }));
+            }
+        }
+        Ok(infos)
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatOrc {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let metas = futures::future::try_join_all(
+            file_infos
+                .iter()
+                .map(|f| read_metadata_async(op, &f.path, f.size)),
+        )
+        .await?;
+        Self::make_splits(file_infos, metas)
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        let size = op.stat(path).await?.content_length();
+        let metadata = read_metadata_async(op, path, size).await?;
+        let arrow_schema = orc::read::infer_schema(&metadata.footer)?;
+        Ok(Arc::new(TableSchema::from(&arrow_schema)))
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_copy_aligned(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct OrcFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for OrcFormatPipe {
+    type SplitMeta = SplitMeta;
+    type ReadBatch = Vec<u8>;
+    type RowBatch = StripeInMemory;
+    type AligningState = AligningState;
+    type BlockBuilder = OrcBlockBuilder;
+
+    #[async_backtrace::framed]
+    async fn read_split(
+        ctx: Arc<InputContext>,
+        split_info: Arc<SplitInfo>,
+    ) -> Result<Self::RowBatch> {
+        let meta = Self::get_split_meta(&split_info).expect("must success");
+        let op = ctx.source.get_operator()?;
+        let columns = Arc::new(get_used_columns(&meta.file.fields, &ctx.schema)?);
+
+        // only the bytes of the stripe are read
+        let offset = split_info.offset as u64;
+        let data = op
+            .range_read(
+                &split_info.file.path,
+                offset..offset + split_info.size as u64,
+            )
+            .await?;
+        Ok(StripeInMemory {
+            split_info: split_info.to_string(),
+            file: meta.file.clone(),
+            stripe: meta.stripe,
+            offset,
+            data: Arc::new(data),
+            columns,
+        })
+    }
+}
+
+pub struct FileMeta {
+    pub metadata: FileMetadata,
+    // all fields in the orc file
+    pub fields: Vec<Field>,
+}
+
+#[derive(Clone)]
+pub struct SplitMeta {
+    pub file: Arc<FileMeta>,
+    pub stripe: usize,
+}
+
+impl Debug for SplitMeta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "orc split meta")
+    }
+}
+
+impl serde::Serialize for SplitMeta {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        unimplemented!()
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for SplitMeta {
+    fn deserialize<D: Deserializer<'a>>(_deserializer: D) -> Result<Self, D::Error> {
+        unimplemented!()
+    }
+}
+
+#[typetag::serde(name = "orc_split")]
+impl DynData for SplitMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+pub struct StripeInMemory {
+    pub split_info: String,
+    pub file: Arc<FileMeta>,
+    pub stripe: usize,
+    // offset of `data` in the file, it is the whole file when aligned from a stream,
+    // or only the stripe when read by split.
+    pub offset: u64,
+    pub data: Arc<Vec<u8>>,
+    // (position in the orc schema, field in the orc file) of the columns to read
+    pub columns: Arc<Vec<(usize, Field)>>,
+}
+
+impl RowBatchTrait for StripeInMemory {
+    fn size(&self) -> usize {
+        stripe_size(&self.file.metadata, self.stripe)
+    }
+
+    fn rows(&self) -> usize {
+        self.file.metadata.footer.stripes[self.stripe].number_of_rows() as usize
+    }
+}
+
+impl StripeInMemory {
+    fn get_arrow_chunk(&self) -> Result<ArrowChunk<Box<dyn Array>>> {
+        let metadata = &self.file.metadata;
+        let mut reader = OffsetReader {
+            inner: Cursor::new(&self.data[..]),
+            offset: self.offset,
+        };
+        let mut scratch = vec![];
+        let footer = read_stripe_footer(&mut reader, metadata, self.stripe, &mut scratch)
+            .map_err(|e| self.orc_error(e))?;
+        let footer = Arc::new(footer);
+
+        let root = metadata
+            .footer
+            .types
+            .first()
+            .ok_or_else(|| self.orc_error("missing root type"))?;
+        let mut arrays = Vec::with_capacity(self.columns.len());
+        for (position, field) in self.columns.iter() {
+            let column = read_stripe_column(
+                &mut reader,
+                metadata,
+                self.stripe,
+                footer.clone(),
+                root.subtypes[*position],
+                mem::take(&mut scratch),
+            )
+            .map_err(|e| self.orc_error(e))?;
+            arrays.push(orc::read::deserialize(field.data_type.clone(), &column)?);
+            scratch = column.into_inner();
+        }
+        Ok(ArrowChunk::try_new(arrays)?)
+    }
+
+    fn orc_error(&self, e: impl Debug) -> ErrorCode {
+        ErrorCode::BadBytes(format!(
+            "fail to read orc stripe {}: {:?}",
+            self.split_info, e
+        ))
+    }
+}
+
+impl Debug for StripeInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StripeInMemory")
+    }
+}
+
+pub struct OrcBlockBuilder {
+    ctx: Arc<InputContext>,
+}
+
+impl BlockBuilderTrait for OrcBlockBuilder {
+    type Pipe = OrcFormatPipe;
+
+    fn create(ctx: Arc<InputContext>) -> Self {
+        OrcBlockBuilder { ctx }
+    }
+
+    fn deserialize(&mut self, batch: Option<StripeInMemory>) -> Result<Vec<DataBlock>> {
+        if let Some(stripe) = batch {
+            let chunk = stripe.get_arrow_chunk()?;
+
+            let fields: Vec<DataField> = stripe
+                .columns
+                .iter()
+                .map(|(_, f)| DataField::from(f))
+                .collect::<Vec<_>>();
+
+            let input_schema = DataSchema::new(fields);
+            let block = DataBlock::from_arrow_chunk(&chunk, &input_schema)?;
+
+            let block_total_rows = block.num_rows();
+            let num_rows_per_block = self.ctx.block_compact_thresholds.max_rows_per_block;
+            let blocks: Vec<DataBlock> = (0..block_total_rows)
+                .step_by(num_rows_per_block)
+                .map(|idx| {
+                    if idx + num_rows_per_block < block_total_rows {
+                        block.slice(idx..idx + num_rows_per_block)
+                    } else {
+                        block.slice(idx..block_total_rows)
+                    }
+                })
+                .collect();
+
+            Ok(blocks)
+        } else {
+            Ok(vec![])
+        }
+    }
+}
+
+pub struct AligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for AligningState {
+    type Pipe = OrcFormatPipe;
+
+    fn try_create(ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
+        Ok(AligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<StripeInMemory>> {
+        if let Some(b) = read_batch {
+            self.buffers.push(b);
+            return Ok(vec![]);
+        }
+
+        let split_info = self.split_info.to_string();
+        let file_in_memory = Arc::new(self.buffers.concat());
+        let size = file_in_memory.len();
+        let metadata = read_metadata(&mut Cursor::new(&file_in_memory[..])).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "fail to read metadata of orc file {}: {:?}",
+                self.split_info.file.path, e
+            ))
+        })?;
+        let schema = orc::read::infer_schema(&metadata.footer)?;
+        let columns = Arc::new(get_used_columns(&schema.fields, &self.ctx.schema)?);
+        let num_stripes = metadata.footer.stripes.len();
+        let file = Arc::new(FileMeta {
+            metadata,
+            fields: schema.fields,
+        });
+
+        let row_batches = (0..num_stripes)
+            .map(|stripe| StripeInMemory {
+                split_info: split_info.clone(),
+                file: file.clone(),
+                stripe,
+                offset: 0,
+                data: file_in_memory.clone(),
+                columns: columns.clone(),
+            })
+            .collect::<Vec<_>>();
+        tracing::info!(
+            "align orc file {} of {} bytes to {} stripes",
+            self.split_info.file.path,
+            size,
+            row_batches.len()
+        );
+        Ok(row_batches)
+    }
+}
+
+// reads a part of the file which starts at `offset`, the positions to seek are of the whole file
+struct OffsetReader<'a> {
+    inner: Cursor<&'a [u8]>,
+    offset: u64,
+}
+
+impl<'a> Read for OffsetReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl<'a> Seek for OffsetReader<'a> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(n) => SeekFrom::Start(n.checked_sub(self.offset).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "seek before the stripe")
+            })?),
+            pos => pos,
+        };
+        Ok(self.inner.seek(pos)? + self.offset)
+    }
+}
+
+#[async_backtrace::framed]
+async fn read_metadata_async(op: &Operator, path: &str, size: u64) -> Result<FileMetadata> {
+    if size > ORC_TAIL_SIZE {
+        let tail = op.range_read(path, size - ORC_TAIL_SIZE..size).await?;
+        if let Ok(metadata) = read_metadata(&mut Cursor::new(tail)) {
+            return Ok(metadata);
+        }
+    }
+    // the footer is larger than the tail, or the file is small
+    let data = op.read(path).await?;
+    read_metadata(&mut Cursor::new(data)).map_err(|e| {
+        ErrorCode::BadBytes(format!(
+            "fail to read metadata of orc file {}: {:?}",
+            path, e
+        ))
+    })
+}
+
+fn stripe_size(metadata: &FileMetadata, stripe: usize) -> usize {
+    let info = &metadata.footer.stripes[stripe];
+    (info.index_length() + info.data_length() + info.footer_length()) as usize
+}
+
+fn get_used_columns(fields: &[Field], schema: &TableSchemaRef) -> Result<Vec<(usize, Field)>> {
+    let mut columns = Vec::with_capacity(schema.num_fields());
+    for f in schema.fields().iter() {
+        match fields
+            .iter()
+            .rposition(|c| c.name.eq_ignore_ascii_case(f.name()))
+        {
+            Some(position) => columns.push((position, fields[position].clone())),
+            None => {
+                return Err(ErrorCode::TableSchemaMismatch(format!(
+                    "schema field size mismatch, expected to find column: {}",
+                    f.name()
+                )));
+            }
+        }
+    }
+    Ok(columns)
+}
@@ -41,6 +41,7 @@ use opendal::Operator;
 
 use crate::input_formats::impls::InputFormatCSV;
 use crate::input_formats::impls::InputFormatNDJson;
+use crate::input_formats::impls::InputFormatOrc;
 use crate::input_formats::impls::InputFormatParquet;
 use crate::input_formats::impls::InputFormatTSV;
 use crate::input_formats::impls::InputFormatXML;
@@ -144,6 +145,7 @@ impl InputContext {
             StageFileFormatType::Csv => Ok(Arc::new(InputFormatCSV::create())),
             StageFileFormatType::NdJson => Ok(Arc::new(InputFormatNDJson::create())),
             StageFileFormatType::Parquet => Ok(Arc::new(InputFormatParquet {})),
+            StageFileFormatType::Orc => Ok(Arc::new(InputFormatOrc {})),
             StageFileFormatType::Xml => Ok(Arc::new(InputFormatXML::create())),
             format => Err(ErrorCode::Internal(format!(
                 "Unsupported file format: {:?}",
@@ -25,6 +25,8 @@ pub mod transform_multi_sort_merge;
 pub mod transform_sort_merge;
 pub mod transform_sort_partial;
 
+pub use sort::try_create_sorted_stream_merger;
+pub use sort::SortedStreamMerger;
 pub use transform::*;
 pub use transform_accumulating::*;
 pub use transform_accumulating_async::*;
@@ -0,0 +1,357 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::VecDeque;
+
+use common_arrow::arrow::compute::sort::row::RowConverter as ArrowRowConverter;
+use common_arrow::arrow::compute::sort::row::Rows as ArrowRows;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::DateType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::with_number_mapped_type;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+
+use super::Cursor;
+use super::RowConverter;
+use super::Rows;
+use super::SimpleRowConverter;
+use super::SimpleRows;
+
+/// Merge several sorted streams of data blocks into one sorted stream.
+///
+/// The streams are not bound to input ports. The caller feeds the merger with the next
+/// block of the streams returned by [`SortedStreamMerger::pending_streams`], so the blocks
+/// can come from anywhere, e.g. the input ports of `MultiSortMergeProcessor` or the sorted
+/// runs spilled to storage by an external sort.
+pub trait SortedStreamMerger: Send {
+    /// The streams whose next block is required before the merging can go on.
+    fn pending_streams(&self) -> Vec<usize>;
+
+    /// Feed the next block of the stream, `None` means the stream is finished.
+    fn push_block(&mut self, stream: usize, block: Option<DataBlock>) -> Result<()>;
+
+    /// Merge the buffered blocks into an output block.
+    ///
+    /// Returns `None` if more data is required or the merger is finished.
+    fn next_block(&mut self) -> Result<Option<DataBlock>>;
+
+    fn is_finished(&self) -> bool;
+}
+
+pub struct HeapMerger<R, Converter>
+where
+    R: Rows,
+    Converter: RowConverter<R>,
+{
+    output_schema: DataSchemaRef,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+
+    // Parameters
+    block_size: usize,
+    limit: Option<usize>,
+
+    /// For each stream, maintain a dequeue of data blocks, the last one is read by the cursor.
+    blocks: Vec<VecDeque<DataBlock>>,
+    /// If the cursor of the stream has finished and the next block is required.
+    cursor_finished: Vec<bool>,
+    /// If the stream is finished.
+    stream_finished: Vec<bool>,
+    /// The accumulated rows for the next output data block.
+    ///
+    /// Data format: (stream_index, block_index, row_index)
+    in_progress_rows: Vec<(usize, usize, usize)>,
+    /// Heap that yields [`Cursor`] in increasing order.
+    heap: BinaryHeap<Reverse<Cursor<R>>>,
+    /// Used to convert columns to rows.
+    row_converter: Converter,
+}
+
+impl<R, Converter> HeapMerger<R, Converter>
+where
+    R: Rows,
+    Converter: RowConverter<R>,
+{
+    pub fn create(
+        num_streams: usize,
+        output_schema: DataSchemaRef,
+        block_size: usize,
+        limit: Option<usize>,
+        sort_columns_descriptions: Vec<SortColumnDescription>,
+    ) -> Result<Self> {
+        let row_converter =
+            Converter::create(sort_columns_descriptions.clone(), output_schema.clone())?;
+        Ok(Self {
+            output_schema,
+            sort_columns_descriptions,
+            block_size,
+            limit,
+            blocks: vec![VecDeque::with_capacity(2); num_streams],
+            cursor_finished: vec![true; num_streams],
+            stream_finished: vec![false; num_streams],
+            in_progress_rows: vec![],
+            heap: BinaryHeap::with_capacity(num_streams),
+            row_converter,
+        })
+    }
+
+    /// The max number of rows of the next output block.
+    fn output_capacity(&self) -> usize {
+        match self.limit {
+            Some(limit) => limit.min(self.block_size),
+            None => self.block_size,
+        }
+    }
+
+    /// Drain `self.in_progress_rows` to build a output data block.
+    fn build_block(&mut self) -> Result<DataBlock> {
+        let num_rows = self.in_progress_rows.len();
+        debug_assert!(num_rows > 0);
+
+        let mut blocks_num_pre_sum = Vec::with_capacity(self.blocks.len());
+        let mut len = 0;
+        for block in self.blocks.iter() {
+            blocks_num_pre_sum.push(len);
+            len += block.len();
+        }
+
+        // Compute the indices of the output block.
+        let first_row = &self.in_progress_rows[0];
+        let mut index = blocks_num_pre_sum[first_row.0] + first_row.1;
+        let mut start_row_index = first_row.2;
+        let mut end_row_index = start_row_index + 1;
+        let mut indices = Vec::new();
+        for row in self.in_progress_rows.iter().skip(1) {
+            let next_index = blocks_num_pre_sum[row.0] + row.1;
+            if next_index == index {
+                // Within a same block.
+                end_row_index += 1;
+                continue;
+            }
+            // Record a range in the block.
+            indices.push((index, start_row_index, end_row_index - start_row_index));
+            // Start to record a new block.
+            index = next_index;
+            start_row_index = row.2;
+            end_row_index = start_row_index + 1;
+        }
+        indices.push((index, start_row_index, end_row_index - start_row_index));
+
+        let columns = (0..self.output_schema.fields().len())
+            .map(|col_id| {
+                let candidate_cols = self
+                    .blocks
+                    .iter()
+                    .flatten()
+                    .map(|block| block.get_by_offset(col_id).clone())
+                    .collect::<Vec<_>>();
+                DataBlock::take_column_by_slices_limit(&candidate_cols, &indices, None)
+            })
+            .collect::<Vec<_>>();
+
+        self.in_progress_rows.clear();
+        // All blocks except the last one of each stream are drained into the output block.
+        for blocks in self.blocks.iter_mut() {
+            if blocks.len() > 1 {
+                blocks.drain(0..(blocks.len() - 1));
+            }
+        }
+
+        self.limit = self.limit.map(|limit| limit - num_rows);
+        Ok(DataBlock::new(columns, num_rows))
+    }
+
+    fn is_stream_pending(&self, stream: usize) -> bool {
+        self.cursor_finished[stream] && !self.stream_finished[stream]
+    }
+
+    fn has_pending_stream(&self) -> bool {
+        (0..self.blocks.len()).any(|i| self.is_stream_pending(i))
+    }
+}
+
+impl<R, Converter> SortedStreamMerger for HeapMerger<R, Converter>
+where
+    R: Rows + Send,
+    Converter: RowConverter<R> + Send,
+{
+    fn pending_streams(&self) -> Vec<usize> {
+        if self.is_finished() {
+            return vec![];
+        }
+
+        (0..self.blocks.len())
+            .filter(|i| self.is_stream_pending(*i))
+            .collect()
+    }
+
+    fn push_block(&mut self, stream: usize, block: Option<DataBlock>) -> Result<()> {
+        match block {
+            None => {
+                self.stream_finished[stream] = true;
+            }
+            Some(block) if block.is_empty() => {}
+            Some(block) => {
+                let columns = self
+                    .sort_columns_descriptions
+                    .iter()
+                    .map(|d| block.get_by_offset(d.offset).clone())
+                    .collect::<Vec<_>>();
+                let rows = self.row_converter.convert(&columns, block.num_rows())?;
+                self.blocks[stream].push_back(block);
+                self.heap.push(Reverse(Cursor::try_create(stream, rows)));
+                self.cursor_finished[stream] = false;
+            }
+        }
+        Ok(())
+    }
+
+    fn next_block(&mut self) -> Result<Option<DataBlock>> {
+        if self.is_finished() {
+            return Ok(None);
+        }
+
+        let capacity = self.output_capacity();
+        // The next rows can be determined only if every unfinished stream has a cursor in the heap.
+        while self.in_progress_rows.len() < capacity && !self.has_pending_stream() {
+            let Some(Reverse(mut cursor)) = self.heap.pop() else {
+                break;
+            };
+
+            let stream = cursor.input_index;
+            let block_index = self.blocks[stream].len() - 1;
+            match self.heap.peek() {
+                // If the last row of current block is smaller than the next cursor,
+                // we can drain the whole block.
+                Some(Reverse(next_cursor)) if !cursor.last().le(&next_cursor.current()) => {
+                    while !cursor.is_finished()
+                        && self.in_progress_rows.len() < capacity
+                        && cursor.le(next_cursor)
+                    {
+                        self.in_progress_rows
+                            .push((stream, block_index, cursor.advance()));
+                    }
+                }
+                _ => {
+                    while !cursor.is_finished() && self.in_progress_rows.len() < capacity {
+                        self.in_progress_rows
+                            .push((stream, block_index, cursor.advance()));
+                    }
+                }
+            }
+
+            if cursor.is_finished() {
+                // We have read all rows of this block, need to read a new one.
+                self.cursor_finished[stream] = true;
+            } else {
+                self.heap.push(Reverse(cursor));
+            }
+        }
+
+        let drained = self.heap.is_empty() && !self.has_pending_stream();
+        if self.in_progress_rows.len() >= capacity || (drained && !self.in_progress_rows.is_empty())
+        {
+            return Ok(Some(self.build_block()?));
+        }
+        Ok(None)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.limit == Some(0)
+            || (self.heap.is_empty()
+                && self.in_progress_rows.is_empty()
+                && self.stream_finished.iter().all(|finished| *finished))
+    }
+}
+
+pub fn try_create_sorted_stream_merger(
+    num_streams: usize,
+    output_schema: DataSchemaRef,
+    block_size: usize,
+    limit: Option<usize>,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+) -> Result<Box<dyn SortedStreamMerger>> {
+    let merger: Box<dyn SortedStreamMerger> = if sort_columns_descriptions.len() == 1 {
+        let sort_type = output_schema
+            .field(sort_columns_descriptions[0].offset)
+            .data_type();
+        match sort_type {
+            DataType::Number(num_ty) => with_number_mapped_type!(|NUM_TYPE| match num_ty {
+                NumberDataType::NUM_TYPE => Box::new(HeapMerger::<
+                    SimpleRows<NumberType<NUM_TYPE>>,
+                    SimpleRowConverter<NumberType<NUM_TYPE>>,
+                >::create(
+                    num_streams,
+                    output_schema,
+                    block_size,
+                    limit,
+                    sort_columns_descriptions,
+                )?),
+            }),
+            DataType::Date => Box::new(HeapMerger::<
+                SimpleRows<DateType>,
+                SimpleRowConverter<DateType>,
+            >::create(
+                num_streams,
+                output_schema,
+                block_size,
+                limit,
+                sort_columns_descriptions,
+            )?),
+            DataType::Timestamp => Box::new(HeapMerger::<
+                SimpleRows<TimestampType>,
+                SimpleRowConverter<TimestampType>,
+            >::create(
+                num_streams,
+                output_schema,
+                block_size,
+                limit,
+                sort_columns_descriptions,
+            )?),
+            DataType::String => Box::new(HeapMerger::<
+                SimpleRows<StringType>,
+                SimpleRowConverter<StringType>,
+            >::create(
+                num_streams,
+                output_schema,
+                block_size,
+                limit,
+                sort_columns_descriptions,
+            )?),
+            _ => Box::new(HeapMerger::<ArrowRows, ArrowRowConverter>::create(
+                num_streams,
+                output_schema,
+                block_size,
+                limit,
+                sort_columns_descriptions,
+            )?),
+        }
+    } else {
+        Box::new(HeapMerger::<ArrowRows, ArrowRowConverter>::create(
+            num_streams,
+            output_schema,
+            block_size,
+            limit,
+            sort_columns_descriptions,
+        )?)
+    };
+    Ok(merger)
+}
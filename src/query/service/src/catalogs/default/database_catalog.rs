@@ -33,6 +33,8 @@ use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SetDatabaseOwnerReply;
+use common_meta_app::schema::SetDatabaseOwnerReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -207,6 +209,26 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.rename_database(req).await
     }
 
+    #[async_backtrace::framed]
+    async fn set_database_owner(&self, req: SetDatabaseOwnerReq) -> Result<SetDatabaseOwnerReply> {
+        if req.name_ident.tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while set database owner)",
+            ));
+        }
+        info!("Set database owner from req:{:?}", req);
+
+        if self
+            .immutable_catalog
+            .exists_database(&req.name_ident.tenant, &req.name_ident.db_name)
+            .await?
+        {
+            return self.immutable_catalog.set_database_owner(req).await;
+        }
+
+        self.mutable_catalog.set_database_owner(req).await
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let res = self.immutable_catalog.get_table_by_info(table_info);
         match res {
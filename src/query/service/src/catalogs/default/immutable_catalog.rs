@@ -32,6 +32,8 @@ use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SetDatabaseOwnerReply;
+use common_meta_app::schema::SetDatabaseOwnerReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -124,6 +126,13 @@ impl Catalog for ImmutableCatalog {
         Err(ErrorCode::Unimplemented("Cannot rename system database"))
     }
 
+    #[async_backtrace::framed]
+    async fn set_database_owner(&self, _req: SetDatabaseOwnerReq) -> Result<SetDatabaseOwnerReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot set owner of system database",
+        ))
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let table_id = table_info.ident.table_id;
 
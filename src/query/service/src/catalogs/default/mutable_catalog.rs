@@ -39,6 +39,8 @@ use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SetDatabaseOwnerReply;
+use common_meta_app::schema::SetDatabaseOwnerReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -215,6 +217,12 @@ impl Catalog for MutableCatalog {
         Ok(res)
     }
 
+    #[async_backtrace::framed]
+    async fn set_database_owner(&self, req: SetDatabaseOwnerReq) -> Result<SetDatabaseOwnerReply> {
+        let res = self.ctx.meta.set_database_owner(req).await?;
+        Ok(res)
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let storage = self.ctx.storage_factory.clone();
         storage.get_table(table_info)
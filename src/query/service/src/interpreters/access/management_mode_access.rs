@@ -88,6 +88,14 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreateUDF(_)
                 | Plan::AlterUDF(_)
                 | Plan::DropUDF(_)
+
+                // Row access policy
+                | Plan::CreateRowAccessPolicy(_)
+                | Plan::DropRowAccessPolicy(_)
+
+                // Masking policy
+                | Plan::CreateMaskingPolicy(_)
+                | Plan::DropMaskingPolicy(_)
                 | Plan::UseDatabase(_)
                 | Plan::Call(_) => true,
                 _ => false
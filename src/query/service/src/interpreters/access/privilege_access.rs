@@ -33,6 +33,26 @@ impl PrivilegeAccess {
     pub fn create(ctx: Arc<QueryContext>) -> Box<dyn AccessChecker> {
         Box::new(PrivilegeAccess { ctx })
     }
+
+    // The privileges on the objects can be granted or revoked by the one who is able to grant
+    // privileges globally, or by the owner of all these objects.
+    #[async_backtrace::framed]
+    async fn validate_grant_privileges(&self, objects: &[GrantObject]) -> Result<()> {
+        let session = self.ctx.get_current_session();
+        if session
+            .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Grant])
+            .await
+            .is_ok()
+        {
+            return Ok(());
+        }
+        for object in objects {
+            session
+                .validate_privilege(object, vec![UserPrivilegeType::Ownership])
+                .await?;
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -63,6 +83,15 @@ impl AccessChecker for PrivilegeAccess {
                     if table.is_source_of_view() {
                         continue;
                     }
+                    // The columns which are not granted are hidden by the binder.
+                    if self
+                        .ctx
+                        .get_granted_columns(table.catalog(), table.database(), table.name())
+                        .await?
+                        .is_some()
+                    {
+                        continue;
+                    }
                     session
                         .validate_privilege(
                             &GrantObject::Table(
@@ -86,12 +115,19 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?
             }
-            Plan::CreateUDF(_) | Plan::CreateDatabase(_) => {
+            Plan::CreateUDF(_)
+            | Plan::CreateRowAccessPolicy(_)
+            | Plan::CreateMaskingPolicy(_)
+            | Plan::CreateDatabase(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Create])
                     .await?;
             }
-            Plan::DropDatabase(_) | Plan::UndropDatabase(_) | Plan::DropUDF(_) => {
+            Plan::DropDatabase(_)
+            | Plan::UndropDatabase(_)
+            | Plan::DropUDF(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::DropMaskingPolicy(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Drop])
                     .await?;
@@ -203,6 +239,78 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::RenameTableColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::ModifyTableColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::AddTableRowAccessPolicy(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::DropTableRowAccessPolicy(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::CreateInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::DropInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::AlterTableClusterKey(plan) => {
                 session
                     .validate_privilege(
@@ -324,6 +432,23 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::MergeInto(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![
+                            UserPrivilegeType::Insert,
+                            UserPrivilegeType::Update,
+                            UserPrivilegeType::Delete,
+                        ],
+                    )
+                    .await?;
+                self.check(&plan.input).await?;
+            }
             Plan::CreateView(plan) => {
                 session
                     .validate_privilege(
@@ -377,13 +502,30 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::ShowGrants(_)
             | Plan::ShowRoles(_)
             | Plan::GrantRole(_)
-            | Plan::GrantPriv(_)
-            | Plan::RevokePriv(_)
             | Plan::RevokeRole(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Grant])
                     .await?;
             }
+            Plan::GrantPriv(plan) => {
+                self.validate_grant_privileges(&plan.on).await?;
+            }
+            Plan::RevokePriv(plan) => {
+                self.validate_grant_privileges(&plan.on).await?;
+            }
+            Plan::GrantOwnership(plan) => {
+                // The ownership can be transferred by its current owner, or by the one who is
+                // able to grant privileges globally.
+                if session
+                    .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Grant])
+                    .await
+                    .is_err()
+                {
+                    session
+                        .validate_privilege(&plan.object, vec![UserPrivilegeType::Ownership])
+                        .await?;
+                }
+            }
             Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::Kill(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
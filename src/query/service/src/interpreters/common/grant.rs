@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_meta_app::principal::GrantObject;
+use common_users::BUILTIN_ROLE_PUBLIC;
 
 use crate::procedures::ProcedureFactory;
 use crate::sessions::QueryContext;
@@ -48,6 +49,18 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Column(catalog_name, database_name, table_name, column_name) => {
+            let catalog = ctx.get_catalog(catalog_name)?;
+            let table = catalog
+                .get_table(tenant.as_str(), database_name, table_name)
+                .await?;
+            if table.schema().field_with_name(column_name).is_err() {
+                return Err(common_exception::ErrorCode::UnknownColumn(format!(
+                    "column {} not exists in table {}.{}",
+                    column_name, database_name, table_name,
+                )));
+            }
+        }
         GrantObject::Database(catalog_name, database_name) => {
             let catalog = ctx.get_catalog(catalog_name)?;
             if !catalog
@@ -65,3 +78,11 @@ pub async fn validate_grant_object_exists(
 
     Ok(())
 }
+
+/// Returns the owner of the objects created by the current role. Every session has the PUBLIC
+/// role, so the objects created under it are left without an owner.
+pub fn get_object_owner(ctx: &Arc<QueryContext>) -> Option<String> {
+    ctx.get_current_role()
+        .map(|role| role.name)
+        .filter(|name| name != BUILTIN_ROLE_PUBLIC)
+}
@@ -13,7 +13,13 @@
 // limitations under the License.
 
 mod grant;
+mod quota;
 mod table;
 
+pub use grant::get_object_owner;
 pub use grant::validate_grant_object_exists;
+pub use quota::add_user_storage_usage;
+pub use quota::check_user_storage_quota;
+pub use quota::create_query_mem_stat;
+pub use quota::limit_max_threads;
 pub use table::append2table;
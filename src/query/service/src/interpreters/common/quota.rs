@@ -0,0 +1,138 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::runtime::MemStat;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::UserInfo;
+use storages_common_table_meta::table::OPT_KEY_CREATOR;
+
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+
+/// Create the memory stat of the query, which is accounted to the memory stat of the current user.
+///
+/// Returns None if the user has no memory quota, and rejects the query if the quota is used up.
+pub fn create_query_mem_stat(ctx: &QueryContext) -> Result<Option<Arc<MemStat>>> {
+    // Nothing to enforce if the query is not issued by an authenticated user.
+    let Ok(user) = ctx.get_current_user() else {
+        return Ok(None);
+    };
+
+    let max_memory = user.quota.max_memory_in_bytes;
+    if max_memory == 0 {
+        return Ok(None);
+    }
+
+    let user_mem_stat = SessionManager::instance().get_user_mem_stat(&user.identity());
+    user_mem_stat.set_limit(max_memory as i64);
+
+    let used = user_mem_stat.get_memory_usage();
+    if used >= max_memory as i64 {
+        return Err(ErrorCode::UserQuotaExceeded(format!(
+            "Max memory quota of user {} exceeded: used {} bytes, quota {} bytes",
+            user.identity(),
+            used,
+            max_memory
+        )));
+    }
+
+    Ok(Some(MemStat::create_child(
+        format!("Query-{}", ctx.get_id()),
+        Some(user_mem_stat),
+    )))
+}
+
+/// Limit the executor threads of the query by the cpu quota of the current user.
+pub fn limit_max_threads(ctx: &QueryContext, max_threads: usize) -> usize {
+    match ctx.get_current_user() {
+        Ok(user) if user.quota.max_cpu != 0 => max_threads.min(user.quota.max_cpu as usize),
+        _ => max_threads,
+    }
+}
+
+/// The storage used by a user is counted from the tables at most once in the interval,
+/// listing all the tables on every INSERT and COPY is too costly.
+/// The data written by the user in between is added to the counted usage.
+const STORAGE_USAGE_COUNT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Check the storage used by the tables created by the current user does not exceed the quota.
+#[async_backtrace::framed]
+pub async fn check_user_storage_quota(ctx: &QueryContext) -> Result<()> {
+    let Ok(user) = ctx.get_current_user() else {
+        return Ok(());
+    };
+
+    let max_storage = user.quota.max_storage_in_bytes;
+    if max_storage == 0 {
+        return Ok(());
+    }
+
+    let identity = user.identity();
+    let session_manager = SessionManager::instance();
+    let used = match session_manager.get_user_storage_usage(&identity, STORAGE_USAGE_COUNT_INTERVAL)
+    {
+        Some(used) => used,
+        None => {
+            let mut used = 0;
+            let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+            for database in catalog.list_databases(&ctx.get_tenant()).await? {
+                for table in database.list_tables().await? {
+                    let meta = &table.get_table_info().meta;
+                    if is_created_by(&user, &meta.options) {
+                        used += meta.statistics.compressed_data_bytes
+                            + meta.statistics.index_data_bytes;
+                    }
+                }
+            }
+            session_manager.set_user_storage_usage(&identity, used);
+            used
+        }
+    };
+
+    if used >= max_storage {
+        return Err(ErrorCode::UserQuotaExceeded(format!(
+            "Max storage quota of user {} exceeded: used {} bytes, quota {} bytes",
+            identity, used, max_storage
+        )));
+    }
+
+    Ok(())
+}
+
+/// Add the data written into the table to the storage used by the current user,
+/// if the table is created by the user.
+pub fn add_user_storage_usage(ctx: &QueryContext, table: &dyn Table) {
+    let Ok(user) = ctx.get_current_user() else {
+        return;
+    };
+
+    if user.quota.max_storage_in_bytes != 0
+        && is_created_by(&user, &table.get_table_info().meta.options)
+    {
+        let bytes = ctx.get_write_progress_value().bytes as u64;
+        SessionManager::instance().add_user_storage_usage(&user.identity(), bytes);
+    }
+}
+
+fn is_created_by(user: &UserInfo, options: &BTreeMap<String, String>) -> bool {
+    options.get(OPT_KEY_CREATOR) == Some(&user.identity().to_string())
+}
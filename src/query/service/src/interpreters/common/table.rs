@@ -22,6 +22,7 @@ use common_exception::Result;
 use common_expression::DataSchemaRef;
 use common_pipeline_core::Pipeline;
 
+use crate::interpreters::common::add_user_storage_usage;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -77,11 +78,17 @@ pub fn append2table(
             if may_error.is_none() {
                 let append_entries = ctx.consume_precommit_blocks();
                 // We must put the commit operation to global runtime, which will avoid the "dispatch dropped without returning error" in tower
-                return GlobalIORuntime::instance().block_on(async move {
-                    table
-                        .commit_insertion(ctx, append_entries, None, overwrite)
-                        .await
-                });
+                GlobalIORuntime::instance().block_on({
+                    let ctx = ctx.clone();
+                    let table = table.clone();
+                    async move {
+                        table
+                            .commit_insertion(ctx, append_entries, None, overwrite)
+                            .await
+                    }
+                })?;
+                add_user_storage_usage(&ctx, table.as_ref());
+                return Ok(());
             }
 
             Err(may_error.as_ref().unwrap().clone())
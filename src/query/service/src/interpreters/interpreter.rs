@@ -15,6 +15,7 @@
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use common_base::runtime::TrackedFuture;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -22,6 +23,8 @@ use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::SendableDataBlockStream;
 
+use crate::interpreters::common::create_query_mem_stat;
+use crate::interpreters::common::limit_max_threads;
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
 use crate::pipelines::executor::ExecutorSettings;
@@ -53,7 +56,16 @@ pub trait Interpreter: Sync + Send {
         InterpreterMetrics::record_query_start(&ctx);
         log_query_start(&ctx);
 
-        let mut build_res = match self.execute2().await {
+        // The building of the pipeline is accounted to the memory quota of the user as well.
+        let build_res = match create_query_mem_stat(&ctx) {
+            Ok(mem_stat) => {
+                ctx.set_mem_stat(mem_stat.clone());
+                TrackedFuture::create_with_mem_stat(mem_stat, self.execute2()).await
+            }
+            Err(cause) => Err(cause),
+        };
+
+        let mut build_res = match build_res {
             Ok(build_res) => build_res,
             Err(build_error) => {
                 InterpreterMetrics::record_query_error(&ctx);
@@ -82,8 +94,10 @@ pub trait Interpreter: Sync + Send {
 
         let settings = ctx.get_settings();
         let query_id = ctx.get_id();
-        build_res.set_max_threads(settings.get_max_threads()? as usize);
-        let settings = ExecutorSettings::try_create(&settings, query_id)?;
+        let max_threads = limit_max_threads(&ctx, settings.get_max_threads()? as usize);
+        build_res.set_max_threads(max_threads);
+        let settings =
+            ExecutorSettings::try_create(&settings, query_id)?.with_mem_stat(ctx.get_mem_stat());
 
         if build_res.main_pipeline.is_complete_pipeline()? {
             let mut pipelines = build_res.sources_pipelines;
@@ -42,6 +42,7 @@ use tracing::error;
 use tracing::info;
 
 use crate::interpreters::common::append2table;
+use crate::interpreters::common::check_user_storage_quota;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
@@ -542,6 +543,10 @@ impl Interpreter for CopyInterpreter {
     #[tracing::instrument(level = "debug", name = "copy_interpreter_execute_v2", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if !matches!(&self.plan, CopyPlan::IntoStage { .. }) {
+            check_user_storage_quota(&self.ctx).await?;
+        }
+
         match &self.plan {
             CopyPlan::IntoTable {
                 catalog_name,
@@ -16,10 +16,12 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_types::MatchSeq;
 use common_sql::plans::CreateDatabasePlan;
 use common_users::UserApiProvider;
 
+use crate::interpreters::common::get_object_owner;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -57,7 +59,9 @@ impl Interpreter for CreateDatabaseInterpreter {
                 quota.max_databases
             )));
         };
-        catalog.create_database(self.plan.clone().into()).await?;
+        let mut req: CreateDatabaseReq = self.plan.clone().into();
+        req.meta.owner = get_object_owner(&self.ctx);
+        catalog.create_database(req).await?;
 
         Ok(PipelineBuildResult::create())
     }
@@ -176,6 +176,18 @@ impl InterpreterFactory {
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
+            Plan::RenameTableColumn(rename_table_column) => Ok(Arc::new(
+                RenameTableColumnInterpreter::try_create(ctx, *rename_table_column.clone())?,
+            )),
+            Plan::ModifyTableColumn(modify_table_column) => Ok(Arc::new(
+                ModifyTableColumnInterpreter::try_create(ctx, *modify_table_column.clone())?,
+            )),
+            Plan::AddTableRowAccessPolicy(add_policy) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *add_policy.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(drop_policy) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *drop_policy.clone())?,
+            )),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
@@ -214,6 +226,14 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Indexes
+            Plan::CreateInvertedIndex(create_index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *create_index.clone())?,
+            )),
+            Plan::DropInvertedIndex(drop_index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *drop_index.clone())?,
+            )),
+
             // Users
             Plan::CreateUser(create_user) => Ok(Arc::new(CreateUserInterpreter::try_create(
                 ctx,
@@ -242,6 +262,10 @@ impl InterpreterFactory {
                 *update.clone(),
             )?)),
 
+            Plan::MergeInto(merge_into) => {
+                MergeIntoInterpreter::try_create(ctx, *merge_into.clone())
+            }
+
             // Roles
             Plan::CreateRole(create_role) => Ok(Arc::new(CreateRoleInterpreter::try_create(
                 ctx,
@@ -305,6 +329,9 @@ impl InterpreterFactory {
                 ctx,
                 *revoke_role.clone(),
             )?)),
+            Plan::GrantOwnership(grant_ownership) => Ok(Arc::new(
+                GrantOwnershipInterpreter::try_create(ctx, *grant_ownership.clone())?,
+            )),
             Plan::CreateUDF(create_user_udf) => Ok(Arc::new(CreateUserUDFInterpreter::try_create(
                 ctx,
                 *create_user_udf.clone(),
@@ -318,6 +345,22 @@ impl InterpreterFactory {
                 *drop_udf.clone(),
             )?)),
 
+            // Row access policies
+            Plan::CreateRowAccessPolicy(create_policy) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *create_policy.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(drop_policy) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *drop_policy.clone())?,
+            )),
+
+            // Masking policies
+            Plan::CreateMaskingPolicy(create_policy) => Ok(Arc::new(
+                CreateMaskingPolicyInterpreter::try_create(ctx, *create_policy.clone())?,
+            )),
+            Plan::DropMaskingPolicy(drop_policy) => Ok(Arc::new(
+                DropMaskingPolicyInterpreter::try_create(ctx, *drop_policy.clone())?,
+            )),
+
             Plan::Presign(presign) => Ok(Arc::new(PresignInterpreter::try_create(
                 ctx,
                 *presign.clone(),
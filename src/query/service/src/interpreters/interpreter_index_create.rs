@@ -0,0 +1,111 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableIndex;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateInvertedIndexPlan;
+use common_storages_fuse::FuseTable;
+use storages_common_index::InvertedIndex;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateInvertedIndexPlan,
+}
+
+impl CreateInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateInvertedIndexPlan) -> Result<Self> {
+        Ok(CreateInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+
+        let catalog = self.ctx.get_catalog(catalog_name)?;
+        let table = catalog
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await?;
+
+        // The index files are written along with the segments of fuse tables.
+        FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support inverted index",
+                db_name,
+                tbl_name,
+                table.engine()
+            ))
+        })?;
+
+        let table_info = table.get_table_info();
+        let schema = table_info.schema();
+        let mut column_ids = Vec::with_capacity(self.plan.columns.len());
+        for column in &self.plan.columns {
+            let field = schema.field_with_name(column).map_err(|_| {
+                ErrorCode::UnknownColumn(format!(
+                    "column {} doesn't exist in table {}.{}",
+                    column, db_name, tbl_name
+                ))
+            })?;
+            if !InvertedIndex::supported_type(&field.data_type().into()) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "inverted index only supports String columns, but column {} is {}",
+                    column,
+                    field.data_type()
+                )));
+            }
+            column_ids.push(field.column_id());
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.add_index(
+            TableIndex {
+                name: self.plan.index_name.clone(),
+                column_ids,
+            },
+            self.plan.if_not_exists,
+        )?;
+        if new_table_meta == table_info.meta {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+        };
+        catalog.update_table_meta(table_info, req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
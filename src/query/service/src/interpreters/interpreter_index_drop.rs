@@ -0,0 +1,83 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::DropInvertedIndexPlan;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropInvertedIndexPlan,
+}
+
+impl DropInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropInvertedIndexPlan) -> Result<Self> {
+        Ok(DropInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "DropInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let table = catalog
+            .get_table(
+                self.ctx.get_tenant().as_str(),
+                &self.plan.database,
+                &self.plan.table,
+            )
+            .await?;
+
+        let table_info = table.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.drop_index(&self.plan.index_name, self.plan.if_exists)?;
+        if new_table_meta == table_info.meta {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let no_index_left = new_table_meta.indexes.is_empty();
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+        };
+        catalog.update_table_meta(table_info, req).await?;
+
+        // The index file of a segment is shared by all the inverted indexes of the
+        // table, it is removed once the last one is dropped.
+        if no_index_left {
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            fuse_table
+                .purge_inverted_index_files(self.ctx.clone())
+                .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -83,6 +83,7 @@ use tracing::error;
 use tracing::info;
 
 use crate::interpreters::common::append2table;
+use crate::interpreters::common::check_user_storage_quota;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
@@ -194,6 +195,37 @@ impl InsertInterpreter {
         Ok((Arc::new(DataSchema::new(attachment_fields)), const_values))
     }
 
+    fn partition_columns(&self) -> Vec<(DataField, Scalar)> {
+        self.plan
+            .partition
+            .iter()
+            .map(|(field, value)| (DataField::from(field), value.clone()))
+            .collect()
+    }
+
+    /// Append the static partition columns to the inserted blocks,
+    /// returns the schema of the blocks.
+    fn add_partition_columns(&self, pipeline: &mut Pipeline) -> Result<DataSchemaRef> {
+        if self.plan.partition.is_empty() {
+            return Ok(self.plan.schema());
+        }
+
+        let input_schema = self.plan.schema();
+        let output_schema = self.plan.schema_with_partition();
+        let values = self.plan.partition_values();
+        pipeline.add_transform(|transform_input_port, transform_output_port| {
+            TransformAddConstColumns::try_create(
+                self.ctx.clone(),
+                transform_input_port,
+                transform_output_port,
+                input_schema.clone(),
+                output_schema.clone(),
+                values.clone(),
+            )
+        })?;
+        Ok(output_schema)
+    }
+
     #[async_backtrace::framed]
     async fn build_insert_from_stage_pipeline(
         &self,
@@ -266,6 +298,7 @@ impl InsertInterpreter {
             })?;
         }
 
+        let source_schema = self.add_partition_columns(pipeline)?;
         pipeline.add_transform(|transform_input_port, transform_output_port| {
             TransformResortAddOn::try_create(
                 ctx.clone(),
@@ -351,6 +384,8 @@ impl Interpreter for InsertInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        check_user_storage_quota(&self.ctx).await?;
+
         let plan = &self.plan;
         let table = self
             .ctx
@@ -466,6 +501,7 @@ impl Interpreter for InsertInterpreter {
                                 select_column_bindings,
                                 insert_schema: self.plan.schema(),
                                 cast_needed: self.check_schema_cast(plan)?,
+                                partition_columns: self.partition_columns(),
                             },
                         )));
                         select_plan
@@ -480,6 +516,7 @@ impl Interpreter for InsertInterpreter {
                             select_column_bindings,
                             insert_schema: self.plan.schema(),
                             cast_needed: self.check_schema_cast(plan)?,
+                            partition_columns: self.partition_columns(),
                         }))
                     }
                 };
@@ -520,10 +557,11 @@ impl Interpreter for InsertInterpreter {
             _ => AppendMode::Normal,
         };
 
+        let source_schema = self.add_partition_columns(&mut build_res.main_pipeline)?;
         append2table(
             self.ctx.clone(),
             table.clone(),
-            plan.schema(),
+            source_schema,
             &mut build_res,
             self.plan.overwrite,
             true,
@@ -0,0 +1,53 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::CreateMaskingPolicyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateMaskingPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaskingPolicyPlan,
+}
+
+impl CreateMaskingPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaskingPolicyPlan) -> Result<Self> {
+        Ok(CreateMaskingPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaskingPolicyInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaskingPolicyInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let _ = UserApiProvider::instance()
+            .add_masking_policy(&tenant, self.plan.policy.clone(), self.plan.if_not_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::MergeInto;
+use common_sql::plans::Plan;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
+use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct MergeIntoInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: MergeInto,
+}
+
+impl MergeIntoInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: MergeInto) -> Result<InterpreterPtr> {
+        Ok(Arc::new(MergeIntoInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for MergeIntoInterpreter {
+    fn name(&self) -> &str {
+        "MergeIntoInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+
+        if table.get_table_info().meta.default_cluster_key_id.is_some() {
+            return Err(ErrorCode::StorageOther(
+                "merge into table with cluster key definition is not supported yet",
+            ));
+        }
+
+        let (s_expr, metadata, bind_context, formatted_ast) = match plan.input.as_ref() {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                formatted_ast,
+                ..
+            } => (s_expr, metadata, bind_context, formatted_ast),
+            v => unreachable!("Input plan must be Query, but it's {}", v),
+        };
+
+        let select_interpreter = SelectInterpreter::try_create(
+            self.ctx.clone(),
+            *(bind_context.clone()),
+            *s_expr.clone(),
+            metadata.clone(),
+            formatted_ast.clone(),
+            false,
+        )?;
+        let mut build_res = select_interpreter.execute2().await?;
+
+        // cast the target columns back to the table schema, and the action column to UInt8
+        let select_schema = plan.input.schema();
+        let source_schema = plan.source_schema();
+        let func_ctx = self.ctx.get_function_context()?;
+        build_res
+            .main_pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformCastSchema::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    select_schema.clone(),
+                    source_schema.clone(),
+                    func_ctx,
+                )
+            })?;
+
+        table
+            .merge_into(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                plan.on_conflict_fields.clone(),
+            )
+            .await?;
+        Ok(build_res)
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::SetDatabaseOwnerReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::GrantOwnershipPlan;
+use common_users::UserApiProvider;
+use common_users::BUILTIN_ROLE_PUBLIC;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct GrantOwnershipInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: GrantOwnershipPlan,
+}
+
+impl GrantOwnershipInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: GrantOwnershipPlan) -> Result<Self> {
+        Ok(GrantOwnershipInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for GrantOwnershipInterpreter {
+    fn name(&self) -> &str {
+        "GrantOwnershipInterpreter"
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+
+        // Every session has the PUBLIC role, owning an object by it grants the object to everyone.
+        if self.plan.role == BUILTIN_ROLE_PUBLIC {
+            return Err(ErrorCode::IllegalGrant(format!(
+                "Ownership can not be granted to role {}",
+                BUILTIN_ROLE_PUBLIC
+            )));
+        }
+
+        // Check if the new owner role exists.
+        UserApiProvider::instance()
+            .get_role(&tenant, self.plan.role.clone())
+            .await?;
+
+        match &self.plan.object {
+            GrantObject::Database(catalog_name, database_name) => {
+                let catalog = self.ctx.get_catalog(catalog_name)?;
+                let req = SetDatabaseOwnerReq {
+                    name_ident: DatabaseNameIdent {
+                        tenant: tenant.clone(),
+                        db_name: database_name.clone(),
+                    },
+                    owner: self.plan.role.clone(),
+                };
+                catalog.set_database_owner(req).await?;
+            }
+            GrantObject::Table(catalog_name, database_name, table_name) => {
+                let catalog = self.ctx.get_catalog(catalog_name)?;
+                let table = catalog
+                    .get_table(tenant.as_str(), database_name, table_name)
+                    .await?;
+
+                let table_info = table.get_table_info();
+                if table_info.db_type != DatabaseType::NormalDB {
+                    return Err(ErrorCode::TableEngineNotSupported(format!(
+                        "{}.{} doesn't support ownership",
+                        database_name, table_name
+                    )));
+                }
+
+                let mut new_table_meta = table_info.meta.clone();
+                new_table_meta.owner = Some(self.plan.role.clone());
+
+                let req = UpdateTableMetaReq {
+                    table_id: table_info.ident.table_id,
+                    seq: MatchSeq::Exact(table_info.ident.seq),
+                    new_table_meta,
+                    copied_files: None,
+                };
+                catalog.update_table_meta(table_info, req).await?;
+            }
+            object => {
+                return Err(ErrorCode::IllegalGrant(format!(
+                    "Ownership can not be granted on {}",
+                    object
+                )));
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
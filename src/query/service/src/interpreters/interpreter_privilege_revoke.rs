@@ -48,7 +48,9 @@ impl Interpreter for RevokePrivilegeInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let plan = self.plan.clone();
 
-        validate_grant_object_exists(&self.ctx, &plan.on).await?;
+        for object in &plan.on {
+            validate_grant_object_exists(&self.ctx, object).await?;
+        }
 
         // TODO: check user existence
         // TODO: check privilege on granting on the grant object
@@ -56,16 +58,18 @@ impl Interpreter for RevokePrivilegeInterpreter {
         let tenant = self.ctx.get_tenant();
         let user_mgr = UserApiProvider::instance();
 
-        match plan.principal {
-            PrincipalIdentity::User(user) => {
-                user_mgr
-                    .revoke_privileges_from_user(&tenant, user, plan.on, plan.priv_types)
-                    .await?;
-            }
-            PrincipalIdentity::Role(role) => {
-                user_mgr
-                    .revoke_privileges_from_role(&tenant, &role, plan.on, plan.priv_types)
-                    .await?;
+        for object in plan.on {
+            match &plan.principal {
+                PrincipalIdentity::User(user) => {
+                    user_mgr
+                        .revoke_privileges_from_user(&tenant, user.clone(), object, plan.priv_types)
+                        .await?;
+                }
+                PrincipalIdentity::Role(role) => {
+                    user_mgr
+                        .revoke_privileges_from_role(&tenant, role, object, plan.priv_types)
+                        .await?;
+                }
             }
         }
 
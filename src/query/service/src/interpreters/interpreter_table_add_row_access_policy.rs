@@ -0,0 +1,124 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableRowAccessPolicy;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::AddTableRowAccessPolicyPlan;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableRowAccessPolicyPlan,
+}
+
+impl AddTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(AddTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AddTableRowAccessPolicyInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+
+        let catalog = self.ctx.get_catalog(catalog_name)?;
+        let table = catalog
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await?;
+
+        let table_info = table.get_table_info();
+        if table_info.engine() == VIEW_ENGINE {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is VIEW that doesn't support row access policy",
+                db_name, tbl_name
+            )));
+        }
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support row access policy",
+                db_name, tbl_name
+            )));
+        }
+
+        let policy = UserApiProvider::instance()
+            .get_row_access_policy(self.ctx.get_tenant().as_str(), &self.plan.policy)
+            .await?;
+        if policy.args.len() != self.plan.columns.len() {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "row access policy {} requires {} columns, but got {}",
+                policy.name,
+                policy.args.len(),
+                self.plan.columns.len()
+            )));
+        }
+
+        let schema = table_info.schema();
+        let mut column_ids = Vec::with_capacity(self.plan.columns.len());
+        for ((arg_name, arg_type), column) in policy.args.iter().zip(&self.plan.columns) {
+            let field = schema.field_with_name(column).map_err(|_| {
+                ErrorCode::UnknownColumn(format!(
+                    "column {} doesn't exist in table {}.{}",
+                    column, db_name, tbl_name
+                ))
+            })?;
+            if field.data_type().remove_nullable() != arg_type.remove_nullable() {
+                return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                    "argument {} of row access policy {} is {}, but column {} is {}",
+                    arg_name,
+                    policy.name,
+                    arg_type,
+                    column,
+                    field.data_type()
+                )));
+            }
+            column_ids.push(field.column_id());
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.add_row_access_policy(TableRowAccessPolicy {
+            name: policy.name,
+            column_ids,
+        })?;
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+        };
+        catalog.update_table_meta(table_info, req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
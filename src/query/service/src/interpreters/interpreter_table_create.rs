@@ -33,6 +33,7 @@ use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
+use crate::interpreters::common::get_object_owner;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -143,6 +144,7 @@ impl CreateTableInterpreter {
             table: self.plan.table.clone(),
             table_id: table.get_id(),
             schema: self.plan.schema.clone(),
+            partition: vec![],
             overwrite: false,
             source: InsertInputSource::SelectPlan(select_plan),
         };
@@ -222,6 +224,7 @@ impl CreateTableInterpreter {
             } else {
                 Default::default()
             },
+            owner: get_object_owner(&self.ctx),
             ..Default::default()
         };
         if let Some(cluster_key) = &self.plan.cluster_key {
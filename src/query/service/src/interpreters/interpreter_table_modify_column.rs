@@ -0,0 +1,168 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::ModifyColumnAction;
+use common_sql::plans::ModifyTableColumnPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct ModifyTableColumnInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ModifyTableColumnPlan,
+}
+
+impl ModifyTableColumnInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ModifyTableColumnPlan) -> Result<Self> {
+        Ok(ModifyTableColumnInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ModifyTableColumnInterpreter {
+    fn name(&self) -> &str {
+        "ModifyTableColumnInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+
+        let table = self
+            .ctx
+            .get_catalog(catalog_name)?
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await?;
+
+        let table_info = table.get_table_info();
+        if table_info.engine() == VIEW_ENGINE {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is VIEW that doesn't support alter",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support alter",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        match &self.plan.action {
+            ModifyColumnAction::SetDataType(data_type) => {
+                // Only fuse tables in parquet format know how to read the blocks written
+                // before the change and cast them to the new type.
+                let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+                    ErrorCode::TableEngineNotSupported(format!(
+                        "{}.{} engine is {} that doesn't support modify column type",
+                        &self.plan.database,
+                        &self.plan.table,
+                        table.engine()
+                    ))
+                })?;
+                if fuse_table.is_native() {
+                    return Err(ErrorCode::TableEngineNotSupported(format!(
+                        "{}.{} in native storage format doesn't support modify column type",
+                        &self.plan.database, &self.plan.table
+                    )));
+                }
+
+                // Statistics of old blocks are kept under the old column id, which the
+                // cluster statistics can not follow.
+                let cluster_keys = table.cluster_keys(self.ctx.clone());
+                if cluster_keys.iter().any(|cluster_key| {
+                    cluster_key
+                        .as_expr(&BUILTIN_FUNCTIONS)
+                        .column_refs()
+                        .contains_key(&self.plan.column)
+                }) {
+                    return Err(ErrorCode::InvalidClusterKeys(format!(
+                        "Cannot modify the type of column {} which is used in the cluster key of {}.{}",
+                        self.plan.column, &self.plan.database, &self.plan.table
+                    )));
+                }
+
+                // The masking policy is bound to the type of the column.
+                let column_id = table_info.schema().column_id_of(&self.plan.column)?;
+                if let Some(policy) = new_table_meta.column_mask_policy.get(&column_id) {
+                    return Err(ErrorCode::IllegalMaskingPolicy(format!(
+                        "Cannot modify the type of column {} which is masked by masking policy {}",
+                        self.plan.column, policy
+                    )));
+                }
+
+                new_table_meta.modify_column_type(&self.plan.column, data_type.clone())?;
+            }
+            ModifyColumnAction::SetDefault(default_expr) => {
+                new_table_meta.set_column_default_expr(&self.plan.column, default_expr.clone())?;
+            }
+            ModifyColumnAction::Comment(comment) => {
+                new_table_meta.set_column_comment(&self.plan.column, comment.clone())?;
+            }
+            ModifyColumnAction::SetMaskingPolicy(Some(policy)) => {
+                let policy = UserApiProvider::instance()
+                    .get_masking_policy(self.ctx.get_tenant().as_str(), policy)
+                    .await?;
+                let schema = table_info.schema();
+                let field = schema.field_with_name(&self.plan.column)?;
+                let (arg_name, arg_type) = &policy.arg;
+                if field.data_type().remove_nullable() != arg_type.remove_nullable() {
+                    return Err(ErrorCode::IllegalMaskingPolicy(format!(
+                        "argument {} of masking policy {} is {}, but column {} is {}",
+                        arg_name,
+                        policy.name,
+                        arg_type,
+                        self.plan.column,
+                        field.data_type()
+                    )));
+                }
+                new_table_meta.set_column_mask_policy(&self.plan.column, &policy.name)?;
+            }
+            ModifyColumnAction::SetMaskingPolicy(None) => {
+                new_table_meta.unset_column_mask_policy(&self.plan.column)?;
+            }
+        }
+
+        let catalog = self.ctx.get_catalog(catalog_name)?;
+        let table_id = table_info.ident.table_id;
+        let table_version = table_info.ident.seq;
+
+        let req = UpdateTableMetaReq {
+            table_id,
+            seq: MatchSeq::Exact(table_version),
+            new_table_meta,
+            copied_files: None,
+        };
+
+        catalog.update_table_meta(table_info, req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -26,6 +26,7 @@ use common_sql::Planner;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 
+use crate::interpreters::common::get_object_owner;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -131,6 +132,7 @@ impl CreateViewInterpreter {
             table_meta: TableMeta {
                 engine: VIEW_ENGINE.to_string(),
                 options,
+                owner: get_object_owner(&self.ctx),
                 ..Default::default()
             },
         };
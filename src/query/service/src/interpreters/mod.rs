@@ -33,9 +33,15 @@ mod interpreter_factory;
 mod interpreter_file_format_create;
 mod interpreter_file_format_drop;
 mod interpreter_file_format_show;
+mod interpreter_index_create;
+mod interpreter_index_drop;
 mod interpreter_insert;
 mod interpreter_kill;
+mod interpreter_masking_policy_create;
+mod interpreter_masking_policy_drop;
+mod interpreter_merge_into;
 mod interpreter_metrics;
+mod interpreter_ownership_grant;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -47,6 +53,8 @@ mod interpreter_role_grant;
 mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_show;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_share_alter_tenants;
@@ -63,15 +71,19 @@ mod interpreter_share_show_grant_tenants;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
 mod interpreter_table_add_column;
+mod interpreter_table_add_row_access_policy;
 mod interpreter_table_analyze;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_row_access_policy;
 mod interpreter_table_exists;
+mod interpreter_table_modify_column;
 mod interpreter_table_optimize;
 mod interpreter_table_recluster;
 mod interpreter_table_rename;
+mod interpreter_table_rename_column;
 mod interpreter_table_revert;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
@@ -108,9 +120,15 @@ pub use interpreter_database_undrop::UndropDatabaseInterpreter;
 pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
+pub use interpreter_index_create::CreateInvertedIndexInterpreter;
+pub use interpreter_index_drop::DropInvertedIndexInterpreter;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_masking_policy_create::CreateMaskingPolicyInterpreter;
+pub use interpreter_masking_policy_drop::DropMaskingPolicyInterpreter;
+pub use interpreter_merge_into::MergeIntoInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
+pub use interpreter_ownership_grant::GrantOwnershipInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_query_log::InterpreterQueryLog;
@@ -120,6 +138,8 @@ pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
@@ -135,15 +155,19 @@ pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_row_access_policy::AddTableRowAccessPolicyInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_row_access_policy::DropTableRowAccessPolicyInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
+pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
+pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
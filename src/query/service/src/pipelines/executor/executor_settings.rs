@@ -15,12 +15,15 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use common_base::runtime::MemStat;
 use common_exception::Result;
 use common_settings::Settings;
 
 pub struct ExecutorSettings {
     pub query_id: Arc<String>,
     pub max_execute_time: Duration,
+    // Memory stat that the executor threads report to, None means inheriting from the caller.
+    pub mem_stat: Option<Arc<MemStat>>,
 }
 
 impl ExecutorSettings {
@@ -29,6 +32,12 @@ impl ExecutorSettings {
         Ok(ExecutorSettings {
             query_id: Arc::new(query_id),
             max_execute_time: Duration::from_millis(max_execute_time),
+            mem_stat: None,
         })
     }
+
+    pub fn with_mem_stat(mut self, mem_stat: Option<Arc<MemStat>>) -> Self {
+        self.mem_stat = mem_stat;
+        self
+    }
 }
@@ -22,6 +22,7 @@ use common_base::runtime::GlobalIORuntime;
 use common_base::runtime::Runtime;
 use common_base::runtime::Thread;
 use common_base::runtime::ThreadJoinHandle;
+use common_base::runtime::ThreadTracker;
 use common_base::runtime::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -284,6 +285,13 @@ impl PipelineExecutor {
     fn execute_threads(self: &Arc<Self>, threads: usize) -> Vec<ThreadJoinHandle<Result<()>>> {
         let mut thread_join_handles = Vec::with_capacity(threads);
 
+        // The spawned threads account their memory to the mem stat of the query.
+        let _guard = self
+            .settings
+            .mem_stat
+            .clone()
+            .map(|mem_stat| ThreadTracker::enter(Some(mem_stat)));
+
         for thread_num in 0..threads {
             let this = self.clone();
             #[allow(unused_mut)]
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_channel::Receiver;
@@ -23,6 +24,7 @@ use common_expression::with_hash_method;
 use common_expression::with_mappedhash_method;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
 use common_expression::FunctionContext;
 use common_expression::HashMethodKind;
 use common_expression::SortColumnDescription;
@@ -36,6 +38,7 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_sinks::EmptySink;
 use common_pipeline_sinks::Sinker;
 use common_pipeline_sinks::UnionReceiveSink;
+use common_pipeline_sources::OneBlockSource;
 use common_pipeline_transforms::processors::transforms::try_add_multi_sort_merge;
 use common_pipeline_transforms::processors::transforms::try_create_transform_sort_merge;
 use common_profile::ProfSpanSetRef;
@@ -55,6 +58,7 @@ use common_sql::executor::Limit;
 use common_sql::executor::PhysicalPlan;
 use common_sql::executor::Project;
 use common_sql::executor::ProjectSet;
+use common_sql::executor::RecursiveCteScan;
 use common_sql::executor::RuntimeFilterSource;
 use common_sql::executor::Sort;
 use common_sql::executor::TableScan;
@@ -74,10 +78,12 @@ use crate::api::ExchangeInjector;
 use crate::pipelines::processors::transforms::build_partition_bucket;
 use crate::pipelines::processors::transforms::AggregateInjector;
 use crate::pipelines::processors::transforms::FinalSingleStateAggregator;
+use crate::pipelines::processors::transforms::GraceHashJoinSpiller;
 use crate::pipelines::processors::transforms::HashJoinDesc;
 use crate::pipelines::processors::transforms::PartialSingleStateAggregator;
 use crate::pipelines::processors::transforms::RightSemiAntiJoinCompactor;
 use crate::pipelines::processors::transforms::RuntimeFilterState;
+use crate::pipelines::processors::transforms::TransformAddConstColumns;
 use crate::pipelines::processors::transforms::TransformAggregateSpillWriter;
 use crate::pipelines::processors::transforms::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::TransformLeftJoin;
@@ -85,6 +91,7 @@ use crate::pipelines::processors::transforms::TransformMarkJoin;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
 use crate::pipelines::processors::transforms::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::TransformPartialGroupBy;
+use crate::pipelines::processors::transforms::TransformRecursiveCteSource;
 use crate::pipelines::processors::transforms::TransformRightJoin;
 use crate::pipelines::processors::transforms::TransformRightSemiAntiJoin;
 use crate::pipelines::processors::transforms::TransformWindow;
@@ -101,6 +108,7 @@ use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::processors::TransformRuntimeFilter;
 use crate::pipelines::processors::TransformSortPartial;
+use crate::pipelines::processors::TransformSortSpill;
 use crate::pipelines::Pipeline;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -116,6 +124,8 @@ pub struct PipelineBuilder {
     pub join_state: Option<Arc<JoinHashTable>>,
     // record the index of join build side pipeline in `pipelines`
     pub index: Option<usize>,
+    // Working tables of the recursive ctes which are being evaluated, keyed by cte name
+    pub cte_working_tables: HashMap<String, DataBlock>,
 
     enable_profiling: bool,
     prof_span_set: ProfSpanSetRef,
@@ -137,6 +147,7 @@ impl PipelineBuilder {
             prof_span_set,
             exchange_injector: DefaultExchangeInjector::create(),
             index: None,
+            cte_working_tables: HashMap::new(),
         }
     }
 
@@ -174,7 +185,11 @@ impl PipelineBuilder {
             PhysicalPlan::HashJoin(join) => self.build_join(join),
             PhysicalPlan::ExchangeSink(sink) => self.build_exchange_sink(sink),
             PhysicalPlan::ExchangeSource(source) => self.build_exchange_source(source),
-            PhysicalPlan::UnionAll(union_all) => self.build_union_all(union_all),
+            PhysicalPlan::UnionAll(union_all) => match union_all.cte_name {
+                Some(_) => self.build_recursive_cte(union_all),
+                None => self.build_union_all(union_all),
+            },
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
             PhysicalPlan::DistributedInsertSelect(insert_select) => {
                 self.build_distributed_insert_select(insert_select)
             }
@@ -201,6 +216,7 @@ impl PipelineBuilder {
             join.build.output_schema()?,
             join.probe.output_schema()?,
             HashJoinDesc::create(join)?,
+            GraceHashJoinSpiller::try_create(self.ctx.clone(), join)?,
         )
     }
 
@@ -211,20 +227,18 @@ impl PipelineBuilder {
         join_state: Arc<JoinHashTable>,
     ) -> Result<()> {
         let build_side_context = QueryContext::create_from(self.ctx.clone());
-        let build_side_builder = PipelineBuilder::create(
+        let mut build_side_builder = PipelineBuilder::create(
             build_side_context,
             self.enable_profiling,
             self.prof_span_set.clone(),
         );
+        build_side_builder.cte_working_tables = self.cte_working_tables.clone();
         let mut build_res = build_side_builder.finalize(build)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
 
         let create_sink_processor = |input| {
-            let transform = Sinker::<SinkBuildHashTable>::create(
-                input,
-                SinkBuildHashTable::try_create(join_state.clone())?,
-            );
+            let transform = SinkBuildHashTable::create(input, join_state.clone())?;
 
             if self.enable_profiling {
                 Ok(ProcessorPtr::create(ProfileWrapper::create(
@@ -827,16 +841,35 @@ impl PipelineBuilder {
             }
         })?;
 
-        // Merge
+        // Merge, spill the sorted runs to storage if the memory usage exceeds the threshold.
+        let spilling_bytes_threshold = self
+            .ctx
+            .get_settings()
+            .get_sort_spilling_bytes_threshold()?;
+        let operator = DataOperator::instance().operator();
+        let location_prefix = format!("_sort_spill/{}", self.ctx.get_tenant());
         self.main_pipeline.add_transform(|input, output| {
-            let transform = try_create_transform_sort_merge(
-                input,
-                output,
-                input_schema.clone(),
-                block_size,
-                limit,
-                sort_desc.clone(),
-            )?;
+            let transform = match spilling_bytes_threshold {
+                0 => try_create_transform_sort_merge(
+                    input,
+                    output,
+                    input_schema.clone(),
+                    block_size,
+                    limit,
+                    sort_desc.clone(),
+                )?,
+                _ => TransformSortSpill::create(
+                    input,
+                    output,
+                    input_schema.clone(),
+                    sort_desc.clone(),
+                    block_size,
+                    limit,
+                    spilling_bytes_threshold,
+                    operator.clone(),
+                    location_prefix.clone(),
+                ),
+            };
 
             if self.enable_profiling {
                 Ok(ProcessorPtr::create(ProfileWrapper::create(
@@ -1019,8 +1052,9 @@ impl PipelineBuilder {
         union_plan: &UnionAll,
     ) -> Result<Receiver<DataBlock>> {
         let union_ctx = QueryContext::create_from(self.ctx.clone());
-        let pipeline_builder =
+        let mut pipeline_builder =
             PipelineBuilder::create(union_ctx, self.enable_profiling, self.prof_span_set.clone());
+        pipeline_builder.cte_working_tables = self.cte_working_tables.clone();
         let mut build_res = pipeline_builder.finalize(input)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -1074,6 +1108,37 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    pub fn build_recursive_cte(&mut self, union_all: &UnionAll) -> Result<()> {
+        self.main_pipeline.add_source(
+            |output| {
+                TransformRecursiveCteSource::try_create(
+                    self.ctx.clone(),
+                    output,
+                    union_all.clone(),
+                    self.cte_working_tables.clone(),
+                )
+            },
+            1,
+        )
+    }
+
+    pub fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        let working_table = self
+            .cte_working_tables
+            .get(&scan.cte_name)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Working table of recursive cte `{}` is not found",
+                    scan.cte_name
+                ))
+            })?;
+        self.main_pipeline.add_source(
+            |output| OneBlockSource::create(output, working_table.clone()),
+            1,
+        )
+    }
+
     pub fn build_distributed_insert_select(
         &mut self,
         insert_select: &DistributedInsertSelect,
@@ -1111,9 +1176,34 @@ impl PipelineBuilder {
             .get_catalog(&insert_select.catalog)?
             .get_table_by_info(&insert_select.table_info)?;
 
+        let source_schema = if insert_select.partition_columns.is_empty() {
+            insert_schema.clone()
+        } else {
+            // Append the static partition columns.
+            let mut fields = insert_schema.fields().clone();
+            let mut values = Vec::with_capacity(insert_select.partition_columns.len());
+            for (field, value) in insert_select.partition_columns.iter() {
+                fields.push(field.clone());
+                values.push(value.clone());
+            }
+            let partition_schema = DataSchemaRefExt::create(fields);
+            self.main_pipeline
+                .add_transform(|transform_input_port, transform_output_port| {
+                    TransformAddConstColumns::try_create(
+                        self.ctx.clone(),
+                        transform_input_port,
+                        transform_output_port,
+                        insert_schema.clone(),
+                        partition_schema.clone(),
+                        values.clone(),
+                    )
+                })?;
+            partition_schema
+        };
+
         // Fill missing columns.
         {
-            let source_schema = insert_schema;
+            let source_schema = &source_schema;
             if source_schema.fields().len() < table.schema().fields().len() {
                 self.main_pipeline.add_transform(
                     |transform_input_port, transform_output_port| {
@@ -1167,10 +1257,10 @@ impl PipelineBuilder {
         for _ in 0..output_size / 2 {
             let input = InputPort::create();
             items.push(PipeItem::create(
-                ProcessorPtr::create(Sinker::<SinkBuildHashTable>::create(
+                ProcessorPtr::create(SinkBuildHashTable::create(
                     input.clone(),
-                    SinkBuildHashTable::try_create(self.join_state.as_ref().unwrap().clone())?,
-                )),
+                    self.join_state.as_ref().unwrap().clone(),
+                )?),
                 vec![input],
                 vec![],
             ));
@@ -39,4 +39,5 @@ pub use transforms::TransformLimit;
 pub use transforms::TransformResortAddOn;
 pub use transforms::TransformRuntimeFilter;
 pub use transforms::TransformSortPartial;
+pub use transforms::TransformSortSpill;
 pub use transforms::TransformWindow;
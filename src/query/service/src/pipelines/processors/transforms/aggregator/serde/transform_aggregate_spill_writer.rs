@@ -154,7 +154,9 @@ impl<Method: HashMethodBounds> Processor for TransformAggregateSpillWriter<Metho
                 return Ok(());
             }
 
-            return Err(ErrorCode::Internal(""));
+            return Err(ErrorCode::Internal(
+                "TransformAggregateSpillWriter only recv AggregateMeta",
+            ));
         }
 
         Ok(())
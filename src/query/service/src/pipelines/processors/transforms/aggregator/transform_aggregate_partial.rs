@@ -15,6 +15,8 @@
 use std::sync::Arc;
 use std::vec;
 
+use common_base::runtime::MemStat;
+use common_base::runtime::ThreadTracker;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -83,6 +85,10 @@ pub struct TransformPartialAggregate<Method: HashMethodBounds> {
     hash_table: HashTable<Method>,
 
     params: Arc<AggregatorParams>,
+    /// Tracks the memory allocated since the last spilling, including the memory held by
+    /// the aggregate states outside the arena, e.g. the sets of `uniq` and the values of
+    /// `quantile_cont`. Only used if spilling is enabled.
+    states_mem_stat: Option<Arc<MemStat>>,
 }
 
 impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
@@ -113,6 +119,7 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
                 params,
                 hash_table,
                 settings: AggregateSettings::try_from(ctx)?,
+                states_mem_stat: None,
             },
         ))
     }
@@ -171,6 +178,35 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
         Ok(())
     }
 
+    fn tracked_execute_one_block(&mut self, block: DataBlock) -> Result<()> {
+        if self.settings.spilling_bytes_threshold_per_proc == usize::MAX {
+            return self.execute_one_block(block);
+        }
+
+        let mem_stat = self
+            .states_mem_stat
+            .get_or_insert_with(|| {
+                MemStat::create_child(
+                    String::from("TransformPartialAggregate"),
+                    MemStat::current(),
+                )
+            })
+            .clone();
+        let _guard = ThreadTracker::enter(Some(mem_stat));
+        self.execute_one_block(block)
+    }
+
+    // The allocated bytes of the hash table cell, or the tracked memory if it's larger.
+    fn allocated_bytes(&self, cell_allocated_bytes: usize) -> usize {
+        match &self.states_mem_stat {
+            None => cell_allocated_bytes,
+            Some(mem_stat) => {
+                let tracked = mem_stat.get_memory_usage().max(0) as usize;
+                cell_allocated_bytes.max(tracked)
+            }
+        }
+    }
+
     fn execute_one_block(&mut self, block: DataBlock) -> Result<()> {
         let block = block.convert_to_full();
 
@@ -233,13 +269,13 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
     const NAME: &'static str = "TransformPartialAggregate";
 
     fn transform(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
-        self.execute_one_block(block)?;
+        self.tracked_execute_one_block(block)?;
 
         #[allow(clippy::collapsible_if)]
         if Method::SUPPORT_PARTITIONED {
             if matches!(&self.hash_table, HashTable::HashTable(cell)
                 if cell.len() >= self.settings.convert_threshold ||
-                    cell.allocated_bytes() >= self.settings.spilling_bytes_threshold_per_proc
+                    self.allocated_bytes(cell.allocated_bytes()) >= self.settings.spilling_bytes_threshold_per_proc
             ) {
                 if let HashTable::HashTable(cell) = std::mem::take(&mut self.hash_table) {
                     self.hash_table = HashTable::PartitionedHashTable(
@@ -248,7 +284,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
                 }
             }
 
-            if matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if cell.allocated_bytes() > self.settings.spilling_bytes_threshold_per_proc)
+            if matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if self.allocated_bytes(cell.allocated_bytes()) > self.settings.spilling_bytes_threshold_per_proc)
             {
                 if let HashTable::PartitionedHashTable(v) = std::mem::take(&mut self.hash_table) {
                     let _dropper = v._dropper.clone();
@@ -265,6 +301,8 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
                         }
                     }
 
+                    // The states are released by the spill writer, start tracking from scratch.
+                    self.states_mem_stat = None;
                     let method = PartitionedHashMethod::<Method>::create(self.method.clone());
                     let new_hashtable = method.create_hash_table()?;
                     self.hash_table = HashTable::PartitionedHashTable(HashTableCell::create(
@@ -0,0 +1,467 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::DataBlock;
+use common_sql::executor::HashJoin;
+use common_sql::plans::JoinType;
+use common_storage::DataOperator;
+use opendal::Operator;
+use tracing::error;
+use tracing::info;
+
+use super::HashJoinDesc;
+use super::HashJoinState;
+use super::JoinHashTable;
+use super::ProbeState;
+use crate::api::FlightScatter;
+use crate::api::HashFlightScatter;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Number of partitions of a grace hash join, only the first one is kept in memory
+/// once the build side is spilled.
+const GRACE_JOIN_PARTITIONS: usize = 8;
+
+/// A block written to storage.
+struct SpilledBlock {
+    location: String,
+    columns_layout: Vec<usize>,
+}
+
+/// A spilled partition of the grace hash join.
+///
+/// The build blocks are read and loaded into the hash table first, then the probe blocks
+/// are read and probed one by one.
+pub struct SpilledPartition {
+    operator: Operator,
+    join_state: Arc<JoinHashTable>,
+    build_blocks: VecDeque<SpilledBlock>,
+    build_finished: bool,
+    probe_blocks: VecDeque<SpilledBlock>,
+    probe_state: ProbeState,
+}
+
+impl SpilledPartition {
+    /// Returns true if all spilled blocks of the partition are built or probed.
+    pub(crate) fn is_drained(&self) -> bool {
+        self.build_finished && self.probe_blocks.is_empty()
+    }
+}
+
+impl Drop for SpilledPartition {
+    /// The blocks are left in storage if the query fails or is aborted while joining
+    /// the partition.
+    fn drop(&mut self) {
+        let blocks = self
+            .build_blocks
+            .drain(..)
+            .chain(self.probe_blocks.drain(..));
+        delete_blocks_in_background(&self.operator, blocks.collect());
+    }
+}
+
+// Delete the spilled blocks when the spiller or the partition is dropped, which can't
+// wait for the IO.
+fn delete_blocks_in_background(operator: &Operator, blocks: Vec<SpilledBlock>) {
+    if blocks.is_empty() {
+        return;
+    }
+
+    let operator = operator.clone();
+    GlobalIORuntime::instance().spawn(async move {
+        for block in blocks {
+            if let Err(cause) = operator.delete(&block.location).await {
+                error!(
+                    "Cannot delete spill file {}, cause: {:?}",
+                    block.location, cause
+                );
+            }
+        }
+    });
+}
+
+/// Spiller of the grace hash join.
+///
+/// Once the build side exceeds `join_spilling_bytes_threshold`, the build rows are
+/// hash-partitioned by the join keys, the first partition is kept in the join hash table
+/// and the others are spilled to storage, so are the probe rows of the spilled partitions.
+/// After the in-memory partition is probed, the last probe processor joins the spilled
+/// partitions one by one.
+///
+/// The spilled blocks are serialized in the sync path, the IO is done by the processors
+/// in `async_process`, see [`GraceHashJoinSpiller::flush`].
+pub struct GraceHashJoinSpiller {
+    ctx: Arc<QueryContext>,
+    join: HashJoin,
+    spilling_bytes_threshold: usize,
+    operator: Operator,
+    location_prefix: String,
+    build_scatter: Box<dyn FlightScatter>,
+    probe_scatter: Box<dyn FlightScatter>,
+
+    is_spilled: AtomicBool,
+    /// Memory size of the build blocks buffered before spilling.
+    build_memory_size: Mutex<usize>,
+    /// Spilled blocks of the build side, indexed by partition.
+    build_partitions: Mutex<Vec<Vec<SpilledBlock>>>,
+    /// Spilled blocks of the probe side, indexed by partition.
+    probe_partitions: Mutex<Vec<Vec<SpilledBlock>>>,
+    /// Serialized blocks which are not written to storage yet, (location, data).
+    pending_writes: Mutex<Vec<(String, Vec<u8>)>>,
+    /// Number of the probe processors which are still probing the in-memory partition.
+    probe_workers: Mutex<usize>,
+    /// The next spilled partition to join.
+    next_partition: Mutex<usize>,
+}
+
+impl GraceHashJoinSpiller {
+    pub fn try_create(ctx: Arc<QueryContext>, join: &HashJoin) -> Result<Option<Arc<Self>>> {
+        let spilling_bytes_threshold = ctx.get_settings().get_join_spilling_bytes_threshold()?;
+        // Join types which need the whole build side to produce the results are not supported.
+        let supported = matches!(
+            join.join_type,
+            JoinType::Inner | JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti
+        );
+        if spilling_bytes_threshold == 0 || !supported || !ctx.get_cluster().is_empty() {
+            return Ok(None);
+        }
+
+        let func_ctx = ctx.get_function_context()?;
+        let build_scatter = HashFlightScatter::try_create(
+            func_ctx,
+            join.build_keys.clone(),
+            GRACE_JOIN_PARTITIONS,
+        )?;
+        let probe_scatter = HashFlightScatter::try_create(
+            func_ctx,
+            join.probe_keys.clone(),
+            GRACE_JOIN_PARTITIONS,
+        )?;
+        let location_prefix = format!("_join_spill/{}", ctx.get_tenant());
+
+        Ok(Some(Arc::new(GraceHashJoinSpiller {
+            ctx,
+            join: join.clone(),
+            spilling_bytes_threshold,
+            operator: DataOperator::instance().operator(),
+            location_prefix,
+            build_scatter,
+            probe_scatter,
+            is_spilled: AtomicBool::new(false),
+            build_memory_size: Mutex::new(0),
+            build_partitions: Mutex::new((0..GRACE_JOIN_PARTITIONS).map(|_| vec![]).collect()),
+            probe_partitions: Mutex::new((0..GRACE_JOIN_PARTITIONS).map(|_| vec![]).collect()),
+            pending_writes: Mutex::new(vec![]),
+            probe_workers: Mutex::new(0),
+            next_partition: Mutex::new(1),
+        })))
+    }
+
+    pub fn is_spilled(&self) -> bool {
+        self.is_spilled.load(Ordering::Acquire)
+    }
+
+    /// Buffer the build block, the buffered blocks are partitioned and spilled once
+    /// the memory exceeds the threshold.
+    pub(crate) fn build(&self, buffer: &mut Vec<DataBlock>, input: DataBlock) -> Result<()> {
+        if self.is_spilled() {
+            let block = self.spill_block(input, true)?;
+            if !block.is_empty() {
+                buffer.push(block);
+            }
+            return Ok(());
+        }
+
+        let mut memory_size = self.build_memory_size.lock().unwrap();
+        *memory_size += input.memory_size();
+        buffer.push(input);
+        if *memory_size <= self.spilling_bytes_threshold {
+            return Ok(());
+        }
+
+        info!(
+            "Hash join build side exceeds {} bytes, start spilling",
+            self.spilling_bytes_threshold
+        );
+        self.is_spilled.store(true, Ordering::Release);
+        for block in std::mem::take(buffer) {
+            let block = self.spill_block(block, true)?;
+            if !block.is_empty() {
+                buffer.push(block);
+            }
+        }
+        Ok(())
+    }
+
+    /// Spill the probe rows of the spilled partitions, returns the rows of the in-memory partition.
+    pub(crate) fn spill_probe_block(&self, input: DataBlock) -> Result<DataBlock> {
+        self.spill_block(input, false)
+    }
+
+    pub(crate) fn has_pending_writes(&self) -> bool {
+        !self.pending_writes.lock().unwrap().is_empty()
+    }
+
+    /// Write the serialized blocks to storage.
+    ///
+    /// A processor must flush before detaching, so all spilled blocks are written
+    /// before the spilled partitions are joined.
+    #[async_backtrace::framed]
+    pub(crate) async fn flush(&self) -> Result<()> {
+        let pending_writes = std::mem::take(&mut *self.pending_writes.lock().unwrap());
+        for (location, data) in pending_writes {
+            let instant = Instant::now();
+            self.operator.write(&location, data).await?;
+
+            info!(
+                "Write hash join spill {} successfully, elapsed: {:?}",
+                location,
+                instant.elapsed()
+            );
+        }
+        Ok(())
+    }
+
+    pub(crate) fn attach_probe(&self) {
+        *self.probe_workers.lock().unwrap() += 1;
+    }
+
+    /// Returns true if it's the last probe processor and the spilled partitions need to be joined.
+    pub(crate) fn detach_probe(&self) -> bool {
+        let mut probe_workers = self.probe_workers.lock().unwrap();
+        *probe_workers -= 1;
+        *probe_workers == 0 && self.is_spilled()
+    }
+
+    /// Take the next spilled partition, its build side is loaded by [`Self::read_partition`].
+    #[async_backtrace::framed]
+    pub(crate) async fn next_partition(&self) -> Result<Option<SpilledPartition>> {
+        loop {
+            let partition = {
+                let mut next_partition = self.next_partition.lock().unwrap();
+                if *next_partition == GRACE_JOIN_PARTITIONS {
+                    return Ok(None);
+                }
+                *next_partition += 1;
+                *next_partition - 1
+            };
+
+            let build_blocks =
+                std::mem::take(&mut self.build_partitions.lock().unwrap()[partition]);
+            let probe_blocks =
+                std::mem::take(&mut self.probe_partitions.lock().unwrap()[partition]);
+            if probe_blocks.is_empty() {
+                // No probe rows, nothing will be produced by the supported join types.
+                for block in build_blocks {
+                    self.delete_block(&block).await;
+                }
+                continue;
+            }
+
+            let join_state = JoinHashTable::create_join_state(
+                self.ctx.clone(),
+                &self.join.build_keys,
+                self.join.build.output_schema()?,
+                self.join.probe.output_schema()?,
+                HashJoinDesc::create(&self.join)?,
+                None,
+            )?;
+            let build_finished = build_blocks.is_empty();
+            if build_finished {
+                join_state.finish()?;
+            }
+
+            info!("Hash join starts to join spilled partition {}", partition);
+            let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+            return Ok(Some(SpilledPartition {
+                operator: self.operator.clone(),
+                join_state,
+                build_blocks: build_blocks.into(),
+                build_finished,
+                probe_blocks: probe_blocks.into(),
+                probe_state: ProbeState::with_capacity(block_size),
+            }));
+        }
+    }
+
+    /// Read the next spilled block of the partition, the build blocks are read before the probe blocks.
+    #[async_backtrace::framed]
+    pub(crate) async fn read_partition(
+        &self,
+        partition: &mut SpilledPartition,
+    ) -> Result<Option<DataBlock>> {
+        let block = match partition.build_blocks.pop_front() {
+            Some(block) => Some(block),
+            None => partition.probe_blocks.pop_front(),
+        };
+        match block {
+            Some(block) => Ok(Some(self.read_block(block).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Build or probe the block returned by [`Self::read_partition`].
+    pub(crate) fn process_partition(
+        &self,
+        partition: &mut SpilledPartition,
+        block: DataBlock,
+    ) -> Result<Vec<DataBlock>> {
+        if !partition.build_finished {
+            partition.join_state.build(block)?;
+            if partition.build_blocks.is_empty() {
+                partition.join_state.finish()?;
+                partition.build_finished = true;
+            }
+            return Ok(vec![]);
+        }
+
+        partition.probe_state.clear();
+        partition
+            .join_state
+            .probe(&block, &mut partition.probe_state)
+    }
+
+    /// Get the rest results of the partition after all probe blocks are probed.
+    pub(crate) fn finish_partition(&self, partition: SpilledPartition) -> Result<Vec<DataBlock>> {
+        match self.join.join_type {
+            JoinType::Left => partition.join_state.left_join_blocks(&[]),
+            _ => Ok(vec![]),
+        }
+    }
+
+    /// Delete the spilled blocks which are never read, e.g. the output is finished early.
+    #[async_backtrace::framed]
+    pub(crate) async fn cleanup(&self, partition: Option<SpilledPartition>) {
+        *self.next_partition.lock().unwrap() = GRACE_JOIN_PARTITIONS;
+        self.pending_writes.lock().unwrap().clear();
+
+        let mut blocks = vec![];
+        if let Some(mut partition) = partition {
+            blocks.extend(partition.build_blocks.drain(..));
+            blocks.extend(partition.probe_blocks.drain(..));
+        }
+        for partitions in [&self.build_partitions, &self.probe_partitions] {
+            for partition in partitions.lock().unwrap().iter_mut() {
+                blocks.append(partition);
+            }
+        }
+
+        for block in blocks {
+            self.delete_block(&block).await;
+        }
+    }
+
+    // Partition the block by the join keys, spill all partitions except the first one.
+    fn spill_block(&self, input: DataBlock, is_build: bool) -> Result<DataBlock> {
+        let (scatter, partitions) = match is_build {
+            true => (&self.build_scatter, &self.build_partitions),
+            false => (&self.probe_scatter, &self.probe_partitions),
+        };
+
+        let mut blocks = scatter.execute(input)?;
+        for (partition, block) in blocks.iter_mut().enumerate().skip(1) {
+            if block.is_empty() {
+                continue;
+            }
+            let spilled_block = self.serialize_block(std::mem::take(block));
+            partitions.lock().unwrap()[partition].push(spilled_block);
+        }
+        Ok(blocks.swap_remove(0))
+    }
+
+    // Serialize the block, which is written to storage by `flush`.
+    fn serialize_block(&self, block: DataBlock) -> SpilledBlock {
+        let mut columns_layout = Vec::with_capacity(block.num_columns());
+        let mut write_data = Vec::with_capacity(block.memory_size());
+        for entry in block.convert_to_full().columns() {
+            let column_data = serialize_column(entry.value.as_column().unwrap());
+            columns_layout.push(column_data.len());
+            write_data.extend(column_data);
+        }
+
+        let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+        self.pending_writes
+            .lock()
+            .unwrap()
+            .push((location.clone(), write_data));
+        SpilledBlock {
+            location,
+            columns_layout,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn read_block(&self, block: SpilledBlock) -> Result<DataBlock> {
+        let instant = Instant::now();
+        let data = self.operator.read(&block.location).await;
+        self.delete_block(&block).await;
+        let data = data?;
+
+        info!(
+            "Read hash join spill {} successfully, elapsed: {:?}",
+            block.location,
+            instant.elapsed()
+        );
+
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(block.columns_layout.len());
+        for column_layout in block.columns_layout {
+            let column =
+                deserialize_column(&data[begin..begin + column_layout]).ok_or_else(|| {
+                    ErrorCode::Internal(format!("Cannot deserialize join spill {}", block.location))
+                })?;
+            columns.push(column);
+            begin += column_layout;
+        }
+        Ok(DataBlock::new_from_columns(columns))
+    }
+
+    #[async_backtrace::framed]
+    async fn delete_block(&self, block: &SpilledBlock) {
+        if let Err(cause) = self.operator.delete(&block.location).await {
+            error!(
+                "Cannot delete spill file {}, cause: {:?}",
+                block.location, cause
+            );
+        }
+    }
+}
+
+impl Drop for GraceHashJoinSpiller {
+    /// The spilled partitions which are never joined are left in storage if the query
+    /// fails or is aborted, the blocks not written yet are deleted too, it's harmless.
+    fn drop(&mut self) {
+        let mut blocks = vec![];
+        for partitions in [&self.build_partitions, &self.probe_partitions] {
+            for partition in partitions.lock().unwrap().iter_mut() {
+                blocks.append(partition);
+            }
+        }
+        delete_blocks_in_background(&self.operator, blocks);
+    }
+}
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_exception::Result;
 use common_expression::DataBlock;
 
+use super::GraceHashJoinSpiller;
 use super::ProbeState;
 use crate::pipelines::processors::transforms::hash_join::desc::JoinState;
 
@@ -58,4 +61,7 @@ pub trait HashJoinState: Send + Sync {
 
     /// Get left join results
     fn left_join_blocks(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>>;
+
+    /// Get the spiller if the join may spill to storage
+    fn spiller(&self) -> Option<Arc<GraceHashJoinSpiller>>;
 }
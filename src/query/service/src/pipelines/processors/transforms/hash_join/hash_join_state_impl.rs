@@ -14,6 +14,7 @@
 
 use std::borrow::BorrowMut;
 use std::sync::atomic::Ordering;
+use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
@@ -23,6 +24,7 @@ use common_expression::DataBlock;
 use common_expression::HashMethod;
 use common_hashtable::HashtableLike;
 
+use super::GraceHashJoinSpiller;
 use super::ProbeState;
 use crate::pipelines::processors::transforms::hash_join::desc::JoinState;
 use crate::pipelines::processors::transforms::hash_join::desc::MarkerKind;
@@ -36,6 +38,12 @@ use crate::sql::planner::plans::JoinType;
 #[async_trait::async_trait]
 impl HashJoinState for JoinHashTable {
     fn build(&self, input: DataBlock) -> Result<()> {
+        if let Some(spiller) = &self.spiller {
+            // The build blocks are buffered until finishing, so that they can be partitioned when spilling.
+            let mut buffer = self.row_space.buffer.write().unwrap();
+            return spiller.build(&mut buffer, input);
+        }
+
         let data_block_size_limit = self.ctx.get_settings().get_max_block_size()? * 16;
         let mut buffer = self.row_space.buffer.write().unwrap();
         buffer.push(input);
@@ -426,6 +434,10 @@ impl HashJoinState for JoinHashTable {
         input_blocks.push(rest_block);
         Ok(input_blocks)
     }
+
+    fn spiller(&self) -> Option<Arc<GraceHashJoinSpiller>> {
+        self.spiller.clone()
+    }
 }
 
 impl JoinHashTable {
@@ -40,6 +40,7 @@ use common_sql::plans::JoinType;
 use ethnum::U256;
 use parking_lot::RwLock;
 
+use super::GraceHashJoinSpiller;
 use super::ProbeState;
 use crate::pipelines::processors::transforms::hash_join::desc::HashJoinDesc;
 use crate::pipelines::processors::transforms::hash_join::row::RowPtr;
@@ -88,6 +89,8 @@ pub struct JoinHashTable {
     pub(crate) probe_schema: DataSchemaRef,
     pub(crate) interrupt: Arc<AtomicBool>,
     pub(crate) finished_notify: Arc<Notify>,
+    /// Spill the build side if it exceeds the memory threshold, see [`GraceHashJoinSpiller`].
+    pub(crate) spiller: Option<Arc<GraceHashJoinSpiller>>,
 }
 
 impl JoinHashTable {
@@ -97,6 +100,7 @@ impl JoinHashTable {
         build_schema: DataSchemaRef,
         probe_schema: DataSchemaRef,
         hash_join_desc: HashJoinDesc,
+        spiller: Option<Arc<GraceHashJoinSpiller>>,
     ) -> Result<Arc<JoinHashTable>> {
         let hash_key_types = build_keys
             .iter()
@@ -113,6 +117,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::SingleString(_) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -123,6 +128,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU8(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -133,6 +139,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU16(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -143,6 +150,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU32(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -153,6 +161,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU64(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -163,6 +172,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU128(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -173,6 +183,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
             HashMethodKind::KeysU256(hash_method) => Arc::new(JoinHashTable::try_create(
                 ctx,
@@ -183,6 +194,7 @@ impl JoinHashTable {
                 build_schema,
                 probe_schema,
                 hash_join_desc,
+                spiller,
             )?),
         })
     }
@@ -193,6 +205,7 @@ impl JoinHashTable {
         mut build_data_schema: DataSchemaRef,
         mut probe_data_schema: DataSchemaRef,
         hash_join_desc: HashJoinDesc,
+        spiller: Option<Arc<GraceHashJoinSpiller>>,
     ) -> Result<Self> {
         if hash_join_desc.join_type == JoinType::Left
             || hash_join_desc.join_type == JoinType::Single
@@ -217,6 +230,7 @@ impl JoinHashTable {
             probe_schema: probe_data_schema,
             finished_notify: Arc::new(Notify::new()),
             interrupt: Arc::new(AtomicBool::new(false)),
+            spiller,
         })
     }
 
@@ -14,6 +14,7 @@
 
 mod common;
 mod desc;
+mod grace_spiller;
 mod hash_join_state;
 mod hash_join_state_impl;
 mod join_hash_table;
@@ -24,6 +25,8 @@ pub(crate) mod row;
 mod util;
 
 pub use desc::HashJoinDesc;
+pub use grace_spiller::GraceHashJoinSpiller;
+pub use grace_spiller::SpilledPartition;
 pub use hash_join_state::HashJoinState;
 pub use join_hash_table::FixedKeyHashTable;
 pub use join_hash_table::HashTable;
@@ -27,11 +27,13 @@ mod profile_wrapper;
 mod runtime_filter;
 mod transform_add_const_columns;
 mod transform_merge_block;
+mod transform_recursive_cte_source;
 mod transform_resort_addon;
 mod transform_right_join;
 mod transform_right_semi_anti_join;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_sort_spill;
 
 pub use aggregator::build_partition_bucket;
 pub use aggregator::AggregateInjector;
@@ -58,6 +60,7 @@ use common_pipeline_transforms::processors::transforms::transform_compact;
 use common_pipeline_transforms::processors::transforms::transform_sort_merge;
 use common_pipeline_transforms::processors::transforms::transform_sort_partial;
 pub use hash_join::FixedKeyHashTable;
+pub use hash_join::GraceHashJoinSpiller;
 pub use hash_join::HashJoinDesc;
 pub use hash_join::HashJoinState;
 pub use hash_join::HashTable;
@@ -81,6 +84,7 @@ pub use transform_limit::TransformLimit;
 pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_recursive_cte_source::TransformRecursiveCteSource;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_right_join::RightJoinCompactor;
 pub use transform_right_join::TransformRightJoin;
@@ -91,5 +95,6 @@ pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_sort_spill::TransformSortSpill;
 pub use window::TransformWindow;
 pub use window::WindowFunctionInfo;
@@ -19,9 +19,10 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
-use common_pipeline_sinks::Sink;
 
+use super::hash_join::GraceHashJoinSpiller;
 use super::hash_join::ProbeState;
+use super::hash_join::SpilledPartition;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
@@ -30,36 +31,117 @@ use crate::pipelines::processors::Processor;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 
+/// Build the hash table by the input blocks.
+///
+/// It works like a sink, except that the blocks spilled by the grace hash join are
+/// written to storage in `async_process`.
 pub struct SinkBuildHashTable {
+    input: Arc<InputPort>,
+    input_data: Option<DataBlock>,
     join_state: Arc<dyn HashJoinState>,
+    spiller: Option<Arc<GraceHashJoinSpiller>>,
+    detached: bool,
 }
 
 impl SinkBuildHashTable {
-    pub fn try_create(join_state: Arc<dyn HashJoinState>) -> Result<Self> {
+    pub fn create(
+        input: Arc<InputPort>,
+        join_state: Arc<dyn HashJoinState>,
+    ) -> Result<Box<dyn Processor>> {
         join_state.attach()?;
-        Ok(Self { join_state })
+        let spiller = join_state.spiller();
+        Ok(Box::new(SinkBuildHashTable {
+            input,
+            input_data: None,
+            join_state,
+            spiller,
+            detached: false,
+        }))
+    }
+}
+
+impl Drop for SinkBuildHashTable {
+    fn drop(&mut self) {
+        if !self.detached {
+            self.detached = true;
+            let _ = self.join_state.detach();
+        }
     }
 }
 
-impl Sink for SinkBuildHashTable {
-    const NAME: &'static str = "BuildHashTable";
+#[async_trait::async_trait]
+impl Processor for SinkBuildHashTable {
+    fn name(&self) -> String {
+        "BuildHashTable".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        // The spilled blocks must be written before detaching.
+        if self
+            .spiller
+            .as_ref()
+            .map_or(false, |s| s.has_pending_writes())
+        {
+            return Ok(Event::Async);
+        }
+
+        if self.input.is_finished() {
+            return match self.detached {
+                false => Ok(Event::Sync),
+                true => Ok(Event::Finished),
+            };
+        }
 
-    fn on_finish(&mut self) -> Result<()> {
-        self.join_state.detach()
+        match self.input.has_data() {
+            true => {
+                self.input_data = Some(self.input.pull_data().unwrap()?);
+                Ok(Event::Sync)
+            }
+            false => {
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+        }
     }
 
     fn interrupt(&self) {
         self.join_state.interrupt()
     }
 
-    fn consume(&mut self, data_block: DataBlock) -> Result<()> {
-        self.join_state.build(data_block)
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            self.join_state.build(data_block)?;
+        } else if !self.detached {
+            self.detached = true;
+            self.join_state.detach()?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(spiller) = &self.spiller {
+            spiller.flush().await?;
+        }
+        Ok(())
     }
 }
 
 enum HashJoinStep {
     Build,
     Probe,
+    /// Join the spilled partitions one by one, only done by the last probe processor.
+    Spill(Arc<GraceHashJoinSpiller>),
+    /// Delete the spilled blocks which are never read because the output is finished early.
+    Cleanup(Arc<GraceHashJoinSpiller>),
 }
 
 pub struct TransformHashJoinProbe {
@@ -71,6 +153,12 @@ pub struct TransformHashJoinProbe {
     step: HashJoinStep,
     join_state: Arc<dyn HashJoinState>,
     probe_state: ProbeState,
+
+    spiller: Option<Arc<GraceHashJoinSpiller>>,
+    spilled_partition: Option<SpilledPartition>,
+    /// The block of the spilled partition read from storage, which is not built or probed yet.
+    spilled_data: Option<DataBlock>,
+    spill_finished: bool,
 }
 
 impl TransformHashJoinProbe {
@@ -82,6 +170,10 @@ impl TransformHashJoinProbe {
         _output_schema: DataSchemaRef,
     ) -> Result<Box<dyn Processor>> {
         let default_block_size = ctx.get_settings().get_max_block_size()?;
+        let spiller = join_state.spiller();
+        if let Some(spiller) = &spiller {
+            spiller.attach_probe();
+        }
         Ok(Box::new(TransformHashJoinProbe {
             input_data: None,
             output_data_blocks: VecDeque::new(),
@@ -90,6 +182,10 @@ impl TransformHashJoinProbe {
             step: HashJoinStep::Build,
             join_state,
             probe_state: ProbeState::with_capacity(default_block_size as usize),
+            spiller,
+            spilled_partition: None,
+            spilled_data: None,
+            spill_finished: false,
         }))
     }
 
@@ -99,6 +195,38 @@ impl TransformHashJoinProbe {
             .extend(self.join_state.probe(block, &mut self.probe_state)?);
         Ok(())
     }
+
+    // Detach from the spiller, returns the spiller if the spilled partitions need to be joined by this processor.
+    fn detach_spiller(&mut self) -> Option<Arc<GraceHashJoinSpiller>> {
+        match self.spiller.take() {
+            Some(spiller) if spiller.detach_probe() => Some(spiller),
+            _ => None,
+        }
+    }
+
+    fn join_spilled_partition(&mut self, spiller: &GraceHashJoinSpiller) -> Result<()> {
+        if let Some(block) = self.spilled_data.take() {
+            let partition = self.spilled_partition.as_mut().unwrap();
+            self.output_data_blocks
+                .extend(spiller.process_partition(partition, block)?);
+        } else if let Some(partition) = self.spilled_partition.take() {
+            self.output_data_blocks
+                .extend(spiller.finish_partition(partition)?);
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn read_spilled_partition(&mut self, spiller: &GraceHashJoinSpiller) -> Result<()> {
+        match self.spilled_partition.as_mut() {
+            None => match spiller.next_partition().await? {
+                Some(partition) => self.spilled_partition = Some(partition),
+                None => self.spill_finished = true,
+            },
+            Some(partition) => self.spilled_data = spiller.read_partition(partition).await?,
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -112,44 +240,84 @@ impl Processor for TransformHashJoinProbe {
     }
 
     fn event(&mut self) -> Result<Event> {
-        match self.step {
-            HashJoinStep::Build => Ok(Event::Async),
-            HashJoinStep::Probe => {
-                if self.output_port.is_finished() {
-                    self.input_port.finish();
-                    return Ok(Event::Finished);
-                }
+        if let HashJoinStep::Build = self.step {
+            return Ok(Event::Async);
+        }
 
-                if !self.output_port.can_push() {
-                    self.input_port.set_not_need_data();
-                    return Ok(Event::NeedConsume);
+        if self.output_port.is_finished() {
+            self.input_port.finish();
+            if !self.spill_finished {
+                let spiller = match &self.step {
+                    HashJoinStep::Spill(spiller) | HashJoinStep::Cleanup(spiller) => {
+                        Some(spiller.clone())
+                    }
+                    _ => self.detach_spiller(),
+                };
+                if let Some(spiller) = spiller {
+                    self.step = HashJoinStep::Cleanup(spiller);
+                    return Ok(Event::Async);
                 }
+            }
+            return Ok(Event::Finished);
+        }
 
-                if !self.output_data_blocks.is_empty() {
-                    let data = self.output_data_blocks.pop_front().unwrap();
-                    self.output_port.push_data(Ok(data));
-                    return Ok(Event::NeedConsume);
-                }
+        if !self.output_port.can_push() {
+            self.input_port.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
 
-                if self.input_data.is_some() {
-                    return Ok(Event::Sync);
-                }
+        if !self.output_data_blocks.is_empty() {
+            let data = self.output_data_blocks.pop_front().unwrap();
+            self.output_port.push_data(Ok(data));
+            return Ok(Event::NeedConsume);
+        }
 
-                if self.input_port.has_data() {
-                    let data = self.input_port.pull_data().unwrap()?;
-                    self.input_data = Some(data);
-                    return Ok(Event::Sync);
-                }
+        if let HashJoinStep::Spill(_) = self.step {
+            if self.spill_finished {
+                self.output_port.finish();
+                return Ok(Event::Finished);
+            }
+            let drained = self
+                .spilled_partition
+                .as_ref()
+                .map_or(false, |partition| partition.is_drained());
+            if self.spilled_data.is_some() || drained {
+                return Ok(Event::Sync);
+            }
+            return Ok(Event::Async);
+        }
 
-                if self.input_port.is_finished() {
-                    self.output_port.finish();
-                    return Ok(Event::Finished);
-                }
+        // The spilled probe blocks must be written before detaching.
+        if self
+            .spiller
+            .as_ref()
+            .map_or(false, |s| s.has_pending_writes())
+        {
+            self.input_port.set_not_need_data();
+            return Ok(Event::Async);
+        }
 
-                self.input_port.set_need_data();
-                Ok(Event::NeedData)
+        if self.input_data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input_port.has_data() {
+            let data = self.input_port.pull_data().unwrap()?;
+            self.input_data = Some(data);
+            return Ok(Event::Sync);
+        }
+
+        if self.input_port.is_finished() {
+            if let Some(spiller) = self.detach_spiller() {
+                self.step = HashJoinStep::Spill(spiller);
+                return Ok(Event::Sync);
             }
+            self.output_port.finish();
+            return Ok(Event::Finished);
         }
+
+        self.input_port.set_need_data();
+        Ok(Event::NeedData)
     }
 
     fn interrupt(&self) {
@@ -157,23 +325,51 @@ impl Processor for TransformHashJoinProbe {
     }
 
     fn process(&mut self) -> Result<()> {
-        match self.step {
+        match &self.step {
             HashJoinStep::Build => Ok(()),
             HashJoinStep::Probe => {
                 if let Some(data) = self.input_data.take() {
-                    let data = data.convert_to_full();
+                    let mut data = data.convert_to_full();
+                    if let Some(spiller) = self.spiller.as_ref().filter(|s| s.is_spilled()) {
+                        // Only the rows of the in-memory partition are probed now.
+                        data = spiller.spill_probe_block(data)?;
+                        if data.is_empty() {
+                            return Ok(());
+                        }
+                    }
                     self.probe(&data)?;
                 }
                 Ok(())
             }
+            HashJoinStep::Spill(spiller) => {
+                let spiller = spiller.clone();
+                self.join_spilled_partition(&spiller)
+            }
+            HashJoinStep::Cleanup(_) => Ok(()),
         }
     }
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        if let HashJoinStep::Build = &self.step {
-            self.join_state.wait_finish().await?;
-            self.step = HashJoinStep::Probe;
+        match &self.step {
+            HashJoinStep::Build => {
+                self.join_state.wait_finish().await?;
+                self.step = HashJoinStep::Probe;
+            }
+            HashJoinStep::Probe => {
+                if let Some(spiller) = &self.spiller {
+                    spiller.flush().await?;
+                }
+            }
+            HashJoinStep::Spill(spiller) => {
+                let spiller = spiller.clone();
+                self.read_spilled_partition(&spiller).await?;
+            }
+            HashJoinStep::Cleanup(spiller) => {
+                let spiller = spiller.clone();
+                spiller.cleanup(self.spilled_partition.take()).await;
+                self.spill_finished = true;
+            }
         }
 
         Ok(())
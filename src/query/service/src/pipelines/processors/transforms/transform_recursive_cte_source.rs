@@ -0,0 +1,181 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_base::base::tokio::task::spawn_blocking;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_profile::ProfSpanSetRef;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::UnionAll;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+
+/// Source of a recursive cte.
+///
+/// It evaluates the anchor part (the left child of the union) once, then evaluates the
+/// recursive part (the right child) repeatedly against the rows produced by the last
+/// iteration, which are visible as the working table, until no more rows are produced.
+///
+/// Each iteration is executed by a nested pipeline, which is pulled in a blocking thread to
+/// keep the worker of the outer pipeline free.
+pub struct TransformRecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    union_plan: UnionAll,
+    cte_name: String,
+    max_depth: u64,
+
+    /// Working tables of the outer recursive ctes.
+    working_tables: HashMap<String, DataBlock>,
+    /// Number of evaluated iterations, the first one is the anchor part.
+    iteration: u64,
+    finished: bool,
+}
+
+impl TransformRecursiveCteSource {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        union_plan: UnionAll,
+        working_tables: HashMap<String, DataBlock>,
+    ) -> Result<ProcessorPtr> {
+        let cte_name = union_plan.cte_name.clone().ok_or_else(|| {
+            ErrorCode::Internal("UnionAll of a recursive cte must have the cte name")
+        })?;
+        let max_depth = ctx.get_settings().get_max_cte_recursive_depth()?;
+        AsyncSourcer::create(ctx.clone(), output, TransformRecursiveCteSource {
+            ctx,
+            union_plan,
+            cte_name,
+            max_depth,
+            working_tables,
+            iteration: 0,
+            finished: false,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_plan(&self, plan: &PhysicalPlan) -> Result<Vec<DataBlock>> {
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let working_tables = self.working_tables.clone();
+        let plan = plan.clone();
+        spawn_blocking(move || Self::execute_pipeline(ctx, working_tables, &plan))
+            .await
+            .map_err(|cause| {
+                ErrorCode::Internal(format!(
+                    "Cannot execute the iteration of recursive cte, cause: {:?}",
+                    cause
+                ))
+            })?
+    }
+
+    fn execute_pipeline(
+        ctx: Arc<QueryContext>,
+        working_tables: HashMap<String, DataBlock>,
+        plan: &PhysicalPlan,
+    ) -> Result<Vec<DataBlock>> {
+        let mut pipeline_builder =
+            PipelineBuilder::create(ctx.clone(), false, ProfSpanSetRef::default());
+        pipeline_builder.cte_working_tables = working_tables;
+        let mut build_res = pipeline_builder.finalize(plan)?;
+
+        let settings = ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+        let mut executor = PipelinePullingExecutor::from_pipelines(build_res, executor_settings)?;
+        executor.start();
+
+        let mut blocks = vec![];
+        while let Some(block) = executor.pull_data()? {
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+
+    // Reorder the columns of the block by the pairs of the union.
+    fn project_block(&self, block: DataBlock, is_left: bool) -> Result<DataBlock> {
+        let schema = if is_left {
+            self.union_plan.left.output_schema()?
+        } else {
+            self.union_plan.right.output_schema()?
+        };
+
+        let num_rows = block.num_rows();
+        let columns = self
+            .union_plan
+            .pairs
+            .iter()
+            .map(|(left, right)| {
+                let name = if is_left { left } else { right };
+                Ok(block.get_by_offset(schema.index_of(name)?).clone())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataBlock::new(columns, num_rows))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for TransformRecursiveCteSource {
+    const NAME: &'static str = "RecursiveCteSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let is_left = self.iteration == 0;
+        let blocks = if is_left {
+            self.execute_plan(&self.union_plan.left).await?
+        } else {
+            self.execute_plan(&self.union_plan.right).await?
+        };
+        let blocks = blocks
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .map(|block| self.project_block(block, is_left))
+            .collect::<Result<Vec<_>>>()?;
+
+        if blocks.is_empty() {
+            self.finished = true;
+            self.working_tables.remove(&self.cte_name);
+            return Ok(None);
+        }
+
+        if self.iteration > self.max_depth {
+            return Err(ErrorCode::Overflow(format!(
+                "Recursive cte `{}` aborted after {} iterations, try increasing the setting `max_cte_recursive_depth` to a larger value",
+                self.cte_name, self.max_depth
+            )));
+        }
+        self.iteration += 1;
+
+        let block = DataBlock::concat(&blocks)?;
+        self.working_tables
+            .insert(self.cte_name.clone(), block.clone());
+        Ok(Some(block))
+    }
+}
@@ -0,0 +1,409 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::try_create_sorted_stream_merger;
+use common_pipeline_transforms::processors::transforms::SortedStreamMerger;
+use opendal::Operator;
+use tracing::error;
+use tracing::info;
+
+/// A sorted block written to storage.
+struct SpilledBlock {
+    location: String,
+    columns_layout: Vec<usize>,
+}
+
+enum State {
+    /// Collecting the sorted blocks from the input.
+    Collect,
+    /// The collected blocks need to be sorted into a run, and written to storage
+    /// if the input is not finished.
+    SortRun,
+    /// The sorted run is being written to storage.
+    Spill(Vec<DataBlock>),
+    /// Merging the sorted runs.
+    Merge,
+    /// The next block of the spilled run is being read from storage.
+    Read(usize),
+    /// The spilled blocks which are never read are being deleted from storage,
+    /// e.g. the merging stops early because of the limit.
+    Cleanup,
+    Finished,
+}
+
+/// External merge sort.
+///
+/// The input blocks are already sorted, they are collected in memory until the memory
+/// usage exceeds `spilling_bytes_threshold`, then the collected blocks are sorted into a
+/// run and written to storage. When the input is finished, the spilled runs and the run
+/// in memory are merged into a sorted stream, which reads only one block of each spilled
+/// run at a time.
+pub struct TransformSortSpill {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    state: State,
+
+    output_schema: DataSchemaRef,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+    block_size: usize,
+    limit: Option<usize>,
+    spilling_bytes_threshold: usize,
+
+    operator: Operator,
+    location_prefix: String,
+
+    /// Blocks collected from the input.
+    blocks: Vec<DataBlock>,
+    blocks_memory_size: usize,
+    /// Runs written to storage, every run is a sequence of sorted blocks.
+    spilled_runs: Vec<VecDeque<SpilledBlock>>,
+    /// The last run, which is kept in memory.
+    memory_run: VecDeque<DataBlock>,
+    merger: Option<Box<dyn SortedStreamMerger>>,
+    output_blocks: VecDeque<DataBlock>,
+}
+
+impl TransformSortSpill {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        output_schema: DataSchemaRef,
+        sort_columns_descriptions: Vec<SortColumnDescription>,
+        block_size: usize,
+        limit: Option<usize>,
+        spilling_bytes_threshold: usize,
+        operator: Operator,
+        location_prefix: String,
+    ) -> Box<dyn Processor> {
+        Box::new(TransformSortSpill {
+            input,
+            output,
+            state: State::Collect,
+            output_schema,
+            sort_columns_descriptions,
+            block_size,
+            limit,
+            spilling_bytes_threshold,
+            operator,
+            location_prefix,
+            blocks: vec![],
+            blocks_memory_size: 0,
+            spilled_runs: vec![],
+            memory_run: VecDeque::new(),
+            merger: None,
+            output_blocks: VecDeque::new(),
+        })
+    }
+
+    /// Sort the collected blocks into a run, which is split into blocks of `block_size` rows.
+    fn sort_run(&mut self) -> Result<Vec<DataBlock>> {
+        let blocks = std::mem::take(&mut self.blocks);
+        self.blocks_memory_size = 0;
+
+        let block = DataBlock::concat(&blocks)?;
+        let block = DataBlock::sort(&block, &self.sort_columns_descriptions, self.limit)?;
+
+        let num_rows = block.num_rows();
+        let mut run = Vec::with_capacity((num_rows + self.block_size - 1) / self.block_size);
+        let mut start = 0;
+        while start < num_rows {
+            let end = (start + self.block_size).min(num_rows);
+            run.push(block.slice(start..end));
+            start = end;
+        }
+        Ok(run)
+    }
+
+    /// Delete the remaining spilled blocks before finishing.
+    fn finish(&mut self) -> Event {
+        if self.spilled_runs.iter().any(|run| !run.is_empty()) {
+            self.state = State::Cleanup;
+            return Event::Async;
+        }
+        Event::Finished
+    }
+
+    // Loop until the merger requires the next block of a spilled run or outputs a block.
+    fn merge(&mut self) -> Result<()> {
+        let merger = self.merger.as_mut().unwrap();
+        loop {
+            if let Some(&stream) = merger.pending_streams().first() {
+                if stream == self.spilled_runs.len() {
+                    merger.push_block(stream, self.memory_run.pop_front())?;
+                    continue;
+                }
+                self.state = State::Read(stream);
+                return Ok(());
+            }
+
+            if let Some(block) = merger.next_block()? {
+                self.output_blocks.push_back(block);
+                return Ok(());
+            }
+
+            if merger.is_finished() {
+                self.state = State::Finished;
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSortSpill {
+    fn name(&self) -> String {
+        String::from("TransformSortSpill")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(self.finish());
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match &self.state {
+            State::Collect => {
+                if self.input.has_data() {
+                    let block = self.input.pull_data().unwrap()?;
+                    if !block.is_empty() {
+                        self.blocks_memory_size += block.memory_size();
+                        self.blocks.push(block);
+                    }
+
+                    if self.blocks_memory_size > self.spilling_bytes_threshold {
+                        self.input.set_not_need_data();
+                        self.state = State::SortRun;
+                        return Ok(Event::Sync);
+                    }
+                }
+
+                if self.input.is_finished() {
+                    self.state = State::SortRun;
+                    return Ok(Event::Sync);
+                }
+
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+            State::SortRun | State::Merge => Ok(Event::Sync),
+            State::Spill(_) | State::Read(_) | State::Cleanup => Ok(Event::Async),
+            State::Finished => {
+                self.output.finish();
+                Ok(self.finish())
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finished) {
+            State::SortRun => {
+                let run = if self.blocks.is_empty() {
+                    vec![]
+                } else {
+                    self.sort_run()?
+                };
+
+                if !self.input.is_finished() {
+                    self.state = State::Spill(run);
+                    return Ok(());
+                }
+
+                if self.spilled_runs.is_empty() {
+                    // All data fits in memory, no need to merge.
+                    self.output_blocks.extend(run);
+                    self.state = State::Finished;
+                    return Ok(());
+                }
+
+                // The spilled runs are followed by the run in memory.
+                self.memory_run = run.into();
+                self.merger = Some(try_create_sorted_stream_merger(
+                    self.spilled_runs.len() + 1,
+                    self.output_schema.clone(),
+                    self.block_size,
+                    self.limit,
+                    self.sort_columns_descriptions.clone(),
+                )?);
+                self.state = State::Merge;
+                self.merge()
+            }
+            State::Merge => {
+                self.state = State::Merge;
+                self.merge()
+            }
+            _ => Err(ErrorCode::Internal("It's a bug.")),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finished) {
+            State::Spill(run) => {
+                // The run is recorded before writing, so that the written blocks can be
+                // deleted when the processor is dropped because of an error.
+                self.spilled_runs.push(VecDeque::with_capacity(run.len()));
+                for block in run {
+                    let instant = Instant::now();
+                    let mut columns_layout = Vec::with_capacity(block.num_columns());
+                    let mut write_data = Vec::with_capacity(block.memory_size());
+                    for entry in block.convert_to_full().columns() {
+                        let column_data = serialize_column(entry.value.as_column().unwrap());
+                        columns_layout.push(column_data.len());
+                        write_data.extend(column_data);
+                    }
+
+                    let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+                    let spilled_run = self.spilled_runs.last_mut().unwrap();
+                    spilled_run.push_back(SpilledBlock {
+                        location: location.clone(),
+                        columns_layout,
+                    });
+                    self.operator.write(&location, write_data).await?;
+
+                    info!(
+                        "Write sort spill {} successfully, elapsed: {:?}",
+                        location,
+                        instant.elapsed()
+                    );
+                }
+
+                if self.spilled_runs.last().map_or(false, |run| run.is_empty()) {
+                    self.spilled_runs.pop();
+                }
+                self.state = State::Collect;
+                Ok(())
+            }
+            State::Read(stream) => {
+                let block = match self.spilled_runs[stream].pop_front() {
+                    None => None,
+                    Some(spilled) => {
+                        let instant = Instant::now();
+                        let data = match self.operator.read(&spilled.location).await {
+                            Ok(data) => data,
+                            Err(cause) => {
+                                // Keep the block to be deleted when the processor is dropped.
+                                self.spilled_runs[stream].push_front(spilled);
+                                return Err(ErrorCode::from(cause));
+                            }
+                        };
+
+                        if let Err(cause) = self.operator.delete(&spilled.location).await {
+                            error!(
+                                "Cannot delete spill file {}, cause: {:?}",
+                                &spilled.location, cause
+                            );
+                        }
+
+                        info!(
+                            "Read sort spill {} successfully, elapsed: {:?}",
+                            &spilled.location,
+                            instant.elapsed()
+                        );
+
+                        let mut begin = 0;
+                        let mut columns = Vec::with_capacity(spilled.columns_layout.len());
+                        for column_layout in spilled.columns_layout {
+                            let column = deserialize_column(&data[begin..begin + column_layout])
+                                .ok_or_else(|| {
+                                    ErrorCode::Internal(format!(
+                                        "Cannot deserialize sort spill {}",
+                                        &spilled.location
+                                    ))
+                                })?;
+                            columns.push(column);
+                            begin += column_layout;
+                        }
+                        Some(DataBlock::new_from_columns(columns))
+                    }
+                };
+
+                self.merger.as_mut().unwrap().push_block(stream, block)?;
+                self.state = State::Merge;
+                Ok(())
+            }
+            State::Cleanup => {
+                for spilled in self.spilled_runs.iter_mut().flat_map(|run| run.drain(..)) {
+                    if let Err(cause) = self.operator.delete(&spilled.location).await {
+                        error!(
+                            "Cannot delete spill file {}, cause: {:?}",
+                            &spilled.location, cause
+                        );
+                    }
+                }
+                self.state = State::Finished;
+                Ok(())
+            }
+            _ => Err(ErrorCode::Internal("It's a bug.")),
+        }
+    }
+}
+
+impl Drop for TransformSortSpill {
+    /// The spilled blocks are left in storage if the query fails or is aborted before
+    /// the merging finishes, delete them in background.
+    fn drop(&mut self) {
+        let locations = self
+            .spilled_runs
+            .iter_mut()
+            .flat_map(|run| run.drain(..))
+            .map(|spilled| spilled.location)
+            .collect::<Vec<_>>();
+        if locations.is_empty() {
+            return;
+        }
+
+        let operator = self.operator.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            for location in locations {
+                if let Err(cause) = operator.delete(&location).await {
+                    error!("Cannot delete spill file {}, cause: {:?}", &location, cause);
+                }
+            }
+        });
+    }
+}
@@ -27,6 +27,7 @@ use common_expression::BlockEntry;
 use common_expression::Column;
 use common_expression::ColumnBuilder;
 use common_expression::DataBlock;
+use common_expression::Scalar;
 use common_expression::ScalarRef;
 use common_expression::Value;
 use common_pipeline_core::processors::port::InputPort;
@@ -81,6 +82,9 @@ pub struct TransformWindow {
 
     /// monotonically increasing index of the current block in the queue.
     first_block: usize,
+    /// Index of the next block to be output. The blocks before it may be still
+    /// kept in the queue if they are referenced by the current partition or frame.
+    next_output_block: usize,
 
     // Partition: [`partition_start`, `partition_end`). `partition_end` is excluded.
     partition_start: RowPtr,
@@ -106,6 +110,10 @@ pub struct TransformWindow {
     current_rank: usize,
     current_rank_count: usize,
     current_dense_rank: usize,
+    // Used for ntile, percent_rank and cume_dist
+    partition_rows: usize,
+    // Used for cume_dist, the end of the peer group of the current row (excluded)
+    current_peer_end: RowPtr,
 }
 
 impl TransformWindow {
@@ -148,6 +156,7 @@ impl TransformWindow {
             blocks: VecDeque::new(),
             outputs: VecDeque::new(),
             first_block: 0,
+            next_output_block: 0,
             partition_start: RowPtr::default(),
             partition_end: RowPtr::default(),
             partition_ended: false,
@@ -163,6 +172,8 @@ impl TransformWindow {
             current_rank: 1,
             current_rank_count: 1,
             current_dense_rank: 1,
+            partition_rows: 0,
+            current_peer_end: RowPtr::default(),
             input_is_finished: false,
         })
     }
@@ -345,7 +356,7 @@ impl TransformWindow {
 
     // Advance the current row to the next row
     // if the current row is the last row of the current block, advance the current block and row = 0
-    fn advance_row(&mut self, mut row: RowPtr) -> RowPtr {
+    fn advance_row(&self, mut row: RowPtr) -> RowPtr {
         if row == self.blocks_end() {
             return row;
         }
@@ -358,6 +369,84 @@ impl TransformWindow {
         row
     }
 
+    // Go back to the previous row, the row should not be the first row of the queue.
+    fn goback_row(&self, mut row: RowPtr) -> RowPtr {
+        if row.row > 0 {
+            row.row -= 1;
+        } else {
+            row.block -= 1;
+            row.row = self.block_rows(row) - 1;
+        }
+        row
+    }
+
+    /// Number of rows in [`start`, `end`).
+    fn rows_between(&self, start: RowPtr, end: RowPtr) -> usize {
+        debug_assert!(start <= end);
+        if start.block == end.block {
+            return end.row - start.row;
+        }
+        let mut rows = self.block_rows(start) - start.row;
+        for block in start.block + 1..end.block {
+            rows += self.block_rows(RowPtr::new(block, 0));
+        }
+        rows + end.row
+    }
+
+    #[inline]
+    fn is_null_at(&self, row: RowPtr, column_index: usize) -> bool {
+        matches!(
+            self.column_at(row, column_index).index(row.row),
+            None | Some(ScalarRef::Null)
+        )
+    }
+
+    /// Find the `n`-th (starts from 1) row in [`start`, `end`) whose value of the column is not NULL.
+    /// Search from `start` if `forward` is true, otherwise from `end`.
+    fn nth_not_null_row(
+        &self,
+        start: RowPtr,
+        end: RowPtr,
+        n: usize,
+        column_index: usize,
+        forward: bool,
+    ) -> Option<RowPtr> {
+        let mut count = 0;
+        if forward {
+            let mut row = start;
+            while row < end {
+                if !self.is_null_at(row, column_index) {
+                    count += 1;
+                    if count == n {
+                        return Some(row);
+                    }
+                }
+                row = self.advance_row(row);
+            }
+        } else {
+            let mut row = end;
+            while row > start {
+                row = self.goback_row(row);
+                if !self.is_null_at(row, column_index) {
+                    count += 1;
+                    if count == n {
+                        return Some(row);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// The end of the peer group (rows with the same order by keys) of the current row.
+    fn peer_group_end(&self) -> RowPtr {
+        let mut row = self.advance_row(self.current_row);
+        while row < self.partition_end && self.is_order_by_keys_equal(self.current_row, row) {
+            row = self.advance_row(row);
+        }
+        row
+    }
+
     #[inline]
     fn is_order_by_keys_equal(&self, lhs: RowPtr, rhs: RowPtr) -> bool {
         debug_assert!({
@@ -378,7 +467,19 @@ impl TransformWindow {
     fn needs_rank(&self) -> bool {
         matches!(
             self.func,
-            WindowFunctionImpl::Rank | WindowFunctionImpl::DenseRank
+            WindowFunctionImpl::Rank
+                | WindowFunctionImpl::DenseRank
+                | WindowFunctionImpl::PercentRank
+        )
+    }
+
+    #[inline(always)]
+    fn needs_partition_rows(&self) -> bool {
+        matches!(
+            self.func,
+            WindowFunctionImpl::Ntile(_)
+                | WindowFunctionImpl::PercentRank
+                | WindowFunctionImpl::CumeDist
         )
     }
 
@@ -479,22 +580,43 @@ impl TransformWindow {
     }
 
     fn check_outputs(&mut self) {
-        while let Some(WindowBlock { block, builder }) = self.blocks.front() {
-            if block.num_rows() == builder.len() {
-                let WindowBlock { mut block, builder } = self.blocks.pop_front().unwrap();
-                let new_column = builder.build();
-                block.add_column(BlockEntry {
-                    data_type: new_column.data_type(),
-                    value: Value::Column(new_column),
-                });
-                self.outputs.push_back(block);
-                self.first_block += 1;
-            } else {
+        let blocks_end = self.blocks_end().block;
+        while self.next_output_block < blocks_end {
+            let WindowBlock { block, builder } =
+                &mut self.blocks[self.next_output_block - self.first_block];
+            if block.num_rows() != builder.len() {
                 break;
             }
+            let data_type = builder.data_type();
+            let new_column =
+                std::mem::replace(builder, ColumnBuilder::with_capacity(&data_type, 0)).build();
+            let mut block = block.clone();
+            block.add_column(BlockEntry {
+                data_type: new_column.data_type(),
+                value: Value::Column(new_column),
+            });
+            self.outputs.push_back(block);
+            self.next_output_block += 1;
+        }
+
+        // Free the output blocks which are not referenced by the current partition and frame.
+        let first_needed_block = self
+            .partition_start
+            .min(self.frame_start)
+            .min(self.prev_frame_start)
+            .block
+            .min(self.next_output_block);
+        while self.first_block < first_needed_block {
+            self.blocks.pop_front();
+            self.first_block += 1;
         }
     }
 
+    #[inline]
+    fn has_pending_blocks(&self) -> bool {
+        self.next_output_block < self.blocks_end().block
+    }
+
     fn apply_aggregate(&self, agg: &WindowFuncAggImpl) -> Result<()> {
         let WindowFuncFrame {
             start_bound,
@@ -514,7 +636,86 @@ impl TransformWindow {
 
     #[inline]
     fn merge_result_of_current_row(&mut self) -> Result<()> {
+        if self.current_row_in_partition == 1 && self.needs_partition_rows() {
+            // The frame of these functions is the whole partition, so the partition is ended.
+            debug_assert!(self.partition_ended);
+            self.partition_rows = self.rows_between(self.partition_start, self.partition_end);
+        }
+
+        let value = match &self.func {
+            WindowFunctionImpl::PercentRank => {
+                let percent = if self.partition_rows <= 1 {
+                    0_f64
+                } else {
+                    (self.current_rank - 1) as f64 / (self.partition_rows - 1) as f64
+                };
+                Some(Scalar::Number(NumberScalar::Float64(percent.into())))
+            }
+            WindowFunctionImpl::CumeDist => {
+                if self.current_row >= self.current_peer_end {
+                    self.current_peer_end = self.peer_group_end();
+                }
+                let rows = self.rows_between(self.partition_start, self.current_peer_end);
+                let cume_dist = rows as f64 / self.partition_rows as f64;
+                Some(Scalar::Number(NumberScalar::Float64(cume_dist.into())))
+            }
+            WindowFunctionImpl::Ntile(n) => {
+                // The first `remainder` buckets have `bucket_rows + 1` rows.
+                let bucket_rows = self.partition_rows / *n;
+                let remainder = self.partition_rows % *n;
+                let row = self.current_row_in_partition - 1;
+                let bucket = if row < remainder * (bucket_rows + 1) {
+                    row / (bucket_rows + 1)
+                } else {
+                    remainder + (row - remainder * (bucket_rows + 1)) / bucket_rows
+                };
+                Some(Scalar::Number(NumberScalar::UInt64(bucket as u64 + 1)))
+            }
+            WindowFunctionImpl::LagLead(lag_lead) => {
+                let row = if !lag_lead.ignore_nulls || lag_lead.offset == 0 {
+                    // The frame only contains the offset row.
+                    (self.frame_start < self.frame_end).then_some(self.frame_start)
+                } else {
+                    self.nth_not_null_row(
+                        self.frame_start,
+                        self.frame_end,
+                        lag_lead.offset,
+                        lag_lead.arg,
+                        !lag_lead.is_lag,
+                    )
+                };
+                let value = match (row, lag_lead.default) {
+                    (Some(row), _) => self.column_at(row, lag_lead.arg).index(row.row),
+                    (None, Some(default)) => self
+                        .column_at(self.current_row, default)
+                        .index(self.current_row.row),
+                    (None, None) => None,
+                };
+                Some(value.map_or(Scalar::Null, |value| value.to_owned()))
+            }
+            WindowFunctionImpl::NthValue(nth) => {
+                let (start, end) = (self.frame_start, self.frame_end);
+                let row = match (nth.n, nth.ignore_nulls) {
+                    _ if start >= end => None,
+                    (Some(n), false) => {
+                        let row = self.add_rows_within_partition(start, n - 1);
+                        (row < end).then_some(row)
+                    }
+                    (None, false) => Some(self.goback_row(end)),
+                    (Some(n), true) => self.nth_not_null_row(start, end, n, nth.arg, true),
+                    (None, true) => self.nth_not_null_row(start, end, 1, nth.arg, false),
+                };
+                let value = row.and_then(|row| self.column_at(row, nth.arg).index(row.row));
+                Some(value.map_or(Scalar::Null, |value| value.to_owned()))
+            }
+            _ => None,
+        };
+
         let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
+        if let Some(value) = value {
+            builder.push(value.as_ref());
+            return Ok(());
+        }
 
         match &self.func {
             WindowFunctionImpl::Aggregate(agg) => {
@@ -535,6 +736,7 @@ impl TransformWindow {
                     self.current_dense_rank as u64,
                 )));
             }
+            _ => unreachable!(),
         };
 
         Ok(())
@@ -638,7 +840,7 @@ impl Processor for TransformWindow {
                     (true, _) => {
                         // input_is_finished should be set after adding block.
                         self.input_is_finished = true;
-                        if !self.blocks.is_empty() {
+                        if self.has_pending_blocks() {
                             self.state = ProcessorState::AddBlock(None);
                             Ok(Event::Sync)
                         } else {
@@ -705,6 +907,7 @@ mod tests {
     use super::TransformWindow;
     use super::WindowBlock;
     use crate::pipelines::processors::transforms::window::transform_window::RowPtr;
+    use crate::pipelines::processors::transforms::window::window_function::WindowFuncLagLeadImpl;
     use crate::pipelines::processors::transforms::window::WindowFunctionInfo;
 
     fn get_transform_window(
@@ -1350,6 +1553,97 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_general_window_functions() -> Result<()> {
+        let input = || DataBlock::new_from_columns(vec![Int32Type::from_data(vec![1, 1, 1, 2, 2])]);
+
+        {
+            // lag(a, 1) over (partition by a)
+            let func = WindowFunctionInfo::LagLead(WindowFuncLagLeadImpl {
+                is_lag: true,
+                offset: 1,
+                arg: 0,
+                default: None,
+                ignore_nulls: false,
+                return_type: DataType::Number(NumberDataType::Int32).wrap_nullable(),
+            });
+            let mut transform = TransformWindow::create(
+                InputPort::create(),
+                OutputPort::create(),
+                func,
+                vec![0],
+                vec![],
+                WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound: WindowFuncFrameBound::Preceding(Some(1)),
+                    end_bound: WindowFuncFrameBound::Preceding(Some(1)),
+                },
+            )?;
+
+            transform.add_block(Some(input()))?;
+            transform.input_is_finished = true;
+            transform.add_block(None)?;
+            transform.check_outputs();
+
+            let output = transform.outputs.pop_front().unwrap();
+
+            assert_blocks_eq(
+                vec![
+                    "+----------+----------+",
+                    "| Column 0 | Column 1 |",
+                    "+----------+----------+",
+                    "| 1        | NULL     |",
+                    "| 1        | 1        |",
+                    "| 1        | 1        |",
+                    "| 2        | NULL     |",
+                    "| 2        | 2        |",
+                    "+----------+----------+",
+                ],
+                &[output],
+            );
+        }
+
+        {
+            // ntile(2) over (partition by a)
+            let mut transform = TransformWindow::create(
+                InputPort::create(),
+                OutputPort::create(),
+                WindowFunctionInfo::Ntile(2),
+                vec![0],
+                vec![],
+                WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound: WindowFuncFrameBound::Preceding(None),
+                    end_bound: WindowFuncFrameBound::Following(None),
+                },
+            )?;
+
+            transform.add_block(Some(input()))?;
+            transform.input_is_finished = true;
+            transform.add_block(None)?;
+            transform.check_outputs();
+
+            let output = transform.outputs.pop_front().unwrap();
+
+            assert_blocks_eq(
+                vec![
+                    "+----------+----------+",
+                    "| Column 0 | Column 1 |",
+                    "+----------+----------+",
+                    "| 1        | 1        |",
+                    "| 1        | 1        |",
+                    "| 1        | 2        |",
+                    "| 2        | 1        |",
+                    "| 2        | 2        |",
+                    "+----------+----------+",
+                ],
+                &[output],
+            );
+        }
+
+        Ok(())
+    }
+
     #[allow(clippy::type_complexity)]
     fn get_transform_window_and_ports(
         window_frame: WindowFuncFrame,
@@ -35,6 +35,11 @@ pub enum WindowFunctionInfo {
     RowNumber,
     Rank,
     DenseRank,
+    PercentRank,
+    CumeDist,
+    Ntile(usize),
+    LagLead(WindowFuncLagLeadImpl),
+    NthValue(WindowFuncNthValueImpl),
 }
 
 pub struct WindowFuncAggImpl {
@@ -86,11 +91,35 @@ impl Drop for WindowFuncAggImpl {
     }
 }
 
+#[derive(Clone)]
+pub struct WindowFuncLagLeadImpl {
+    pub is_lag: bool,
+    pub offset: usize,
+    pub arg: usize,
+    pub default: Option<usize>,
+    pub ignore_nulls: bool,
+    pub return_type: DataType,
+}
+
+#[derive(Clone)]
+pub struct WindowFuncNthValueImpl {
+    /// `None` means the last row of the frame.
+    pub n: Option<usize>,
+    pub arg: usize,
+    pub ignore_nulls: bool,
+    pub return_type: DataType,
+}
+
 pub enum WindowFunctionImpl {
     Aggregate(WindowFuncAggImpl),
     RowNumber,
     Rank,
     DenseRank,
+    PercentRank,
+    CumeDist,
+    Ntile(usize),
+    LagLead(WindowFuncLagLeadImpl),
+    NthValue(WindowFuncNthValueImpl),
 }
 
 impl WindowFunctionInfo {
@@ -115,6 +144,29 @@ impl WindowFunctionInfo {
             WindowFunction::RowNumber => Self::RowNumber,
             WindowFunction::Rank => Self::Rank,
             WindowFunction::DenseRank => Self::DenseRank,
+            WindowFunction::PercentRank => Self::PercentRank,
+            WindowFunction::CumeDist => Self::CumeDist,
+            WindowFunction::Ntile(n) => Self::Ntile(*n as usize),
+            WindowFunction::LagLead(lag_lead) => {
+                let default = match lag_lead.default {
+                    Some(default) => Some(schema.index_of(&default.to_string())?),
+                    None => None,
+                };
+                Self::LagLead(WindowFuncLagLeadImpl {
+                    is_lag: lag_lead.is_lag,
+                    offset: lag_lead.offset as usize,
+                    arg: schema.index_of(&lag_lead.arg.to_string())?,
+                    default,
+                    ignore_nulls: lag_lead.ignore_nulls,
+                    return_type: lag_lead.return_type.clone(),
+                })
+            }
+            WindowFunction::NthValue(nth) => Self::NthValue(WindowFuncNthValueImpl {
+                n: nth.n.map(|n| n as usize),
+                arg: schema.index_of(&nth.arg.to_string())?,
+                ignore_nulls: nth.ignore_nulls,
+                return_type: nth.return_type.clone(),
+            }),
         })
     }
 }
@@ -138,15 +190,23 @@ impl WindowFunctionImpl {
             WindowFunctionInfo::RowNumber => Self::RowNumber,
             WindowFunctionInfo::Rank => Self::Rank,
             WindowFunctionInfo::DenseRank => Self::DenseRank,
+            WindowFunctionInfo::PercentRank => Self::PercentRank,
+            WindowFunctionInfo::CumeDist => Self::CumeDist,
+            WindowFunctionInfo::Ntile(n) => Self::Ntile(n),
+            WindowFunctionInfo::LagLead(lag_lead) => Self::LagLead(lag_lead),
+            WindowFunctionInfo::NthValue(nth) => Self::NthValue(nth),
         })
     }
 
     pub fn return_type(&self) -> Result<DataType> {
         Ok(match self {
             Self::Aggregate(agg) => agg.agg.return_type()?,
-            Self::RowNumber | Self::Rank | Self::DenseRank => {
+            Self::RowNumber | Self::Rank | Self::DenseRank | Self::Ntile(_) => {
                 DataType::Number(NumberDataType::UInt64)
             }
+            Self::PercentRank | Self::CumeDist => DataType::Number(NumberDataType::Float64),
+            Self::LagLead(lag_lead) => lag_lead.return_type.clone(),
+            Self::NthValue(nth) => nth.return_type.clone(),
         })
     }
 }
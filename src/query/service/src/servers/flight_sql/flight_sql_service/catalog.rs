@@ -14,22 +14,34 @@
 
 use std::sync::Arc;
 
+use arrow_array::builder::BinaryBuilder;
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
+use arrow_flight::sql::CommandGetPrimaryKeys;
+use arrow_flight::sql::CommandGetTables;
 use arrow_flight::utils::batches_to_flight_data;
+use arrow_flight::IpcMessage;
+use arrow_flight::SchemaAsIpc;
+use arrow_ipc::writer::IpcWriteOptions;
 use arrow_schema::DataType;
 use arrow_schema::Field;
 use arrow_schema::Schema;
 use common_catalog::catalog::Catalog;
 use common_catalog::catalog::CatalogManager;
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
+use common_expression::DataSchema;
 use futures_util::stream;
 use tonic::Status;
 
 use crate::servers::flight_sql::flight_sql_service::DoGetStream;
 
+const VIEW_ENGINE: &str = "VIEW";
+const TABLE_TYPE_TABLE: &str = "table";
+const TABLE_TYPE_VIEW: &str = "view";
+
 pub(super) struct CatalogInfoProvider {}
 
 impl CatalogInfoProvider {
@@ -44,41 +56,66 @@ impl CatalogInfoProvider {
         Ok(Box::pin(stream))
     }
 
-    async fn get_tables_internal(
-        ctx: Arc<dyn TableContext>,
+    fn catalogs(
         catalog_name: Option<String>,
-        database_name: Option<String>,
-    ) -> common_exception::Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>)> {
-        let tenant = ctx.get_tenant();
+    ) -> common_exception::Result<Vec<(String, Arc<dyn Catalog>)>> {
         let catalog_mgr = CatalogManager::instance();
-        let catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name {
-            vec![(
+        if let Some(catalog_name) = catalog_name {
+            Ok(vec![(
                 catalog_name.clone(),
                 catalog_mgr.get_catalog(&catalog_name)?,
-            )]
+            )])
         } else {
-            catalog_mgr
+            let mut catalogs: Vec<(String, Arc<dyn Catalog>)> = catalog_mgr
                 .catalogs
                 .iter()
                 .map(|r| (r.key().to_string(), r.value().clone()))
-                .collect()
-        };
+                .collect();
+            catalogs.sort_by(|a, b| a.0.cmp(&b.0));
+            Ok(catalogs)
+        }
+    }
 
+    async fn get_schemas_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+    ) -> common_exception::Result<(Vec<String>, Vec<String>)> {
+        let tenant = ctx.get_tenant();
+        let mut catalog_names = vec![];
+        let mut database_names = vec![];
+        for (catalog_name, catalog) in Self::catalogs(catalog_name)?.into_iter() {
+            let dbs = catalog.list_databases(tenant.as_str()).await?;
+            for db in dbs {
+                if like_match(db_schema_filter_pattern.as_deref(), db.name()) {
+                    catalog_names.push(catalog_name.clone());
+                    database_names.push(db.name().to_string());
+                }
+            }
+        }
+        Ok((catalog_names, database_names))
+    }
+
+    async fn get_tables_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+        table_name_filter_pattern: Option<String>,
+        table_types: &[String],
+    ) -> common_exception::Result<(Vec<String>, Vec<String>, Vec<Arc<dyn Table>>)> {
+        let tenant = ctx.get_tenant();
         let mut catalog_names = vec![];
         let mut database_names = vec![];
-        let mut table_names = vec![];
-        let mut table_types = vec![];
-        let table_type = "table".to_string();
-        for (catalog_name, catalog) in catalogs.into_iter() {
-            let dbs = if let Some(database_name) = &database_name {
-                vec![catalog.get_database(tenant.as_str(), database_name).await?]
-            } else {
-                catalog.list_databases(tenant.as_str()).await?
-            };
+        let mut tables = vec![];
+        for (catalog_name, catalog) in Self::catalogs(catalog_name)?.into_iter() {
+            let dbs = catalog.list_databases(tenant.as_str()).await?;
             for db in dbs {
+                if !like_match(db_schema_filter_pattern.as_deref(), db.name()) {
+                    continue;
+                }
                 let db_name = db.name().to_string().into_boxed_str();
                 let db_name: &str = Box::leak(db_name);
-                let tables = match catalog.list_tables(tenant.as_str(), db_name).await {
+                let db_tables = match catalog.list_tables(tenant.as_str(), db_name).await {
                     Ok(tables) => tables,
                     Err(err) if err.code() == ErrorCode::EMPTY_SHARE_ENDPOINT_CONFIG => {
                         tracing::warn!("list tables failed on db {}: {}", db.name(), err);
@@ -86,42 +123,164 @@ impl CatalogInfoProvider {
                     }
                     Err(err) => return Err(err),
                 };
-                for table in tables {
+                for table in db_tables {
+                    if !like_match(table_name_filter_pattern.as_deref(), table.name()) {
+                        continue;
+                    }
+                    let table_type = Self::table_type(table.as_ref());
+                    if !table_types.is_empty()
+                        && !table_types
+                            .iter()
+                            .any(|t| t.eq_ignore_ascii_case(table_type))
+                    {
+                        continue;
+                    }
                     catalog_names.push(catalog_name.clone());
                     database_names.push(db_name.to_string());
-                    table_names.push(table.name().to_string());
-                    table_types.push(table_type.clone());
+                    tables.push(table);
                 }
             }
         }
-        Ok((catalog_names, database_names, table_names, table_types))
+        Ok((catalog_names, database_names, tables))
     }
 
-    pub(crate) async fn get_tables(
+    fn table_type(table: &dyn Table) -> &'static str {
+        if table.engine().eq_ignore_ascii_case(VIEW_ENGINE) {
+            TABLE_TYPE_VIEW
+        } else {
+            TABLE_TYPE_TABLE
+        }
+    }
+
+    pub(crate) fn get_catalogs() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "catalog_name",
+            DataType::Utf8,
+            false,
+        )]));
+        let catalog_names = Self::catalogs(None)
+            .map_err(|e| Status::internal(format!("{e:?}")))?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(catalog_names)])
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) async fn get_schemas(
         ctx: Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
     ) -> Result<DoGetStream, Status> {
         let schema = Arc::new(Schema::new(vec![
             Field::new("catalog_name", DataType::Utf8, false),
             Field::new("db_schema_name", DataType::Utf8, false),
-            Field::new("table_name", DataType::Utf8, false),
-            Field::new("table_type", DataType::Utf8, false),
         ]));
-        let (catalog_name, db_schema_name, table_name, table_type) =
-            Self::get_tables_internal(ctx.clone(), catalog_name, database_name)
+        let (catalog_name, db_schema_name) =
+            Self::get_schemas_internal(ctx.clone(), catalog_name, db_schema_filter_pattern)
                 .await
                 .map_err(|e| Status::internal(format!("{e:?}")))?;
         let batch = RecordBatch::try_new(schema, vec![
             Self::string_array(catalog_name),
             Self::string_array(db_schema_name),
+        ])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) async fn get_tables(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetTables,
+    ) -> Result<DoGetStream, Status> {
+        let mut fields = vec![
+            Field::new("catalog_name", DataType::Utf8, false),
+            Field::new("db_schema_name", DataType::Utf8, false),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("table_type", DataType::Utf8, false),
+        ];
+        if query.include_schema {
+            fields.push(Field::new("table_schema", DataType::Binary, false));
+        }
+        let schema = Arc::new(Schema::new(fields));
+        let (catalog_name, db_schema_name, tables) = Self::get_tables_internal(
+            ctx.clone(),
+            query.catalog,
+            query.db_schema_filter_pattern,
+            query.table_name_filter_pattern,
+            &query.table_types,
+        )
+        .await
+        .map_err(|e| Status::internal(format!("{e:?}")))?;
+
+        let table_name = tables.iter().map(|t| t.name().to_string()).collect();
+        let table_type = tables
+            .iter()
+            .map(|t| Self::table_type(t.as_ref()).to_string())
+            .collect();
+        let mut columns = vec![
+            Self::string_array(catalog_name),
+            Self::string_array(db_schema_name),
             Self::string_array(table_name),
             Self::string_array(table_type),
-        ])
+        ];
+        if query.include_schema {
+            let mut builder = BinaryBuilder::new();
+            for table in tables.iter() {
+                let data_schema = DataSchema::from(table.schema());
+                let arrow_schema = Schema::from(&data_schema);
+                let IpcMessage(bytes) =
+                    SchemaAsIpc::new(&arrow_schema, &IpcWriteOptions::default())
+                        .try_into()
+                        .map_err(|e| Status::internal(format!("{e:?}")))?;
+                builder.append_value(bytes);
+            }
+            columns.push(Arc::new(builder.finish()));
+        }
+        let batch = RecordBatch::try_new(schema, columns)
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) fn get_table_types() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "table_type",
+            DataType::Utf8,
+            false,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(vec![
+            TABLE_TYPE_TABLE.to_string(),
+            TABLE_TYPE_VIEW.to_string(),
+        ])])
         .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
         Self::batch_to_get_stream(batch)
     }
 
+    /// Databend has no primary key constraints, so the result is always empty once
+    /// the table is found.
+    pub(crate) async fn get_primary_keys(
+        ctx: Arc<dyn TableContext>,
+        query: CommandGetPrimaryKeys,
+    ) -> Result<DoGetStream, Status> {
+        let catalog_name = query.catalog.unwrap_or_else(|| ctx.get_current_catalog());
+        let database_name = query
+            .db_schema
+            .unwrap_or_else(|| ctx.get_current_database());
+        ctx.get_table(&catalog_name, &database_name, &query.table)
+            .await
+            .map_err(|e| Status::not_found(format!("{e:?}")))?;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int32, false),
+        ]));
+        Self::batch_to_get_stream(RecordBatch::new_empty(schema))
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
@@ -130,3 +289,33 @@ impl CatalogInfoProvider {
         Arc::new(builder.finish())
     }
 }
+
+/// Match a name against the filter pattern of Flight SQL, which follows the `LIKE` syntax:
+/// `%` matches any sequence of characters and `_` matches exactly one character.
+fn like_match(pattern: Option<&str>, name: &str) -> bool {
+    let pattern = match pattern {
+        Some(pattern) => pattern.chars().collect::<Vec<_>>(),
+        None => return true,
+    };
+    let name = name.chars().collect::<Vec<_>>();
+
+    let (mut p, mut n) = (0, 0);
+    // The position of the last `%` in pattern, and the position in name it has matched up to.
+    let mut backtrack = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '_' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '%' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((bp, bn)) = backtrack {
+            backtrack = Some((bp, bn + 1));
+            p = bp + 1;
+            n = bn + 1;
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '%')
+}
@@ -19,17 +19,17 @@ mod query;
 mod service;
 mod session;
 mod sql_info;
+mod statement;
 
 use std::pin::Pin;
 use std::sync::Arc;
 
 use arrow_flight::FlightData;
 use catalog::CatalogInfoProvider;
-use common_sql::plans::Plan;
-use common_sql::PlanExtras;
 use dashmap::DashMap;
 use futures::Stream;
 use sql_info::SqlInfoProvider;
+use statement::PlannedStatement;
 use tonic::Status;
 use uuid::Uuid;
 
@@ -50,7 +50,7 @@ type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send
 #[derive(Clone)]
 pub struct FlightSqlServiceImpl {
     sessions: Arc<DashMap<String, Arc<Session>>>,
-    statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    statements: Arc<DashMap<Uuid, PlannedStatement>>,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
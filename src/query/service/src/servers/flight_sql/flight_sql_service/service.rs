@@ -158,9 +158,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         tracing::info!("do_get_fallback with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let (plan, plan_extras) = self.get_statement(&handle)?;
         let stream = self
-            .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_query(session, &plan, &plan_extras)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let resp = Response::new(stream);
@@ -173,9 +173,26 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        tracing::info!("get_flight_info_sql_info(query={})", query.query);
-        let _session = self.get_session(&request)?;
-        Ok(simple_flight_info(query))
+        tracing::info!("get_flight_info_statement(query={})", query.query);
+        let session = self.get_session(&request)?;
+        let plan = self
+            .plan_sql(&session, &query.query)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+        let schema = plan.0.schema().as_ref().into();
+        let handle = Uuid::new_v4();
+        self.insert_statement(handle, plan);
+
+        let ticket = TicketStatementQuery {
+            statement_handle: handle.as_bytes().to_vec().into(),
+        };
+        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| status!("Unable to serialize schema", e))?;
+        let IpcMessage(schema_bytes) = message;
+        let mut resp = simple_flight_info(ticket);
+        resp.get_mut().schema = schema_bytes;
+        Ok(resp)
     }
 
     #[async_backtrace::framed]
@@ -190,8 +207,8 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         tracing::info!("get_flight_info_prepared_statement with handle={handle}");
 
-        let handle_plan_ref = self.statements.get(&handle).unwrap();
-        let schema = handle_plan_ref.value().0.schema().as_ref().into();
+        let (plan, _) = self.get_statement(&handle)?;
+        let schema = plan.schema().as_ref().into();
         let loc = Location {
             uri: "grpc+tcp://127.0.0.1".to_string(),
         };
@@ -286,12 +303,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         tracing::info!("get_flight_info_primary_keys({query:?})",);
-        Err(Status::unimplemented(
-            "get_flight_info_primary_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
@@ -335,42 +351,74 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_statement(
         &self,
         ticket: TicketStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        tracing::info!("do_get_statement({ticket:?}");
-        Err(Status::unimplemented("do_get_statement not implemented"))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(ticket.statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        tracing::info!("do_get_statement with handle={handle}");
+
+        // The statement is planned by `get_flight_info_statement` and can only be fetched once.
+        let (plan, plan_extras) = self.remove_statement(&handle)?;
+        let stream = self
+            .execute_query(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_prepared_statement(
         &self,
         query: CommandPreparedStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        tracing::info!("do_get_prepared_statement({query:?}");
-        Err(Status::unimplemented(
-            "do_get_prepared_statement not implemented",
-        ))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        tracing::info!("do_get_prepared_statement with handle={handle}");
+
+        let (plan, plan_extras) = self.get_statement(&handle)?;
+        let stream = self
+            .execute_query(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_catalogs(
         &self,
         _query: CommandGetCatalogs,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_catalogs()");
-        Err(Status::unimplemented("do_get_catalogs not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::CatalogInfoProvider::get_catalogs()?))
     }
 
     #[async_backtrace::framed]
     async fn do_get_schemas(
         &self,
         query: CommandGetDbSchemas,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        tracing::info!("do_get_schemas({query:?}");
-        Err(Status::unimplemented("do_get_schemas not implemented"))
+        tracing::info!("do_get_schemas({query:?})");
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_schemas(
+                context.clone(),
+                query.catalog.clone(),
+                query.db_schema_filter_pattern.clone(),
+            )
+            .await?,
+        ))
     }
 
     #[async_backtrace::framed]
@@ -386,8 +434,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
         Ok(Response::new(
-            super::CatalogInfoProvider::get_tables(context.clone(), query.catalog.clone(), None)
-                .await?,
+            super::CatalogInfoProvider::get_tables(context.clone(), query).await?,
         ))
     }
 
@@ -395,10 +442,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_table_types(
         &self,
         _query: CommandGetTableTypes,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_table_types()");
-        Err(Status::unimplemented("do_get_table_types not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::CatalogInfoProvider::get_table_types()?))
     }
 
     #[async_backtrace::framed]
@@ -415,10 +463,17 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         tracing::info!("do_get_primary_keys({query:?})");
-        Err(Status::unimplemented("do_get_primary_keys not implemented"))
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_primary_keys(context.clone(), query).await?,
+        ))
     }
 
     #[async_backtrace::framed]
@@ -491,9 +546,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         tracing::info!("do_put_prepared_statement_query with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let (plan, plan_extras) = self.get_statement(&handle)?;
         let record_count = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_update(session, &plan, &plan_extras)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let result = DoPutUpdateResult { record_count };
@@ -517,9 +572,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         tracing::info!("do_put_prepared_statement_update with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let (plan, plan_extras) = self.get_statement(&handle)?;
         let res = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_update(session, &plan, &plan_extras)
             .await
             .map_err(|e| status!("fail to execute", e))?;
 
@@ -555,7 +610,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
             query.query
         );
         let schema = (&*data_schema).into();
-        self.statements.insert(handle, plan);
+        self.insert_statement(handle, plan);
         let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
             .try_into()
             .map_err(|e| status!("Unable to serialize schema", e))?;
@@ -574,24 +629,17 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: ActionClosePreparedStatementRequest,
         request: Request<Action>,
     ) {
-        let handle = query.prepared_statement_handle.as_ref();
-        if let Ok(handle) = std::str::from_utf8(handle) {
-            tracing::info!(
-                "do_action_close_prepared_statement with handle {:?}",
-                handle
-            );
-            match Uuid::try_parse(handle) {
-                Ok(handle) => {
-                    if self.get_session(&request).is_ok() {
-                        self.statements.remove(&handle);
-                    }
-                }
-                Err(e) => {
-                    Status::internal(format!(
-                        "do_action_close_prepared_statement Error decoding handle: {e} {handle:?}"
-                    ));
+        // The handle is the raw uuid bytes returned by `do_action_create_prepared_statement`.
+        match Uuid::from_slice(query.prepared_statement_handle.as_ref()) {
+            Ok(handle) => {
+                tracing::info!("do_action_close_prepared_statement with handle={handle}");
+                if self.get_session(&request).is_ok() {
+                    let _ = self.remove_statement(&handle);
                 }
             }
+            Err(e) => {
+                tracing::warn!("do_action_close_prepared_statement Error decoding handle: {e}");
+            }
         }
     }
 
@@ -0,0 +1,72 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::uuid::Uuid;
+use common_sql::plans::Plan;
+use common_sql::PlanExtras;
+use tonic::Status;
+
+use super::FlightSqlServiceImpl;
+
+/// Statements not used for this long are evicted, so the handles of clients that
+/// never fetch or close them do not pile up.
+const STATEMENT_IDLE_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+pub(super) struct PlannedStatement {
+    plan: Plan,
+    plan_extras: PlanExtras,
+    last_used: Instant,
+}
+
+impl FlightSqlServiceImpl {
+    pub(super) fn insert_statement(&self, handle: Uuid, (plan, plan_extras): (Plan, PlanExtras)) {
+        self.evict_idle_statements();
+        self.statements.insert(handle, PlannedStatement {
+            plan,
+            plan_extras,
+            last_used: Instant::now(),
+        });
+    }
+
+    pub(super) fn get_statement(&self, handle: &Uuid) -> Result<(Plan, PlanExtras), Status> {
+        let mut statement = self
+            .statements
+            .get_mut(handle)
+            .ok_or_else(|| Status::not_found(format!("statement not found: {handle}")))?;
+        statement.last_used = Instant::now();
+        Ok((statement.plan.clone(), statement.plan_extras.clone()))
+    }
+
+    pub(super) fn remove_statement(&self, handle: &Uuid) -> Result<(Plan, PlanExtras), Status> {
+        let (_, statement) = self
+            .statements
+            .remove(handle)
+            .ok_or_else(|| Status::not_found(format!("statement not found: {handle}")))?;
+        Ok((statement.plan, statement.plan_extras))
+    }
+
+    fn evict_idle_statements(&self) {
+        let now = Instant::now();
+        self.statements.retain(|handle, statement| {
+            let keep = now.duration_since(statement.last_used) < STATEMENT_IDLE_TIMEOUT;
+            if !keep {
+                tracing::info!("evict idle statement with handle={handle}");
+            }
+            keep
+        });
+    }
+}
@@ -23,9 +23,13 @@ pub use self::http::HttpHandlerKind;
 pub use self::mysql::MySQLConnection;
 pub use self::mysql::MySQLFederated;
 pub use self::mysql::MySQLHandler;
+pub use self::postgres::PostgresConnection;
+pub use self::postgres::PostgresFederated;
+pub use self::postgres::PostgresHandler;
 
 pub(crate) mod federated_helper;
 pub mod flight_sql;
 pub mod http;
 mod mysql;
+mod postgres;
 pub(crate) mod server;
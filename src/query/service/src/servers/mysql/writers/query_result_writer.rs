@@ -165,10 +165,13 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                 },
                 DataType::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
                 DataType::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+                DataType::Interval => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Array(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Map(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Tuple(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Binary => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Decimal(_) => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
                 _ => Err(ErrorCode::Unimplemented(format!(
                     "Unsupported column type:{:?}",
@@ -0,0 +1,476 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+// https://www.postgresql.org/docs/current/protocol-message-formats.html
+const PROTOCOL_VERSION_3: i32 = 196608;
+const SSL_REQUEST_CODE: i32 = 80877103;
+const GSSENC_REQUEST_CODE: i32 = 80877104;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+// Reject any frontend message larger than 1GB, the same limit as PostgreSQL.
+const MAX_MESSAGE_SIZE: usize = 1 << 30;
+
+pub const FORMAT_TEXT: i16 = 0;
+pub const FORMAT_BINARY: i16 = 1;
+
+pub enum StartupRequest {
+    Ssl,
+    Gssenc,
+    Cancel { process_id: i32, secret_key: i32 },
+    Startup { params: HashMap<String, String> },
+}
+
+pub enum FrontendMessage {
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Password(Vec<u8>),
+    Sync,
+    Flush,
+    Terminate,
+    Unknown(u8),
+}
+
+struct MessageBuf {
+    data: Vec<u8>,
+    pos: usize,
+}
+
+impl MessageBuf {
+    fn get_bytes(&mut self, len: usize) -> Result<&[u8]> {
+        if self.pos + len > self.data.len() {
+            return Err(ErrorCode::BadBytes(
+                "invalid message format: message too short",
+            ));
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn get_u8(&mut self) -> Result<u8> {
+        Ok(self.get_bytes(1)?[0])
+    }
+
+    fn get_i16(&mut self) -> Result<i16> {
+        let bytes = self.get_bytes(2)?;
+        Ok(i16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn get_i32(&mut self) -> Result<i32> {
+        let bytes = self.get_bytes(4)?;
+        Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn get_cstr(&mut self) -> Result<String> {
+        let rest = &self.data[self.pos..];
+        match rest.iter().position(|b| *b == 0) {
+            None => Err(ErrorCode::BadBytes(
+                "invalid message format: unterminated string",
+            )),
+            Some(end) => {
+                let s = String::from_utf8(rest[..end].to_vec())
+                    .map_err(|e| ErrorCode::BadBytes(format!("invalid message format: {}", e)))?;
+                self.pos += end + 1;
+                Ok(s)
+            }
+        }
+    }
+
+    fn get_count(&mut self) -> Result<usize> {
+        let count = self.get_i16()?;
+        if count < 0 {
+            return Err(ErrorCode::BadBytes(
+                "invalid message format: negative count",
+            ));
+        }
+        Ok(count as usize)
+    }
+}
+
+#[async_backtrace::framed]
+async fn read_body<R: AsyncRead + Unpin>(reader: &mut R, len: i32) -> Result<MessageBuf> {
+    if len < 4 || len as usize - 4 > MAX_MESSAGE_SIZE {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid message length: {}",
+            len
+        )));
+    }
+    let mut data = vec![0; len as usize - 4];
+    reader.read_exact(&mut data).await?;
+    Ok(MessageBuf { data, pos: 0 })
+}
+
+/// Read the first message of a connection, which has no message type byte.
+#[async_backtrace::framed]
+pub async fn read_startup<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupRequest> {
+    let len = reader.read_i32().await?;
+    let mut buf = read_body(reader, len).await?;
+    match buf.get_i32()? {
+        SSL_REQUEST_CODE => Ok(StartupRequest::Ssl),
+        GSSENC_REQUEST_CODE => Ok(StartupRequest::Gssenc),
+        CANCEL_REQUEST_CODE => Ok(StartupRequest::Cancel {
+            process_id: buf.get_i32()?,
+            secret_key: buf.get_i32()?,
+        }),
+        PROTOCOL_VERSION_3 => {
+            let mut params = HashMap::new();
+            loop {
+                let name = buf.get_cstr()?;
+                if name.is_empty() {
+                    break;
+                }
+                let value = buf.get_cstr()?;
+                params.insert(name, value);
+            }
+            Ok(StartupRequest::Startup { params })
+        }
+        version => Err(ErrorCode::BadBytes(format!(
+            "unsupported frontend protocol {}.{}: server supports 3.0",
+            version >> 16,
+            version & 0xffff
+        ))),
+    }
+}
+
+/// Read a regular frontend message, returns None if the client has closed the connection.
+#[async_backtrace::framed]
+pub async fn read_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<FrontendMessage>> {
+    let tag = match reader.read_u8().await {
+        Ok(tag) => tag,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let len = reader.read_i32().await?;
+    let mut buf = read_body(reader, len).await?;
+
+    let message = match tag {
+        b'Q' => FrontendMessage::Query(buf.get_cstr()?),
+        b'P' => {
+            let name = buf.get_cstr()?;
+            let query = buf.get_cstr()?;
+            let count = buf.get_count()?;
+            let mut param_types = Vec::with_capacity(count);
+            for _ in 0..count {
+                param_types.push(buf.get_i32()? as u32);
+            }
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            }
+        }
+        b'B' => {
+            let portal = buf.get_cstr()?;
+            let statement = buf.get_cstr()?;
+            let count = buf.get_count()?;
+            let mut param_formats = Vec::with_capacity(count);
+            for _ in 0..count {
+                param_formats.push(buf.get_i16()?);
+            }
+            let count = buf.get_count()?;
+            let mut params = Vec::with_capacity(count);
+            for _ in 0..count {
+                let len = buf.get_i32()?;
+                if len < 0 {
+                    params.push(None);
+                } else {
+                    params.push(Some(buf.get_bytes(len as usize)?.to_vec()));
+                }
+            }
+            let count = buf.get_count()?;
+            let mut result_formats = Vec::with_capacity(count);
+            for _ in 0..count {
+                result_formats.push(buf.get_i16()?);
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            }
+        }
+        b'D' => FrontendMessage::Describe {
+            kind: buf.get_u8()?,
+            name: buf.get_cstr()?,
+        },
+        b'E' => FrontendMessage::Execute {
+            portal: buf.get_cstr()?,
+            max_rows: buf.get_i32()?,
+        },
+        b'C' => FrontendMessage::Close {
+            kind: buf.get_u8()?,
+            name: buf.get_cstr()?,
+        },
+        b'p' => {
+            let mut password = buf.data;
+            // The password is sent as a null-terminated string.
+            if password.last() == Some(&0) {
+                password.pop();
+            }
+            FrontendMessage::Password(password)
+        }
+        b'S' => FrontendMessage::Sync,
+        b'H' => FrontendMessage::Flush,
+        b'X' => FrontendMessage::Terminate,
+        other => FrontendMessage::Unknown(other),
+    };
+    Ok(Some(message))
+}
+
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+    pub format: i16,
+}
+
+/// Fields of the ErrorResponse and NoticeResponse messages.
+pub struct ErrorInfo {
+    pub severity: &'static str,
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl ErrorInfo {
+    pub fn error(code: &'static str, message: impl Into<String>) -> Self {
+        ErrorInfo {
+            severity: "ERROR",
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn fatal(code: &'static str, message: impl Into<String>) -> Self {
+        ErrorInfo {
+            severity: "FATAL",
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// Buffers backend messages, they are sent to the client on `flush`.
+pub struct BackendMessageWriter<W: AsyncWrite + Unpin> {
+    inner: W,
+    buf: Vec<u8>,
+}
+
+impl<W: AsyncWrite + Unpin> BackendMessageWriter<W> {
+    pub fn create(inner: W) -> Self {
+        BackendMessageWriter {
+            inner,
+            buf: Vec::with_capacity(8 * 1024),
+        }
+    }
+
+    fn begin(&mut self, tag: u8) -> usize {
+        self.buf.push(tag);
+        let pos = self.buf.len();
+        self.buf.extend_from_slice(&[0; 4]);
+        pos
+    }
+
+    fn end(&mut self, pos: usize) {
+        let len = (self.buf.len() - pos) as i32;
+        self.buf[pos..pos + 4].copy_from_slice(&len.to_be_bytes());
+    }
+
+    fn put_i16(&mut self, v: i16) {
+        self.buf.extend_from_slice(&v.to_be_bytes());
+    }
+
+    fn put_i32(&mut self, v: i32) {
+        self.buf.extend_from_slice(&v.to_be_bytes());
+    }
+
+    fn put_cstr(&mut self, s: &str) {
+        self.buf.extend_from_slice(s.as_bytes());
+        self.buf.push(0);
+    }
+
+    fn empty_message(&mut self, tag: u8) {
+        let pos = self.begin(tag);
+        self.end(pos);
+    }
+
+    /// The single byte answer to SSLRequest and GSSENCRequest.
+    pub fn ssl_not_supported(&mut self) {
+        self.buf.push(b'N');
+    }
+
+    pub fn authentication_ok(&mut self) {
+        let pos = self.begin(b'R');
+        self.put_i32(0);
+        self.end(pos);
+    }
+
+    pub fn authentication_cleartext_password(&mut self) {
+        let pos = self.begin(b'R');
+        self.put_i32(3);
+        self.end(pos);
+    }
+
+    pub fn parameter_status(&mut self, name: &str, value: &str) {
+        let pos = self.begin(b'S');
+        self.put_cstr(name);
+        self.put_cstr(value);
+        self.end(pos);
+    }
+
+    pub fn backend_key_data(&mut self, process_id: i32, secret_key: i32) {
+        let pos = self.begin(b'K');
+        self.put_i32(process_id);
+        self.put_i32(secret_key);
+        self.end(pos);
+    }
+
+    /// We do not support transactions, so the status is always idle.
+    pub fn ready_for_query(&mut self) {
+        let pos = self.begin(b'Z');
+        self.buf.push(b'I');
+        self.end(pos);
+    }
+
+    pub fn row_description(&mut self, fields: &[FieldDescription]) {
+        let pos = self.begin(b'T');
+        self.put_i16(fields.len() as i16);
+        for field in fields {
+            self.put_cstr(&field.name);
+            // table oid and column attribute number.
+            self.put_i32(0);
+            self.put_i16(0);
+            self.put_i32(field.type_oid as i32);
+            self.put_i16(field.type_size);
+            // type modifier.
+            self.put_i32(-1);
+            self.put_i16(field.format);
+        }
+        self.end(pos);
+    }
+
+    pub fn data_row(&mut self, values: &[Option<Vec<u8>>]) {
+        let pos = self.begin(b'D');
+        self.put_i16(values.len() as i16);
+        for value in values {
+            match value {
+                None => self.put_i32(-1),
+                Some(v) => {
+                    self.put_i32(v.len() as i32);
+                    self.buf.extend_from_slice(v);
+                }
+            }
+        }
+        self.end(pos);
+    }
+
+    pub fn parameter_description(&mut self, types: &[u32]) {
+        let pos = self.begin(b't');
+        self.put_i16(types.len() as i16);
+        for ty in types {
+            self.put_i32(*ty as i32);
+        }
+        self.end(pos);
+    }
+
+    pub fn command_complete(&mut self, tag: &str) {
+        let pos = self.begin(b'C');
+        self.put_cstr(tag);
+        self.end(pos);
+    }
+
+    pub fn error_response(&mut self, error: &ErrorInfo) {
+        let pos = self.begin(b'E');
+        self.buf.push(b'S');
+        self.put_cstr(error.severity);
+        self.buf.push(b'V');
+        self.put_cstr(error.severity);
+        self.buf.push(b'C');
+        self.put_cstr(error.code);
+        self.buf.push(b'M');
+        self.put_cstr(&error.message);
+        self.buf.push(0);
+        self.end(pos);
+    }
+
+    pub fn empty_query_response(&mut self) {
+        self.empty_message(b'I');
+    }
+
+    pub fn parse_complete(&mut self) {
+        self.empty_message(b'1');
+    }
+
+    pub fn bind_complete(&mut self) {
+        self.empty_message(b'2');
+    }
+
+    pub fn close_complete(&mut self) {
+        self.empty_message(b'3');
+    }
+
+    pub fn no_data(&mut self) {
+        self.empty_message(b'n');
+    }
+
+    pub fn portal_suspended(&mut self) {
+        self.empty_message(b's');
+    }
+
+    pub fn buffered_size(&self) -> usize {
+        self.buf.len()
+    }
+
+    #[async_backtrace::framed]
+    pub async fn flush(&mut self) -> Result<()> {
+        if !self.buf.is_empty() {
+            self.inner.write_all(&self.buf).await?;
+            self.buf.clear();
+        }
+        self.inner.flush().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_config::DATABEND_COMMIT_VERSION;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use ctor::ctor;
+use regex::Regex;
+
+use crate::servers::federated_helper::FederatedHelper;
+use crate::servers::postgres::POSTGRES_VERSION;
+
+pub struct PostgresFederated {
+    postgres_version: String,
+    databend_version: String,
+    current_database: String,
+}
+
+impl PostgresFederated {
+    pub fn create(current_database: String) -> Self {
+        PostgresFederated {
+            postgres_version: POSTGRES_VERSION.to_string(),
+            databend_version: DATABEND_COMMIT_VERSION.to_string(),
+            current_database,
+        }
+    }
+
+    // Build block for select function or show variable.
+    // Format:
+    // |name|
+    // |value|
+    fn single_value_block(name: &str, value: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let schema = TableSchemaRefExt::create(vec![TableField::new(name, TableDataType::String)]);
+        let block = DataBlock::new_from_columns(vec![StringType::from_data(vec![
+            value.as_bytes().to_vec(),
+        ])]);
+        Some((schema, block))
+    }
+
+    // Check SELECT version(), current_schema() and friends, whose values depend on the session.
+    fn federated_select_function_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #[ctor]
+        static SELECT_FUNCTION_RULES: Vec<(Regex, &'static str)> = vec![
+            (
+                Regex::new("(?i)^(SELECT (pg_catalog\\.)?version\\(\\)\\s*;?)$").unwrap(),
+                "version",
+            ),
+            (
+                // pgjdbc, sqlalchemy.
+                Regex::new("(?i)^(SELECT (pg_catalog\\.)?current_schema\\(\\)\\s*;?)$").unwrap(),
+                "current_schema",
+            ),
+        ];
+
+        for (regex, name) in SELECT_FUNCTION_RULES.iter() {
+            if regex.is_match(query) {
+                let value = match *name {
+                    "version" => format!(
+                        "PostgreSQL {} (Databend {})",
+                        self.postgres_version, self.databend_version
+                    ),
+                    _ => self.current_database.clone(),
+                };
+                return Self::single_value_block(name, &value);
+            }
+        }
+        None
+    }
+
+    // Check SHOW variable and current_setting(variable).
+    fn federated_show_variables_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #[ctor]
+        static SHOW_VARIABLES_RULES: Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)> = vec![
+            (
+                // sqlalchemy.
+                Regex::new("(?i)^(SHOW TRANSACTION ISOLATION LEVEL(.*))").unwrap(),
+                PostgresFederated::single_value_block("transaction_isolation", "read committed"),
+            ),
+            (
+                // sqlalchemy.
+                Regex::new("(?i)^(SHOW standard_conforming_strings(.*))").unwrap(),
+                PostgresFederated::single_value_block("standard_conforming_strings", "on"),
+            ),
+            (
+                Regex::new("(?i)^(SHOW server_version(.*))").unwrap(),
+                PostgresFederated::single_value_block("server_version", POSTGRES_VERSION),
+            ),
+            (
+                Regex::new("(?i)^(SHOW server_encoding(.*))").unwrap(),
+                PostgresFederated::single_value_block("server_encoding", "UTF8"),
+            ),
+            (
+                Regex::new("(?i)^(SHOW client_encoding(.*))").unwrap(),
+                PostgresFederated::single_value_block("client_encoding", "UTF8"),
+            ),
+            (
+                Regex::new("(?i)^(SHOW search_path(.*))").unwrap(),
+                PostgresFederated::single_value_block("search_path", "\"$user\", public"),
+            ),
+            (
+                Regex::new("(?i)^(SHOW DateStyle(.*))").unwrap(),
+                PostgresFederated::single_value_block("DateStyle", "ISO, MDY"),
+            ),
+            (
+                Regex::new("(?i)^(SHOW max_identifier_length(.*))").unwrap(),
+                PostgresFederated::single_value_block("max_identifier_length", "63"),
+            ),
+            (
+                // Grafana.
+                Regex::new("(?i)^(SELECT current_setting\\('server_version_num'\\)::int/100 as version(.*))").unwrap(),
+                PostgresFederated::single_value_block("version", "1200"),
+            ),
+            (
+                Regex::new("(?i)^(SELECT current_setting\\('server_version_num'\\)(.*))").unwrap(),
+                PostgresFederated::single_value_block("current_setting", "120000"),
+            ),
+        ];
+
+        FederatedHelper::block_match_rule(query, &SHOW_VARIABLES_RULES)
+    }
+
+    // Check for SET, transaction and pg_catalog probes, this is the final check of the federated query.
+    fn federated_mixed_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #[ctor]
+        static MIXED_RULES: Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)> = vec![
+            // Txn.
+            (Regex::new("(?i)^(BEGIN(.*))").unwrap(), None),
+            (Regex::new("(?i)^(START TRANSACTION(.*))").unwrap(), None),
+            (Regex::new("(?i)^(COMMIT(.*))").unwrap(), None),
+            (Regex::new("(?i)^(ROLLBACK(.*))").unwrap(), None),
+            (Regex::new("(?i)^(END(\\s*;)?)$").unwrap(), None),
+            (Regex::new("(?i)^(ABORT(\\s*;)?)$").unwrap(), None),
+            // Client setup, pgjdbc, psycopg, npgsql.
+            (Regex::new("(?i)^(SET extra_float_digits(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET application_name(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET client_encoding(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET client_min_messages(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET DateStyle(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET IntervalStyle(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET bytea_output(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET search_path(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET statement_timeout(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET lock_timeout(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET TIME ZONE(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET SESSION CHARACTERISTICS(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET SESSION AUTHORIZATION(.*))").unwrap(), None),
+            // Connection pools, pgbouncer.
+            (Regex::new("(?i)^(DISCARD ALL(.*))").unwrap(), None),
+            (Regex::new("(?i)^(DEALLOCATE(.*))").unwrap(), None),
+            // Catalog probes of drivers and tools, Databend has no pg_catalog.
+            (Regex::new("(?is)^(SELECT(.*)\\bpg_catalog\\.pg_[a-z_]+\\b(.*))").unwrap(), None),
+            (
+                Regex::new("(?is)^(SELECT(.*)\\bFROM\\s+pg_(type|namespace|class|attribute|proc|settings|database|roles|user|index|description|constraint|enum|range|extension)\\b(.*))").unwrap(),
+                None,
+            ),
+        ];
+
+        FederatedHelper::block_match_rule(query, &MIXED_RULES)
+    }
+
+    // Check the query is a federated or driver setup command.
+    // Here we fake some values for the command which Databend not supported.
+    pub fn check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
+        // First to check the select function.
+        let select_function = self
+            .federated_select_function_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk));
+        if select_function.is_some() {
+            return select_function;
+        }
+
+        // Then to check the show variables.
+        let show_variables = self
+            .federated_show_variables_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk));
+        if show_variables.is_some() {
+            return show_variables;
+        }
+
+        // Last check.
+        self.federated_mixed_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk))
+    }
+}
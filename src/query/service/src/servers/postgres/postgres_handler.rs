@@ -0,0 +1,171 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_base::base::tokio::net::TcpStream;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::runtime::Runtime;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::future::AbortHandle;
+use futures::future::AbortRegistration;
+use futures::future::Abortable;
+use futures::StreamExt;
+use tokio_stream::wrappers::TcpListenerStream;
+use tracing::error;
+use tracing::info;
+use tracing::warn;
+
+use crate::servers::postgres::postgres_codec::read_startup;
+use crate::servers::postgres::postgres_codec::BackendMessageWriter;
+use crate::servers::postgres::postgres_codec::ErrorInfo;
+use crate::servers::postgres::postgres_codec::StartupRequest;
+use crate::servers::postgres::postgres_session::PostgresConnection;
+use crate::servers::server::ListeningStream;
+use crate::servers::server::Server;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+pub struct PostgresHandler {
+    abort_handle: AbortHandle,
+    abort_registration: Option<AbortRegistration>,
+    join_handle: Option<JoinHandle<()>>,
+}
+
+impl PostgresHandler {
+    pub fn create() -> Result<Box<dyn Server>> {
+        let (abort_handle, registration) = AbortHandle::new_pair();
+        Ok(Box::new(PostgresHandler {
+            abort_handle,
+            abort_registration: Some(registration),
+            join_handle: None,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn listener_tcp(listening: SocketAddr) -> Result<(TcpListenerStream, SocketAddr)> {
+        let listener = tokio::net::TcpListener::bind(listening)
+            .await
+            .map_err(|e| {
+                ErrorCode::TokioError(format!("{{{}:{}}} {}", listening.ip(), listening.port(), e))
+            })?;
+        let listener_addr = listener.local_addr()?;
+        Ok((TcpListenerStream::new(listener), listener_addr))
+    }
+
+    fn listen_loop(&self, stream: ListeningStream, rt: Arc<Runtime>) -> impl Future<Output = ()> {
+        stream.for_each(move |accept_socket| {
+            let executor = rt.clone();
+            let sessions = SessionManager::instance();
+            async move {
+                match accept_socket {
+                    Err(error) => error!("Broken session connection: {}", error),
+                    Ok(socket) => PostgresHandler::accept_socket(sessions, executor, socket),
+                };
+            }
+        })
+    }
+
+    fn accept_socket(sessions: Arc<SessionManager>, executor: Arc<Runtime>, socket: TcpStream) {
+        executor.spawn(async move {
+            match sessions.create_session(SessionType::Postgres).await {
+                Err(error) => {
+                    warn!("create session failed, {:?}", error);
+                    Self::reject_session(socket, error).await
+                }
+                Ok(session) => {
+                    info!("Postgres connection coming: {:?}", socket.peer_addr());
+                    if let Err(error) = PostgresConnection::run_on_stream(session, socket) {
+                        error!("Unexpected error occurred during query: {:?}", error);
+                    };
+                }
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn reject_session(mut stream: TcpStream, error: ErrorCode) {
+        let sqlstate = match error.code() {
+            ErrorCode::TOO_MANY_USER_CONNECTIONS => "53300",
+            _ => "XX000",
+        };
+
+        let (mut reader, writer) = stream.split();
+        let mut writer = BackendMessageWriter::create(writer);
+        let res: Result<()> = async {
+            // Answer the SSL requests, then reject the startup message.
+            while let StartupRequest::Ssl | StartupRequest::Gssenc =
+                read_startup(&mut reader).await?
+            {
+                writer.ssl_not_supported();
+                writer.flush().await?;
+            }
+            writer.error_response(&ErrorInfo::fatal(sqlstate, error.message()));
+            writer.flush().await
+        }
+        .await;
+
+        if let Err(error) = res {
+            error!(
+                "Unexpected error occurred during reject connection: {:?}",
+                error
+            );
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Server for PostgresHandler {
+    #[async_backtrace::framed]
+    async fn shutdown(&mut self, graceful: bool) {
+        if !graceful {
+            return;
+        }
+
+        self.abort_handle.abort();
+
+        if let Some(join_handle) = self.join_handle.take() {
+            if let Err(error) = join_handle.await {
+                error!(
+                    "Unexpected error during shutdown PostgresHandler. cause {}",
+                    error
+                );
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn start(&mut self, listening: SocketAddr) -> Result<SocketAddr> {
+        match self.abort_registration.take() {
+            None => Err(ErrorCode::Internal("PostgresHandler already running.")),
+            Some(registration) => {
+                let rejected_rt = Arc::new(Runtime::with_worker_threads(
+                    1,
+                    Some("postgres-handler".to_string()),
+                )?);
+                let (stream, listener) = Self::listener_tcp(listening).await?;
+                let stream = Abortable::new(stream, registration);
+                self.join_handle = Some(tokio::spawn(
+                    async_backtrace::location!().frame(self.listen_loop(stream, rejected_rt)),
+                ));
+                Ok(listener)
+            }
+        }
+    }
+}
@@ -0,0 +1,897 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SendableDataBlockStream;
+use common_formats::field_encoder::FieldEncoderValues;
+use common_meta_app::principal::AuthType;
+use common_sql::plans::Plan;
+use common_sql::Planner;
+use common_users::UserApiProvider;
+use futures_util::StreamExt;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use rand::Rng;
+use tracing::error;
+use tracing::info;
+use tracing::Instrument;
+
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::postgres::postgres_codec::read_message;
+use crate::servers::postgres::postgres_codec::read_startup;
+use crate::servers::postgres::postgres_codec::BackendMessageWriter;
+use crate::servers::postgres::postgres_codec::ErrorInfo;
+use crate::servers::postgres::postgres_codec::FieldDescription;
+use crate::servers::postgres::postgres_codec::FrontendMessage;
+use crate::servers::postgres::postgres_codec::StartupRequest;
+use crate::servers::postgres::postgres_codec::FORMAT_TEXT;
+use crate::servers::postgres::postgres_types::bind_params;
+use crate::servers::postgres::postgres_types::count_params;
+use crate::servers::postgres::postgres_types::data_type_oid;
+use crate::servers::postgres::postgres_types::encode_field;
+use crate::servers::postgres::postgres_types::param_to_literal;
+use crate::servers::postgres::postgres_types::type_size;
+use crate::servers::postgres::postgres_types::TEXT_OID;
+use crate::servers::postgres::postgres_types::UNSPECIFIED_OID;
+use crate::servers::postgres::PostgresFederated;
+use crate::servers::postgres::POSTGRES_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::TableContext;
+use crate::stream::DataBlockStream;
+
+// Flush the buffered result rows to the client once they exceed 100KB.
+const RESULT_SET_FLUSH_SIZE: usize = 100 * 1024;
+
+// The (process id, secret key) sent in BackendKeyData to the session id, a CancelRequest
+// with the same key kills the running query of the session.
+static CANCEL_KEYS: Lazy<Mutex<HashMap<(i32, i32), String>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+struct PreparedStatement {
+    query: String,
+    param_types: Vec<u32>,
+}
+
+enum PlannedQuery {
+    Federated {
+        schema: DataSchemaRef,
+        block: DataBlock,
+    },
+    Plan {
+        context: Arc<QueryContext>,
+        plan: Box<Plan>,
+        mask_sql: String,
+    },
+}
+
+impl PlannedQuery {
+    /// The schema of the result set, None if the query has no result set.
+    fn result_schema(&self) -> Option<DataSchemaRef> {
+        match self {
+            PlannedQuery::Federated { schema, .. } if !schema.fields().is_empty() => {
+                Some(schema.clone())
+            }
+            PlannedQuery::Plan { plan, .. } if plan.has_result_set() => Some(plan.schema()),
+            _ => None,
+        }
+    }
+}
+
+struct RunningQuery {
+    blocks: SendableDataBlockStream,
+    schema: DataSchemaRef,
+    has_result_set: bool,
+    context: Option<Arc<QueryContext>>,
+    // The columns of the block being sent, and the index of the next row to send.
+    current: Option<(Vec<Column>, usize)>,
+    rows_sent: usize,
+}
+
+impl RunningQuery {
+    fn command_tag(&self, query: &str) -> String {
+        if self.has_result_set {
+            return format!("SELECT {}", self.rows_sent);
+        }
+
+        let affected_rows = self
+            .context
+            .as_ref()
+            .map(|ctx| ctx.get_write_progress_value().rows)
+            .unwrap_or_default();
+        let words = query
+            .split_whitespace()
+            .take(2)
+            .map(|w| w.trim_end_matches(';').to_ascii_uppercase())
+            .collect::<Vec<_>>();
+        match words.first().map(|w| w.as_str()) {
+            None => "".to_string(),
+            Some("INSERT") | Some("REPLACE") => format!("INSERT 0 {}", affected_rows),
+            Some("UPDATE") | Some("DELETE") => format!("{} {}", words[0], affected_rows),
+            Some("CREATE") | Some("DROP") | Some("ALTER") => words.join(" "),
+            Some(_) => words[0].clone(),
+        }
+    }
+}
+
+struct Portal {
+    query: String,
+    result_formats: Vec<i16>,
+    planned: Option<PlannedQuery>,
+    running: Option<RunningQuery>,
+}
+
+pub struct InteractiveWorker {
+    session: Arc<Session>,
+    client_addr: String,
+    process_id: i32,
+    secret_key: i32,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+}
+
+impl InteractiveWorker {
+    pub fn create(session: Arc<Session>, client_addr: String) -> InteractiveWorker {
+        let mut rng = rand::thread_rng();
+        InteractiveWorker {
+            session,
+            client_addr,
+            process_id: rng.gen_range(1..i32::MAX),
+            secret_key: rng.gen(),
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn run<R, W>(mut self, mut reader: R, writer: W) -> Result<()>
+    where
+        R: AsyncRead + Send + Unpin,
+        W: AsyncWrite + Send + Unpin,
+    {
+        let mut writer = BackendMessageWriter::create(writer);
+        if !self.startup(&mut reader, &mut writer).await? {
+            return Ok(());
+        }
+
+        let cancel_key = (self.process_id, self.secret_key);
+        CANCEL_KEYS.lock().insert(cancel_key, self.session.get_id());
+        let res = self.run_loop(&mut reader, &mut writer).await;
+        CANCEL_KEYS.lock().remove(&cancel_key);
+        res
+    }
+
+    fn cancel_query(process_id: i32, secret_key: i32) {
+        let session_id = CANCEL_KEYS.lock().get(&(process_id, secret_key)).cloned();
+        let session = session_id.and_then(|id| SessionManager::instance().get_session_by_id(&id));
+        if let Some(session) = session {
+            info!("Postgres cancel request for session {}", session.get_id());
+            session.force_kill_query(ErrorCode::AbortedQuery(
+                "canceling statement due to user request",
+            ));
+        }
+    }
+
+    /// Handle the startup and authentication of the connection, returns false if the
+    /// connection should be closed.
+    #[async_backtrace::framed]
+    async fn startup<R, W>(
+        &mut self,
+        reader: &mut R,
+        writer: &mut BackendMessageWriter<W>,
+    ) -> Result<bool>
+    where
+        R: AsyncRead + Send + Unpin,
+        W: AsyncWrite + Send + Unpin,
+    {
+        let params = loop {
+            match read_startup(reader).await? {
+                StartupRequest::Ssl | StartupRequest::Gssenc => {
+                    writer.ssl_not_supported();
+                    writer.flush().await?;
+                }
+                StartupRequest::Cancel {
+                    process_id,
+                    secret_key,
+                } => {
+                    Self::cancel_query(process_id, secret_key);
+                    return Ok(false);
+                }
+                StartupRequest::Startup { params } => break params,
+            }
+        };
+
+        let user = match params.get("user") {
+            Some(user) if !user.is_empty() => user.clone(),
+            _ => {
+                writer.error_response(&ErrorInfo::fatal(
+                    "28000",
+                    "no PostgreSQL user name specified in startup packet",
+                ));
+                writer.flush().await?;
+                return Ok(false);
+            }
+        };
+
+        if let Err(failure) = self.authenticate(&user, reader, writer).await {
+            error!(
+                "Postgres handler authenticate failed, \
+                    user_name: {}, \
+                    client_address: {}, \
+                    failure_cause: {}",
+                user, self.client_addr, failure
+            );
+            writer.error_response(&ErrorInfo::fatal(
+                "28P01",
+                format!("authentication failed for user \"{}\"", user),
+            ));
+            writer.flush().await?;
+            return Ok(false);
+        }
+        writer.authentication_ok();
+
+        // Clients like psql use the user name as the database if it is not given.
+        if let Some(database) = params.get("database").filter(|db| !db.is_empty()) {
+            if let Err(cause) = self.do_init(database).await {
+                if database != &user {
+                    writer.error_response(&ErrorInfo::fatal("3D000", cause.message()));
+                    writer.flush().await?;
+                    return Ok(false);
+                }
+            }
+        }
+
+        let timezone = self.session.get_format_settings()?.timezone.to_string();
+        let application_name = params.get("application_name").cloned().unwrap_or_default();
+        for (name, value) in [
+            ("server_version", POSTGRES_VERSION),
+            ("server_encoding", "UTF8"),
+            ("client_encoding", "UTF8"),
+            ("DateStyle", "ISO, MDY"),
+            ("IntervalStyle", "postgres"),
+            ("TimeZone", timezone.as_str()),
+            ("integer_datetimes", "on"),
+            ("standard_conforming_strings", "on"),
+            ("application_name", application_name.as_str()),
+        ] {
+            writer.parameter_status(name, value);
+        }
+        writer.backend_key_data(self.process_id, self.secret_key);
+        writer.ready_for_query();
+        writer.flush().await?;
+        Ok(true)
+    }
+
+    /// Authenticate the user with the method of its auth type, the password is asked in
+    /// clear text, it should be protected by the network.
+    #[async_backtrace::framed]
+    async fn authenticate<R, W>(
+        &self,
+        user: &str,
+        reader: &mut R,
+        writer: &mut BackendMessageWriter<W>,
+    ) -> Result<()>
+    where
+        R: AsyncRead + Send + Unpin,
+        W: AsyncWrite + Send + Unpin,
+    {
+        let client_ip = self.client_addr.split(':').collect::<Vec<_>>()[0].to_string();
+        let tenant = self.session.get_current_tenant();
+        let user_info = UserApiProvider::instance()
+            .get_user_with_client_ip(&tenant, user, &client_ip)
+            .await?;
+
+        let credential = match user_info.auth_info.get_type() {
+            AuthType::NoPassword => Credential::Password {
+                name: user.to_string(),
+                password: None,
+                hostname: Some(client_ip),
+            },
+            AuthType::Sha256Password | AuthType::DoubleSha1Password => Credential::Password {
+                name: user.to_string(),
+                password: Some(Self::read_password(reader, writer).await?),
+                hostname: Some(client_ip),
+            },
+            AuthType::JWT => {
+                let token = Self::read_password(reader, writer).await?;
+                Credential::Jwt {
+                    token: String::from_utf8(token).map_err(|_| {
+                        ErrorCode::AuthenticateFailure("jwt token is not valid utf-8")
+                    })?,
+                }
+            }
+        };
+        AuthMgr::instance()
+            .auth(self.session.clone(), &credential)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn read_password<R, W>(
+        reader: &mut R,
+        writer: &mut BackendMessageWriter<W>,
+    ) -> Result<Vec<u8>>
+    where
+        R: AsyncRead + Send + Unpin,
+        W: AsyncWrite + Send + Unpin,
+    {
+        writer.authentication_cleartext_password();
+        writer.flush().await?;
+        match read_message(reader).await? {
+            Some(FrontendMessage::Password(password)) => Ok(password),
+            _ => Err(ErrorCode::AuthenticateFailure("expected password response")),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn run_loop<R, W>(
+        &mut self,
+        reader: &mut R,
+        writer: &mut BackendMessageWriter<W>,
+    ) -> Result<()>
+    where
+        R: AsyncRead + Send + Unpin,
+        W: AsyncWrite + Send + Unpin,
+    {
+        // After an error in the extended query protocol, messages are discarded until Sync.
+        let mut skip_until_sync = false;
+        loop {
+            let message = match read_message(reader).await? {
+                None => return Ok(()),
+                Some(message) => message,
+            };
+
+            if self.session.is_aborting() {
+                writer.error_response(&ErrorInfo::fatal(
+                    "57P01",
+                    "Aborting this connection. because we are try aborting server.",
+                ));
+                writer.flush().await?;
+                return Err(ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                ));
+            }
+
+            match message {
+                FrontendMessage::Terminate => return Ok(()),
+                FrontendMessage::Query(query) => {
+                    self.on_query(&query, writer).await?;
+                    writer.ready_for_query();
+                    writer.flush().await?;
+                }
+                FrontendMessage::Sync => {
+                    skip_until_sync = false;
+                    writer.ready_for_query();
+                    writer.flush().await?;
+                }
+                FrontendMessage::Flush => writer.flush().await?,
+                _ if skip_until_sync => {}
+                message => {
+                    if let Err(cause) = self.on_extended(message, writer).await {
+                        writer.error_response(&error_info(&cause));
+                        skip_until_sync = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Simple query protocol.
+    #[async_backtrace::framed]
+    async fn on_query<W>(
+        &mut self,
+        query: &str,
+        writer: &mut BackendMessageWriter<W>,
+    ) -> Result<()>
+    where
+        W: AsyncWrite + Send + Unpin,
+    {
+        // A simple query destroys the unnamed statement and portal.
+        self.statements.remove("");
+        self.portals.remove("");
+
+        let query = trim_query(query);
+        if query.is_empty() {
+            writer.empty_query_response();
+            return Ok(());
+        }
+
+        let mut running = match self.plan_query(query).await {
+            Ok(planned) => match self.start_query(planned).await {
+                Ok(running) => running,
+                Err(cause) => {
+                    writer.error_response(&error_info(&cause.display_with_sql(query)));
+                    return Ok(());
+                }
+            },
+            Err(cause) => {
+                writer.error_response(&error_info(&cause.display_with_sql(query)));
+                return Ok(());
+            }
+        };
+
+        if running.has_result_set {
+            writer.row_description(&field_descriptions(&running.schema, &[]));
+        }
+        match self.send_rows(&mut running, &[], 0, writer).await {
+            Ok(_) => writer.command_complete(&running.command_tag(query)),
+            Err(cause) => writer.error_response(&error_info(&cause.display_with_sql(query))),
+        }
+        Ok(())
+    }
+
+    /// Extended query protocol.
+    #[async_backtrace::framed]
+    async fn on_extended<W>(
+        &mut self,
+        message: FrontendMessage,
+        writer: &mut BackendMessageWriter<W>,
+    ) -> Result<()>
+    where
+        W: AsyncWrite + Send + Unpin,
+    {
+        match message {
+            FrontendMessage::Parse {
+                name,
+                query,
+                mut param_types,
+            } => {
+                if !name.is_empty() && self.statements.contains_key(&name) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" already exists",
+                        name
+                    )));
+                }
+                let query = trim_query(&query).to_string();
+                let param_count = count_params(&query).max(param_types.len());
+                param_types.resize(param_count, UNSPECIFIED_OID);
+                self.statements
+                    .insert(name, PreparedStatement { query, param_types });
+                writer.parse_complete();
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            } => {
+                let statement = self.get_statement(&statement)?;
+                let literals = params
+                    .iter()
+                    .enumerate()
+                    .map(|(i, param)| {
+                        let type_oid = statement
+                            .param_types
+                            .get(i)
+                            .copied()
+                            .unwrap_or(UNSPECIFIED_OID);
+                        param_to_literal(type_oid, format_code(&param_formats, i), param.as_deref())
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let query = bind_params(&statement.query, &literals);
+                self.portals.insert(portal, Portal {
+                    query,
+                    result_formats,
+                    planned: None,
+                    running: None,
+                });
+                writer.bind_complete();
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let statement = self.get_statement(&name)?;
+                let param_types = statement
+                    .param_types
+                    .iter()
+                    .map(|ty| {
+                        if *ty == UNSPECIFIED_OID {
+                            TEXT_OID
+                        } else {
+                            *ty
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                // The result columns are unknown until the parameters are bound, so plan
+                // the query with NULL parameters to find them.
+                let nulls = vec!["NULL".to_string(); statement.param_types.len()];
+                let query = bind_params(&statement.query, &nulls);
+                writer.parameter_description(&param_types);
+
+                let schema = if query.is_empty() {
+                    None
+                } else {
+                    match self.plan_query(&query).await {
+                        Ok(planned) => planned.result_schema(),
+                        Err(_) => None,
+                    }
+                };
+                match schema {
+                    Some(schema) => writer.row_description(&field_descriptions(&schema, &[])),
+                    None => writer.no_data(),
+                }
+            }
+            FrontendMessage::Describe { kind: b'P', name } => {
+                let mut portal = self.portals.remove(&name).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!("portal \"{}\" does not exist", name))
+                })?;
+                let res = self.describe_portal(&mut portal, writer).await;
+                self.portals.insert(name, portal);
+                res?;
+            }
+            FrontendMessage::Execute { portal, max_rows } => {
+                let mut p = self.portals.remove(&portal).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!("portal \"{}\" does not exist", portal))
+                })?;
+                let res = self.execute_portal(&mut p, max_rows, writer).await;
+                self.portals.insert(portal, p);
+                res?;
+            }
+            FrontendMessage::Close { kind, name } => {
+                if kind == b'S' {
+                    self.statements.remove(&name);
+                } else {
+                    self.portals.remove(&name);
+                }
+                writer.close_complete();
+            }
+            FrontendMessage::Describe { kind, .. } => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid DESCRIBE message subtype {}",
+                    kind
+                )));
+            }
+            FrontendMessage::Password(_) => {
+                return Err(ErrorCode::BadBytes("unexpected password message"));
+            }
+            FrontendMessage::Unknown(tag) => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid frontend message type {}",
+                    tag
+                )));
+            }
+            FrontendMessage::Query(_)
+            | FrontendMessage::Sync
+            | FrontendMessage::Flush
+            | FrontendMessage::Terminate => unreachable!(),
+        }
+        Ok(())
+    }
+
+    fn get_statement(&self, name: &str) -> Result<&PreparedStatement> {
+        self.statements.get(name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("prepared statement \"{}\" does not exist", name))
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn describe_portal<W>(
+        &self,
+        portal: &mut Portal,
+        writer: &mut BackendMessageWriter<W>,
+    ) -> Result<()>
+    where
+        W: AsyncWrite + Send + Unpin,
+    {
+        let schema = if let Some(running) = &portal.running {
+            running.has_result_set.then(|| running.schema.clone())
+        } else if portal.query.is_empty() {
+            None
+        } else {
+            // Keep the plan, so Execute does not need to plan the query again.
+            let planned = self.plan_query(&portal.query).await?;
+            let schema = planned.result_schema();
+            portal.planned = Some(planned);
+            schema
+        };
+        match schema {
+            Some(schema) => {
+                writer.row_description(&field_descriptions(&schema, &portal.result_formats))
+            }
+            None => writer.no_data(),
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_portal<W>(
+        &self,
+        portal: &mut Portal,
+        max_rows: i32,
+        writer: &mut BackendMessageWriter<W>,
+    ) -> Result<()>
+    where
+        W: AsyncWrite + Send + Unpin,
+    {
+        if portal.query.is_empty() {
+            writer.empty_query_response();
+            return Ok(());
+        }
+
+        let mut running = match portal.running.take() {
+            Some(running) => running,
+            None => {
+                let planned = match portal.planned.take() {
+                    Some(planned) => planned,
+                    None => self.plan_query(&portal.query).await?,
+                };
+                self.start_query(planned).await?
+            }
+        };
+        let max_rows = max_rows.max(0) as usize;
+        if self
+            .send_rows(&mut running, &portal.result_formats, max_rows, writer)
+            .await?
+        {
+            writer.command_complete(&running.command_tag(&portal.query));
+        } else {
+            portal.running = Some(running);
+            writer.portal_suspended();
+        }
+        Ok(())
+    }
+
+    // Check the query is a federated or driver setup command.
+    // Here we fake some values for the command which Databend not supported.
+    fn federated_server_command_check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
+        // INSERT don't need Postgres federated check
+        if query.len() > 6 && query[..6].eq_ignore_ascii_case("INSERT") {
+            return None;
+        }
+        let federated = PostgresFederated::create(self.session.get_current_database());
+        federated.check(query)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    #[async_backtrace::framed]
+    async fn plan_query(&self, query: &str) -> Result<PlannedQuery> {
+        match self.federated_server_command_check(query) {
+            Some((schema, block)) => {
+                info!("Federated query: {}", query);
+                Ok(PlannedQuery::Federated { schema, block })
+            }
+            None => {
+                info!("Normal query: {}", query);
+                let context = self.session.create_query_context().await?;
+                let mut planner = Planner::new(context.clone());
+                let (plan, extras) = planner.plan_sql(query).await?;
+                Ok(PlannedQuery::Plan {
+                    context,
+                    plan: Box::new(plan),
+                    mask_sql: extras.stament.to_mask_sql(),
+                })
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn start_query(&self, planned: PlannedQuery) -> Result<RunningQuery> {
+        match planned {
+            PlannedQuery::Federated { schema, block } => Ok(RunningQuery {
+                blocks: DataBlockStream::create(None, vec![block]).boxed(),
+                has_result_set: !schema.fields().is_empty(),
+                schema,
+                context: None,
+                current: None,
+                rows_sent: 0,
+            }),
+            PlannedQuery::Plan {
+                context,
+                plan,
+                mask_sql,
+            } => {
+                context.attach_query_str(plan.to_string(), mask_sql);
+                let interpreter = match InterpreterFactory::get(context.clone(), &plan).await {
+                    Ok(interpreter) => interpreter,
+                    Err(e) => {
+                        InterpreterQueryLog::fail_to_start(context, e.clone());
+                        return Err(e);
+                    }
+                };
+                let blocks = Self::exec_query(interpreter.clone(), &context).await?;
+                Ok(RunningQuery {
+                    blocks,
+                    schema: interpreter.schema(),
+                    has_result_set: plan.has_result_set(),
+                    context: Some(context),
+                    current: None,
+                    rows_sent: 0,
+                })
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(interpreter, context))]
+    #[async_backtrace::framed]
+    async fn exec_query(
+        interpreter: Arc<dyn Interpreter>,
+        context: &Arc<QueryContext>,
+    ) -> Result<SendableDataBlockStream> {
+        let query_result = context.try_spawn({
+            let ctx = context.clone();
+            async move {
+                let mut data_stream = interpreter.execute(ctx.clone()).await?;
+
+                // Wrap the data stream, log finish event at the end of stream
+                let intercepted_stream = async_stream::stream! {
+
+                    while let Some(item) = data_stream.next().await {
+                        yield item
+                    };
+                };
+
+                Ok::<_, ErrorCode>(intercepted_stream.boxed())
+            }
+            .in_current_span()
+        })?;
+
+        query_result.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        )?
+    }
+
+    /// Send the rows of the query as DataRow messages, at most `max_rows` rows if it is not
+    /// zero. Returns true if all the rows have been sent.
+    #[async_backtrace::framed]
+    async fn send_rows<W>(
+        &self,
+        running: &mut RunningQuery,
+        result_formats: &[i16],
+        max_rows: usize,
+        writer: &mut BackendMessageWriter<W>,
+    ) -> Result<bool>
+    where
+        W: AsyncWrite + Send + Unpin,
+    {
+        let format = self.session.get_format_settings()?;
+        let encoder = FieldEncoderValues::create_for_postgres_handler(format.timezone);
+        let fields = running.schema.fields();
+        let type_oids = fields
+            .iter()
+            .map(|f| data_type_oid(f.data_type()))
+            .collect::<Vec<_>>();
+        let formats = (0..fields.len())
+            .map(|i| format_code(result_formats, i))
+            .collect::<Vec<_>>();
+
+        let mut sent = 0;
+        loop {
+            if running.current.is_none() {
+                match running.blocks.next().await {
+                    None => return Ok(true),
+                    // For statements without result sets, we still need to pull the stream
+                    // because errors may occur in the stream.
+                    Some(block) if !running.has_result_set => {
+                        block?;
+                        continue;
+                    }
+                    Some(block) => {
+                        let columns = block?
+                            .convert_to_full()
+                            .columns()
+                            .iter()
+                            .map(|column| column.value.clone().into_column().unwrap())
+                            .collect::<Vec<_>>();
+                        running.current = Some((columns, 0));
+                    }
+                }
+            }
+
+            let (columns, row_index) = running.current.as_mut().unwrap();
+            let num_rows = columns.first().map(|c| c.len()).unwrap_or(0);
+            while *row_index < num_rows {
+                if max_rows > 0 && sent >= max_rows {
+                    return Ok(false);
+                }
+                let values = columns
+                    .iter()
+                    .enumerate()
+                    .map(|(i, column)| {
+                        encode_field(column, *row_index, type_oids[i], formats[i], &encoder)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                writer.data_row(&values);
+                *row_index += 1;
+                sent += 1;
+                running.rows_sent += 1;
+
+                if writer.buffered_size() >= RESULT_SET_FLUSH_SIZE {
+                    writer.flush().await?;
+                }
+            }
+            running.current = None;
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn do_init(&mut self, database_name: &str) -> Result<()> {
+        let init_query = format!("USE `{}`;", database_name);
+        let planned = self.plan_query(&init_query).await?;
+        let mut running = self.start_query(planned).await?;
+        while let Some(block) = running.blocks.next().await {
+            block?;
+        }
+        Ok(())
+    }
+}
+
+fn trim_query(query: &str) -> &str {
+    query.trim().trim_end_matches(';').trim_end()
+}
+
+/// The format code of the i-th value: no code means all text, a single code applies to all.
+fn format_code(formats: &[i16], index: usize) -> i16 {
+    match formats.len() {
+        0 => FORMAT_TEXT,
+        1 => formats[0],
+        _ => formats.get(index).copied().unwrap_or(FORMAT_TEXT),
+    }
+}
+
+fn field_descriptions(schema: &DataSchemaRef, result_formats: &[i16]) -> Vec<FieldDescription> {
+    schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let type_oid = data_type_oid(field.data_type());
+            FieldDescription {
+                name: field.name().to_string(),
+                type_oid,
+                type_size: type_size(type_oid),
+                format: format_code(result_formats, i),
+            }
+        })
+        .collect()
+}
+
+fn error_info(error: &ErrorCode) -> ErrorInfo {
+    // https://www.postgresql.org/docs/current/errcodes-appendix.html
+    let sqlstate = match error.code() {
+        ErrorCode::SYNTAX_EXCEPTION => "42601",
+        ErrorCode::SEMANTIC_ERROR => "42000",
+        ErrorCode::UNKNOWN_DATABASE => "3D000",
+        ErrorCode::UNKNOWN_TABLE => "42P01",
+        ErrorCode::UNKNOWN_COLUMN => "42703",
+        ErrorCode::UNKNOWN_FUNCTION => "42883",
+        ErrorCode::PERMISSION_DENIED => "42501",
+        ErrorCode::AUTHENTICATE_FAILURE => "28P01",
+        ErrorCode::BAD_ARGUMENTS => "22023",
+        ErrorCode::BAD_BYTES => "08P01",
+        ErrorCode::UNIMPLEMENTED => "0A000",
+        ErrorCode::ABORTED_QUERY => "57014",
+        ErrorCode::ABORTED_SESSION => "57P01",
+        _ => "XX000",
+    };
+    if error.code() != ErrorCode::ABORTED_QUERY && error.code() != ErrorCode::ABORTED_SESSION {
+        error!("OnQuery Error: {:?}", error);
+    }
+    ErrorInfo::error(sqlstate, error.message())
+}
@@ -0,0 +1,447 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::ScalarRef;
+use common_formats::field_encoder::FieldEncoderRowBased;
+use common_formats::field_encoder::FieldEncoderValues;
+
+use crate::servers::postgres::postgres_codec::FORMAT_BINARY;
+use crate::servers::postgres::postgres_codec::FORMAT_TEXT;
+
+// Type oids from the pg_type catalog of PostgreSQL.
+pub const UNSPECIFIED_OID: u32 = 0;
+pub const BOOL_OID: u32 = 16;
+pub const BYTEA_OID: u32 = 17;
+pub const INT8_OID: u32 = 20;
+pub const INT2_OID: u32 = 21;
+pub const INT4_OID: u32 = 23;
+pub const TEXT_OID: u32 = 25;
+pub const OID_OID: u32 = 26;
+pub const JSON_OID: u32 = 114;
+pub const FLOAT4_OID: u32 = 700;
+pub const FLOAT8_OID: u32 = 701;
+pub const UNKNOWN_OID: u32 = 705;
+pub const BPCHAR_OID: u32 = 1042;
+pub const VARCHAR_OID: u32 = 1043;
+pub const DATE_OID: u32 = 1082;
+pub const TIMESTAMP_OID: u32 = 1114;
+pub const TIMESTAMPTZ_OID: u32 = 1184;
+pub const NUMERIC_OID: u32 = 1700;
+
+// Days and microseconds between 1970-01-01 (unix epoch) and 2000-01-01 (postgres epoch).
+const POSTGRES_EPOCH_DAYS: i32 = 10957;
+const POSTGRES_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+/// Map the data type of a result column to the oid of a PostgreSQL type.
+///
+/// Types without a PostgreSQL counterpart are sent as `text`, in the same text form
+/// the other handlers use.
+pub fn data_type_oid(data_type: &DataType) -> u32 {
+    match data_type.remove_nullable() {
+        DataType::Boolean => BOOL_OID,
+        DataType::String => TEXT_OID,
+        DataType::Binary => BYTEA_OID,
+        DataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8 | NumberDataType::UInt8 | NumberDataType::Int16 => INT2_OID,
+            NumberDataType::UInt16 | NumberDataType::Int32 => INT4_OID,
+            NumberDataType::UInt32 | NumberDataType::Int64 => INT8_OID,
+            NumberDataType::UInt64 => NUMERIC_OID,
+            NumberDataType::Float32 => FLOAT4_OID,
+            NumberDataType::Float64 => FLOAT8_OID,
+        },
+        DataType::Decimal(_) => NUMERIC_OID,
+        DataType::Date => DATE_OID,
+        DataType::Timestamp => TIMESTAMP_OID,
+        DataType::Variant => JSON_OID,
+        _ => TEXT_OID,
+    }
+}
+
+/// The `typlen` of the type, -1 means a variable length type.
+pub fn type_size(type_oid: u32) -> i16 {
+    match type_oid {
+        BOOL_OID => 1,
+        INT2_OID => 2,
+        INT4_OID | FLOAT4_OID | DATE_OID | OID_OID => 4,
+        INT8_OID | FLOAT8_OID | TIMESTAMP_OID | TIMESTAMPTZ_OID => 8,
+        _ => -1,
+    }
+}
+
+/// Encode a value of the column to the format requested by the client, None is NULL.
+pub fn encode_field(
+    column: &Column,
+    row_index: usize,
+    type_oid: u32,
+    format: i16,
+    encoder: &FieldEncoderValues,
+) -> Result<Option<Vec<u8>>> {
+    let value = unsafe { column.index_unchecked(row_index) };
+    let mut buf = Vec::new();
+    match value {
+        ScalarRef::Null => return Ok(None),
+        ScalarRef::Boolean(v) if format == FORMAT_TEXT => {
+            buf.push(if v { b't' } else { b'f' });
+        }
+        ScalarRef::Binary(v) if format == FORMAT_TEXT => {
+            buf.extend_from_slice(b"\\x");
+            for b in v {
+                buf.extend_from_slice(format!("{:02x}", b).as_bytes());
+            }
+        }
+        _ if format == FORMAT_TEXT => {
+            encoder.write_field(column, row_index, &mut buf, true);
+        }
+        _ if format == FORMAT_BINARY => {
+            encode_binary(value, column, row_index, type_oid, encoder, &mut buf)?;
+        }
+        _ => {
+            return Err(ErrorCode::BadArguments(format!(
+                "unsupported format code: {}",
+                format
+            )));
+        }
+    }
+    Ok(Some(buf))
+}
+
+fn encode_binary(
+    value: ScalarRef,
+    column: &Column,
+    row_index: usize,
+    type_oid: u32,
+    encoder: &FieldEncoderValues,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    match value {
+        ScalarRef::Boolean(v) => buf.push(v as u8),
+        ScalarRef::String(v) | ScalarRef::Binary(v) => buf.extend_from_slice(v),
+        ScalarRef::Date(v) => buf.extend_from_slice(&(v - POSTGRES_EPOCH_DAYS).to_be_bytes()),
+        ScalarRef::Timestamp(v) => {
+            buf.extend_from_slice(&(v - POSTGRES_EPOCH_MICROS).to_be_bytes())
+        }
+        ScalarRef::Number(number) => match (number, type_oid) {
+            (NumberScalar::Int8(v), INT2_OID) => buf.extend_from_slice(&(v as i16).to_be_bytes()),
+            (NumberScalar::UInt8(v), INT2_OID) => buf.extend_from_slice(&(v as i16).to_be_bytes()),
+            (NumberScalar::Int16(v), INT2_OID) => buf.extend_from_slice(&v.to_be_bytes()),
+            (NumberScalar::UInt16(v), INT4_OID) => buf.extend_from_slice(&(v as i32).to_be_bytes()),
+            (NumberScalar::Int32(v), INT4_OID) => buf.extend_from_slice(&v.to_be_bytes()),
+            (NumberScalar::UInt32(v), INT8_OID) => buf.extend_from_slice(&(v as i64).to_be_bytes()),
+            (NumberScalar::Int64(v), INT8_OID) => buf.extend_from_slice(&v.to_be_bytes()),
+            (NumberScalar::UInt64(v), NUMERIC_OID) => {
+                buf.extend_from_slice(&encode_numeric(&v.to_string())?)
+            }
+            (NumberScalar::Float32(v), FLOAT4_OID) => buf.extend_from_slice(&v.0.to_be_bytes()),
+            (NumberScalar::Float64(v), FLOAT8_OID) => buf.extend_from_slice(&v.0.to_be_bytes()),
+            _ => {
+                return Err(ErrorCode::Internal(format!(
+                    "mismatched number {:?} for type oid {}",
+                    number, type_oid
+                )));
+            }
+        },
+        ScalarRef::Decimal(v) => buf.extend_from_slice(&encode_numeric(&v.to_string())?),
+        // `text` and `json` share the same representation in text and binary format.
+        _ => encoder.write_field(column, row_index, buf, true),
+    }
+    Ok(())
+}
+
+/// Encode a decimal string like `-12.340` to the binary format of `numeric`, which
+/// is a list of base 10000 digits with a weight, sign and display scale.
+fn encode_numeric(s: &str) -> Result<Vec<u8>> {
+    const NUMERIC_POS: i16 = 0x0000;
+    const NUMERIC_NEG: i16 = 0x4000;
+
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(rest) => (NUMERIC_NEG, rest),
+        None => (NUMERIC_POS, s),
+    };
+    let (int_part, frac_part) = s.split_once('.').unwrap_or((s, ""));
+    if !int_part
+        .bytes()
+        .chain(frac_part.bytes())
+        .all(|c| c.is_ascii_digit())
+    {
+        return Err(ErrorCode::BadArguments(format!(
+            "invalid numeric value: {}",
+            s
+        )));
+    }
+    let dscale = frac_part.len() as i16;
+
+    // Pad both parts to a multiple of 4 digits, so they can be split into base 10000 digits.
+    let int_pad = (4 - int_part.len() % 4) % 4;
+    let frac_pad = (4 - frac_part.len() % 4) % 4;
+    let digits_str = format!(
+        "{}{}{}{}",
+        "0".repeat(int_pad),
+        int_part,
+        frac_part,
+        "0".repeat(frac_pad)
+    );
+    let mut digits = digits_str
+        .as_bytes()
+        .chunks(4)
+        .map(|c| std::str::from_utf8(c).unwrap().parse::<i16>().unwrap())
+        .collect::<Vec<_>>();
+    let mut weight = ((int_part.len() + int_pad) / 4) as i16 - 1;
+
+    // Strip the leading and trailing zero digits.
+    while digits.first() == Some(&0) {
+        digits.remove(0);
+        weight -= 1;
+    }
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        weight = 0;
+    }
+
+    let mut buf = Vec::with_capacity(8 + digits.len() * 2);
+    buf.extend_from_slice(&(digits.len() as i16).to_be_bytes());
+    buf.extend_from_slice(&weight.to_be_bytes());
+    buf.extend_from_slice(&sign.to_be_bytes());
+    buf.extend_from_slice(&dscale.to_be_bytes());
+    for digit in digits {
+        buf.extend_from_slice(&digit.to_be_bytes());
+    }
+    Ok(buf)
+}
+
+/// Convert a parameter of the Bind message to a SQL literal, which replaces the
+/// `$n` placeholder in the query.
+pub fn param_to_literal(type_oid: u32, format: i16, value: Option<&[u8]>) -> Result<String> {
+    let value = match value {
+        None => return Ok("NULL".to_string()),
+        Some(value) => value,
+    };
+    match format {
+        FORMAT_TEXT => text_param_to_literal(type_oid, value),
+        FORMAT_BINARY => binary_param_to_literal(type_oid, value),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "unsupported format code: {}",
+            format
+        ))),
+    }
+}
+
+fn text_param_to_literal(type_oid: u32, value: &[u8]) -> Result<String> {
+    let s = std::str::from_utf8(value)
+        .map_err(|e| ErrorCode::BadBytes(format!("invalid utf-8 sequence in parameter: {}", e)))?;
+    match type_oid {
+        BOOL_OID => match s.trim().to_ascii_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => Ok("TRUE".to_string()),
+            "f" | "false" | "n" | "no" | "off" | "0" => Ok("FALSE".to_string()),
+            _ => Err(invalid_param("boolean", s)),
+        },
+        INT2_OID | INT4_OID | INT8_OID | OID_OID => match s.trim().parse::<i64>() {
+            Ok(v) => Ok(v.to_string()),
+            Err(_) => Err(invalid_param("integer", s)),
+        },
+        FLOAT4_OID | FLOAT8_OID | NUMERIC_OID => {
+            let s = s.trim();
+            match s.parse::<f64>() {
+                Ok(v) if v.is_finite() && s.bytes().all(|c| b"0123456789+-.eE".contains(&c)) => {
+                    Ok(s.to_string())
+                }
+                Ok(_) => Ok(format!("{}::DOUBLE", quote_string(s.as_bytes()))),
+                Err(_) => Err(invalid_param("numeric", s)),
+            }
+        }
+        DATE_OID => Ok(format!("{}::DATE", quote_string(value))),
+        TIMESTAMP_OID | TIMESTAMPTZ_OID => Ok(format!("{}::TIMESTAMP", quote_string(value))),
+        BYTEA_OID => match s.strip_prefix("\\x") {
+            Some(hex) => {
+                if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(invalid_param("bytea", s));
+                }
+                Ok(format!("to_binary(unhex('{}'))", hex))
+            }
+            None => Ok(format!("to_binary({})", quote_string(value))),
+        },
+        _ => Ok(quote_string(value)),
+    }
+}
+
+fn binary_param_to_literal(type_oid: u32, value: &[u8]) -> Result<String> {
+    fn fixed<const N: usize>(value: &[u8], type_name: &str) -> Result<[u8; N]> {
+        value.try_into().map_err(|_| {
+            ErrorCode::BadBytes(format!(
+                "insufficient data left in message for {} parameter",
+                type_name
+            ))
+        })
+    }
+
+    match type_oid {
+        BOOL_OID => {
+            let [v] = fixed::<1>(value, "boolean")?;
+            Ok(if v != 0 { "TRUE" } else { "FALSE" }.to_string())
+        }
+        INT2_OID => Ok(i16::from_be_bytes(fixed(value, "smallint")?).to_string()),
+        INT4_OID | OID_OID => Ok(i32::from_be_bytes(fixed(value, "integer")?).to_string()),
+        INT8_OID => Ok(i64::from_be_bytes(fixed(value, "bigint")?).to_string()),
+        FLOAT4_OID => float_literal(f32::from_be_bytes(fixed(value, "real")?) as f64),
+        FLOAT8_OID => float_literal(f64::from_be_bytes(fixed(value, "double precision")?)),
+        DATE_OID => {
+            let days = i32::from_be_bytes(fixed(value, "date")?);
+            let date = NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .checked_add_signed(Duration::days(days as i64))
+                .ok_or_else(|| invalid_param("date", &days.to_string()))?;
+            Ok(format!("'{}'::DATE", date.format("%Y-%m-%d")))
+        }
+        TIMESTAMP_OID | TIMESTAMPTZ_OID => {
+            let micros = i64::from_be_bytes(fixed(value, "timestamp")?);
+            let ts = NaiveDateTime::from_timestamp_micros(micros + POSTGRES_EPOCH_MICROS)
+                .ok_or_else(|| invalid_param("timestamp", &micros.to_string()))?;
+            Ok(format!(
+                "'{}'::TIMESTAMP",
+                ts.format("%Y-%m-%d %H:%M:%S%.6f")
+            ))
+        }
+        BYTEA_OID => {
+            let hex = value
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>();
+            Ok(format!("to_binary(unhex('{}'))", hex))
+        }
+        UNSPECIFIED_OID | TEXT_OID | VARCHAR_OID | BPCHAR_OID | UNKNOWN_OID | JSON_OID => {
+            Ok(quote_string(value))
+        }
+        _ => Err(ErrorCode::Unimplemented(format!(
+            "binary format of parameter type oid {} is not supported",
+            type_oid
+        ))),
+    }
+}
+
+fn float_literal(v: f64) -> Result<String> {
+    if v.is_finite() {
+        Ok(format!("{:?}", v))
+    } else {
+        Ok(format!("'{}'::DOUBLE", v))
+    }
+}
+
+fn invalid_param(type_name: &str, value: &str) -> ErrorCode {
+    ErrorCode::BadArguments(format!(
+        "invalid input syntax for type {}: \"{}\"",
+        type_name, value
+    ))
+}
+
+fn quote_string(value: &[u8]) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('\'');
+    for c in String::from_utf8_lossy(value).chars() {
+        match c {
+            '\'' => quoted.push_str("''"),
+            '\\' => quoted.push_str("\\\\"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
+
+/// Replace the `$n` placeholders outside of literals, identifiers and comments with the
+/// given parameters. The placeholders beyond the number of parameters are left untouched,
+/// as they are positional columns of stage queries, like `SELECT $1 FROM @stage`.
+pub fn bind_params(query: &str, params: &[String]) -> String {
+    replace_placeholders(query, |n| params.get(n - 1).map(|p| p.as_str()))
+}
+
+/// The number of parameters a query refers to, i.e. the max `n` of `$n` placeholders.
+pub fn count_params(query: &str) -> usize {
+    let mut max = 0;
+    replace_placeholders(query, |n| {
+        max = max.max(n);
+        None
+    });
+    max
+}
+
+fn replace_placeholders<'a, F>(query: &str, mut replace: F) -> String
+where F: FnMut(usize) -> Option<&'a str> {
+    let chars = query.chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(query.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        match chars[i] {
+            quote @ ('\'' | '"' | '`') => {
+                i += 1;
+                while i < chars.len() {
+                    if chars[i] == '\\' && quote == '\'' {
+                        i += 2;
+                    } else if chars[i] == quote && chars.get(i + 1) == Some(&quote) {
+                        // A doubled quote is an escaped quote.
+                        i += 2;
+                    } else if chars[i] == quote {
+                        break;
+                    } else {
+                        i += 1;
+                    }
+                }
+                i = (i + 1).min(chars.len());
+            }
+            '-' if chars.get(i + 1) == Some(&'-') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    i += 1;
+                }
+                i = (i + 2).min(chars.len());
+            }
+            '$' => {
+                i += 1;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                let index = chars[start + 1..i]
+                    .iter()
+                    .collect::<String>()
+                    .parse::<usize>();
+                if let Ok(n) = index {
+                    if n >= 1 {
+                        if let Some(param) = replace(n) {
+                            result.push_str(param);
+                            continue;
+                        }
+                    }
+                }
+            }
+            _ => i += 1,
+        }
+        result.extend(&chars[start..i]);
+    }
+    result
+}
@@ -29,6 +29,8 @@ use std::time::SystemTime;
 use common_base::base::tokio::task::JoinHandle;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
+use common_base::runtime::MemStat;
+use common_base::runtime::TrackedFuture;
 use common_base::runtime::TrySpawn;
 use common_catalog::plan::DataSourceInfo;
 use common_catalog::plan::DataSourcePlan;
@@ -204,6 +206,14 @@ impl QueryContext {
         self.shared.set_executor(weak_ptr)
     }
 
+    pub fn set_mem_stat(&self, mem_stat: Option<Arc<MemStat>>) {
+        self.shared.set_mem_stat(mem_stat)
+    }
+
+    pub fn get_mem_stat(&self) -> Option<Arc<MemStat>> {
+        self.shared.get_mem_stat()
+    }
+
     pub fn attach_stage(&self, attachment: StageAttachment) {
         self.shared.attach_stage(attachment);
     }
@@ -495,6 +505,18 @@ impl TableContext for QueryContext {
         self.shared.get_table(catalog, database, table).await
     }
 
+    #[async_backtrace::framed]
+    async fn get_granted_columns(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Option<HashSet<String>>> {
+        self.get_current_session()
+            .get_granted_columns(catalog, database, table)
+            .await
+    }
+
     #[async_backtrace::framed]
     async fn filter_out_copied_files(
         &self,
@@ -566,7 +588,14 @@ impl TrySpawn for QueryContext {
         T: Future + Send + 'static,
         T::Output: Send + 'static,
     {
-        Ok(self.shared.try_get_runtime()?.spawn(task))
+        let runtime = self.shared.try_get_runtime()?;
+        // The tasks of the query are accounted to the memory quota of the user as well.
+        Ok(match self.get_mem_stat() {
+            Some(mem_stat) => {
+                runtime.spawn(TrackedFuture::create_with_mem_stat(Some(mem_stat), task))
+            }
+            None => runtime.spawn(task),
+        })
     }
 }
 
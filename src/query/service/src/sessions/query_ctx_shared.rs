@@ -21,6 +21,7 @@ use std::sync::Weak;
 use std::time::SystemTime;
 
 use common_base::base::Progress;
+use common_base::runtime::MemStat;
 use common_base::runtime::Runtime;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
@@ -74,6 +75,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) catalog_manager: Arc<CatalogManager>,
     pub(in crate::sessions) data_operator: DataOperator,
     pub(in crate::sessions) executor: Arc<RwLock<Weak<PipelineExecutor>>>,
+    /// Memory stat of the query accounted to the quota of the user, None if no quota.
+    pub(in crate::sessions) mem_stat: Arc<RwLock<Option<Arc<MemStat>>>>,
     pub(in crate::sessions) precommit_blocks: Arc<RwLock<Vec<DataBlock>>>,
     pub(in crate::sessions) stage_attachment: Arc<RwLock<Option<StageAttachment>>>,
     pub(in crate::sessions) created_time: SystemTime,
@@ -107,6 +110,7 @@ impl QueryContextShared {
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
             affect: Arc::new(Mutex::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
+            mem_stat: Arc::new(RwLock::new(None)),
             precommit_blocks: Arc::new(RwLock::new(vec![])),
             stage_attachment: Arc::new(RwLock::new(None)),
             created_time: SystemTime::now(),
@@ -313,6 +317,14 @@ impl QueryContextShared {
         *executor = weak_ptr;
     }
 
+    pub fn set_mem_stat(&self, mem_stat: Option<Arc<MemStat>>) {
+        *self.mem_stat.write() = mem_stat;
+    }
+
+    pub fn get_mem_stat(&self) -> Option<Arc<MemStat>> {
+        self.mem_stat.read().clone()
+    }
+
     pub fn push_precommit_block(&self, block: DataBlock) {
         let mut blocks = self.precommit_blocks.write();
         blocks.push(block);
@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
 use chrono_tz::Tz;
+use common_catalog::catalog::CatalogManager;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -24,10 +26,12 @@ use common_io::prelude::FormatSettings;
 use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeSet;
 use common_meta_app::principal::UserPrivilegeType;
 use common_settings::ChangeValue;
 use common_settings::Settings;
 use common_users::RoleCacheManager;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
 use common_users::BUILTIN_ROLE_PUBLIC;
 use parking_lot::RwLock;
 
@@ -297,6 +301,65 @@ impl Session {
         Ok(related_roles)
     }
 
+    // Returns the current role and all the roles granted to it directly or indirectly, the
+    // privileges of all these roles are effective in the current session.
+    #[async_backtrace::framed]
+    pub async fn get_all_effective_roles(self: &Arc<Self>) -> Result<Vec<RoleInfo>> {
+        self.ensure_current_role().await?;
+        let Some(current_role) = self.get_current_role() else {
+            return Ok(vec![]);
+        };
+
+        let tenant = self.get_current_tenant();
+        let mut roles = RoleCacheManager::instance()
+            .find_related_roles(&tenant, &[current_role.name.clone()])
+            .await?;
+        // a role granted with ACCOUNT_ADMIN is as powerful as ACCOUNT_ADMIN on privilege checks.
+        if roles.iter().any(|r| r.name == BUILTIN_ROLE_ACCOUNT_ADMIN) {
+            roles = RoleCacheManager::instance()
+                .find_related_roles(&tenant, &[BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()])
+                .await?;
+        }
+        // the PUBLIC role might not be persisted.
+        if !roles.iter().any(|r| r.name == current_role.name) {
+            roles.push(current_role);
+        }
+        Ok(roles)
+    }
+
+    // The owner of a database has all the privileges on the database and the tables inside it,
+    // and the owner of a table has all the privileges on the table. Every session has the PUBLIC
+    // role, so it never owns anything.
+    #[async_backtrace::framed]
+    async fn is_owned_by(self: &Arc<Self>, object: &GrantObject, roles: &[RoleInfo]) -> bool {
+        let (catalog_name, database_name, table_name) = match object {
+            GrantObject::Global => return false,
+            GrantObject::Database(catalog, database) => (catalog, database, None),
+            GrantObject::Table(catalog, database, table)
+            | GrantObject::Column(catalog, database, table, _) => (catalog, database, Some(table)),
+        };
+        let is_owner = |owner: &Option<String>| match owner {
+            Some(owner) if owner != BUILTIN_ROLE_PUBLIC => roles.iter().any(|r| &r.name == owner),
+            _ => false,
+        };
+
+        let tenant = self.get_current_tenant();
+        let Ok(catalog) = CatalogManager::instance().get_catalog(catalog_name) else {
+            return false;
+        };
+        if let Some(table_name) = table_name {
+            if let Ok(table) = catalog.get_table(&tenant, database_name, table_name).await {
+                if is_owner(&table.get_table_info().meta.owner) {
+                    return true;
+                }
+            }
+        }
+        match catalog.get_database(&tenant, database_name).await {
+            Ok(database) => is_owner(&database.get_db_info().meta.owner),
+            Err(_) => false,
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn validate_privilege(
         self: &Arc<Self>,
@@ -312,16 +375,25 @@ impl Session {
             return Ok(());
         }
 
-        // 2. check the current role's privilege set
-        self.ensure_current_role().await?;
-        let current_role = self.get_current_role();
-        let role_verified = current_role
-            .map(|r| r.grants.verify_privilege(object, privilege.clone()))
-            .unwrap_or(false);
+        // 2. check the privilege set of the current role and the roles granted to it
+        let effective_roles = self.get_all_effective_roles().await?;
+        let role_verified = effective_roles
+            .iter()
+            .any(|r| r.grants.verify_privilege(object, privilege.clone()));
         if role_verified {
             return Ok(());
         }
 
+        // 3. check whether the object is owned by any of these roles, only the privileges on
+        // databases and tables come with the ownership.
+        let owner_privileges = UserPrivilegeSet::available_privileges_on_table();
+        let covered_by_ownership = privilege
+            .iter()
+            .all(|p| *p == UserPrivilegeType::Ownership || owner_privileges.has_privilege(*p));
+        if covered_by_ownership && self.is_owned_by(object, &effective_roles).await {
+            return Ok(());
+        }
+
         Err(ErrorCode::PermissionDenied(format!(
             "Permission denied, user {} requires {:?} privilege on {}",
             &current_user.identity(),
@@ -330,6 +402,62 @@ impl Session {
         )))
     }
 
+    /// Returns the columns of the table granted to the current user and role by column level
+    /// privileges, None means the columns of the table are not restricted.
+    #[async_backtrace::framed]
+    pub async fn get_granted_columns(
+        self: &Arc<Self>,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Option<HashSet<String>>> {
+        let Ok(current_user) = self.get_current_user() else {
+            return Ok(None);
+        };
+
+        let object =
+            GrantObject::Table(catalog.to_string(), database.to_string(), table.to_string());
+        let privilege = vec![UserPrivilegeType::Select];
+        if current_user
+            .grants
+            .verify_privilege(&object, privilege.clone())
+        {
+            return Ok(None);
+        }
+
+        let effective_roles = self.get_all_effective_roles().await?;
+        if effective_roles
+            .iter()
+            .any(|r| r.grants.verify_privilege(&object, privilege.clone()))
+        {
+            return Ok(None);
+        }
+        if self.is_owned_by(&object, &effective_roles).await {
+            return Ok(None);
+        }
+
+        let mut columns = HashSet::new();
+        columns.extend(current_user.grants.granted_columns(
+            catalog,
+            database,
+            table,
+            UserPrivilegeType::Select,
+        ));
+        for role in &effective_roles {
+            columns.extend(role.grants.granted_columns(
+                catalog,
+                database,
+                table,
+                UserPrivilegeType::Select,
+            ));
+        }
+
+        match columns.is_empty() {
+            true => Ok(None),
+            false => Ok(Some(columns)),
+        }
+    }
+
     pub fn get_settings(self: &Arc<Self>) -> Arc<Settings> {
         self.session_ctx.get_settings()
     }
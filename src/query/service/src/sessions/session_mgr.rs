@@ -20,14 +20,17 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 use std::time::Duration;
+use std::time::Instant;
 
 use common_base::base::tokio;
 use common_base::base::GlobalInstance;
 use common_base::base::SignalStream;
+use common_base::runtime::MemStat;
 use common_config::GlobalConfig;
 use common_config::InnerConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::principal::UserIdentity;
 use common_metrics::label_counter;
 use common_metrics::label_gauge;
 use common_settings::Settings;
@@ -54,6 +57,13 @@ pub struct SessionManager {
     // When typ is MySQL, insert into this map, key is id, val is MySQL connection id.
     pub(crate) mysql_conn_map: Arc<RwLock<HashMap<Option<u32>, String>>>,
     pub(in crate::sessions) mysql_basic_conn_id: AtomicU32,
+
+    // Memory stat of each user, the queries of a user report memory usage to it.
+    pub(in crate::sessions) user_mem_stats: Arc<RwLock<HashMap<String, Arc<MemStat>>>>,
+
+    // Storage used by each user and when it was counted from the tables owned by the user,
+    // the data written by the user since then is added to it.
+    pub(in crate::sessions) user_storage_usages: Arc<RwLock<HashMap<String, (Instant, u64)>>>,
 }
 
 impl SessionManager {
@@ -71,6 +81,8 @@ impl SessionManager {
             status: Arc::new(RwLock::new(SessionManagerStatus::default())),
             mysql_conn_map: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
+            user_mem_stats: Arc::new(RwLock::new(HashMap::new())),
+            user_storage_usages: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
@@ -78,6 +90,40 @@ impl SessionManager {
         GlobalInstance::get()
     }
 
+    pub fn get_user_mem_stat(&self, user: &UserIdentity) -> Arc<MemStat> {
+        let key = user.to_string();
+        if let Some(mem_stat) = self.user_mem_stats.read().get(&key) {
+            return mem_stat.clone();
+        }
+
+        self.user_mem_stats
+            .write()
+            .entry(key)
+            .or_insert_with(|| MemStat::create_child(format!("User-{}", user), None))
+            .clone()
+    }
+
+    /// Returns the storage used by the user, None if it was not counted within the interval.
+    pub fn get_user_storage_usage(&self, user: &UserIdentity, interval: Duration) -> Option<u64> {
+        match self.user_storage_usages.read().get(&user.to_string()) {
+            Some((counted_at, used)) if counted_at.elapsed() < interval => Some(*used),
+            _ => None,
+        }
+    }
+
+    pub fn set_user_storage_usage(&self, user: &UserIdentity, used: u64) {
+        self.user_storage_usages
+            .write()
+            .insert(user.to_string(), (Instant::now(), used));
+    }
+
+    /// Adds the bytes written by the user to the storage usage counted before.
+    pub fn add_user_storage_usage(&self, user: &UserIdentity, bytes: u64) {
+        if let Some((_, used)) = self.user_storage_usages.write().get_mut(&user.to_string()) {
+            *used += bytes;
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn create_session(&self, typ: SessionType) -> Result<Arc<Session>> {
         {
@@ -18,6 +18,7 @@ use std::fmt;
 pub enum SessionType {
     Clickhouse,
     MySQL,
+    Postgres,
     HTTPQuery,
     HTTPStreamingLoad,
     ClickHouseHttpHandler,
@@ -44,6 +45,7 @@ impl fmt::Display for SessionType {
             SessionType::ClickHouseHttpHandler => "ClickhouseHTTPHandler".to_string(),
             SessionType::Clickhouse => "Clickhouse".to_string(),
             SessionType::MySQL => "MySQL".to_string(),
+            SessionType::Postgres => "Postgres".to_string(),
             SessionType::HTTPQuery => "HTTPQuery".to_string(),
             SessionType::HTTPStreamingLoad => "HTTPStreamingLoad".to_string(),
             SessionType::Dummy => "Dummy".to_string(),
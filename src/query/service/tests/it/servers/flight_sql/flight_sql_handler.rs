@@ -20,8 +20,10 @@ use std::io::Write;
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::CommandGetDbSchemas;
 use arrow_flight::utils::flight_data_to_batches;
 use arrow_flight::FlightData;
+use arrow_flight::FlightInfo;
 use arrow_schema::ArrowError;
 use common_base::base::tokio;
 use common_config::InnerConfig;
@@ -67,15 +69,22 @@ async fn run_query(
         affected_rows.to_string()
     } else {
         let flight_info = stmt.execute().await?;
-        let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
-        let flight_data = client.do_get(ticket).await?;
-        let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
-        let batches = flight_data_to_batches(&flight_data)?;
-        pretty_format_batches(batches.as_slice())?.to_string()
+        fetch_flight_info(client, flight_info).await?
     };
     Ok(res)
 }
 
+async fn fetch_flight_info(
+    client: &mut FlightSqlServiceClient,
+    flight_info: FlightInfo,
+) -> std::result::Result<String, ArrowError> {
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+    let batches = flight_data_to_batches(&flight_data)?;
+    Ok(pretty_format_batches(batches.as_slice())?.to_string())
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -131,6 +140,40 @@ async fn test_query() -> Result<()> {
             };
             writeln!(file, "{}", res).unwrap();
         }
+
+        let metadata_cases = [
+            "execute",
+            "get_catalogs",
+            "get_db_schemas",
+            "get_table_types",
+        ];
+        for case in metadata_cases {
+            writeln!(file, "---------- Input ----------").unwrap();
+            writeln!(file, "{}", case).unwrap();
+            writeln!(file, "---------- Output ---------").unwrap();
+            let flight_info = match case {
+                "execute" => client.execute("select 1 as a".to_string()).await,
+                "get_catalogs" => client.get_catalogs().await,
+                "get_db_schemas" => {
+                    client
+                        .get_db_schemas(CommandGetDbSchemas {
+                            catalog: None,
+                            db_schema_filter_pattern: Some("syst_m%".to_string()),
+                        })
+                        .await
+                }
+                "get_table_types" => client.get_table_types().await,
+                _ => unreachable!(),
+            };
+            let res = match flight_info {
+                Ok(flight_info) => match fetch_flight_info(&mut client, flight_info).await {
+                    Ok(s) => s,
+                    Err(e) => format!("{e:?}"),
+                },
+                Err(e) => format!("{e:?}"),
+            };
+            writeln!(file, "{}", res).unwrap();
+        }
     };
     tokio::pin!(serve_future);
 
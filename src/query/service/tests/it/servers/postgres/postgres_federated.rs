@@ -0,0 +1,96 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::block_debug::assert_blocks_eq;
+use databend_query::servers::PostgresFederated;
+
+#[test]
+fn test_postgres_federated() -> Result<()> {
+    let federated = PostgresFederated::create("default".to_string());
+
+    //
+    {
+        let query = "select 1";
+        let result = federated.check(query);
+        assert!(result.is_none());
+    }
+
+    // functions
+    {
+        let query = "SELECT current_schema()";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((schema, block)) = result {
+            assert_eq!(schema.field(0).name(), "current_schema");
+            let expect = vec![
+                "+-----------+",
+                "| Column 0  |",
+                "+-----------+",
+                "| \"default\" |",
+                "+-----------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+
+        let query = "select pg_catalog.version()";
+        let result = federated.check(query);
+        assert!(result.is_some());
+    }
+
+    // show variables
+    {
+        let query = "show transaction isolation level";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((_, block)) = result {
+            let expect = vec![
+                "+------------------+",
+                "| Column 0         |",
+                "+------------------+",
+                "| \"read committed\" |",
+                "+------------------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    // client setup and catalog probes
+    {
+        for query in [
+            "SET extra_float_digits = 3",
+            "SET application_name = 'PostgreSQL JDBC Driver'",
+            "BEGIN",
+            "SELECT typinput='array_in'::regproc, typtype\n  FROM pg_catalog.pg_type\n  LEFT JOIN (select ns.oid as nspoid from pg_catalog.pg_namespace ns) r on r.nspoid = typnamespace",
+        ] {
+            let result = federated.check(query);
+            assert!(result.is_some(), "{}", query);
+            if let Some((schema, block)) = result {
+                assert!(schema.fields().is_empty());
+                assert_eq!(block.num_rows(), 0);
+            }
+        }
+
+        // Databend settings are not federated.
+        let query = "SET max_threads = 4";
+        let result = federated.check(query);
+        assert!(result.is_none());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,167 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::servers::PostgresHandler;
+use tokio_postgres::Client;
+use tokio_postgres::NoTls;
+use tokio_postgres::SimpleQueryMessage;
+
+use crate::tests::ConfigBuilder;
+use crate::tests::TestGlobalServices;
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_simple_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create()?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let client = create_connection(listening.port()).await?;
+
+    let messages = client
+        .simple_query("SELECT 1 AS a, 'x' AS b, NULL AS c")
+        .await
+        .map_err(to_error_code)?;
+    let rows = messages
+        .iter()
+        .filter_map(|m| match m {
+            SimpleQueryMessage::Row(row) => Some(row),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get("a"), Some("1"));
+    assert_eq!(rows[0].get("b"), Some("x"));
+    assert_eq!(rows[0].get("c"), None);
+    assert!(matches!(
+        messages.last(),
+        Some(SimpleQueryMessage::CommandComplete(1))
+    ));
+
+    // Federated query.
+    let messages = client
+        .simple_query("SET extra_float_digits = 3")
+        .await
+        .map_err(to_error_code)?;
+    assert!(matches!(
+        messages.last(),
+        Some(SimpleQueryMessage::CommandComplete(0))
+    ));
+
+    // Errors are reported and the connection is still usable.
+    let error = client.simple_query("SELECT * FROM not_exists").await;
+    assert!(error.is_err());
+    let messages = client
+        .simple_query("SELECT number FROM numbers(3)")
+        .await
+        .map_err(to_error_code)?;
+    assert!(matches!(
+        messages.last(),
+        Some(SimpleQueryMessage::CommandComplete(3))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_extended_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create()?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let client = create_connection(listening.port()).await?;
+
+    let rows = client
+        .query(
+            "SELECT number::INT AS n FROM numbers(5) WHERE number < 3",
+            &[],
+        )
+        .await
+        .map_err(to_error_code)?;
+    let values = rows.iter().map(|r| r.get::<_, i32>(0)).collect::<Vec<_>>();
+    assert_eq!(values, vec![0, 1, 2]);
+
+    let rows = client
+        .query("SELECT $1 AS s", &[&"it's"])
+        .await
+        .map_err(to_error_code)?;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<_, String>(0), "it's");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_rejected_session() -> Result<()> {
+    let _guard =
+        TestGlobalServices::setup(ConfigBuilder::create().max_active_sessions(1).build()).await?;
+
+    let mut handler = PostgresHandler::create()?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    {
+        // Accepted connection
+        let client = create_connection(listening.port()).await?;
+
+        // Rejected connection
+        match create_connection(listening.port()).await {
+            Ok(_) => panic!("Expected rejected connection"),
+            Err(error) => {
+                assert!(
+                    error
+                        .message()
+                        .contains("has exceeded the max_active_sessions limit (1)"),
+                    "{}",
+                    error.message()
+                );
+            }
+        };
+
+        drop(client);
+    }
+
+    // Wait for the connection to be destroyed
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    // Accepted connection
+    create_connection(listening.port()).await?;
+
+    Ok(())
+}
+
+async fn create_connection(port: u16) -> Result<Client> {
+    let config = format!("host=127.0.0.1 port={} user=root dbname=default", port);
+    let (client, connection) = tokio_postgres::connect(&config, NoTls)
+        .await
+        .map_err(to_error_code)?;
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+    Ok(client)
+}
+
+fn to_error_code(error: tokio_postgres::Error) -> ErrorCode {
+    let message = match error.as_db_error() {
+        Some(db_error) => db_error.message().to_string(),
+        None => error.to_string(),
+    };
+    ErrorCode::UnknownException(format!("Reject connection, cause: {}", message))
+}
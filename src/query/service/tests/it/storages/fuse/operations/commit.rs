@@ -54,6 +54,8 @@ use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SetDatabaseOwnerReply;
+use common_meta_app::schema::SetDatabaseOwnerReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -559,6 +561,15 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn get_granted_columns(
+        &self,
+        _catalog: &str,
+        _database: &str,
+        _table: &str,
+    ) -> Result<Option<HashSet<String>>> {
+        todo!()
+    }
+
     async fn filter_out_copied_files(
         &self,
         _catalog_name: &str,
@@ -603,6 +614,10 @@ impl Catalog for FakedCatalog {
         todo!()
     }
 
+    async fn set_database_owner(&self, _req: SetDatabaseOwnerReq) -> Result<SetDatabaseOwnerReply> {
+        todo!()
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         self.cat.get_table_by_info(table_info)
     }
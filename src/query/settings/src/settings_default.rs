@@ -110,6 +110,11 @@ impl DefaultSettings {
                     desc: "Sets the maximum number of values that can be included in an IN expression to be converted to an OR operator.",
                     possible_values: None,
                 }),
+                ("max_cte_recursive_depth", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the maximum recursion depth of a recursive common table expression (CTE).",
+                    possible_values: None,
+                }),
                 ("unquoted_ident_case_sensitive", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Determines whether Databend treats unquoted identifiers as case-sensitive.",
@@ -233,6 +238,16 @@ impl DefaultSettings {
                     desc: "Sets the maximum amount of memory in bytes that an aggregator can use before spilling data to storage during query execution.",
                     possible_values: None,
                 }),
+                ("sort_spilling_bytes_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum amount of memory in bytes that a sorter can use before spilling data to storage during query execution.",
+                    possible_values: None,
+                }),
+                ("join_spilling_bytes_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum amount of memory in bytes that the build side of a hash join can use before spilling data to storage during query execution.",
+                    possible_values: None,
+                }),
                 ("group_by_shuffle_mode", DefaultSettingValue {
                     value: UserSettingValue::String(String::from("before_merge")),
                     desc: "Group by shuffle mode, 'before_partial' is more balanced, but more data needs to exchange.",
@@ -189,6 +189,10 @@ impl Settings {
         self.try_get_u64("max_inlist_to_or")
     }
 
+    pub fn get_max_cte_recursive_depth(&self) -> Result<u64> {
+        self.try_get_u64("max_cte_recursive_depth")
+    }
+
     pub fn get_unquoted_ident_case_sensitive(&self) -> Result<bool> {
         Ok(self.try_get_u64("unquoted_ident_case_sensitive")? != 0)
     }
@@ -312,6 +316,22 @@ impl Settings {
         self.try_set_u64("spilling_bytes_threshold_per_proc", value as u64)
     }
 
+    pub fn get_sort_spilling_bytes_threshold(&self) -> Result<usize> {
+        Ok(self.try_get_u64("sort_spilling_bytes_threshold")? as usize)
+    }
+
+    pub fn set_sort_spilling_bytes_threshold(&self, value: usize) -> Result<()> {
+        self.try_set_u64("sort_spilling_bytes_threshold", value as u64)
+    }
+
+    pub fn get_join_spilling_bytes_threshold(&self) -> Result<usize> {
+        Ok(self.try_get_u64("join_spilling_bytes_threshold")? as usize)
+    }
+
+    pub fn set_join_spilling_bytes_threshold(&self, value: usize) -> Result<()> {
+        self.try_set_u64("join_spilling_bytes_threshold", value as u64)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
@@ -31,6 +31,7 @@ use super::Limit;
 use super::PhysicalPlan;
 use super::Project;
 use super::ProjectSet;
+use super::RecursiveCteScan;
 use super::Sort;
 use super::TableScan;
 use super::UnionAll;
@@ -137,6 +138,7 @@ fn to_format_tree(
         PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::RecursiveCteScan(plan) => recursive_cte_scan_to_format_tree(plan),
         PhysicalPlan::ExchangeSource(plan) => exchange_source_to_format_tree(plan),
         PhysicalPlan::ExchangeSink(plan) => {
             exchange_sink_to_format_tree(plan, metadata, prof_span_set)
@@ -752,6 +754,10 @@ fn union_all_to_format_tree(
 ) -> Result<FormatTreeNode<String>> {
     let mut children = vec![];
 
+    if let Some(cte_name) = &plan.cte_name {
+        children.push(FormatTreeNode::new(format!("recursive cte: {cte_name}")));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -775,6 +781,20 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn recursive_cte_scan_to_format_tree(plan: &RecursiveCteScan) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!("cte: {}", plan.cte_name))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCteScan".to_string(),
+        children,
+    ))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
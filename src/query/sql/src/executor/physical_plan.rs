@@ -290,15 +290,45 @@ pub enum WindowFunction {
     RowNumber,
     Rank,
     DenseRank,
+    PercentRank,
+    CumeDist,
+    Ntile(u64),
+    LagLead(LagLeadFunctionDesc),
+    NthValue(NthValueFunctionDesc),
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct LagLeadFunctionDesc {
+    pub is_lag: bool,
+    pub offset: u64,
+    pub arg: IndexType,
+    pub default: Option<IndexType>,
+    pub ignore_nulls: bool,
+    pub return_type: DataType,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct NthValueFunctionDesc {
+    /// `None` means the last row of the frame.
+    pub n: Option<u64>,
+    pub arg: IndexType,
+    pub ignore_nulls: bool,
+    pub return_type: DataType,
 }
 
 impl WindowFunction {
     fn data_type(&self) -> DataType {
         match self {
             WindowFunction::Aggregate(agg) => agg.sig.return_type.clone(),
-            WindowFunction::RowNumber | WindowFunction::Rank | WindowFunction::DenseRank => {
-                DataType::Number(NumberDataType::UInt64)
+            WindowFunction::RowNumber
+            | WindowFunction::Rank
+            | WindowFunction::DenseRank
+            | WindowFunction::Ntile(_) => DataType::Number(NumberDataType::UInt64),
+            WindowFunction::PercentRank | WindowFunction::CumeDist => {
+                DataType::Number(NumberDataType::Float64)
             }
+            WindowFunction::LagLead(lag_lead) => lag_lead.return_type.clone(),
+            WindowFunction::NthValue(nth) => nth.return_type.clone(),
         }
     }
 }
@@ -310,6 +340,28 @@ impl Display for WindowFunction {
             WindowFunction::RowNumber => write!(f, "row_number"),
             WindowFunction::Rank => write!(f, "rank"),
             WindowFunction::DenseRank => write!(f, "dense_rank"),
+            WindowFunction::PercentRank => write!(f, "percent_rank"),
+            WindowFunction::CumeDist => write!(f, "cume_dist"),
+            WindowFunction::Ntile(n) => write!(f, "ntile({n})"),
+            WindowFunction::LagLead(lag_lead) => {
+                let name = if lag_lead.is_lag { "lag" } else { "lead" };
+                write!(f, "{name}")?;
+                if lag_lead.ignore_nulls {
+                    write!(f, " ignore nulls")?;
+                }
+                Ok(())
+            }
+            WindowFunction::NthValue(nth) => {
+                match nth.n {
+                    Some(1) => write!(f, "first_value")?,
+                    Some(n) => write!(f, "nth_value({n})")?,
+                    None => write!(f, "last_value")?,
+                }
+                if nth.ignore_nulls {
+                    write!(f, " ignore nulls")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -553,6 +605,9 @@ pub struct UnionAll {
     pub right: Box<PhysicalPlan>,
     pub pairs: Vec<(String, String)>,
     pub schema: DataSchemaRef,
+    /// Set if this is the union of a recursive cte, the right child
+    /// is evaluated repeatedly until the working table is empty.
+    pub cte_name: Option<String>,
 
     /// Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -564,6 +619,25 @@ impl UnionAll {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCteScan {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_name: String,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct DistributedInsertSelect {
     pub input: Box<PhysicalPlan>,
@@ -573,6 +647,8 @@ pub struct DistributedInsertSelect {
     pub select_schema: DataSchemaRef,
     pub select_column_bindings: Vec<ColumnBinding>,
     pub cast_needed: bool,
+    /// static partition columns appended to the inserted blocks
+    pub partition_columns: Vec<(DataField, Scalar)>,
 }
 
 impl DistributedInsertSelect {
@@ -621,6 +697,7 @@ pub enum PhysicalPlan {
     HashJoin(HashJoin),
     Exchange(Exchange),
     UnionAll(UnionAll),
+    RecursiveCteScan(RecursiveCteScan),
     RuntimeFilterSource(RuntimeFilterSource),
 
     /// For insert into ... select ... in cluster
@@ -657,6 +734,7 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSource(plan) => plan.output_schema(),
             PhysicalPlan::ExchangeSink(plan) => plan.output_schema(),
             PhysicalPlan::UnionAll(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCteScan(plan) => plan.output_schema(),
             PhysicalPlan::DistributedInsertSelect(plan) => plan.output_schema(),
             PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
             PhysicalPlan::RuntimeFilterSource(plan) => plan.output_schema(),
@@ -678,6 +756,7 @@ impl PhysicalPlan {
             PhysicalPlan::HashJoin(_) => "HashJoin".to_string(),
             PhysicalPlan::Exchange(_) => "Exchange".to_string(),
             PhysicalPlan::UnionAll(_) => "UnionAll".to_string(),
+            PhysicalPlan::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
             PhysicalPlan::DistributedInsertSelect(_) => "DistributedInsertSelect".to_string(),
             PhysicalPlan::ExchangeSource(_) => "Exchange Source".to_string(),
             PhysicalPlan::ExchangeSink(_) => "Exchange Sink".to_string(),
@@ -703,6 +782,7 @@ impl PhysicalPlan {
             ),
             PhysicalPlan::Exchange(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::ExchangeSource(_) => Box::new(std::iter::empty()),
+            PhysicalPlan::RecursiveCteScan(_) => Box::new(std::iter::empty()),
             PhysicalPlan::ExchangeSink(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::UnionAll(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
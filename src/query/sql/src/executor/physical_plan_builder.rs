@@ -44,7 +44,9 @@ use super::AggregatePartial;
 use super::Exchange as PhysicalExchange;
 use super::Filter;
 use super::HashJoin;
+use super::LagLeadFunctionDesc;
 use super::Limit;
+use super::NthValueFunctionDesc;
 use super::ProjectSet;
 use super::Sort;
 use super::TableScan;
@@ -54,6 +56,7 @@ use crate::executor::table_read_plan::ToReadDataSourcePlan;
 use crate::executor::EvalScalar;
 use crate::executor::FragmentKind;
 use crate::executor::PhysicalPlan;
+use crate::executor::RecursiveCteScan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::SortDesc;
 use crate::executor::UnionAll;
@@ -731,6 +734,50 @@ impl PhysicalPlanBuilder {
                     WindowFuncType::RowNumber => WindowFunction::RowNumber,
                     WindowFuncType::Rank => WindowFunction::Rank,
                     WindowFuncType::DenseRank => WindowFunction::DenseRank,
+                    WindowFuncType::PercentRank => WindowFunction::PercentRank,
+                    WindowFuncType::CumeDist => WindowFunction::CumeDist,
+                    WindowFuncType::Ntile(ntile) => WindowFunction::Ntile(ntile.n),
+                    WindowFuncType::LagLead(lag_lead) => {
+                        let arg = if let ScalarExpr::BoundColumnRef(col) = lag_lead.arg.as_ref() {
+                            col.column.index
+                        } else {
+                            return Err(ErrorCode::Internal(
+                                "Window's lag/lead function argument must be a BoundColumnRef",
+                            ));
+                        };
+                        let default = match lag_lead.default.as_deref() {
+                            Some(ScalarExpr::BoundColumnRef(col)) => Some(col.column.index),
+                            Some(_) => {
+                                return Err(ErrorCode::Internal(
+                                    "Window's lag/lead function default value must be a BoundColumnRef",
+                                ));
+                            }
+                            None => None,
+                        };
+                        WindowFunction::LagLead(LagLeadFunctionDesc {
+                            is_lag: lag_lead.is_lag,
+                            offset: lag_lead.offset,
+                            arg,
+                            default,
+                            ignore_nulls: lag_lead.ignore_nulls,
+                            return_type: *lag_lead.return_type.clone(),
+                        })
+                    }
+                    WindowFuncType::NthValue(nth) => {
+                        let arg = if let ScalarExpr::BoundColumnRef(col) = nth.arg.as_ref() {
+                            col.column.index
+                        } else {
+                            return Err(ErrorCode::Internal(
+                                "Window's nth_value function argument must be a BoundColumnRef",
+                            ));
+                        };
+                        WindowFunction::NthValue(NthValueFunctionDesc {
+                            n: nth.n,
+                            arg,
+                            ignore_nulls: nth.ignore_nulls,
+                            return_type: *nth.return_type.clone(),
+                        })
+                    }
                 };
 
                 Ok(PhysicalPlan::Window(Window {
@@ -819,11 +866,28 @@ impl PhysicalPlanBuilder {
                     right: Box::new(self.build(s_expr.child(1)?).await?),
                     pairs,
                     schema: DataSchemaRefExt::create(fields),
+                    cte_name: op.cte_name.clone(),
 
                     stat_info: Some(stat_info),
                 }))
             }
 
+            RelOperator::RecursiveCteScan(scan) => {
+                let fields = scan
+                    .columns
+                    .iter()
+                    .map(|column| {
+                        DataField::new(&column.index.to_string(), *column.data_type.clone())
+                    })
+                    .collect::<Vec<_>>();
+                Ok(PhysicalPlan::RecursiveCteScan(RecursiveCteScan {
+                    plan_id: self.next_plan_id(),
+                    cte_name: scan.cte_name.clone(),
+                    schema: DataSchemaRefExt::create(fields),
+                    stat_info: Some(stat_info),
+                }))
+            }
+
             RelOperator::RuntimeFilterSource(op) => {
                 let left_side = Box::new(self.build(s_expr.child(0)?).await?);
                 let left_schema = left_side.output_schema()?;
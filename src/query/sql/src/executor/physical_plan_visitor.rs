@@ -30,6 +30,7 @@ use super::Project;
 use super::ProjectSet;
 use super::Sort;
 use super::TableScan;
+use crate::executor::RecursiveCteScan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::UnionAll;
 use crate::executor::Window;
@@ -52,6 +53,7 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::ExchangeSource(plan) => self.replace_exchange_source(plan),
             PhysicalPlan::ExchangeSink(plan) => self.replace_exchange_sink(plan),
             PhysicalPlan::UnionAll(plan) => self.replace_union(plan),
+            PhysicalPlan::RecursiveCteScan(plan) => self.replace_recursive_cte_scan(plan),
             PhysicalPlan::DistributedInsertSelect(plan) => self.replace_insert_select(plan),
             PhysicalPlan::ProjectSet(plan) => self.replace_project_set(plan),
             PhysicalPlan::RuntimeFilterSource(plan) => self.replace_runtime_filter_source(plan),
@@ -229,10 +231,15 @@ pub trait PhysicalPlanReplacer {
             right: Box::new(right),
             schema: plan.schema.clone(),
             pairs: plan.pairs.clone(),
+            cte_name: plan.cte_name.clone(),
             stat_info: plan.stat_info.clone(),
         }))
     }
 
+    fn replace_recursive_cte_scan(&mut self, plan: &RecursiveCteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::RecursiveCteScan(plan.clone()))
+    }
+
     fn replace_insert_select(&mut self, plan: &DistributedInsertSelect) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -245,6 +252,7 @@ pub trait PhysicalPlanReplacer {
                 insert_schema: plan.insert_schema.clone(),
                 select_column_bindings: plan.select_column_bindings.clone(),
                 cast_needed: plan.cast_needed,
+                partition_columns: plan.partition_columns.clone(),
             },
         )))
     }
@@ -320,7 +328,7 @@ impl PhysicalPlan {
                 PhysicalPlan::Exchange(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
-                PhysicalPlan::ExchangeSource(_) => {}
+                PhysicalPlan::ExchangeSource(_) | PhysicalPlan::RecursiveCteScan(_) => {}
                 PhysicalPlan::ExchangeSink(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -44,7 +44,9 @@ use crate::plans::CastExpr;
 use crate::plans::ComparisonExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
 use crate::plans::NotExpr;
+use crate::plans::NthValueFunction;
 use crate::plans::OrExpr;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
@@ -274,13 +276,22 @@ impl<'a> AggregateAndWindowRewriter<'a> {
     }
 
     fn replace_window_function(&mut self, window: &WindowFunc) -> Result<ScalarExpr> {
-        let window_infos = &mut self.bind_context.windows;
+        // The window function has been rewritten before, e.g. it is referenced by
+        // both the select list and the qualify clause.
+        if self
+            .bind_context
+            .windows
+            .window_functions_map
+            .contains_key(&window.display_name)
+        {
+            return Ok(window.clone().into());
+        }
 
         let mut replaced_partition_items: Vec<ScalarExpr> =
             Vec::with_capacity(window.partition_by.len());
         let mut replaced_order_by_items: Vec<WindowOrderBy> =
             Vec::with_capacity(window.order_by.len());
-        let mut agg_args = vec![];
+        let mut window_args = vec![];
 
         let window_func_name = window.func.func_name();
         let func = match &window.func {
@@ -289,39 +300,11 @@ impl<'a> AggregateAndWindowRewriter<'a> {
                 let mut replaced_args: Vec<ScalarExpr> = Vec::with_capacity(agg.args.len());
                 for (i, arg) in agg.args.iter().enumerate() {
                     let name = format!("{}_arg_{}", &window_func_name, i);
-                    if let ScalarExpr::BoundColumnRef(column_ref) = arg {
-                        replaced_args.push(column_ref.clone().into());
-                        agg_args.push(ScalarItem {
-                            index: column_ref.column.index,
-                            scalar: arg.clone(),
-                        });
-                    } else {
-                        let index = self
-                            .metadata
-                            .write()
-                            .add_derived_column(name.clone(), arg.data_type()?);
-
-                        // Generate a ColumnBinding for each argument of aggregates
-                        let column_binding = ColumnBinding {
-                            database_name: None,
-                            table_name: None,
-                            column_name: name,
-                            index,
-                            data_type: Box::new(arg.data_type()?),
-                            visibility: Visibility::Visible,
-                        };
-                        replaced_args.push(
-                            BoundColumnRef {
-                                span: arg.span(),
-                                column: column_binding.clone(),
-                            }
-                            .into(),
-                        );
-                        agg_args.push(ScalarItem {
-                            index,
-                            scalar: arg.clone(),
-                        });
-                    }
+                    replaced_args.push(self.replace_window_function_arg(
+                        name,
+                        arg,
+                        &mut window_args,
+                    )?);
                 }
                 WindowFuncType::Aggregate(AggregateFunction {
                     display_name: agg.display_name.clone(),
@@ -332,6 +315,40 @@ impl<'a> AggregateAndWindowRewriter<'a> {
                     return_type: agg.return_type.clone(),
                 })
             }
+            WindowFuncType::LagLead(lag_lead) => {
+                let name = format!("{}_arg_0", &window_func_name);
+                let arg =
+                    self.replace_window_function_arg(name, &lag_lead.arg, &mut window_args)?;
+                let default = match &lag_lead.default {
+                    Some(default) => {
+                        let name = format!("{}_default", &window_func_name);
+                        Some(Box::new(self.replace_window_function_arg(
+                            name,
+                            default,
+                            &mut window_args,
+                        )?))
+                    }
+                    None => None,
+                };
+                WindowFuncType::LagLead(LagLeadFunction {
+                    is_lag: lag_lead.is_lag,
+                    arg: Box::new(arg),
+                    offset: lag_lead.offset,
+                    default,
+                    ignore_nulls: lag_lead.ignore_nulls,
+                    return_type: lag_lead.return_type.clone(),
+                })
+            }
+            WindowFuncType::NthValue(nth) => {
+                let name = format!("{}_arg_0", &window_func_name);
+                let arg = self.replace_window_function_arg(name, &nth.arg, &mut window_args)?;
+                WindowFuncType::NthValue(NthValueFunction {
+                    n: nth.n,
+                    arg: Box::new(arg),
+                    ignore_nulls: nth.ignore_nulls,
+                    return_type: nth.return_type.clone(),
+                })
+            }
             func => func.clone(),
         };
 
@@ -436,13 +453,14 @@ impl<'a> AggregateAndWindowRewriter<'a> {
         let window_info = WindowFunctionInfo {
             index,
             func: func.clone(),
-            arguments: agg_args,
+            arguments: window_args,
             partition_by_items,
             order_by_items,
             frame: window.frame.clone(),
         };
 
         // push window info to BindContext
+        let window_infos = &mut self.bind_context.windows;
         window_infos.window_functions.push(window_info);
         window_infos.window_functions_map.insert(
             window.display_name.clone(),
@@ -459,6 +477,47 @@ impl<'a> AggregateAndWindowRewriter<'a> {
 
         Ok(replaced_window.into())
     }
+
+    /// Replace an argument of window function with a column, and collect the argument
+    /// into `window_args` which will be evaluated before the window.
+    fn replace_window_function_arg(
+        &self,
+        name: String,
+        arg: &ScalarExpr,
+        window_args: &mut Vec<ScalarItem>,
+    ) -> Result<ScalarExpr> {
+        if let ScalarExpr::BoundColumnRef(column_ref) = arg {
+            window_args.push(ScalarItem {
+                index: column_ref.column.index,
+                scalar: arg.clone(),
+            });
+            return Ok(column_ref.clone().into());
+        }
+
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column(name.clone(), arg.data_type()?);
+
+        // Generate a ColumnBinding for each argument of window function
+        let column_binding = ColumnBinding {
+            database_name: None,
+            table_name: None,
+            column_name: name,
+            index,
+            data_type: Box::new(arg.data_type()?),
+            visibility: Visibility::Visible,
+        };
+        window_args.push(ScalarItem {
+            index,
+            scalar: arg.clone(),
+        });
+        Ok(BoundColumnRef {
+            span: arg.span(),
+            column: column_binding,
+        }
+        .into())
+    }
 }
 
 impl Binder {
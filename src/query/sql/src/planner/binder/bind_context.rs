@@ -14,7 +14,10 @@
 
 use std::collections::BTreeMap;
 use std::hash::Hash;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
+use common_ast::ast::Expr;
 use common_ast::ast::Query;
 use common_ast::ast::TableAlias;
 use common_catalog::plan::InternalColumn;
@@ -46,6 +49,7 @@ pub enum ExprContext {
     SelectClause,
     WhereClause,
     HavingClause,
+    QualifyClause,
     OrderByClause,
     LimitClause,
 
@@ -162,12 +166,31 @@ pub struct BindContext {
     pub srfs: DashMap<String, ScalarExpr>,
 
     pub expr_context: ExprContext,
+
+    /// The `match(column, query)` conjuncts of the WHERE clause, as (column, query).
+    /// `score()` is computed against them.
+    pub match_predicates: Vec<(Expr, Expr)>,
 }
 
 #[derive(Clone, Debug)]
 pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
+    /// Whether the cte is declared in `WITH RECURSIVE`.
+    pub recursive: bool,
+    /// Set while binding the recursive part of a recursive cte,
+    /// references to the cte are bound to its working table.
+    pub working_table: Option<CteWorkingTable>,
+}
+
+/// The working table of a recursive cte, it holds the rows produced
+/// by the last iteration.
+#[derive(Clone, Debug)]
+pub struct CteWorkingTable {
+    /// Names and types of the columns, which are decided by the anchor part.
+    pub columns: Vec<(String, DataType)>,
+    /// Whether the working table is referenced, shared by all the clones.
+    pub referenced: Arc<AtomicBool>,
 }
 
 impl BindContext {
@@ -183,6 +206,7 @@ impl BindContext {
             view_info: None,
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
+            match_predicates: vec![],
         }
     }
 
@@ -198,6 +222,7 @@ impl BindContext {
             view_info: None,
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
+            match_predicates: vec![],
         }
     }
 
@@ -193,6 +193,10 @@ impl<'a> Binder {
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
 
+            // Indexes
+            Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(stmt).await?,
+            Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(stmt).await?,
+
             // Users
             Statement::CreateUser(stmt) => self.bind_create_user(stmt).await?,
             Statement::DropUser { if_exists, user } => Plan::DropUser(Box::new(DropUserPlan {
@@ -244,6 +248,7 @@ impl<'a> Binder {
                     .await?
             }
             Statement::Update(stmt) => self.bind_update(bind_context, stmt).await?,
+            Statement::MergeInto(stmt) => self.bind_merge_into(bind_context, stmt).await?,
 
             // Permissions
             Statement::Grant(stmt) => self.bind_grant(stmt).await?,
@@ -251,6 +256,7 @@ impl<'a> Binder {
                 principal: principal.clone(),
             })),
             Statement::Revoke(stmt) => self.bind_revoke(stmt).await?,
+            Statement::GrantOwnership(stmt) => self.bind_grant_ownership(stmt).await?,
 
             // File Formats
             Statement::CreateFileFormat{  if_not_exists, name, file_format_options} =>  Plan::CreateFileFormat(Box::new(CreateFileFormatPlan {
@@ -324,6 +330,17 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 name: udf_name.to_string(),
             })),
+
+            // Row access policies
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => self.bind_drop_row_access_policy(stmt).await?,
+
+            // Masking policies
+            Statement::CreateMaskingPolicy(stmt) => self.bind_create_masking_policy(stmt).await?,
+            Statement::DropMaskingPolicy(stmt) => self.bind_drop_masking_policy(stmt).await?,
+
             Statement::Call(stmt) => Plan::Call(Box::new(CallPlan {
                 name: stmt.name.clone(),
                 args: stmt.args.clone(),
@@ -687,6 +687,7 @@ fn check_transform_query(
             if select.group_by.is_none()
                 && !select.distinct
                 && select.having.is_none()
+                && select.qualify.is_none()
                 && select.from.len() == 1
             {
                 if let TableReference::Stage {
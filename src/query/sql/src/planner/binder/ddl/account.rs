@@ -16,17 +16,24 @@ use common_ast::ast::AccountMgrLevel;
 use common_ast::ast::AccountMgrSource;
 use common_ast::ast::AlterUserStmt;
 use common_ast::ast::CreateUserStmt;
+use common_ast::ast::GrantOwnershipStmt;
 use common_ast::ast::GrantStmt;
+use common_ast::ast::Identifier;
 use common_ast::ast::RevokeStmt;
+use common_base::runtime::MemStat;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserQuota;
 use common_users::UserApiProvider;
 
+use crate::normalize_identifier;
 use crate::plans::AlterUserPlan;
 use crate::plans::CreateUserPlan;
+use crate::plans::GrantOwnershipPlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::Plan;
@@ -57,7 +64,7 @@ impl Binder {
                 let priv_types = grant_object.available_privileges();
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -70,7 +77,21 @@ impl Binder {
                 }
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
+                    priv_types,
+                };
+                Ok(Plan::GrantPriv(Box::new(plan)))
+            }
+            AccountMgrSource::ColumnPrivs {
+                privilege,
+                columns,
+                level,
+            } => {
+                let mut priv_types = UserPrivilegeSet::empty();
+                priv_types.set_privilege(*privilege);
+                let plan = GrantPrivilegePlan {
+                    principal: principal.clone(),
+                    on: self.convert_to_column_grant_objects(level, columns)?,
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -100,7 +121,7 @@ impl Binder {
                 let priv_types = grant_object.available_privileges();
                 let plan = RevokePrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
@@ -113,12 +134,45 @@ impl Binder {
                 }
                 let plan = RevokePrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
             }
+            AccountMgrSource::ColumnPrivs {
+                privilege,
+                columns,
+                level,
+            } => {
+                let mut priv_types = UserPrivilegeSet::empty();
+                priv_types.set_privilege(*privilege);
+                let plan = RevokePrivilegePlan {
+                    principal: principal.clone(),
+                    on: self.convert_to_column_grant_objects(level, columns)?,
+                    priv_types,
+                };
+                Ok(Plan::RevokePriv(Box::new(plan)))
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_grant_ownership(
+        &mut self,
+        stmt: &GrantOwnershipStmt,
+    ) -> Result<Plan> {
+        let GrantOwnershipStmt { level, role } = stmt;
+
+        if let AccountMgrLevel::Global = level {
+            return Err(ErrorCode::IllegalGrant(
+                "Ownership can only be granted on a database or a table",
+            ));
         }
+        let plan = GrantOwnershipPlan {
+            object: self.convert_to_grant_object(level),
+            role: role.clone(),
+        };
+        Ok(Plan::GrantOwnership(Box::new(plan)))
     }
 
     pub(in crate::planner::binder) fn convert_to_grant_object(
@@ -144,6 +198,47 @@ impl Binder {
         }
     }
 
+    pub(in crate::planner::binder) fn convert_to_column_grant_objects(
+        &self,
+        source: &AccountMgrLevel,
+        columns: &[Identifier],
+    ) -> Result<Vec<GrantObject>> {
+        let AccountMgrLevel::Table(database_name, table_name) = source else {
+            return Err(ErrorCode::IllegalGrant(
+                "Column privileges can only be granted on a table",
+            ));
+        };
+        let catalog_name = self.ctx.get_current_catalog();
+        let database_name = database_name
+            .clone()
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        Ok(columns
+            .iter()
+            .map(|column| {
+                GrantObject::Column(
+                    catalog_name.clone(),
+                    database_name.clone(),
+                    table_name.clone(),
+                    normalize_identifier(column, &self.name_resolution_ctx).name,
+                )
+            })
+            .collect())
+    }
+
+    // The memory limit below the lowest one is raised to it, reject it instead of accepting
+    // a quota which is not enforced.
+    fn validate_user_quota(quota: &UserQuota) -> Result<()> {
+        let max_memory = quota.max_memory_in_bytes;
+        if max_memory != 0 && max_memory < MemStat::LOWEST_LIMIT as u64 {
+            return Err(ErrorCode::BadArguments(format!(
+                "MAX_MEMORY_IN_BYTES must be 0 or at least {}, but got {}",
+                MemStat::LOWEST_LIMIT,
+                max_memory
+            )));
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_create_user(
         &mut self,
@@ -156,13 +251,17 @@ impl Binder {
             user_options,
         } = stmt;
         let mut user_option = UserOption::default();
+        let mut user_quota = UserQuota::no_limit();
         for option in user_options {
             option.apply(&mut user_option);
+            option.apply_quota(&mut user_quota);
         }
+        Self::validate_user_quota(&user_quota)?;
         let plan = CreateUserPlan {
             user: user.clone(),
             auth_info: AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?,
             user_option,
+            user_quota,
             if_not_exists: *if_not_exists,
         };
         Ok(Plan::CreateUser(Box::new(plan)))
@@ -202,18 +301,27 @@ impl Binder {
         };
 
         let mut user_option = user_info.option.clone();
+        let mut user_quota = user_info.quota.clone();
         for option in user_options {
             option.apply(&mut user_option);
+            option.apply_quota(&mut user_quota);
         }
+        Self::validate_user_quota(&user_quota)?;
         let new_user_option = if user_option == user_info.option {
             None
         } else {
             Some(user_option)
         };
+        let new_user_quota = if user_quota == user_info.quota {
+            None
+        } else {
+            Some(user_quota)
+        };
         let plan = AlterUserPlan {
             user: user_info.identity(),
             auth_info: new_auth_info,
             user_option: new_user_option,
+            user_quota: new_user_quota,
         };
 
         Ok(Plan::AlterUser(Box::new(plan)))
@@ -30,7 +30,9 @@ use common_expression::DataSchemaRefExt;
 use common_meta_app::schema::CatalogMeta;
 use common_meta_app::schema::CatalogOption;
 use common_meta_app::schema::CatalogType;
+use common_meta_app::schema::DeltaCatalogOption;
 use common_meta_app::schema::IcebergCatalogOption;
+use common_meta_app::storage::StorageParams;
 use url::Url;
 
 use crate::binder::parse_uri_location;
@@ -151,11 +153,9 @@ impl Binder {
                 CatalogOption::Hive(address.to_string())
             }
             CatalogType::Iceberg => {
-                let mut catalog_options = options.clone();
-
                 // getting other options to create this catalog
                 let flatten = matches!(
-                    catalog_options
+                    options
                         .get("flatten")
                         .map(|v| v.to_lowercase())
                         .unwrap_or_default()
@@ -163,53 +163,7 @@ impl Binder {
                     "true" | "on"
                 );
 
-                // the uri should in the same schema as in stages
-                let uri = catalog_options
-                    .remove("url") // has to be removed, or UriLocation will complain about unknown field.
-                    .ok_or_else(|| ErrorCode::InvalidArgument("expected field: URL"))?;
-
-                // create a uri location
-                let mut location = if let Some(path) = uri.strip_prefix("fs://") {
-                    UriLocation::new(
-                        "fs".to_string(),
-                        "".to_string(),
-                        path.to_string(),
-                        "".to_string(),
-                        catalog_options,
-                    )
-                } else {
-                    let parsed = Url::parse(&uri).map_err(|err| {
-                        ErrorCode::InvalidArgument(format!("expected valid URL: {:?}", err))
-                    })?;
-                    let name = parsed
-                        .host_str()
-                        .map(|hostname| {
-                            if let Some(port) = parsed.port() {
-                                format!("{}:{}", hostname, port)
-                            } else {
-                                hostname.to_string()
-                            }
-                        })
-                        .ok_or_else(|| {
-                            ErrorCode::InvalidArgument("expected valid URI: no hostname section")
-                        })?;
-
-                    let path = if parsed.path().is_empty() {
-                        "/".to_string()
-                    } else {
-                        parsed.path().to_string()
-                    };
-
-                    UriLocation::new(
-                        parsed.scheme().to_string(),
-                        name,
-                        path,
-                        "".to_string(),
-                        catalog_options,
-                    )
-                };
-
-                let (sp, _) = parse_uri_location(&mut location)?;
+                let sp = parse_catalog_url(options.clone())?;
 
                 let opt = IcebergCatalogOption {
                     storage_params: Box::new(sp),
@@ -217,6 +171,14 @@ impl Binder {
                 };
                 CatalogOption::Iceberg(opt)
             }
+            CatalogType::Delta => {
+                let sp = parse_catalog_url(options.clone())?;
+
+                let opt = DeltaCatalogOption {
+                    storage_params: Box::new(sp),
+                };
+                CatalogOption::Delta(opt)
+            }
         };
 
         Ok(CatalogMeta {
@@ -225,3 +187,52 @@ impl Binder {
         })
     }
 }
+
+/// Parse the `URL` option of external catalogs into storage params,
+/// the url should be in the same schema as in stages.
+fn parse_catalog_url(mut catalog_options: BTreeMap<String, String>) -> Result<StorageParams> {
+    let uri = catalog_options
+        .remove("url") // has to be removed, or UriLocation will complain about unknown field.
+        .ok_or_else(|| ErrorCode::InvalidArgument("expected field: URL"))?;
+
+    // create a uri location
+    let mut location = if let Some(path) = uri.strip_prefix("fs://") {
+        UriLocation::new(
+            "fs".to_string(),
+            "".to_string(),
+            path.to_string(),
+            "".to_string(),
+            catalog_options,
+        )
+    } else {
+        let parsed = Url::parse(&uri)
+            .map_err(|err| ErrorCode::InvalidArgument(format!("expected valid URL: {:?}", err)))?;
+        let name = parsed
+            .host_str()
+            .map(|hostname| {
+                if let Some(port) = parsed.port() {
+                    format!("{}:{}", hostname, port)
+                } else {
+                    hostname.to_string()
+                }
+            })
+            .ok_or_else(|| ErrorCode::InvalidArgument("expected valid URI: no hostname section"))?;
+
+        let path = if parsed.path().is_empty() {
+            "/".to_string()
+        } else {
+            parsed.path().to_string()
+        };
+
+        UriLocation::new(
+            parsed.scheme().to_string(),
+            name,
+            path,
+            "".to_string(),
+            catalog_options,
+        )
+    };
+
+    let (sp, _) = parse_uri_location(&mut location)?;
+    Ok(sp)
+}
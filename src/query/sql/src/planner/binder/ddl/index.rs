@@ -0,0 +1,85 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateInvertedIndexStmt;
+use common_ast::ast::DropInvertedIndexStmt;
+use common_exception::Result;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::plans::CreateInvertedIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+        let columns = columns
+            .iter()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .collect::<Vec<_>>();
+
+        let plan = CreateInvertedIndexPlan {
+            if_not_exists: *if_not_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+            columns,
+        };
+        Ok(Plan::CreateInvertedIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        let plan = DropInvertedIndexPlan {
+            if_exists: *if_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+        };
+        Ok(Plan::DropInvertedIndex(Box::new(plan)))
+    }
+}
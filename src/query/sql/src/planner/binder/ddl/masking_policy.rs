@@ -0,0 +1,91 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateMaskingPolicyStmt;
+use common_ast::ast::DropMaskingPolicyStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::MaskingPolicy;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::resolve_type_name;
+use crate::planner::udf_validator::UDFValidator;
+use crate::plans::CreateMaskingPolicyPlan;
+use crate::plans::DropMaskingPolicyPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_masking_policy(
+        &mut self,
+        stmt: &CreateMaskingPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateMaskingPolicyStmt {
+            if_not_exists,
+            name,
+            arg,
+            return_type,
+            body,
+            comment,
+        } = stmt;
+
+        // The masked value replaces the value of the column, so it must keep the type of the column.
+        let arg_type = resolve_type_name(&arg.data_type)?;
+        let return_type = resolve_type_name(return_type)?;
+        if arg_type.remove_nullable() != return_type.remove_nullable() {
+            return Err(ErrorCode::IllegalMaskingPolicy(format!(
+                "masking policy must return the type of its argument {}, but got {}",
+                arg_type, return_type
+            )));
+        }
+
+        // The body can only reference the argument, which is bound to the masked column.
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let mut validator = UDFValidator {
+            name: name.clone(),
+            parameters: vec![arg.name.to_string()],
+            ..Default::default()
+        };
+        validator.verify_definition_expr(body)?;
+
+        let arg_name = normalize_identifier(&arg.name, &self.name_resolution_ctx).name;
+        let policy = MaskingPolicy::new(
+            &name,
+            (arg_name, arg_type),
+            return_type,
+            &body.to_string(),
+            comment.as_deref().unwrap_or_default(),
+        );
+        Ok(Plan::CreateMaskingPolicy(Box::new(
+            CreateMaskingPolicyPlan {
+                if_not_exists: *if_not_exists,
+                policy,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_masking_policy(
+        &mut self,
+        stmt: &DropMaskingPolicyStmt,
+    ) -> Result<Plan> {
+        let DropMaskingPolicyStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropMaskingPolicy(Box::new(DropMaskingPolicyPlan {
+            if_exists: *if_exists,
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        })))
+    }
+}
@@ -16,7 +16,10 @@ mod account;
 mod catalog;
 mod column;
 mod database;
+mod index;
+mod masking_policy;
 mod role;
+mod row_access_policy;
 mod share;
 mod stage;
 mod table;
@@ -0,0 +1,99 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateRowAccessPolicyStmt;
+use common_ast::ast::DropRowAccessPolicyStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_meta_app::principal::RowAccessPolicy;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::resolve_type_name;
+use crate::planner::udf_validator::UDFValidator;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_row_access_policy(
+        &mut self,
+        stmt: &CreateRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateRowAccessPolicyStmt {
+            if_not_exists,
+            name,
+            args,
+            return_type,
+            body,
+            comment,
+        } = stmt;
+
+        if resolve_type_name(return_type)?.remove_nullable() != TableDataType::Boolean {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "row access policy must return BOOLEAN, but got {}",
+                return_type
+            )));
+        }
+
+        // The body can only reference the arguments, which are bound to the columns of tables.
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let mut validator = UDFValidator {
+            name: name.clone(),
+            parameters: args.iter().map(|arg| arg.name.to_string()).collect(),
+            ..Default::default()
+        };
+        validator.verify_definition_expr(body)?;
+
+        let args = args
+            .iter()
+            .map(|arg| {
+                Ok((
+                    normalize_identifier(&arg.name, &self.name_resolution_ctx).name,
+                    resolve_type_name(&arg.data_type)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let policy = RowAccessPolicy::new(
+            &name,
+            args,
+            &body.to_string(),
+            comment.as_deref().unwrap_or_default(),
+        );
+        Ok(Plan::CreateRowAccessPolicy(Box::new(
+            CreateRowAccessPolicyPlan {
+                if_not_exists: *if_not_exists,
+                policy,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_row_access_policy(
+        &mut self,
+        stmt: &DropRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DropRowAccessPolicyStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropRowAccessPolicy(Box::new(
+            DropRowAccessPolicyPlan {
+                if_exists: *if_exists,
+                name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            },
+        )))
+    }
+}
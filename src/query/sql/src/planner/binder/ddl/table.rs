@@ -30,6 +30,7 @@ use common_ast::ast::ExistsTableStmt;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::Literal;
+use common_ast::ast::ModifyColumnAction as AstModifyColumnAction;
 use common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
 use common_ast::ast::OptimizeTableStmt;
 use common_ast::ast::RenameTableStmt;
@@ -55,6 +56,7 @@ use common_expression::types::DataType;
 use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
@@ -64,7 +66,9 @@ use common_storage::DataOperator;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::is_reserved_opt_key;
+use storages_common_table_meta::table::OPT_KEY_CREATOR;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use tracing::debug;
@@ -80,6 +84,7 @@ use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CastExpr;
@@ -88,11 +93,15 @@ use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::ModifyColumnAction;
+use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTableAction;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::Plan;
 use crate::plans::ReclusterTablePlan;
+use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
@@ -434,6 +443,14 @@ impl Binder {
             let db_id = db.get_db_info().ident.db_id;
             options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
 
+            // The storage of the table is charged to the storage quota of its creator.
+            let creator = self.ctx.get_current_user()?.identity();
+            options.insert(OPT_KEY_CREATOR.to_owned(), creator.to_string());
+
+            if let Some(columns) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+                Self::validate_ngram_index_columns(&schema, columns)?;
+            }
+
             let config = GlobalConfig::instance();
             let is_blocking_fs = matches!(
                 storage_params.as_ref().unwrap_or(&config.storage.params),
@@ -619,6 +636,55 @@ impl Binder {
                     field_comments,
                 })))
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => Ok(Plan::RenameTableColumn(Box::new(RenameTableColumnPlan {
+                catalog,
+                database,
+                table,
+                old_column: normalize_identifier(old_column, &self.name_resolution_ctx).name,
+                new_column: normalize_identifier(new_column, &self.name_resolution_ctx).name,
+            }))),
+            AlterTableAction::ModifyColumn { column, action } => {
+                let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+                let action = match action {
+                    AstModifyColumnAction::SetDataType(type_name) => {
+                        ModifyColumnAction::SetDataType(resolve_type_name(type_name)?)
+                    }
+                    AstModifyColumnAction::SetDefault(default_expr) => {
+                        let schema = self
+                            .ctx
+                            .get_table(&catalog, &database, &table)
+                            .await?
+                            .schema();
+                        let field = schema.field_with_name(&column)?;
+                        let default_expr = self
+                            .analyze_column_default_expr(default_expr, field.data_type())
+                            .await?;
+                        ModifyColumnAction::SetDefault(Some(default_expr))
+                    }
+                    AstModifyColumnAction::DropDefault => ModifyColumnAction::SetDefault(None),
+                    AstModifyColumnAction::Comment(comment) => {
+                        ModifyColumnAction::Comment(comment.clone())
+                    }
+                    AstModifyColumnAction::SetMaskingPolicy(policy) => {
+                        ModifyColumnAction::SetMaskingPolicy(Some(
+                            normalize_identifier(policy, &self.name_resolution_ctx).name,
+                        ))
+                    }
+                    AstModifyColumnAction::UnsetMaskingPolicy => {
+                        ModifyColumnAction::SetMaskingPolicy(None)
+                    }
+                };
+                Ok(Plan::ModifyTableColumn(Box::new(ModifyTableColumnPlan {
+                    catalog,
+                    database,
+                    table,
+                    column,
+                    action,
+                })))
+            }
             AlterTableAction::DropColumn { column } => {
                 Ok(Plan::DropTableColumn(Box::new(DropTableColumnPlan {
                     catalog,
@@ -696,6 +762,26 @@ impl Binder {
                     point,
                 })))
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => Ok(
+                Plan::AddTableRowAccessPolicy(Box::new(AddTableRowAccessPolicyPlan {
+                    catalog,
+                    database,
+                    table,
+                    policy: normalize_identifier(policy, &self.name_resolution_ctx).name,
+                    columns: columns
+                        .iter()
+                        .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                        .collect(),
+                })),
+            ),
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    catalog,
+                    database,
+                    table,
+                    policy: normalize_identifier(policy, &self.name_resolution_ctx).name,
+                }),
+            )),
         }
     }
 
@@ -860,14 +946,6 @@ impl Binder {
         &self,
         columns: &[ColumnDefinition],
     ) -> Result<(TableSchemaRef, Vec<Option<String>>, Vec<String>)> {
-        let mut bind_context = BindContext::new();
-        let mut scalar_binder = ScalarBinder::new(
-            &mut bind_context,
-            self.ctx.clone(),
-            &self.name_resolution_ctx,
-            self.metadata.clone(),
-            &[],
-        );
         let mut fields = Vec::with_capacity(columns.len());
         let mut fields_default_expr = Vec::with_capacity(columns.len());
         let mut fields_comments = Vec::with_capacity(columns.len());
@@ -878,27 +956,10 @@ impl Binder {
             fields.push(TableField::new(&name, schema_data_type.clone()));
             fields_default_expr.push({
                 if let Some(default_expr) = &column.default_expr {
-                    let (expr, _) = scalar_binder.bind(default_expr).await?;
-                    let is_try = schema_data_type.is_nullable();
-                    let cast_expr_to_field_type = ScalarExpr::CastExpr(CastExpr {
-                        span: expr.span(),
-                        is_try,
-                        target_type: Box::new(DataType::from(&schema_data_type)),
-                        argument: Box::new(expr),
-                    })
-                    .as_expr_with_col_index()?;
-                    let (fold_to_constant, _) = ConstantFolder::fold(
-                        &cast_expr_to_field_type,
-                        self.ctx.get_function_context()?,
-                        &BUILTIN_FUNCTIONS,
-                    );
-                    if let common_expression::Expr::Constant { .. } = fold_to_constant {
-                        Some(default_expr.to_string())
-                    } else {
-                        return Err(ErrorCode::SemanticError(format!(
-                            "default expression {cast_expr_to_field_type} is not a valid constant",
-                        )));
-                    }
+                    Some(
+                        self.analyze_column_default_expr(default_expr, &schema_data_type)
+                            .await?,
+                    )
                 } else {
                     None
                 }
@@ -910,6 +971,44 @@ impl Binder {
         Ok((schema, fields_default_expr, fields_comments))
     }
 
+    /// Check that the default expression of a column folds to a constant of the column type.
+    #[async_backtrace::framed]
+    async fn analyze_column_default_expr(
+        &self,
+        default_expr: &Expr,
+        data_type: &TableDataType,
+    ) -> Result<String> {
+        let mut bind_context = BindContext::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (expr, _) = scalar_binder.bind(default_expr).await?;
+        let is_try = data_type.is_nullable();
+        let cast_expr_to_field_type = ScalarExpr::CastExpr(CastExpr {
+            span: expr.span(),
+            is_try,
+            target_type: Box::new(DataType::from(data_type)),
+            argument: Box::new(expr),
+        })
+        .as_expr_with_col_index()?;
+        let (fold_to_constant, _) = ConstantFolder::fold(
+            &cast_expr_to_field_type,
+            self.ctx.get_function_context()?,
+            &BUILTIN_FUNCTIONS,
+        );
+        if let common_expression::Expr::Constant { .. } = fold_to_constant {
+            Ok(default_expr.to_string())
+        } else {
+            Err(ErrorCode::SemanticError(format!(
+                "default expression {cast_expr_to_field_type} is not a valid constant",
+            )))
+        }
+    }
+
     #[async_backtrace::framed]
     async fn analyze_create_table_schema(
         &self,
@@ -956,6 +1055,21 @@ impl Binder {
         Ok(())
     }
 
+    fn validate_ngram_index_columns(schema: &TableSchemaRef, columns: &str) -> Result<()> {
+        for name in columns.split(',').map(str::trim) {
+            let field = schema.field_with_name(name).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!("n-gram index column {name} does not exist"))
+            })?;
+            if field.data_type().remove_nullable() != TableDataType::String {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "n-gram index column {name} must be of type String, but got {}",
+                    field.data_type()
+                )));
+            }
+        }
+        Ok(())
+    }
+
     fn insert_table_option_with_validation(
         &self,
         options: &mut BTreeMap<String, String>,
@@ -74,10 +74,12 @@ impl<'a> Binder {
                 let filter_expr = rewriter.rewrite(&filter_expr)?;
                 (None, Some(filter_expr))
             } else {
-                (Some(scalar), None)
+                let scalar = self.inline_masked_columns(&scalar)?;
+                let selection = Self::with_row_access_predicate(&table_expr, Some(scalar));
+                (selection, None)
             }
         } else {
-            (None, None)
+            (Self::with_row_access_predicate(&table_expr, None), None)
         };
 
         let plan = DeletePlan {
@@ -14,18 +14,29 @@
 
 use std::sync::Arc;
 
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
 use common_ast::ast::InsertSource;
 use common_ast::ast::InsertStmt;
 use common_ast::ast::Statement;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ConstantFolder;
+use common_expression::Scalar;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::principal::FileFormatOptions;
 
+use super::wrap_cast;
 use crate::binder::Binder;
 use crate::normalize_identifier;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
+use crate::planner::semantic::TypeChecker;
 use crate::plans::Insert;
 use crate::plans::InsertInputSource;
 use crate::plans::Plan;
@@ -42,6 +53,7 @@ impl Binder {
             catalog,
             database,
             table,
+            partition,
             columns,
             source,
             overwrite,
@@ -54,18 +66,38 @@ impl Binder {
             .await?;
         let table_id = table.get_id();
 
+        let partition = self
+            .bind_insert_partition(bind_context, &table.schema(), partition)
+            .await?;
+        let is_partition_column =
+            |name: &str| partition.iter().any(|(field, _)| field.name() == name);
+
         let schema = if columns.is_empty() {
-            table.schema()
+            let schema = table.schema();
+            if partition.is_empty() {
+                schema
+            } else {
+                // The static partition columns are not provided by the source.
+                let fields = schema
+                    .fields()
+                    .iter()
+                    .filter(|field| !is_partition_column(field.name()))
+                    .cloned()
+                    .collect();
+                TableSchemaRefExt::create(fields)
+            }
         } else {
             let schema = table.schema();
             let fields = columns
                 .iter()
                 .map(|ident| {
-                    schema
-                        .field_with_name(
-                            &normalize_identifier(ident, &self.name_resolution_ctx).name,
-                        )
-                        .map(|v| v.clone())
+                    let name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+                    if is_partition_column(&name) {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "column {name} is given by the PARTITION clause"
+                        )));
+                    }
+                    schema.field_with_name(&name).map(|v| v.clone())
                 })
                 .collect::<Result<Vec<_>>>()?;
             TableSchemaRefExt::create(fields)
@@ -117,10 +149,53 @@ impl Binder {
             table: table_name,
             table_id,
             schema,
+            partition,
             overwrite: *overwrite,
             source: input_source?,
         };
 
         Ok(Plan::Insert(Box::new(plan)))
     }
+
+    /// Bind the values of `PARTITION (...)`, they are cast to the types of the columns.
+    #[async_backtrace::framed]
+    async fn bind_insert_partition(
+        &mut self,
+        bind_context: &mut BindContext,
+        schema: &TableSchemaRef,
+        partition: &[(Identifier, Expr)],
+    ) -> Result<Vec<(TableField, Scalar)>> {
+        let mut values: Vec<(TableField, Scalar)> = Vec::with_capacity(partition.len());
+        for (column, value) in partition {
+            let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+            if values.iter().any(|(field, _)| field.name() == &name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicated partition column {name}"
+                )));
+            }
+            let field = schema.field_with_name(&name)?.clone();
+
+            let mut type_checker = TypeChecker::new(
+                bind_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let (scalar, _) = *type_checker.resolve(value).await?;
+            let scalar = wrap_cast(&scalar, &DataType::from(field.data_type()));
+            let expr = scalar.as_expr_with_col_index()?;
+            let (expr, _) =
+                ConstantFolder::fold(&expr, self.ctx.get_function_context()?, &BUILTIN_FUNCTIONS);
+            match expr {
+                common_expression::Expr::Constant { scalar, .. } => values.push((field, scalar)),
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "value of partition column {name} must be constant"
+                    )));
+                }
+            }
+        }
+        Ok(values)
+    }
 }
@@ -0,0 +1,263 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use common_ast::ast::split_conjunctions_expr;
+use common_ast::ast::split_equivalent_predicate_expr;
+use common_ast::ast::Expr;
+use common_ast::ast::MatchOperation;
+use common_ast::ast::MergeIntoStmt;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::optimizer::optimize;
+use crate::optimizer::OptimizerConfig;
+use crate::optimizer::OptimizerContext;
+use crate::plans::MergeInto;
+use crate::plans::Plan;
+use crate::plans::MERGE_INTO_ACTION_COLUMN;
+use crate::plans::MERGE_INTO_ACTION_DELETE;
+use crate::plans::MERGE_INTO_ACTION_INSERT;
+use crate::plans::MERGE_INTO_ACTION_UPDATE;
+use crate::BindContext;
+
+impl Binder {
+    /// `MERGE INTO` is rewritten to a query over `<source> LEFT OUTER JOIN <target>`,
+    /// which produces the new version of every affected row, plus an action column
+    /// telling the storage whether the row updates, deletes or inserts.
+    ///
+    /// The `ON` condition must be a conjunction of equalities on target columns,
+    /// those columns are used as the keys that identify the rows to be replaced.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_merge_into(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &MergeIntoStmt,
+    ) -> Result<Plan> {
+        let MergeIntoStmt {
+            catalog,
+            database,
+            table: table_ident,
+            target_alias,
+            source,
+            join_expr,
+            matched_clauses,
+            unmatched_clauses,
+        } = stmt;
+
+        let (catalog_name, database_name, table_name) =
+            self.normalize_object_identifier_triple(catalog, database, table_ident);
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let table_id = table.get_id();
+        let schema = table.schema();
+        if schema.field_with_name(MERGE_INTO_ACTION_COLUMN).is_ok() {
+            return Err(ErrorCode::SemanticError(format!(
+                "MERGE INTO target table can not have a column named `{}`",
+                MERGE_INTO_ACTION_COLUMN
+            )));
+        }
+
+        // The name used to reference the target table inside the rewritten query.
+        let target_ident = target_alias.as_ref().unwrap_or(table_ident);
+        let target_name = normalize_identifier(target_ident, &self.name_resolution_ctx).name;
+
+        // 1. extract the merge keys from the join condition
+        let mut on_conflict_fields = Vec::new();
+        let mut key_columns = Vec::new();
+        for predicate in split_conjunctions_expr(join_expr) {
+            let key_column = split_equivalent_predicate_expr(&predicate).and_then(|(l, r)| {
+                self.target_column_of(&target_name, &l)
+                    .or_else(|| self.target_column_of(&target_name, &r))
+            });
+            match key_column {
+                Some(column) => {
+                    let field = schema.field_with_name(&column)?.clone();
+                    if !on_conflict_fields.contains(&field) {
+                        on_conflict_fields.push(field);
+                        key_columns.push(column);
+                    }
+                }
+                None => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "MERGE INTO only supports equality conditions on columns of the target table `{}`, but got `{}`",
+                        target_name, predicate
+                    )));
+                }
+            }
+        }
+
+        // 2. rewrite to a query
+        let matched = format!("({target_ident}.`{}` IS NOT NULL)", key_columns[0]);
+        let not_matched = format!("({target_ident}.`{}` IS NULL)", key_columns[0]);
+
+        // (condition, action, column name -> new value) of every WHEN branch, in order.
+        let mut branches = Vec::with_capacity(matched_clauses.len() + unmatched_clauses.len());
+        for clause in matched_clauses {
+            let condition = match &clause.selection {
+                Some(selection) => format!("{matched} AND ({selection})"),
+                None => matched.clone(),
+            };
+            match &clause.operation {
+                MatchOperation::Update { update_list } => {
+                    let mut values = HashMap::with_capacity(update_list.len());
+                    for update_expr in update_list {
+                        let column =
+                            normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
+                        schema.field_with_name(&column)?;
+                        if key_columns.contains(&column) {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "MERGE INTO can not update column `{}` which is used in the ON condition",
+                                column
+                            )));
+                        }
+                        if values
+                            .insert(column.clone(), update_expr.expr.to_string())
+                            .is_some()
+                        {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Multiple assignments in the single statement to column `{}`",
+                                column
+                            )));
+                        }
+                    }
+                    branches.push((condition, MERGE_INTO_ACTION_UPDATE, values));
+                }
+                MatchOperation::Delete => {
+                    branches.push((condition, MERGE_INTO_ACTION_DELETE, HashMap::new()))
+                }
+            }
+        }
+        for clause in unmatched_clauses {
+            let condition = match &clause.selection {
+                Some(selection) => format!("{not_matched} AND ({selection})"),
+                None => not_matched.clone(),
+            };
+            let columns = if clause.columns.is_empty() {
+                schema
+                    .fields()
+                    .iter()
+                    .map(|f| f.name().clone())
+                    .collect::<Vec<_>>()
+            } else {
+                clause
+                    .columns
+                    .iter()
+                    .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                    .collect::<Vec<_>>()
+            };
+            if columns.len() != clause.values.len() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Number of columns ({}) does not match number of values ({}) in MERGE INTO insert clause",
+                    columns.len(),
+                    clause.values.len()
+                )));
+            }
+            let mut values = HashMap::with_capacity(columns.len());
+            for (column, value) in columns.into_iter().zip(clause.values.iter()) {
+                schema.field_with_name(&column)?;
+                if values.insert(column.clone(), value.to_string()).is_some() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Column `{}` is specified more than once in MERGE INTO insert clause",
+                        column
+                    )));
+                }
+            }
+            branches.push((condition, MERGE_INTO_ACTION_INSERT, values));
+        }
+
+        let mut projections = Vec::with_capacity(schema.num_fields() + 1);
+        for field in schema.fields() {
+            let mut case = String::from("CASE");
+            for (condition, action, values) in &branches {
+                let value = match values.get(field.name()) {
+                    Some(value) => value.clone(),
+                    None if *action == MERGE_INTO_ACTION_INSERT => field
+                        .default_expr()
+                        .cloned()
+                        .unwrap_or_else(|| "NULL".to_string()),
+                    None => format!("{target_ident}.`{}`", field.name()),
+                };
+                write!(case, " WHEN {condition} THEN {value}").unwrap();
+            }
+            write!(case, " END AS `{}`", field.name()).unwrap();
+            projections.push(case);
+        }
+        let mut action = String::from("CASE");
+        for (condition, value, _) in &branches {
+            write!(action, " WHEN {condition} THEN {value}").unwrap();
+        }
+        write!(action, " END AS `{MERGE_INTO_ACTION_COLUMN}`").unwrap();
+        projections.push(action);
+
+        let target_table = catalog
+            .iter()
+            .chain(database)
+            .chain(Some(table_ident))
+            .map(|ident| ident.to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+        let alias_clause = target_alias
+            .as_ref()
+            .map(|alias| format!(" AS {alias}"))
+            .unwrap_or_default();
+        let query = format!(
+            "SELECT * FROM (SELECT {} FROM {source} LEFT OUTER JOIN {target_table}{alias_clause} ON {join_expr}) AS _merge_into_source WHERE `{MERGE_INTO_ACTION_COLUMN}` IS NOT NULL",
+            projections.join(", "),
+        );
+
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(&query)?;
+        let (statement, _) = parse_sql(&tokens, sql_dialect)?;
+        let input = self.bind_statement(bind_context, &statement).await?;
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: false,
+        }));
+        let input = optimize(self.ctx.clone(), opt_ctx, input)?;
+
+        let plan = MergeInto {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            table_id,
+            on_conflict_fields,
+            schema,
+            input: Box::new(input),
+        };
+        Ok(Plan::MergeInto(Box::new(plan)))
+    }
+
+    /// Returns the column name if `expr` is a column of the merge target.
+    fn target_column_of(&self, target_name: &str, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::ColumnRef {
+                table: Some(table),
+                column,
+                ..
+            } if normalize_identifier(table, &self.name_resolution_ctx).name == target_name => {
+                Some(normalize_identifier(column, &self.name_resolution_ctx).name)
+            }
+            _ => None,
+        }
+    }
+}
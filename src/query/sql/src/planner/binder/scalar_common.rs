@@ -201,6 +201,14 @@ pub fn prune_by_children(scalar: &ScalarExpr, columns: &HashSet<ScalarExpr>) ->
                 WindowFuncType::Aggregate(agg) => {
                     agg.args.iter().all(|arg| prune_by_children(arg, columns))
                 }
+                WindowFuncType::LagLead(lag_lead) => {
+                    prune_by_children(&lag_lead.arg, columns)
+                        && lag_lead
+                            .default
+                            .iter()
+                            .all(|default| prune_by_children(default, columns))
+                }
+                WindowFuncType::NthValue(nth) => prune_by_children(&nth.arg, columns),
                 _ => false,
             };
             flag || scalar
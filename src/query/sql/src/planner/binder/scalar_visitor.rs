@@ -68,10 +68,22 @@ pub trait ScalarVisitor: Sized {
                                     order_by,
                                     ..
                                 }) => {
-                                    if let WindowFuncType::Aggregate(agg) = func {
-                                        for arg in &agg.args {
-                                            stack.push(RecursionProcessing::Call(arg));
+                                    match func {
+                                        WindowFuncType::Aggregate(agg) => {
+                                            for arg in &agg.args {
+                                                stack.push(RecursionProcessing::Call(arg));
+                                            }
                                         }
+                                        WindowFuncType::LagLead(lag_lead) => {
+                                            stack.push(RecursionProcessing::Call(&lag_lead.arg));
+                                            if let Some(default) = &lag_lead.default {
+                                                stack.push(RecursionProcessing::Call(default));
+                                            }
+                                        }
+                                        WindowFuncType::NthValue(nth) => {
+                                            stack.push(RecursionProcessing::Call(&nth.arg));
+                                        }
+                                        _ => {}
                                     }
                                     for arg in partition_by.iter() {
                                         stack.push(RecursionProcessing::Call(arg));
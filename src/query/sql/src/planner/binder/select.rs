@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 
 use async_recursion::async_recursion;
 use common_ast::ast::BinaryOperator;
@@ -42,6 +45,7 @@ use crate::binder::join::JoinConditions;
 use crate::binder::project_set::SrfCollector;
 use crate::binder::scalar_common::split_conjunctions;
 use crate::binder::CteInfo;
+use crate::binder::CteWorkingTable;
 use crate::binder::ExprContext;
 use crate::binder::Visibility;
 use crate::optimizer::SExpr;
@@ -109,6 +113,7 @@ impl Binder {
         let stmt = new_stmt.as_ref().unwrap_or(stmt);
 
         if let Some(expr) = &stmt.selection {
+            from_context.match_predicates = collect_match_predicates(expr);
             s_expr = self.bind_where(&mut from_context, expr, s_expr).await?;
         }
 
@@ -153,6 +158,15 @@ impl Binder {
             None
         };
 
+        let qualify = if let Some(qualify) = &stmt.qualify {
+            Some(
+                self.analyze_window_qualify(&mut from_context, &select_list, qualify)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
         let order_items = self
             .analyze_order_items(
                 &from_context,
@@ -181,6 +195,13 @@ impl Binder {
             s_expr = self.bind_window_function(window_info, s_expr).await?;
         }
 
+        // QUALIFY filters on the results of window functions.
+        if let Some((qualify, span)) = qualify {
+            s_expr = self
+                .bind_qualify(&mut from_context, qualify, span, s_expr)
+                .await?;
+        }
+
         if stmt.distinct {
             s_expr = self.bind_distinct(
                 stmt.span,
@@ -258,6 +279,8 @@ impl Binder {
                 let cte_info = CteInfo {
                     columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
                     query: cte.query.clone(),
+                    recursive: with.recursive,
+                    working_table: None,
                 };
                 bind_context.ctes_map.insert(table_name, cte_info);
             }
@@ -345,6 +368,26 @@ impl Binder {
     ) -> Result<(SExpr, BindContext)> {
         let (left_expr, left_bind_context) = self.bind_set_expr(bind_context, left, &[]).await?;
         let (right_expr, right_bind_context) = self.bind_set_expr(bind_context, right, &[]).await?;
+        self.bind_set_operator_with_children(
+            left,
+            right,
+            op,
+            all,
+            (left_expr, left_bind_context),
+            (right_expr, right_bind_context),
+        )
+    }
+
+    /// Bind the set operator whose children are already bound.
+    fn bind_set_operator_with_children(
+        &mut self,
+        left: &SetExpr,
+        right: &SetExpr,
+        op: &SetOperator,
+        all: &bool,
+        (left_expr, left_bind_context): (SExpr, BindContext),
+        (right_expr, right_bind_context): (SExpr, BindContext),
+    ) -> Result<(SExpr, BindContext)> {
         let mut coercion_types = Vec::with_capacity(left_bind_context.columns.len());
         if left_bind_context.columns.len() != right_bind_context.columns.len() {
             return Err(ErrorCode::SemanticError(
@@ -448,7 +491,10 @@ impl Binder {
             coercion_types,
         )?;
 
-        let union_plan = UnionAll { pairs };
+        let union_plan = UnionAll {
+            pairs,
+            cte_name: None,
+        };
         let mut new_expr = SExpr::create_binary(union_plan.into(), left_expr, right_expr);
         if distinct {
             new_expr = self.bind_distinct(
@@ -463,6 +509,134 @@ impl Binder {
         Ok((new_expr, new_bind_context))
     }
 
+    /// Bind the query of a recursive cte, which should be `<anchor> UNION ALL <recursive part>`.
+    ///
+    /// References to the cte in the recursive part are bound to its working table, which holds
+    /// the rows produced by the last iteration. The types of the working table are decided by
+    /// the anchor part, the recursive part will be casted to them.
+    #[async_backtrace::framed]
+    pub(super) async fn bind_recursive_cte(
+        &mut self,
+        bind_context: &mut BindContext,
+        cte_name: &str,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = &cte_info.query;
+
+        // References out of the recursive part are bound to an empty working table, and
+        // reported after binding.
+        let invalid_reference = Arc::new(AtomicBool::new(false));
+        bind_context.ctes_map.insert(cte_name.to_string(), CteInfo {
+            working_table: Some(CteWorkingTable {
+                columns: vec![],
+                referenced: invalid_reference.clone(),
+            }),
+            ..cte_info.clone()
+        });
+        let invalid_reference_error = || {
+            ErrorCode::SemanticError(format!(
+                "Recursive reference to cte `{cte_name}` must be in the right side of UNION ALL"
+            ))
+        };
+
+        let set_operation = match &query.body {
+            SetExpr::SetOperation(set_operation)
+                if query.order_by.is_empty()
+                    && query.limit.is_empty()
+                    && query.offset.is_none() =>
+            {
+                set_operation
+            }
+            _ => {
+                let (s_expr, mut new_bind_context) = self.bind_query(bind_context, query).await?;
+                if invalid_reference.load(Ordering::Relaxed) {
+                    return Err(invalid_reference_error());
+                }
+                bind_context.ctes_map.remove(cte_name);
+                new_bind_context.ctes_map.remove(cte_name);
+                return Ok((s_expr, new_bind_context));
+            }
+        };
+
+        let (left_expr, left_context) = self
+            .bind_set_expr(bind_context, &set_operation.left, &[])
+            .await?;
+        if invalid_reference.load(Ordering::Relaxed) {
+            return Err(invalid_reference_error().set_span(set_operation.left.span()));
+        }
+
+        let columns = left_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, column)| {
+                let name = cte_info
+                    .columns_alias
+                    .get(idx)
+                    .cloned()
+                    .unwrap_or_else(|| column.column_name.clone());
+                (name, *column.data_type.clone())
+            })
+            .collect();
+        let referenced = Arc::new(AtomicBool::new(false));
+        bind_context.ctes_map.insert(cte_name.to_string(), CteInfo {
+            working_table: Some(CteWorkingTable {
+                columns,
+                referenced: referenced.clone(),
+            }),
+            ..cte_info.clone()
+        });
+        let (right_expr, right_context) = self
+            .bind_set_expr(bind_context, &set_operation.right, &[])
+            .await?;
+        bind_context.ctes_map.remove(cte_name);
+
+        if !referenced.load(Ordering::Relaxed) {
+            // Not a self-referencing cte, it's a normal set operation of the bound children.
+            return self.bind_set_operator_with_children(
+                &set_operation.left,
+                &set_operation.right,
+                &set_operation.op,
+                &set_operation.all,
+                (left_expr, left_context),
+                (right_expr, right_context),
+            );
+        }
+        if !matches!(set_operation.op, SetOperator::Union) || !set_operation.all {
+            return Err(ErrorCode::SemanticError(format!(
+                "Recursive cte `{cte_name}` only supports UNION ALL between the anchor part and the recursive part"
+            ))
+            .set_span(set_operation.span));
+        }
+        if left_context.columns.len() != right_context.columns.len() {
+            return Err(ErrorCode::SemanticError(
+                "SetOperation must have the same number of columns",
+            ));
+        }
+
+        let coercion_types = left_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect();
+        let (new_bind_context, pairs, left_expr, right_expr) = self.coercion_union_type(
+            set_operation.left.span(),
+            set_operation.right.span(),
+            left_context,
+            right_context,
+            left_expr,
+            right_expr,
+            coercion_types,
+        )?;
+
+        let union_plan = UnionAll {
+            pairs,
+            cte_name: Some(cte_name.to_string()),
+        };
+        let s_expr = SExpr::create_binary(union_plan.into(), left_expr, right_expr);
+        Ok((s_expr, new_bind_context))
+    }
+
     fn bind_intersect(
         &mut self,
         left_span: Span,
@@ -660,6 +834,29 @@ impl Binder {
     }
 }
 
+/// Collect the `match(column, query)` conjuncts of the predicate, they are the
+/// full-text searches `score()` refers to.
+fn collect_match_predicates(expr: &Expr) -> Vec<(Expr, Expr)> {
+    match expr {
+        Expr::BinaryOp {
+            op: BinaryOperator::And,
+            left,
+            right,
+            ..
+        } => {
+            let mut predicates = collect_match_predicates(left);
+            predicates.extend(collect_match_predicates(right));
+            predicates
+        }
+        Expr::FunctionCall { name, args, .. }
+            if name.name.eq_ignore_ascii_case("match") && args.len() == 2 =>
+        {
+            vec![(args[0].clone(), args[1].clone())]
+        }
+        _ => vec![],
+    }
+}
+
 /// It is useful when implementing some SQL syntax sugar,
 ///
 /// [`column_binding`] contains the column binding information of the SelectStmt.
@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::default::Default;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use async_recursion::async_recursion;
@@ -28,11 +29,13 @@ use common_ast::ast::Statement;
 use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
 use common_ast::ast::TimeTravelPoint;
+use common_ast::parser::parse_expr;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::StageTableInfo;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
@@ -46,9 +49,13 @@ use common_expression::ColumnId;
 use common_expression::ConstantFolder;
 use common_expression::FunctionKind;
 use common_expression::Scalar;
+use common_expression::TableSchemaRef;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::schema::TableRowAccessPolicy;
+use common_pipeline_sources::input_formats::InputContext;
+use common_storage::init_stage_operator;
 use common_storage::DataOperator;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
@@ -56,6 +63,7 @@ use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultCacheMetaManager;
 use common_storages_result_cache::ResultCacheReader;
 use common_storages_result_cache::ResultScan;
+use common_storages_stage::StageTable;
 use common_storages_view::view_table::QUERY;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
@@ -66,11 +74,20 @@ use crate::binder::table_args::bind_table_args;
 use crate::binder::Binder;
 use crate::binder::ColumnBinding;
 use crate::binder::CteInfo;
+use crate::binder::CteWorkingTable;
 use crate::binder::ExprContext;
 use crate::binder::Visibility;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::RecursiveCteScan;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Statistics;
 use crate::BaseTableColumn;
@@ -386,6 +403,7 @@ impl Binder {
                         selection: None,
                         group_by: None,
                         having: None,
+                        qualify: None,
                     };
                     self.bind_select_stmt(&mut bind_context, &stmt, &[]).await
                 } else {
@@ -465,42 +483,58 @@ impl Binder {
         alias: &Option<TableAlias>,
         files_to_copy: Option<Vec<StageFileInfo>>,
     ) -> Result<(SExpr, BindContext)> {
-        if matches!(
-            stage_info.file_format_options.format,
-            StageFileFormatType::Parquet
-        ) {
-            let read_options = ParquetReadOptions::default();
-
-            let table =
+        let table = match stage_info.file_format_options.format {
+            StageFileFormatType::Parquet => {
+                let read_options = ParquetReadOptions::default();
                 ParquetTable::create(stage_info.clone(), files_info, read_options, files_to_copy)
-                    .await?;
+                    .await?
+            }
+            StageFileFormatType::Orc => {
+                // the schema is inferred from the first file
+                let operator = init_stage_operator(&stage_info)?;
+                let first_file = match &files_to_copy {
+                    Some(files) if !files.is_empty() => files[0].path.clone(),
+                    _ => files_info.first_file(&operator).await?.path,
+                };
+                let schema =
+                    InputContext::get_input_format(&stage_info.file_format_options.format)?
+                        .infer_schema(&first_file, &operator)
+                        .await?;
+                StageTable::try_create(StageTableInfo {
+                    schema,
+                    stage_info: stage_info.clone(),
+                    files_info,
+                    files_to_copy,
+                })?
+            }
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "stage table function only support parquet and orc format for now",
+                ));
+            }
+        };
 
-            let table_alias_name = if let Some(table_alias) = alias {
-                Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
-            } else {
-                None
-            };
+        let table_alias_name = if let Some(table_alias) = alias {
+            Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
+        } else {
+            None
+        };
 
-            let table_index = self.metadata.write().add_table(
-                CATALOG_DEFAULT.to_string(),
-                "system".to_string(),
-                table.clone(),
-                table_alias_name,
-                false,
-            );
+        let table_index = self.metadata.write().add_table(
+            CATALOG_DEFAULT.to_string(),
+            "system".to_string(),
+            table.clone(),
+            table_alias_name,
+            false,
+        );
 
-            let (s_expr, mut bind_context) = self
-                .bind_base_table(bind_context, "system", table_index)
-                .await?;
-            if let Some(alias) = alias {
-                bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
-            }
-            Ok((s_expr, bind_context))
-        } else {
-            Err(ErrorCode::Unimplemented(
-                "stage table function only support parquet format for now",
-            ))
+        let (s_expr, mut bind_context) = self
+            .bind_base_table(bind_context, "system", table_index)
+            .await?;
+        if let Some(alias) = alias {
+            bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
         }
+        Ok((s_expr, bind_context))
     }
 
     #[async_backtrace::framed]
@@ -586,6 +620,16 @@ impl Binder {
         alias: &Option<TableAlias>,
         cte_info: &CteInfo,
     ) -> Result<(SExpr, BindContext)> {
+        if let Some(working_table) = &cte_info.working_table {
+            return self.bind_recursive_cte_scan(
+                span,
+                bind_context,
+                table_name,
+                alias,
+                working_table,
+            );
+        }
+
         let mut new_bind_context = BindContext {
             parent: Some(Box::new(bind_context.clone())),
             bound_internal_columns: BTreeMap::new(),
@@ -597,10 +641,15 @@ impl Binder {
             view_info: None,
             srfs: Default::default(),
             expr_context: ExprContext::default(),
+            match_predicates: vec![],
+        };
+        let (s_expr, mut new_bind_context) = if cte_info.recursive {
+            self.bind_recursive_cte(&mut new_bind_context, table_name, cte_info)
+                .await?
+        } else {
+            self.bind_query(&mut new_bind_context, &cte_info.query)
+                .await?
         };
-        let (s_expr, mut new_bind_context) = self
-            .bind_query(&mut new_bind_context, &cte_info.query)
-            .await?;
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
@@ -634,6 +683,67 @@ impl Binder {
         Ok((s_expr, new_bind_context))
     }
 
+    /// Bind a reference to a recursive cte inside its recursive part, the rows come from
+    /// the working table which holds the result of the last iteration.
+    fn bind_recursive_cte_scan(
+        &mut self,
+        span: Span,
+        bind_context: &BindContext,
+        cte_name: &str,
+        alias: &Option<TableAlias>,
+        working_table: &CteWorkingTable,
+    ) -> Result<(SExpr, BindContext)> {
+        working_table.referenced.store(true, Ordering::Relaxed);
+
+        let mut cols_alias = working_table
+            .columns
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        if let Some(alias) = alias {
+            if alias.columns.len() > cols_alias.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "table has {} columns available but {} columns specified",
+                    cols_alias.len(),
+                    alias.columns.len()
+                ))
+                .set_span(span));
+            }
+            for (idx, col_alias) in alias.columns.iter().enumerate() {
+                cols_alias[idx] = col_alias.name.clone();
+            }
+        }
+        let table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| cte_name.to_string());
+
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let mut columns = Vec::with_capacity(cols_alias.len());
+        for (column_name, (_, data_type)) in cols_alias.into_iter().zip(&working_table.columns) {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), data_type.clone());
+            let column = ColumnBinding {
+                database_name: None,
+                table_name: Some(table_name.clone()),
+                column_name,
+                index,
+                data_type: Box::new(data_type.clone()),
+                visibility: Visibility::Visible,
+            };
+            new_bind_context.add_column_binding(column.clone());
+            columns.push(column);
+        }
+
+        let scan = RecursiveCteScan {
+            cte_name: cte_name.to_string(),
+            columns,
+        };
+        Ok((SExpr::create_leaf(scan.into()), new_bind_context))
+    }
+
     #[async_backtrace::framed]
     async fn bind_base_table(
         &mut self,
@@ -646,6 +756,23 @@ impl Binder {
         let table = self.metadata.read().table(table_index).clone();
         let statistics_provider = table.table().column_statistics_provider().await?;
 
+        // The columns without granted privileges are hidden from the query,
+        // the tables inside a view are checked by the privileges of the view.
+        let granted_columns = if table.is_source_of_view() {
+            None
+        } else {
+            self.ctx
+                .get_granted_columns(table.catalog(), table.database(), table.name())
+                .await?
+        };
+        // The result differs from user to user, it must not be served from the result cache.
+        if granted_columns.is_some() {
+            self.ctx.set_cacheable(false);
+        }
+        let table_schema = table.table().schema();
+        // All the columns of the table, including the hidden ones, the row access policy can use them.
+        let mut table_columns: HashMap<String, ColumnBinding> = HashMap::new();
+
         let mut col_stats: HashMap<IndexType, Option<ColumnStatistics>> = HashMap::new();
         for column in columns.iter() {
             match column {
@@ -669,6 +796,18 @@ impl Binder {
                             Visibility::Visible
                         },
                     };
+                    if path_indices.is_none() {
+                        table_columns.insert(column_name.clone(), column_binding.clone());
+                    }
+                    if let Some(granted_columns) = &granted_columns {
+                        let root_column_name = match path_indices {
+                            Some(indices) => table_schema.field(indices[0]).name(),
+                            None => column_name,
+                        };
+                        if !granted_columns.contains(root_column_name) {
+                            continue;
+                        }
+                    }
                     bind_context.add_column_binding(column_binding);
                     if path_indices.is_none() {
                         if let Some(col_id) = *leaf_index {
@@ -687,39 +826,270 @@ impl Binder {
         let is_accurate = table.table().engine().to_lowercase() == "fuse";
         let stat = table.table().table_statistics()?;
 
-        Ok((
-            SExpr::create_leaf(
-                Scan {
-                    table_index,
-                    columns: columns
-                        .into_iter()
-                        .map(|col| match col {
-                            ColumnEntry::BaseTableColumn(BaseTableColumn {
-                                column_index, ..
-                            }) => column_index,
-                            ColumnEntry::DerivedColumn(DerivedColumn { column_index, .. }) => {
-                                column_index
-                            }
-                            ColumnEntry::InternalColumn(TableInternalColumn {
-                                column_index,
-                                ..
-                            }) => column_index,
-                        })
-                        .collect(),
-                    push_down_predicates: None,
-                    limit: None,
-                    order_by: None,
-                    statistics: Statistics {
-                        statistics: stat,
-                        col_stats,
-                        is_accurate,
-                    },
-                    prewhere: None,
-                }
-                .into(),
-            ),
-            bind_context,
-        ))
+        let scan = SExpr::create_leaf(
+            Scan {
+                table_index,
+                columns: columns
+                    .into_iter()
+                    .map(|col| match col {
+                        ColumnEntry::BaseTableColumn(BaseTableColumn { column_index, .. }) => {
+                            column_index
+                        }
+                        ColumnEntry::DerivedColumn(DerivedColumn { column_index, .. }) => {
+                            column_index
+                        }
+                        ColumnEntry::InternalColumn(TableInternalColumn {
+                            column_index, ..
+                        }) => column_index,
+                    })
+                    .collect(),
+                push_down_predicates: None,
+                limit: None,
+                order_by: None,
+                statistics: Statistics {
+                    statistics: stat,
+                    col_stats,
+                    is_accurate,
+                },
+                prewhere: None,
+            }
+            .into(),
+        );
+
+        // The rows invisible to the current role are filtered out right above the scan,
+        // so the policy applies before any other predicate is pushed down.
+        let row_access_policy = &table.table().get_table_info().meta.row_access_policy;
+        let s_expr = match row_access_policy {
+            Some(policy) => {
+                // The visible rows differ from role to role, the result must not be cached.
+                self.ctx.set_cacheable(false);
+                let predicate = self
+                    .bind_row_access_policy(policy, &table_schema, &table_columns)
+                    .await?;
+                SExpr::create_unary(
+                    Filter {
+                        predicates: vec![predicate],
+                        is_having: false,
+                    }
+                    .into(),
+                    scan,
+                )
+            }
+            None => scan,
+        };
+
+        let column_mask_policy = &table.table().get_table_info().meta.column_mask_policy;
+        let s_expr = self
+            .bind_column_mask_policies(column_mask_policy, &table_schema, &mut bind_context, s_expr)
+            .await?;
+
+        Ok((s_expr, bind_context))
+    }
+
+    /// Mask the columns with masking policies right above the scan. The masked values are
+    /// evaluated into derived columns which take the place of the columns in `bind_context`,
+    /// so every reference to the columns sees the masked values, including the predicates.
+    #[async_backtrace::framed]
+    async fn bind_column_mask_policies(
+        &mut self,
+        column_mask_policy: &BTreeMap<ColumnId, String>,
+        table_schema: &TableSchemaRef,
+        bind_context: &mut BindContext,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let mut items = Vec::with_capacity(column_mask_policy.len());
+        for (column_id, policy) in column_mask_policy.iter() {
+            let Some(field) = table_schema
+                .fields()
+                .iter()
+                .find(|field| field.column_id() == *column_id)
+            else {
+                continue;
+            };
+            // The columns without granted privileges are not bound.
+            let Some(column) = bind_context.columns.iter_mut().find(|column| {
+                column.column_name == *field.name() && column.visibility == Visibility::Visible
+            }) else {
+                continue;
+            };
+
+            // The masked values differ from role to role, the result must not be cached.
+            self.ctx.set_cacheable(false);
+            let masking_policy = UserApiProvider::instance()
+                .get_masking_policy(self.ctx.get_tenant().as_str(), policy)
+                .await?;
+            let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+            let tokens = tokenize_sql(&masking_policy.body)?;
+            let body = parse_expr(&tokens, sql_dialect)?;
+
+            let mut policy_context = BindContext::new();
+            policy_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: masking_policy.arg.0.clone(),
+                ..column.clone()
+            });
+            let mut scalar_binder = ScalarBinder::new(
+                &mut policy_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let (mask, data_type) = scalar_binder.bind(&body).await?;
+            if data_type.remove_nullable() != column.data_type.remove_nullable() {
+                return Err(ErrorCode::IllegalMaskingPolicy(format!(
+                    "masking policy {} must return {}, but got {}",
+                    masking_policy.name, column.data_type, data_type
+                )));
+            }
+            let mask = self.rename_policy_columns(&mask, &[column.clone()])?;
+
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column.column_name.clone(), data_type.clone());
+            self.metadata.write().add_masked_column(index, mask.clone());
+            column.index = index;
+            column.data_type = Box::new(data_type);
+            items.push(ScalarItem {
+                scalar: mask,
+                index,
+            });
+        }
+
+        if items.is_empty() {
+            return Ok(s_expr);
+        }
+        Ok(SExpr::create_unary(EvalScalar { items }.into(), s_expr))
+    }
+
+    /// Replace the masked columns referenced by `scalar` with their masking expressions, the
+    /// predicates and the values of DELETE and UPDATE are evaluated on the columns of the table.
+    pub(in crate::planner::binder) fn inline_masked_columns(
+        &self,
+        scalar: &ScalarExpr,
+    ) -> Result<ScalarExpr> {
+        let metadata = self.metadata.read();
+        self.rewrite_scalar_with_replacement(scalar, &|scalar| match scalar {
+            ScalarExpr::BoundColumnRef(column_ref) => {
+                Ok(metadata.masked_column(column_ref.column.index).cloned())
+            }
+            _ => Ok(None),
+        })
+    }
+
+    /// Bind the body of the row access policy attached to the table into a predicate,
+    /// the arguments of the policy are bound to the columns of the table.
+    #[async_backtrace::framed]
+    async fn bind_row_access_policy(
+        &mut self,
+        policy: &TableRowAccessPolicy,
+        table_schema: &TableSchemaRef,
+        table_columns: &HashMap<String, ColumnBinding>,
+    ) -> Result<ScalarExpr> {
+        let row_access_policy = UserApiProvider::instance()
+            .get_row_access_policy(self.ctx.get_tenant().as_str(), &policy.name)
+            .await?;
+        if row_access_policy.args.len() != policy.column_ids.len() {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "row access policy {} requires {} columns, but got {}",
+                policy.name,
+                row_access_policy.args.len(),
+                policy.column_ids.len()
+            )));
+        }
+
+        let mut policy_context = BindContext::new();
+        let mut policy_columns = Vec::with_capacity(policy.column_ids.len());
+        for ((arg_name, _), column_id) in row_access_policy.args.iter().zip(&policy.column_ids) {
+            let column_binding = table_schema
+                .fields()
+                .iter()
+                .find(|field| field.column_id() == *column_id)
+                .and_then(|field| table_columns.get(field.name()))
+                .ok_or_else(|| {
+                    ErrorCode::IllegalRowAccessPolicy(format!(
+                        "column of row access policy {} doesn't exist",
+                        policy.name
+                    ))
+                })?;
+            policy_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: arg_name.clone(),
+                visibility: Visibility::Visible,
+                ..column_binding.clone()
+            });
+            policy_columns.push(column_binding.clone());
+        }
+
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(&row_access_policy.body)?;
+        let body = parse_expr(&tokens, sql_dialect)?;
+        let mut scalar_binder = ScalarBinder::new(
+            &mut policy_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (predicate, data_type) = scalar_binder.bind(&body).await?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "row access policy {} must return BOOLEAN, but got {}",
+                policy.name, data_type
+            )));
+        }
+        self.rename_policy_columns(&predicate, &policy_columns)
+    }
+
+    /// The columns in the body of a policy are bound by the argument names of the policy, rename
+    /// them back to the columns of the table, the predicates of DELETE and UPDATE resolve the
+    /// columns by names.
+    fn rename_policy_columns(
+        &self,
+        scalar: &ScalarExpr,
+        columns: &[ColumnBinding],
+    ) -> Result<ScalarExpr> {
+        self.rewrite_scalar_with_replacement(scalar, &|scalar| match scalar {
+            ScalarExpr::BoundColumnRef(column_ref) => Ok(columns
+                .iter()
+                .find(|column| column.index == column_ref.column.index)
+                .map(|column| {
+                    ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        span: column_ref.span,
+                        column: column.clone(),
+                    })
+                })),
+            _ => Ok(None),
+        })
+    }
+
+    /// AND the predicate of the row access policy bound above the scan of `table_expr`
+    /// into the selection of DELETE or UPDATE, so that only the visible rows are modified.
+    pub(in crate::planner::binder) fn with_row_access_predicate(
+        table_expr: &SExpr,
+        selection: Option<ScalarExpr>,
+    ) -> Option<ScalarExpr> {
+        // The masking policies are bound above the row access policy.
+        let table_expr = match table_expr.plan() {
+            RelOperator::EvalScalar(_) => &table_expr.children()[0],
+            _ => table_expr,
+        };
+        let predicate = match table_expr.plan() {
+            RelOperator::Filter(filter) => filter.predicates[0].clone(),
+            _ => return selection,
+        };
+        match selection {
+            Some(selection) => Some(ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "and".to_string(),
+                params: vec![],
+                arguments: vec![predicate, selection],
+            })),
+            None => Some(predicate),
+        }
     }
 
     #[async_backtrace::framed]
@@ -63,7 +63,7 @@ impl Binder {
             ));
         };
 
-        let (_, mut context) = self.bind_table_reference(bind_context, table).await?;
+        let (table_expr, mut context) = self.bind_table_reference(bind_context, table).await?;
 
         let table = self
             .ctx
@@ -96,7 +96,7 @@ impl Binder {
                     "Update does not support subquery temporarily",
                 ));
             }
-            update_columns.insert(index, scalar);
+            update_columns.insert(index, self.inline_masked_columns(&scalar)?);
         }
 
         let push_downs = if let Some(expr) = selection {
@@ -106,7 +106,7 @@ impl Binder {
                     "Update does not support subquery temporarily",
                 ));
             }
-            Some(scalar)
+            Some(self.inline_masked_columns(&scalar)?)
         } else {
             None
         };
@@ -116,7 +116,7 @@ impl Binder {
             database: database_name,
             table: table_name,
             update_list: update_columns,
-            selection: push_downs,
+            selection: Self::with_row_access_predicate(&table_expr, push_downs),
             bind_context: Box::new(context.clone()),
         };
         Ok(Plan::Update(Box::new(plan)))
@@ -14,16 +14,29 @@
 
 use std::collections::HashMap;
 
+use common_ast::ast::Expr;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_exception::Span;
 
+use super::select::SelectList;
+use crate::binder::aggregate::AggregateAndWindowRewriter;
+use crate::binder::split_conjunctions;
+use crate::binder::ExprContext;
+use crate::binder::ScalarBinder;
 use crate::optimizer::SExpr;
+use crate::planner::semantic::GroupingChecker;
 use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::Window;
 use crate::plans::WindowFuncFrame;
 use crate::plans::WindowFuncType;
+use crate::BindContext;
 use crate::Binder;
 use crate::IndexType;
+use crate::WindowChecker;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -66,6 +79,70 @@ impl Binder {
 
         Ok(new_expr)
     }
+
+    /// Analyze window functions in qualify clause, this will rewrite window functions.
+    /// See `AggregateAndWindowRewriter` for more details.
+    #[async_backtrace::framed]
+    pub(super) async fn analyze_window_qualify<'a>(
+        &mut self,
+        bind_context: &mut BindContext,
+        select_list: &SelectList<'a>,
+        qualify: &Expr,
+    ) -> Result<(ScalarExpr, Span)> {
+        let aliases = select_list
+            .items
+            .iter()
+            .map(|item| (item.alias.clone(), item.scalar.clone()))
+            .collect::<Vec<_>>();
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &aliases,
+        );
+        let (scalar, _) = scalar_binder.bind(qualify).await?;
+        let mut rewriter = AggregateAndWindowRewriter::new(bind_context, self.metadata.clone());
+        let scalar = rewriter.visit(&scalar)?;
+
+        if bind_context.windows.window_functions.is_empty() {
+            return Err(
+                ErrorCode::SemanticError("QUALIFY clause must refer to window functions")
+                    .set_span(qualify.span()),
+            );
+        }
+
+        Ok((scalar, qualify.span()))
+    }
+
+    /// Bind the qualify clause as a filter upon the window operators.
+    #[async_backtrace::framed]
+    pub(super) async fn bind_qualify(
+        &mut self,
+        bind_context: &mut BindContext,
+        qualify: ScalarExpr,
+        span: Span,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        bind_context.set_expr_context(ExprContext::QualifyClause);
+
+        let scalar = if bind_context.in_grouping {
+            let mut grouping_checker = GroupingChecker::new(bind_context);
+            grouping_checker.resolve(&qualify, span)?
+        } else {
+            let mut window_checker = WindowChecker::new(bind_context);
+            window_checker.resolve(&qualify)?
+        };
+
+        let predicates = split_conjunctions(&scalar);
+
+        let filter = Filter {
+            predicates,
+            is_having: false,
+        };
+
+        Ok(SExpr::create_unary(filter.into(), child))
+    }
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug)]
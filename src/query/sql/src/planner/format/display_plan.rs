@@ -59,6 +59,14 @@ impl Plan {
             Plan::RenameTable(rename_table) => Ok(format!("{:?}", rename_table)),
             Plan::AddTableColumn(add_table_column) => Ok(format!("{:?}", add_table_column)),
             Plan::DropTableColumn(drop_table_column) => Ok(format!("{:?}", drop_table_column)),
+            Plan::RenameTableColumn(rename_table_column) => {
+                Ok(format!("{:?}", rename_table_column))
+            }
+            Plan::ModifyTableColumn(modify_table_column) => {
+                Ok(format!("{:?}", modify_table_column))
+            }
+            Plan::AddTableRowAccessPolicy(add_policy) => Ok(format!("{:?}", add_policy)),
+            Plan::DropTableRowAccessPolicy(drop_policy) => Ok(format!("{:?}", drop_policy)),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => {
                 Ok(format!("{:?}", alter_table_cluster_key))
             }
@@ -76,11 +84,16 @@ impl Plan {
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
 
+            // Indexes
+            Plan::CreateInvertedIndex(create_index) => Ok(format!("{:?}", create_index)),
+            Plan::DropInvertedIndex(drop_index) => Ok(format!("{:?}", drop_index)),
+
             // Insert
             Plan::Insert(insert) => Ok(format!("{:?}", insert)),
             Plan::Replace(replace) => Ok(format!("{:?}", replace)),
             Plan::Delete(delete) => Ok(format!("{:?}", delete)),
             Plan::Update(update) => Ok(format!("{:?}", update)),
+            Plan::MergeInto(merge_into) => Ok(format!("{:?}", merge_into)),
 
             // Stages
             Plan::CreateStage(create_stage) => Ok(format!("{:?}", create_stage)),
@@ -98,11 +111,16 @@ impl Plan {
             Plan::ShowGrants(show_grants) => Ok(format!("{:?}", show_grants)),
             Plan::RevokePriv(revoke_priv) => Ok(format!("{:?}", revoke_priv)),
             Plan::RevokeRole(revoke_role) => Ok(format!("{:?}", revoke_role)),
+            Plan::GrantOwnership(grant_ownership) => Ok(format!("{:?}", grant_ownership)),
             Plan::CreateUser(create_user) => Ok(format!("{:?}", create_user)),
             Plan::DropUser(drop_user) => Ok(format!("{:?}", drop_user)),
             Plan::CreateUDF(create_user_udf) => Ok(format!("{:?}", create_user_udf)),
             Plan::AlterUDF(alter_user_udf) => Ok(format!("{alter_user_udf:?}")),
             Plan::DropUDF(drop_udf) => Ok(format!("{drop_udf:?}")),
+            Plan::CreateRowAccessPolicy(create_policy) => Ok(format!("{:?}", create_policy)),
+            Plan::DropRowAccessPolicy(drop_policy) => Ok(format!("{:?}", drop_policy)),
+            Plan::CreateMaskingPolicy(create_policy) => Ok(format!("{:?}", create_policy)),
+            Plan::DropMaskingPolicy(drop_policy) => Ok(format!("{:?}", drop_policy)),
             Plan::AlterUser(alter_user) => Ok(format!("{:?}", alter_user)),
             Plan::CreateRole(create_role) => Ok(format!("{:?}", create_role)),
             Plan::DropRole(drop_role) => Ok(format!("{:?}", drop_role)),
@@ -82,6 +82,7 @@ impl Display for FormatContext {
                 RelOperator::RuntimeFilterSource(_) => write!(f, "RuntimeFilterSource"),
                 RelOperator::Window(_) => write!(f, "WindowFunc"),
                 RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
+                RelOperator::RecursiveCteScan(_) => write!(f, "RecursiveCteScan"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -27,6 +27,8 @@ use common_expression::TableDataType;
 use common_expression::TableField;
 use parking_lot::RwLock;
 
+use crate::plans::ScalarExpr;
+
 /// Planner use [`usize`] as it's index type.
 ///
 /// This type will be used across the whole planner.
@@ -51,6 +53,9 @@ pub type MetadataRef = Arc<RwLock<Metadata>>;
 pub struct Metadata {
     tables: Vec<TableEntry>,
     columns: Vec<ColumnEntry>,
+    /// Masking expressions of the masked columns, keyed by the index of the derived column
+    /// holding the masked value.
+    masked_columns: HashMap<IndexType, ScalarExpr>,
 }
 
 impl Metadata {
@@ -134,6 +139,14 @@ impl Metadata {
         column_index
     }
 
+    pub fn add_masked_column(&mut self, index: IndexType, mask: ScalarExpr) {
+        self.masked_columns.insert(index, mask);
+    }
+
+    pub fn masked_column(&self, index: IndexType) -> Option<&ScalarExpr> {
+        self.masked_columns.get(&index)
+    }
+
     pub fn add_derived_column(&mut self, alias: String, data_type: DataType) -> IndexType {
         let column_index = self.columns.len();
         let column_entry = ColumnEntry::DerivedColumn(DerivedColumn {
@@ -39,7 +39,7 @@ impl CostModel for DefaultCostModel {
 fn compute_cost_impl(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
     match &m_expr.plan {
         RelOperator::Scan(plan) => compute_cost_scan(memo, m_expr, plan),
-        RelOperator::DummyTableScan(_) => Ok(Cost(0.0)),
+        RelOperator::DummyTableScan(_) | RelOperator::RecursiveCteScan(_) => Ok(Cost(0.0)),
         RelOperator::Join(plan) => compute_cost_join(memo, m_expr, plan),
         RelOperator::UnionAll(_) => compute_cost_union_all(memo, m_expr),
 
@@ -20,7 +20,6 @@ use crate::optimizer::SExpr;
 use crate::plans::Aggregate;
 use crate::plans::EvalScalar;
 use crate::plans::RelOperator;
-use crate::plans::WindowFuncType;
 use crate::MetadataRef;
 
 pub struct UnusedColumnPruner {
@@ -159,11 +158,7 @@ impl UnusedColumnPruner {
             }
             RelOperator::Window(p) => {
                 if required.contains(&p.index) {
-                    if let WindowFuncType::Aggregate(agg) = &p.function {
-                        agg.args.iter().for_each(|item| {
-                            required.extend(item.used_columns());
-                        });
-                    }
+                    required.extend(p.function.used_columns());
                     p.partition_by.iter().for_each(|item| {
                         required.insert(item.index);
                     });
@@ -220,7 +215,7 @@ impl UnusedColumnPruner {
                 ))
             }
 
-            RelOperator::DummyTableScan(_) => Ok(expr.clone()),
+            RelOperator::DummyTableScan(_) | RelOperator::RecursiveCteScan(_) => Ok(expr.clone()),
 
             _ => Err(ErrorCode::Internal(
                 "Attempting to prune columns of a physical plan is not allowed",
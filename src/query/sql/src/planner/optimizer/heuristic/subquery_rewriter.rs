@@ -129,7 +129,9 @@ impl SubqueryRewriter {
                 self.rewrite(s_expr.child(0)?)?,
             )),
 
-            RelOperator::DummyTableScan(_) | RelOperator::Scan(_) => Ok(s_expr.clone()),
+            RelOperator::DummyTableScan(_)
+            | RelOperator::RecursiveCteScan(_)
+            | RelOperator::Scan(_) => Ok(s_expr.clone()),
 
             _ => Err(ErrorCode::Internal("Invalid plan type")),
         }
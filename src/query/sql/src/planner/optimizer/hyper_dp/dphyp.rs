@@ -118,6 +118,7 @@ impl DPhpy {
             RelOperator::Window(_)
             | RelOperator::UnionAll(_)
             | RelOperator::DummyTableScan(_)
+            | RelOperator::RecursiveCteScan(_)
             | RelOperator::RuntimeFilterSource(_) => Ok(false),
         }
     }
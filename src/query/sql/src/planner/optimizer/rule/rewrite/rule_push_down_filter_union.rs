@@ -97,6 +97,11 @@ impl Rule for RulePushDownFilterUnion {
         let filter: Filter = s_expr.plan().clone().try_into()?;
         let union_s_expr = s_expr.child(0)?;
         let union: UnionAll = union_s_expr.plan().clone().try_into()?;
+        // Filtering the recursive part of a recursive cte would change the rows
+        // fed into the next iteration.
+        if union.cte_name.is_some() {
+            return Ok(());
+        }
 
         // Create a filter which matches union's right child.
         let index_pairs: HashMap<IndexType, IndexType> =
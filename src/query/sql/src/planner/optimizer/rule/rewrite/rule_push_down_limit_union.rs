@@ -77,7 +77,9 @@ impl Rule for RulePushDownLimitUnion {
         let union_s_expr = s_expr.child(0)?;
         let union: UnionAll = union_s_expr.plan().clone().try_into()?;
 
-        if limit.limit.is_none() {
+        // The limit can't be pushed down to the recursive part of a recursive cte,
+        // the rows are required as the input of the next iteration.
+        if limit.limit.is_none() || union.cte_name.is_some() {
             return Ok(());
         }
         // Create limit which will be pushed down
@@ -232,6 +232,7 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::UnionAll(_)
         | RelOperator::Sort(_)
         | RelOperator::DummyTableScan(_)
+        | RelOperator::RecursiveCteScan(_)
         | RelOperator::RuntimeFilterSource(_)
         | RelOperator::Pattern(_) => false,
         RelOperator::Join(op) => {
@@ -261,10 +262,7 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
                     .partition_by
                     .iter()
                     .any(|expr| find_subquery_in_expr(&expr.scalar))
-                || match &op.function {
-                    WindowFuncType::Aggregate(agg) => agg.args.iter().any(find_subquery_in_expr),
-                    _ => false,
-                }
+                || find_subquery_in_window_func(&op.function)
         }
         RelOperator::ProjectSet(op) => op
             .srfs
@@ -273,6 +271,21 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
     }
 }
 
+fn find_subquery_in_window_func(func: &WindowFuncType) -> bool {
+    match func {
+        WindowFuncType::Aggregate(agg) => agg.args.iter().any(find_subquery_in_expr),
+        WindowFuncType::LagLead(lag_lead) => {
+            find_subquery_in_expr(&lag_lead.arg)
+                || lag_lead
+                    .default
+                    .as_ref()
+                    .map_or(false, |default| find_subquery_in_expr(default))
+        }
+        WindowFuncType::NthValue(nth) => find_subquery_in_expr(&nth.arg),
+        _ => false,
+    }
+}
+
 fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
     match expr {
         ScalarExpr::BoundColumnRef(_)
@@ -289,11 +302,8 @@ fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
             find_subquery_in_expr(&expr.left) || find_subquery_in_expr(&expr.right)
         }
         ScalarExpr::WindowFunction(expr) => {
-            let flag = match &expr.func {
-                WindowFuncType::Aggregate(agg) => agg.args.iter().any(find_subquery_in_expr),
-                _ => false,
-            };
-            flag || expr.partition_by.iter().any(find_subquery_in_expr)
+            find_subquery_in_window_func(&expr.func)
+                || expr.partition_by.iter().any(find_subquery_in_expr)
                 || expr.order_by.iter().any(|o| find_subquery_in_expr(&o.expr))
         }
         ScalarExpr::AggregateFunction(expr) => expr.args.iter().any(find_subquery_in_expr),
@@ -26,12 +26,14 @@ use common_meta_app::principal::PrincipalIdentity;
 use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserQuota;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateUserPlan {
     pub user: UserIdentity,
     pub auth_info: AuthInfo,
     pub user_option: UserOption,
+    pub user_quota: UserQuota,
     pub if_not_exists: bool,
 }
 
@@ -47,6 +49,7 @@ pub struct AlterUserPlan {
     // None means no change to make
     pub auth_info: Option<AuthInfo>,
     pub user_option: Option<UserOption>,
+    pub user_quota: Option<UserQuota>,
 }
 
 impl AlterUserPlan {
@@ -156,7 +159,7 @@ impl ShowRolesPlan {
 pub struct GrantPrivilegePlan {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
-    pub on: GrantObject,
+    pub on: Vec<GrantObject>,
 }
 
 impl GrantPrivilegePlan {
@@ -165,11 +168,23 @@ impl GrantPrivilegePlan {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GrantOwnershipPlan {
+    pub object: GrantObject,
+    pub role: String,
+}
+
+impl GrantOwnershipPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RevokePrivilegePlan {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
-    pub on: GrantObject,
+    pub on: Vec<GrantObject>,
 }
 
 impl RevokePrivilegePlan {
@@ -0,0 +1,49 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateInvertedIndexPlan {
+    pub if_not_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+    pub columns: Vec<String>,
+}
+
+impl CreateInvertedIndexPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropInvertedIndexPlan {
+    pub if_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub index_name: String,
+}
+
+impl DropInvertedIndexPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
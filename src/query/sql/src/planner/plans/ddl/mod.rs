@@ -15,6 +15,9 @@ mod account;
 mod catalog;
 mod database;
 mod file_format;
+mod index;
+mod masking_policy;
+mod row_access_policy;
 mod stage;
 mod table;
 mod udf;
@@ -24,6 +27,9 @@ pub use account::*;
 pub use catalog::*;
 pub use database::*;
 pub use file_format::*;
+pub use index::*;
+pub use masking_policy::*;
+pub use row_access_policy::*;
 pub use stage::*;
 pub use table::*;
 pub use udf::*;
@@ -23,6 +23,7 @@ use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::TableDataType;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
@@ -178,6 +179,48 @@ impl DropTableColumnPlan {
     }
 }
 
+// Table rename column
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenameTableColumnPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub old_column: String,
+    pub new_column: String,
+}
+
+impl RenameTableColumnPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ModifyColumnAction {
+    SetDataType(TableDataType),
+    /// `None` drops the default expression.
+    SetDefault(Option<String>),
+    Comment(String),
+    /// `None` unsets the masking policy.
+    SetMaskingPolicy(Option<String>),
+}
+
+// Table modify column
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModifyTableColumnPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub column: String,
+    pub action: ModifyColumnAction,
+}
+
+impl ModifyTableColumnPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Show.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ShowCreateTablePlan {
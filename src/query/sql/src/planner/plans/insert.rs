@@ -17,7 +17,10 @@ use std::sync::Arc;
 use common_catalog::table_context::StageAttachment;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
+use common_expression::Scalar;
+use common_expression::TableField;
 use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
 use common_meta_app::principal::FileFormatOptions;
 use common_meta_types::MetaId;
 use common_pipeline_sources::input_formats::InputContext;
@@ -50,6 +53,8 @@ pub struct Insert {
     pub table: String,
     pub table_id: MetaId,
     pub schema: TableSchemaRef,
+    /// values of the static partition columns, they are not in the `schema`
+    pub partition: Vec<(TableField, Scalar)>,
     pub overwrite: bool,
     pub source: InsertInputSource,
 }
@@ -68,6 +73,20 @@ impl Insert {
         Arc::new(self.schema.clone().into())
     }
 
+    /// the schema followed by the static partition columns
+    pub fn schema_with_partition(&self) -> DataSchemaRef {
+        let mut fields = self.schema.fields().clone();
+        fields.extend(self.partition.iter().map(|(field, _)| field.clone()));
+        Arc::new(TableSchemaRefExt::create(fields).into())
+    }
+
+    pub fn partition_values(&self) -> Vec<Scalar> {
+        self.partition
+            .iter()
+            .map(|(_, value)| value.clone())
+            .collect()
+    }
+
     pub fn has_select_plan(&self) -> bool {
         matches!(&self.source, InsertInputSource::SelectPlan(_))
     }
@@ -81,6 +100,7 @@ impl std::fmt::Debug for Insert {
             .field("table", &self.table)
             .field("table_id", &self.table_id)
             .field("schema", &self.schema)
+            .field("partition", &self.partition)
             .field("overwrite", &self.overwrite)
             .finish()
     }
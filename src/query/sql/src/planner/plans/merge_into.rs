@@ -0,0 +1,87 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_meta_types::MetaId;
+
+use crate::plans::Plan;
+
+/// Name of the trailing column of the merge source, which holds the action of each row.
+pub const MERGE_INTO_ACTION_COLUMN: &str = "_merge_into_action";
+/// The matched row is replaced by the merge source row.
+pub const MERGE_INTO_ACTION_UPDATE: u8 = 0;
+/// The matched row is removed.
+pub const MERGE_INTO_ACTION_DELETE: u8 = 1;
+/// The merge source row is appended.
+pub const MERGE_INTO_ACTION_INSERT: u8 = 2;
+
+#[derive(Clone)]
+pub struct MergeInto {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub table_id: MetaId,
+    pub on_conflict_fields: Vec<TableField>,
+    /// Schema of the target table.
+    pub schema: TableSchemaRef,
+    /// Query producing the target rows, followed by the action column.
+    pub input: Box<Plan>,
+}
+
+impl PartialEq for MergeInto {
+    fn eq(&self, other: &Self) -> bool {
+        self.catalog == other.catalog
+            && self.database == other.database
+            && self.table == other.table
+            && self.schema == other.schema
+            && self.on_conflict_fields == other.on_conflict_fields
+    }
+}
+
+impl MergeInto {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+
+    /// Schema of the merge source after casting: the target table columns and the action column.
+    pub fn source_schema(&self) -> DataSchemaRef {
+        let mut fields: Vec<DataField> = self.schema.fields().iter().map(DataField::from).collect();
+        fields.push(DataField::new(
+            MERGE_INTO_ACTION_COLUMN,
+            DataType::Number(NumberDataType::UInt8),
+        ));
+        Arc::new(DataSchema::new(fields))
+    }
+}
+
+impl std::fmt::Debug for MergeInto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MergeInto")
+            .field("catalog", &self.catalog)
+            .field("database", &self.database)
+            .field("table", &self.table)
+            .field("table_id", &self.table_id)
+            .field("schema", &self.schema)
+            .field("on", &self.on_conflict_fields)
+            .finish()
+    }
+}
@@ -26,12 +26,14 @@ mod join;
 mod kill;
 mod limit;
 mod list;
+mod merge_into;
 mod operator;
 mod pattern;
 mod plan;
 mod presign;
 mod project_set;
 mod recluster_table;
+mod recursive_cte_scan;
 mod replace;
 mod revert_table;
 mod runtime_filter_source;
@@ -59,6 +61,7 @@ pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
 pub use list::ListPlan;
+pub use merge_into::*;
 pub use operator::*;
 pub use pattern::PatternPlan;
 pub use plan::Plan::*;
@@ -67,6 +70,7 @@ pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte_scan::RecursiveCteScan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
 pub use runtime_filter_source::RuntimeFilterId;
@@ -25,6 +25,7 @@ use super::filter::Filter;
 use super::join::Join;
 use super::limit::Limit;
 use super::pattern::PatternPlan;
+use super::recursive_cte_scan::RecursiveCteScan;
 use super::scan::Scan;
 use super::sort::Sort;
 use super::union_all::UnionAll;
@@ -73,6 +74,7 @@ pub enum RelOp {
     RuntimeFilterSource,
     Window,
     ProjectSet,
+    RecursiveCteScan,
 
     // Pattern
     Pattern,
@@ -94,6 +96,7 @@ pub enum RelOperator {
     RuntimeFilterSource(RuntimeFilterSource),
     Window(Window),
     ProjectSet(ProjectSet),
+    RecursiveCteScan(RecursiveCteScan),
 
     Pattern(PatternPlan),
 }
@@ -115,6 +118,7 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.rel_op(),
             RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
             RelOperator::Window(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -134,6 +138,7 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -153,6 +158,7 @@ impl Operator for RelOperator {
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -206,6 +212,9 @@ impl Operator for RelOperator {
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -470,3 +479,23 @@ impl TryFrom<RelOperator> for ProjectSet {
         }
     }
 }
+
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(value: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
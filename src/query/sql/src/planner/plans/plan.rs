@@ -39,6 +39,7 @@ use crate::plans::share::ShowGrantTenantsOfSharePlan;
 use crate::plans::share::ShowObjectGrantPrivilegesPlan;
 use crate::plans::share::ShowSharesPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
@@ -48,7 +49,10 @@ use crate::plans::CallPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateFileFormatPlan;
+use crate::plans::CreateInvertedIndexPlan;
+use crate::plans::CreateMaskingPolicyPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::CreateUDFPlan;
@@ -59,21 +63,29 @@ use crate::plans::DescribeTablePlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::DropFileFormatPlan;
+use crate::plans::DropInvertedIndexPlan;
+use crate::plans::DropMaskingPolicyPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::GrantOwnershipPlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
+use crate::plans::MergeInto;
+use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
+use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::Replace;
 use crate::plans::RevertTablePlan;
@@ -152,6 +164,10 @@ pub enum Plan {
     RenameTable(Box<RenameTablePlan>),
     AddTableColumn(Box<AddTableColumnPlan>),
     DropTableColumn(Box<DropTableColumnPlan>),
+    RenameTableColumn(Box<RenameTableColumnPlan>),
+    ModifyTableColumn(Box<ModifyTableColumnPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
@@ -166,12 +182,17 @@ pub enum Plan {
     Replace(Box<Replace>),
     Delete(Box<DeletePlan>),
     Update(Box<UpdatePlan>),
+    MergeInto(Box<MergeInto>),
 
     // Views
     CreateView(Box<CreateViewPlan>),
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
 
+    // Indexes
+    CreateInvertedIndex(Box<CreateInvertedIndexPlan>),
+    DropInvertedIndex(Box<DropInvertedIndexPlan>),
+
     // Account
     AlterUser(Box<AlterUserPlan>),
     CreateUser(Box<CreateUserPlan>),
@@ -182,6 +203,14 @@ pub enum Plan {
     AlterUDF(Box<AlterUDFPlan>),
     DropUDF(Box<DropUDFPlan>),
 
+    // Row access policies
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+
+    // Masking policies
+    CreateMaskingPolicy(Box<CreateMaskingPolicyPlan>),
+    DropMaskingPolicy(Box<DropMaskingPolicyPlan>),
+
     // Role
     ShowRoles(Box<ShowRolesPlan>),
     CreateRole(Box<CreateRolePlan>),
@@ -191,6 +220,7 @@ pub enum Plan {
     ShowGrants(Box<ShowGrantsPlan>),
     RevokePriv(Box<RevokePrivilegePlan>),
     RevokeRole(Box<RevokeRolePlan>),
+    GrantOwnership(Box<GrantOwnershipPlan>),
     SetRole(Box<SetRolePlan>),
 
     // FileFormat
@@ -273,6 +303,10 @@ impl Display for Plan {
             Plan::RenameTable(_) => write!(f, "RenameTable"),
             Plan::AddTableColumn(_) => write!(f, "AddTableColumn"),
             Plan::DropTableColumn(_) => write!(f, "DropTableColumn"),
+            Plan::RenameTableColumn(_) => write!(f, "RenameTableColumn"),
+            Plan::ModifyTableColumn(_) => write!(f, "ModifyTableColumn"),
+            Plan::AddTableRowAccessPolicy(_) => write!(f, "AddTableRowAccessPolicy"),
+            Plan::DropTableRowAccessPolicy(_) => write!(f, "DropTableRowAccessPolicy"),
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
             Plan::DropTableClusterKey(_) => write!(f, "DropTableClusterKey"),
             Plan::ReclusterTable(_) => write!(f, "ReclusterTable"),
@@ -283,6 +317,8 @@ impl Display for Plan {
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateInvertedIndex(_) => write!(f, "CreateInvertedIndex"),
+            Plan::DropInvertedIndex(_) => write!(f, "DropInvertedIndex"),
             Plan::AlterUser(_) => write!(f, "AlterUser"),
             Plan::CreateUser(_) => write!(f, "CreateUser"),
             Plan::DropUser(_) => write!(f, "DropUser"),
@@ -299,14 +335,20 @@ impl Display for Plan {
             Plan::ShowGrants(_) => write!(f, "ShowGrants"),
             Plan::ShowRoles(_) => write!(f, "ShowRoles"),
             Plan::RevokePriv(_) => write!(f, "RevokePriv"),
+            Plan::GrantOwnership(_) => write!(f, "GrantOwnership"),
             Plan::RevokeRole(_) => write!(f, "RevokeRole"),
             Plan::CreateUDF(_) => write!(f, "CreateUDF"),
             Plan::AlterUDF(_) => write!(f, "AlterUDF"),
             Plan::DropUDF(_) => write!(f, "DropUDF"),
+            Plan::CreateRowAccessPolicy(_) => write!(f, "CreateRowAccessPolicy"),
+            Plan::DropRowAccessPolicy(_) => write!(f, "DropRowAccessPolicy"),
+            Plan::CreateMaskingPolicy(_) => write!(f, "CreateMaskingPolicy"),
+            Plan::DropMaskingPolicy(_) => write!(f, "DropMaskingPolicy"),
             Plan::Insert(_) => write!(f, "Insert"),
             Plan::Replace(_) => write!(f, "Replace"),
             Plan::Delete(_) => write!(f, "Delete"),
             Plan::Update(_) => write!(f, "Update"),
+            Plan::MergeInto(_) => write!(f, "MergeInto"),
             Plan::Call(_) => write!(f, "Call"),
             Plan::Presign(_) => write!(f, "Presign"),
             Plan::SetVariable(_) => write!(f, "SetVariable"),
@@ -367,6 +409,10 @@ impl Plan {
             Plan::RenameTable(plan) => plan.schema(),
             Plan::AddTableColumn(plan) => plan.schema(),
             Plan::DropTableColumn(plan) => plan.schema(),
+            Plan::RenameTableColumn(plan) => plan.schema(),
+            Plan::ModifyTableColumn(plan) => plan.schema(),
+            Plan::AddTableRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropTableRowAccessPolicy(plan) => plan.schema(),
             Plan::AlterTableClusterKey(plan) => plan.schema(),
             Plan::DropTableClusterKey(plan) => plan.schema(),
             Plan::ReclusterTable(plan) => plan.schema(),
@@ -377,6 +423,8 @@ impl Plan {
             Plan::CreateView(plan) => plan.schema(),
             Plan::AlterView(plan) => plan.schema(),
             Plan::DropView(plan) => plan.schema(),
+            Plan::CreateInvertedIndex(plan) => plan.schema(),
+            Plan::DropInvertedIndex(plan) => plan.schema(),
             Plan::AlterUser(plan) => plan.schema(),
             Plan::CreateUser(plan) => plan.schema(),
             Plan::DropUser(plan) => plan.schema(),
@@ -394,13 +442,19 @@ impl Plan {
             Plan::ShowFileFormats(plan) => plan.schema(),
             Plan::RevokePriv(_) => Arc::new(DataSchema::empty()),
             Plan::RevokeRole(_) => Arc::new(DataSchema::empty()),
+            Plan::GrantOwnership(plan) => plan.schema(),
             Plan::CreateUDF(_) => Arc::new(DataSchema::empty()),
             Plan::AlterUDF(_) => Arc::new(DataSchema::empty()),
             Plan::DropUDF(_) => Arc::new(DataSchema::empty()),
+            Plan::CreateRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropRowAccessPolicy(plan) => plan.schema(),
+            Plan::CreateMaskingPolicy(plan) => plan.schema(),
+            Plan::DropMaskingPolicy(plan) => plan.schema(),
             Plan::Insert(plan) => plan.schema(),
             Plan::Replace(plan) => plan.schema(),
             Plan::Delete(_) => Arc::new(DataSchema::empty()),
             Plan::Update(_) => Arc::new(DataSchema::empty()),
+            Plan::MergeInto(plan) => plan.schema(),
             Plan::Call(_) => Arc::new(DataSchema::empty()),
             Plan::Presign(plan) => plan.schema(),
             Plan::SetVariable(plan) => plan.schema(),
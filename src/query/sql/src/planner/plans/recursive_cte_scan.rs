@@ -0,0 +1,81 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::ColumnBinding;
+
+/// Scan the working table of a recursive cte, which holds the rows
+/// produced by the previous iteration of the recursive `UnionAll`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecursiveCteScan {
+    /// Name of the recursive cte, used to find the working table.
+    pub cte_name: String,
+    /// Output columns, in the same order as the columns of the working table.
+    pub columns: Vec<ColumnBinding>,
+}
+
+impl RecursiveCteScan {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        Ok(self.columns.iter().map(|c| c.index).collect())
+    }
+}
+
+impl Operator for RecursiveCteScan {
+    fn rel_op(&self) -> RelOp {
+        RelOp::RecursiveCteScan
+    }
+
+    fn derive_relational_prop(&self, _rel_expr: &RelExpr) -> Result<RelationalProperty> {
+        Ok(RelationalProperty {
+            output_columns: self.used_columns()?,
+            outer_columns: ColumnSet::new(),
+            used_columns: self.used_columns()?,
+            cardinality: 1.0,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+                is_accurate: false,
+            },
+        })
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        // The working table only lives in the local node.
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
@@ -32,6 +32,9 @@ use crate::IndexType;
 pub struct UnionAll {
     // Pairs of unioned columns
     pub pairs: Vec<(IndexType, IndexType)>,
+    // The name of the recursive cte if this is the union of a recursive cte,
+    // the right child will be evaluated repeatedly against the working table.
+    pub cte_name: Option<String>,
 }
 
 impl UnionAll {
@@ -34,6 +34,7 @@ use crate::optimizer::RequiredProperty;
 use crate::optimizer::Statistics;
 use crate::plans::Operator;
 use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::IndexType;
 
@@ -58,14 +59,7 @@ impl Window {
 
         used_columns.insert(self.index);
 
-        if let WindowFuncType::Aggregate(agg) = &self.function {
-            for scalar in &agg.args {
-                used_columns = used_columns
-                    .union(&scalar.used_columns())
-                    .cloned()
-                    .collect();
-            }
-        }
+        used_columns.extend(self.function.used_columns());
 
         for part in self.partition_by.iter() {
             used_columns.insert(part.index);
@@ -225,6 +219,38 @@ pub enum WindowFuncType {
     RowNumber,
     Rank,
     DenseRank,
+    PercentRank,
+    CumeDist,
+    Ntile(NtileFunction),
+    LagLead(LagLeadFunction),
+    NthValue(NthValueFunction),
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NtileFunction {
+    /// Number of buckets
+    pub n: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LagLeadFunction {
+    /// Is `lag` or `lead`
+    pub is_lag: bool,
+    pub arg: Box<ScalarExpr>,
+    pub offset: u64,
+    /// Returned if the offset row is out of the partition, `NULL` if not set
+    pub default: Option<Box<ScalarExpr>>,
+    pub ignore_nulls: bool,
+    pub return_type: Box<DataType>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NthValueFunction {
+    /// The n-th row (starts from 1) of the window frame, `None` means the last row
+    pub n: Option<u64>,
+    pub arg: Box<ScalarExpr>,
+    pub ignore_nulls: bool,
+    pub return_type: Box<DataType>,
 }
 
 impl WindowFuncType {
@@ -233,6 +259,8 @@ impl WindowFuncType {
             "row_number" => Ok(WindowFuncType::RowNumber),
             "rank" => Ok(WindowFuncType::Rank),
             "dense_rank" => Ok(WindowFuncType::DenseRank),
+            "percent_rank" => Ok(WindowFuncType::PercentRank),
+            "cume_dist" => Ok(WindowFuncType::CumeDist),
             _ => Err(ErrorCode::UnknownFunction(format!(
                 "Unknown window function: {}",
                 name
@@ -245,6 +273,16 @@ impl WindowFuncType {
             WindowFuncType::RowNumber => "row_number".to_string(),
             WindowFuncType::Rank => "rank".to_string(),
             WindowFuncType::DenseRank => "dense_rank".to_string(),
+            WindowFuncType::PercentRank => "percent_rank".to_string(),
+            WindowFuncType::CumeDist => "cume_dist".to_string(),
+            WindowFuncType::Ntile(_) => "ntile".to_string(),
+            WindowFuncType::LagLead(lag_lead) if lag_lead.is_lag => "lag".to_string(),
+            WindowFuncType::LagLead(_) => "lead".to_string(),
+            WindowFuncType::NthValue(nth) => match nth.n {
+                Some(1) => "first_value".to_string(),
+                Some(_) => "nth_value".to_string(),
+                None => "last_value".to_string(),
+            },
         }
     }
 
@@ -253,6 +291,14 @@ impl WindowFuncType {
             WindowFuncType::Aggregate(agg) => {
                 agg.args.iter().flat_map(|arg| arg.used_columns()).collect()
             }
+            WindowFuncType::LagLead(lag_lead) => {
+                let mut used_columns = lag_lead.arg.used_columns();
+                if let Some(default) = &lag_lead.default {
+                    used_columns.extend(default.used_columns());
+                }
+                used_columns
+            }
+            WindowFuncType::NthValue(nth) => nth.arg.used_columns(),
             _ => ColumnSet::new(),
         }
     }
@@ -260,9 +306,15 @@ impl WindowFuncType {
     pub fn return_type(&self) -> DataType {
         match self {
             WindowFuncType::Aggregate(agg) => *agg.return_type.clone(),
-            WindowFuncType::RowNumber | WindowFuncType::Rank | WindowFuncType::DenseRank => {
-                DataType::Number(NumberDataType::UInt64)
+            WindowFuncType::RowNumber
+            | WindowFuncType::Rank
+            | WindowFuncType::DenseRank
+            | WindowFuncType::Ntile(_) => DataType::Number(NumberDataType::UInt64),
+            WindowFuncType::PercentRank | WindowFuncType::CumeDist => {
+                DataType::Number(NumberDataType::Float64)
             }
+            WindowFuncType::LagLead(lag_lead) => *lag_lead.return_type.clone(),
+            WindowFuncType::NthValue(nth) => *nth.return_type.clone(),
         }
     }
 }
@@ -32,6 +32,7 @@ impl VisitorMut for DistinctToGroupBy {
             selection,
             group_by,
             having,
+            qualify,
             ..
         } = stmt;
 
@@ -63,6 +64,7 @@ impl VisitorMut for DistinctToGroupBy {
                             selection: selection.clone(),
                             group_by: Some(GroupBy::Normal(args.clone())),
                             having: None,
+                            qualify: None,
                         })),
                         order_by: vec![],
                         limit: vec![],
@@ -96,6 +98,7 @@ impl VisitorMut for DistinctToGroupBy {
                         selection: None,
                         group_by: None,
                         having: having.clone(),
+                        qualify: qualify.clone(),
                     };
 
                     *stmt = new_stmt;
@@ -79,7 +79,10 @@ use crate::plans::ComparisonExpr;
 use crate::plans::ComparisonOp;
 use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LagLeadFunction;
 use crate::plans::NotExpr;
+use crate::plans::NthValueFunction;
+use crate::plans::NtileFunction;
 use crate::plans::OrExpr;
 use crate::plans::ScalarExpr;
 use crate::plans::SubqueryExpr;
@@ -666,14 +669,11 @@ impl<'a> TypeChecker<'a> {
                             "window function {name} can only be used in window clause"
                         )));
                     }
-                    if !args.is_empty() {
-                        return Err(ErrorCode::SemanticError(format!(
-                            "window function {name} does not have any argument"
-                        )));
-                    }
                     let window = window.as_ref().unwrap();
                     let display_name = format!("{:#}", expr);
-                    let func = WindowFuncType::from_name(&name)?;
+                    let func = self
+                        .resolve_general_window_function(*span, &name, &args, window.ignore_nulls)
+                        .await?;
                     self.resolve_window(*span, display_name, window, func)
                         .await?
                 } else if AggregateFunctionFactory::instance().contains(&name) {
@@ -806,12 +806,7 @@ impl<'a> TypeChecker<'a> {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr).await?,
 
-            Expr::Interval { span, .. } => {
-                return Err(ErrorCode::SemanticError(
-                    "Unsupported interval expression yet".to_string(),
-                )
-                .set_span(*span));
-            }
+            Expr::Interval { span, expr, unit } => self.resolve_interval(*span, expr, unit).await?,
             Expr::DateAdd {
                 span,
                 unit,
@@ -916,7 +911,61 @@ impl<'a> TypeChecker<'a> {
                 nulls_first: o.nulls_first,
             })
         }
+        if window.ignore_nulls.is_some()
+            && !matches!(
+                func,
+                WindowFuncType::LagLead(_) | WindowFuncType::NthValue(_)
+            )
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "IGNORE NULLS and RESPECT NULLS are not supported by window function {}",
+                func.func_name()
+            ))
+            .set_span(span));
+        }
         let frame = Self::check_frame_bound(span, window.window_frame.clone())?;
+        let frame = match &func {
+            // The frame of `lag` and `lead` is decided by the offset, the user specified one is ignored.
+            WindowFuncType::LagLead(lag_lead) => {
+                let (start_bound, end_bound) = match (lag_lead.is_lag, lag_lead.ignore_nulls) {
+                    _ if lag_lead.offset == 0 => (
+                        WindowFuncFrameBound::CurrentRow,
+                        WindowFuncFrameBound::CurrentRow,
+                    ),
+                    (true, false) => (
+                        WindowFuncFrameBound::Preceding(Some(lag_lead.offset as usize)),
+                        WindowFuncFrameBound::Preceding(Some(lag_lead.offset as usize)),
+                    ),
+                    (false, false) => (
+                        WindowFuncFrameBound::Following(Some(lag_lead.offset as usize)),
+                        WindowFuncFrameBound::Following(Some(lag_lead.offset as usize)),
+                    ),
+                    // Nulls are skipped, so all the preceding (or following) rows may be needed.
+                    (true, true) => (
+                        WindowFuncFrameBound::Preceding(None),
+                        WindowFuncFrameBound::Preceding(Some(1)),
+                    ),
+                    (false, true) => (
+                        WindowFuncFrameBound::Following(Some(1)),
+                        WindowFuncFrameBound::Following(None),
+                    ),
+                };
+                WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound,
+                    end_bound,
+                }
+            }
+            // These functions need the whole partition.
+            WindowFuncType::Ntile(_) | WindowFuncType::PercentRank | WindowFuncType::CumeDist => {
+                WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound: WindowFuncFrameBound::Preceding(None),
+                    end_bound: WindowFuncFrameBound::Following(None),
+                }
+            }
+            _ => frame,
+        };
         let data_type = func.return_type();
         let window_func = WindowFunc {
             display_name,
@@ -928,6 +977,134 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((window_func.into(), data_type)))
     }
 
+    /// Resolve the arguments of general window functions, such as `lag(a, 1)`, `ntile(4)`.
+    async fn resolve_general_window_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[&Expr],
+        ignore_nulls: Option<bool>,
+    ) -> Result<WindowFuncType> {
+        let ignore_nulls = ignore_nulls.unwrap_or(false);
+        match func_name {
+            "lag" | "lead" => {
+                if args.is_empty() || args.len() > 3 {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "window function {func_name} takes 1 to 3 arguments, but got {}",
+                        args.len()
+                    ))
+                    .set_span(span));
+                }
+                let box (arg, arg_type) = self.resolve(args[0]).await?;
+                let offset = match args.get(1) {
+                    Some(offset) => Self::resolve_window_function_constant(func_name, offset)?,
+                    None => 1,
+                };
+                // Return NULL if the offset row is out of the partition and no default value.
+                let return_type = if arg_type.is_nullable_or_null() {
+                    arg_type
+                } else {
+                    arg_type.wrap_nullable()
+                };
+                let default = match args.get(2) {
+                    Some(default) => {
+                        let box (default, default_type) = self.resolve(default).await?;
+                        if default_type != return_type {
+                            Some(Box::new(wrap_cast(&default, &return_type)))
+                        } else {
+                            Some(Box::new(default))
+                        }
+                    }
+                    None => None,
+                };
+                Ok(WindowFuncType::LagLead(LagLeadFunction {
+                    is_lag: func_name == "lag",
+                    arg: Box::new(arg),
+                    offset,
+                    default,
+                    ignore_nulls,
+                    return_type: Box::new(return_type),
+                }))
+            }
+            "first_value" | "last_value" | "nth_value" => {
+                let num_args = if func_name == "nth_value" { 2 } else { 1 };
+                if args.len() != num_args {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "window function {func_name} takes {num_args} argument(s), but got {}",
+                        args.len()
+                    ))
+                    .set_span(span));
+                }
+                let box (arg, arg_type) = self.resolve(args[0]).await?;
+                let n = match func_name {
+                    "first_value" => Some(1),
+                    "last_value" => None,
+                    _ => {
+                        let n = Self::resolve_window_function_constant(func_name, args[1])?;
+                        if n == 0 {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "the second argument of window function {func_name} must be greater than 0"
+                            ))
+                            .set_span(args[1].span()));
+                        }
+                        Some(n)
+                    }
+                };
+                let return_type = if arg_type.is_nullable_or_null() {
+                    arg_type
+                } else {
+                    arg_type.wrap_nullable()
+                };
+                Ok(WindowFuncType::NthValue(NthValueFunction {
+                    n,
+                    arg: Box::new(arg),
+                    ignore_nulls,
+                    return_type: Box::new(return_type),
+                }))
+            }
+            "ntile" => {
+                if args.len() != 1 {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "window function {func_name} takes 1 argument, but got {}",
+                        args.len()
+                    ))
+                    .set_span(span));
+                }
+                let n = Self::resolve_window_function_constant(func_name, args[0])?;
+                if n == 0 {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "the argument of window function {func_name} must be greater than 0"
+                    ))
+                    .set_span(args[0].span()));
+                }
+                Ok(WindowFuncType::Ntile(NtileFunction { n }))
+            }
+            _ => {
+                if !args.is_empty() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "window function {func_name} does not have any argument"
+                    ))
+                    .set_span(span));
+                }
+                WindowFuncType::from_name(func_name)
+            }
+        }
+    }
+
+    // The offset of `lag`/`lead`, the n of `nth_value` and `ntile` must be integer constants.
+    fn resolve_window_function_constant(func_name: &str, expr: &Expr) -> Result<u64> {
+        match expr {
+            Expr::Literal {
+                lit: Literal::UInt64(value),
+                ..
+            } => Ok(*value),
+            _ => Err(ErrorCode::SemanticError(format!(
+                "the argument {expr} of window function {func_name} must be a non-negative integer constant"
+            ))
+            .set_span(expr.span())),
+        }
+    }
+
     // just support integer
     #[inline]
     fn resolve_window_frame(expr: &Expr) -> Option<usize> {
@@ -1399,6 +1576,36 @@ impl<'a> TypeChecker<'a> {
             .await
     }
 
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    pub async fn resolve_interval(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        unit: &ASTIntervalKind,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let func_name = match unit {
+            ASTIntervalKind::Day => "to_interval_days",
+            ASTIntervalKind::Hour => "to_interval_hours",
+            ASTIntervalKind::Minute => "to_interval_minutes",
+            ASTIntervalKind::Second => "to_interval_seconds",
+            ASTIntervalKind::Year | ASTIntervalKind::Quarter | ASTIntervalKind::Month => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "INTERVAL ... {unit} is not supported because its length varies, use DATE_ADD({unit}, ...) instead"
+                ))
+                .set_span(span));
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Unsupported interval unit {unit}, only these units are currently supported: [day, hour, minute, second]"
+                ))
+                .set_span(span));
+            }
+        };
+        self.resolve_function(span, func_name, vec![], &[expr])
+            .await
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub async fn resolve_date_trunc(
@@ -1627,6 +1834,39 @@ impl<'a> TypeChecker<'a> {
                     .await,
                 )
             }
+            ("score", &[]) => {
+                // Rewrite score() to the sum of match_score(column, query)
+                // of the `match` predicates in the WHERE clause.
+                let score = self
+                    .bind_context
+                    .match_predicates
+                    .iter()
+                    .map(|(column, query)| Expr::FunctionCall {
+                        span,
+                        distinct: false,
+                        name: Identifier {
+                            name: "match_score".to_string(),
+                            quote: None,
+                            span,
+                        },
+                        args: vec![column.clone(), query.clone()],
+                        params: vec![],
+                        window: None,
+                    })
+                    .reduce(|left, right| Expr::BinaryOp {
+                        span,
+                        op: BinaryOperator::Plus,
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    });
+                match score {
+                    Some(score) => Some(self.resolve(&score).await),
+                    None => Some(Err(ErrorCode::SemanticError(
+                        "score() requires a match(column, query) predicate in the WHERE clause",
+                    )
+                    .set_span(span))),
+                }
+            }
             ("nullif", &[arg_x, arg_y]) => {
                 // Rewrite nullif(x, y) to if(x = y, null, x)
                 Some(
@@ -2571,6 +2811,7 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
         TypeName::String => TableDataType::String,
         TypeName::Timestamp => TableDataType::Timestamp,
         TypeName::Date => TableDataType::Date,
+        TypeName::Interval => TableDataType::Interval,
         TypeName::Array(item_type) => TableDataType::Array(Box::new(resolve_type_name(item_type)?)),
         TypeName::Map { key_type, val_type } => {
             let key_type = resolve_type_name(key_type)?;
@@ -2615,6 +2856,8 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
             TableDataType::Nullable(Box::new(resolve_type_name(inner_type)?))
         }
         TypeName::Variant => TableDataType::Variant,
+        TypeName::Bitmap => TableDataType::Bitmap,
+        TypeName::Binary => TableDataType::Binary,
     };
 
     Ok(data_type)
@@ -33,6 +33,7 @@ use crate::caches::BloomIndexFilterCache;
 use crate::caches::BloomIndexMetaCache;
 use crate::caches::ColumnArrayCache;
 use crate::caches::FileMetaDataCache;
+use crate::caches::InvertedIndexCache;
 use crate::caches::SegmentInfoCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
@@ -48,6 +49,7 @@ pub struct CacheManager {
     segment_info_cache: Option<SegmentInfoCache>,
     bloom_index_filter_cache: Option<BloomIndexFilterCache>,
     bloom_index_meta_cache: Option<BloomIndexMetaCache>,
+    inverted_index_cache: Option<InvertedIndexCache>,
     prune_partitions_cache: Option<PrunePartitionsCache>,
     file_meta_data_cache: Option<FileMetaDataCache>,
     table_data_cache: Option<TableDataCache>,
@@ -88,6 +90,7 @@ impl CacheManager {
                 segment_info_cache: None,
                 bloom_index_filter_cache: None,
                 bloom_index_meta_cache: None,
+                inverted_index_cache: None,
                 prune_partitions_cache: None,
                 file_meta_data_cache: None,
                 table_statistic_cache: None,
@@ -107,6 +110,9 @@ impl CacheManager {
                 config.table_bloom_index_meta_count,
                 "bloom_index_file_meta_data",
             );
+            // one inverted index per segment
+            let inverted_index_cache =
+                Self::new_item_cache(config.table_meta_segment_count, "inverted_index");
             let prune_partitions_cache =
                 Self::new_item_cache(config.table_prune_partitions_count, "prune_partitions");
 
@@ -117,6 +123,7 @@ impl CacheManager {
                 segment_info_cache,
                 bloom_index_filter_cache,
                 bloom_index_meta_cache,
+                inverted_index_cache,
                 prune_partitions_cache,
                 file_meta_data_cache,
                 table_statistic_cache,
@@ -152,6 +159,10 @@ impl CacheManager {
         self.bloom_index_meta_cache.clone()
     }
 
+    pub fn get_inverted_index_cache(&self) -> Option<InvertedIndexCache> {
+        self.inverted_index_cache.clone()
+    }
+
     pub fn get_prune_partitions_cache(&self) -> Option<PrunePartitionsCache> {
         self.prune_partitions_cache.clone()
     }
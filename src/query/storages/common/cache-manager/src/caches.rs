@@ -25,6 +25,7 @@ use storages_common_cache::InMemoryItemCacheHolder;
 use storages_common_cache::NamedCache;
 use storages_common_index::filters::Xor8Filter;
 use storages_common_index::BloomIndexMeta;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
@@ -42,6 +43,8 @@ pub type TableSnapshotStatisticCache = NamedCache<InMemoryItemCacheHolder<TableS
 pub type BloomIndexFilterCache = NamedCache<InMemoryItemCacheHolder<Xor8Filter>>;
 /// In memory object cache of parquet FileMetaData of bloom index data
 pub type BloomIndexMetaCache = NamedCache<InMemoryItemCacheHolder<BloomIndexMeta>>;
+/// In memory object cache of the inverted index of segments
+pub type InvertedIndexCache = NamedCache<InMemoryItemCacheHolder<InvertedIndex>>;
 /// In memory object cache of parquet FileMetaData of external parquet files
 pub type FileMetaDataCache = NamedCache<InMemoryItemCacheHolder<FileMetaData>>;
 
@@ -94,6 +97,13 @@ impl CachedObject<BloomIndexMeta> for BloomIndexMeta {
     }
 }
 
+impl CachedObject<InvertedIndex> for InvertedIndex {
+    type Cache = InvertedIndexCache;
+    fn cache() -> Option<Self::Cache> {
+        CacheManager::instance().get_inverted_index_cache()
+    }
+}
+
 impl CachedObject<(PartStatistics, Partitions)> for (PartStatistics, Partitions) {
     type Cache = PrunePartitionsCache;
     fn cache() -> Option<Self::Cache> {
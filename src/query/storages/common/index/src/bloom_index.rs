@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -28,10 +29,13 @@ use common_expression::types::MapType;
 use common_expression::types::NullableType;
 use common_expression::types::Number;
 use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::types::StringType;
 use common_expression::types::UInt64Type;
 use common_expression::types::ValueType;
 use common_expression::BlockEntry;
 use common_expression::Column;
+use common_expression::ColumnId;
 use common_expression::ConstantFolder;
 use common_expression::DataBlock;
 use common_expression::Expr;
@@ -48,6 +52,7 @@ use storages_common_table_meta::meta::SingleColumnMeta;
 use storages_common_table_meta::meta::Versioned;
 
 use crate::filters::BlockBloomFilterIndexVersion;
+use crate::filters::BlockFilter;
 use crate::filters::Filter;
 use crate::filters::FilterBuilder;
 use crate::filters::V2BloomBlock;
@@ -116,10 +121,14 @@ impl TryFrom<ThriftFileMetaData> for BloomIndexMeta {
 /// ```
 /// We will create table of filters as follows:
 /// ```
-///         +---Bloom(name)--+--Bloom(age)--+
-///         |  123456789abcd |  ac2345bcd   |
-///         +----------------+--------------+
+///         +---Bloom(name)--+--Bloom(age)--+--Ngram(name)--+
+///         |  123456789abcd |  ac2345bcd   |  3a5c7e9b1d2f |
+///         +----------------+--------------+---------------+
 /// ```
+///
+/// String columns listed in the `ngram_index_columns` table option additionally get
+/// a `Ngram` filter, which holds every distinct 3-byte window of the values, so that substring predicates like
+/// `LIKE '%timeout%'` or `position('timeout' IN msg) > 0` can be pruned as well.
 pub struct BloomIndex {
     pub func_ctx: FunctionContext,
 
@@ -140,6 +149,13 @@ pub struct BloomIndex {
     pub column_distinct_count: HashMap<FieldIndex, usize>,
 }
 
+/// Number of bytes of each gram kept by the n-gram filter.
+const NGRAM_SIZE: usize = 3;
+
+/// The n-gram filter of a column is skipped if the block contains more distinct grams than this,
+/// as such filter would be large while rarely able to prune anything.
+const NGRAM_MAX_DISTINCT_KEYS: usize = 1_000_000;
+
 /// FilterExprEvalResult represents the evaluation result of an expression by a filter.
 ///
 /// For example, expression of 'age = 12' should return false is the filter are sure
@@ -180,6 +196,7 @@ impl BloomIndex {
         source_schema: TableSchemaRef,
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
+        ngram_column_ids: &[ColumnId],
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -244,11 +261,11 @@ impl BloomIndex {
         let mut filter_fields = vec![];
         let mut filters = vec![];
         let mut column_distinct_count = HashMap::<usize, usize>::new();
-        for (field, (column, data_type)) in fields.into_iter().zip(columns.iter()) {
+        for (field, (source_column, data_type)) in fields.into_iter().zip(columns.iter()) {
             let (column, validity) = if data_type.is_nullable() {
                 let col = Self::calculate_column_digest(
                     func_ctx,
-                    column,
+                    source_column,
                     data_type,
                     &DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64))),
                 )?;
@@ -258,7 +275,7 @@ impl BloomIndex {
             } else {
                 let col = Self::calculate_column_digest(
                     func_ctx,
-                    column,
+                    source_column,
                     data_type,
                     &DataType::Number(NumberDataType::UInt64),
                 )?;
@@ -294,6 +311,18 @@ impl BloomIndex {
             let filter_name = Self::build_filter_column_name(version, field)?;
             filter_fields.push(TableField::new(&filter_name, TableDataType::String));
             filters.push(Arc::new(filter));
+
+            // n-gram filters are only available since the filter columns are named by column id.
+            if version == BlockFilter::VERSION
+                && !matches!(field.data_type(), TableDataType::Map(_))
+                && ngram_column_ids.contains(&field.column_id())
+            {
+                if let Some(ngram_filter) = Self::build_ngram_filter(source_column, data_type)? {
+                    let ngram_name = Self::build_ngram_column_name(field);
+                    filter_fields.push(TableField::new(&ngram_name, TableDataType::String));
+                    filters.push(Arc::new(ngram_filter));
+                }
+            }
         }
 
         let filter_schema = Arc::new(TableSchema::new(filter_fields));
@@ -349,6 +378,10 @@ impl BloomIndex {
             },
         )?;
 
+        visit_expr_column_contains_constant(&mut expr, &mut |col_name, needles| {
+            Ok(self.find_ngrams(col_name, needles)? == FilterEvalResult::MustFalse)
+        })?;
+
         let (new_expr, _) = ConstantFolder::fold(&expr, self.func_ctx, &BUILTIN_FUNCTIONS);
 
         match new_expr {
@@ -410,6 +443,22 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all string columns that are searched for a constant substring in the expression,
+    /// e.g. `col LIKE '%<constant>%'` or `position(<constant> IN col)`.
+    pub fn find_ngram_columns(expr: &Expr<String>) -> Result<Vec<String>> {
+        let mut cols = Vec::new();
+        visit_expr_column_contains_constant(&mut expr.clone(), &mut |col_name, _| {
+            cols.push(col_name.to_string());
+            Ok(false)
+        })?;
+        Ok(cols)
+    }
+
+    /// The n-gram filter of a string column will be stored with field name 'Ngram(column_id)'
+    pub fn build_ngram_column_name(field: &TableField) -> String {
+        format!("Ngram({})", field.column_id())
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -464,6 +513,60 @@ impl BloomIndex {
             Ok(FilterEvalResult::MustFalse)
         }
     }
+
+    fn find_ngrams(&self, column_name: &str, needles: &[Vec<u8>]) -> Result<FilterEvalResult> {
+        let filter_column =
+            &Self::build_ngram_column_name(self.source_schema.field_with_name(column_name)?);
+
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a n-gram filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+
+        // Every gram of the searched substrings must show up in the block.
+        let missing = needles
+            .iter()
+            .flat_map(|needle| needle.windows(NGRAM_SIZE))
+            .any(|gram| !filter.contains(gram));
+
+        if missing {
+            Ok(FilterEvalResult::MustFalse)
+        } else {
+            Ok(FilterEvalResult::Uncertain)
+        }
+    }
+
+    fn build_ngram_filter(column: &Column, data_type: &DataType) -> Result<Option<Xor8Filter>> {
+        if data_type.remove_nullable() != DataType::String {
+            return Ok(None);
+        }
+        // Values masked by the validity are still indexed, which only leads to false positives.
+        let column = match column {
+            Column::Nullable(box nullable_column) => &nullable_column.column,
+            _ => column,
+        };
+        let column = StringType::try_downcast_column(column).unwrap();
+
+        let mut grams = HashSet::new();
+        for value in column.iter() {
+            grams.extend(value.windows(NGRAM_SIZE));
+            if grams.len() > NGRAM_MAX_DISTINCT_KEYS {
+                return Ok(None);
+            }
+        }
+        if grams.is_empty() {
+            return Ok(None);
+        }
+
+        let mut filter_builder = Xor8Builder::create();
+        for gram in grams {
+            filter_builder.add_key(&gram);
+        }
+        Ok(Some(filter_builder.build()?))
+    }
 }
 
 fn visit_expr_column_eq_constant(
@@ -557,6 +660,100 @@ fn visit_expr_column_eq_constant(
     Ok(())
 }
 
+/// Find patterns like `Column LIKE <constant>`, `position(<constant> IN Column)`,
+/// `locate(<constant>, Column)` or `instr(Column, <constant>)`, and pass the literal substrings
+/// that a matching value must contain to the visitor.
+///
+/// If the visitor returns true, the substrings are known to be absent and the predicate
+/// is rewritten to the result of a failed search.
+fn visit_expr_column_contains_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(&str, &[Vec<u8>]) -> Result<bool>,
+) -> Result<()> {
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let search = match (function.signature.name.as_str(), args.as_slice()) {
+            ("like", [column, Expr::Constant { scalar, .. }]) => {
+                Some((column, scalar, Scalar::Boolean(false)))
+            }
+            ("instr", [column, Expr::Constant { scalar, .. }])
+            | ("position" | "locate", [Expr::Constant { scalar, .. }, column, ..]) => {
+                Some((column, scalar, Scalar::Number(NumberScalar::UInt64(0))))
+            }
+            _ => None,
+        };
+
+        if let Some((Expr::ColumnRef { id, data_type, .. }, Scalar::String(needle), not_found)) =
+            search
+        {
+            if data_type.remove_nullable() == DataType::String {
+                let needles = if function.signature.name == "like" {
+                    like_pattern_literals(needle)
+                } else {
+                    vec![needle.clone()]
+                };
+                let needles = needles
+                    .into_iter()
+                    .filter(|needle| needle.len() >= NGRAM_SIZE)
+                    .collect::<Vec<_>>();
+                if !needles.is_empty() && visitor(id, &needles)? {
+                    *expr = Expr::Constant {
+                        span: *span,
+                        scalar: not_found,
+                        data_type: return_type.clone(),
+                    };
+                }
+                return Ok(());
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_contains_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_contains_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Split a LIKE pattern into the literal substrings between its wildcards, with escapes resolved.
+///
+/// For example, `'%conn_ction\_%timeout'` gives `["conn", "ction_", "timeout"]`.
+fn like_pattern_literals(pattern: &[u8]) -> Vec<Vec<u8>> {
+    let mut literals = vec![];
+    let mut current = vec![];
+    let mut iter = pattern.iter();
+    while let Some(c) = iter.next() {
+        match c {
+            b'%' | b'_' => {
+                if !current.is_empty() {
+                    literals.push(std::mem::take(&mut current));
+                }
+            }
+            b'\\' => current.push(*iter.next().unwrap_or(&b'\\')),
+            _ => current.push(*c),
+        }
+    }
+    if !current.is_empty() {
+        literals.push(current);
+    }
+    literals
+}
+
 fn visit_map_column(
     span: Span,
     args: &[Expr<String>],
@@ -0,0 +1,187 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::ArrayColumn;
+use common_expression::types::ArrayType;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt32Type;
+use common_expression::types::ValueType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_functions::scalars::tokenize;
+
+/// Inverted index of one segment.
+///
+/// For every indexed column, it maps each term to the positions of the blocks
+/// (in the order of `SegmentInfo::blocks`) that contain it.
+///
+/// It is stored as a parquet file of one `(column_id, term, blocks)` row per
+/// term, see [`InvertedIndex::schema`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InvertedIndex {
+    pub columns: BTreeMap<ColumnId, BTreeMap<String, Vec<u32>>>,
+}
+
+impl InvertedIndex {
+    pub const VERSION: u64 = 1;
+
+    /// Schema of the data block the index is serialized to.
+    pub fn schema() -> TableSchemaRef {
+        Arc::new(TableSchema::new(vec![
+            TableField::new("column_id", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new("term", TableDataType::String),
+            TableField::new(
+                "blocks",
+                TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::UInt32))),
+            ),
+        ]))
+    }
+
+    /// Serialize the index to a data block of [`InvertedIndex::schema`].
+    ///
+    /// Columns without any term are omitted.
+    pub fn to_data_block(&self) -> DataBlock {
+        let mut column_ids = vec![];
+        let mut terms = vec![];
+        let mut blocks = vec![];
+        let mut offsets = vec![0u64];
+        for (column_id, column_terms) in &self.columns {
+            for (term, term_blocks) in column_terms {
+                column_ids.push(*column_id);
+                terms.push(term.as_str());
+                blocks.extend_from_slice(term_blocks);
+                offsets.push(blocks.len() as u64);
+            }
+        }
+
+        let num_rows = column_ids.len();
+        let blocks = Column::Array(Box::new(ArrayColumn {
+            values: UInt32Type::from_data(blocks),
+            offsets: offsets.into(),
+        }));
+        DataBlock::new(
+            vec![
+                BlockEntry {
+                    data_type: DataType::Number(NumberDataType::UInt32),
+                    value: Value::Column(UInt32Type::from_data(column_ids)),
+                },
+                BlockEntry {
+                    data_type: DataType::String,
+                    value: Value::Column(StringType::from_data(terms)),
+                },
+                BlockEntry {
+                    data_type: DataType::Array(Box::new(DataType::Number(NumberDataType::UInt32))),
+                    value: Value::Column(blocks),
+                },
+            ],
+            num_rows,
+        )
+    }
+
+    /// Deserialize the index from a data block of [`InvertedIndex::schema`].
+    pub fn from_data_block(block: &DataBlock) -> Result<Self> {
+        let invalid = || ErrorCode::StorageOther("invalid inverted index data block");
+        let entry_column = |offset: usize| {
+            block
+                .columns()
+                .get(offset)
+                .and_then(|entry| entry.value.as_column())
+                .ok_or_else(invalid)
+        };
+        let column_ids = UInt32Type::try_downcast_column(entry_column(0)?).ok_or_else(invalid)?;
+        let terms = StringType::try_downcast_column(entry_column(1)?).ok_or_else(invalid)?;
+        let blocks =
+            ArrayType::<UInt32Type>::try_downcast_column(entry_column(2)?).ok_or_else(invalid)?;
+
+        let mut index = InvertedIndex::default();
+        for (row, column_id) in column_ids.iter().enumerate() {
+            let term = String::from_utf8_lossy(terms.index(row).ok_or_else(invalid)?);
+            let term_blocks = blocks.index(row).ok_or_else(invalid)?;
+            index
+                .columns
+                .entry(*column_id)
+                .or_default()
+                .insert(term.into_owned(), term_blocks.to_vec());
+        }
+        Ok(index)
+    }
+
+    /// Add the terms of a string column of the block at `block_idx`.
+    ///
+    /// Blocks must be added in ascending order of `block_idx`.
+    pub fn add_block_column(&mut self, column_id: ColumnId, block_idx: u32, column: &Column) {
+        let terms = self.columns.entry(column_id).or_default();
+        let mut block_terms = BTreeSet::new();
+        match column.remove_nullable() {
+            Column::String(strings) => {
+                for value in strings.iter() {
+                    block_terms.extend(tokenize(value));
+                }
+            }
+            _ => return,
+        }
+
+        for term in block_terms {
+            let blocks = terms.entry(term).or_default();
+            if blocks.last() != Some(&block_idx) {
+                blocks.push(block_idx);
+            }
+        }
+    }
+
+    /// Positions of the blocks which may contain all the `query` terms in the column.
+    ///
+    /// Returns `None` if the column is not indexed, or the query has no term.
+    pub fn find_blocks(&self, column_id: ColumnId, query: &[u8]) -> Option<BTreeSet<u32>> {
+        let terms = self.columns.get(&column_id)?;
+        let query_terms = tokenize(query);
+        if query_terms.is_empty() {
+            return None;
+        }
+
+        let mut blocks: Option<BTreeSet<u32>> = None;
+        for term in query_terms {
+            let term_blocks = terms
+                .get(&term)
+                .map(|blocks| blocks.iter().copied().collect::<BTreeSet<_>>())
+                .unwrap_or_default();
+            blocks = Some(match blocks {
+                None => term_blocks,
+                Some(blocks) => blocks.intersection(&term_blocks).copied().collect(),
+            });
+        }
+        blocks
+    }
+
+    /// Whether the data type can be indexed.
+    pub fn supported_type(data_type: &DataType) -> bool {
+        matches!(data_type.remove_nullable(), DataType::String)
+    }
+}
@@ -111,6 +111,7 @@ fn test_bloom_filter() -> Result<()> {
         schema,
         LatestBloom::VERSION,
         &blocks_ref,
+        &[],
     )?
     .unwrap();
 
@@ -193,6 +194,151 @@ fn test_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ngram_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "0",
+        TableDataType::String,
+    )]));
+
+    let blocks = vec![DataBlock::new_from_columns(vec![StringType::from_data(
+        vec!["connection timeout", "disk full"],
+    )])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        schema,
+        LatestBloom::VERSION,
+        &blocks_ref,
+        &[0],
+    )?
+    .unwrap();
+
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_like_index(&index, "0", b"%timeout%")
+    );
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_like_index(&index, "0", b"disk%")
+    );
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_like_index(&index, "0", b"%conn_ction time%")
+    );
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_like_index(&index, "0", b"%refused%")
+    );
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_like_index(&index, "0", b"%connection%refused%")
+    );
+    // too short to be checked against the n-gram filter
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_like_index(&index, "0", b"%xy%")
+    );
+
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_position_index(&index, "0", b"full", "gt")
+    );
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_position_index(&index, "0", b"refused", "gt")
+    );
+    // `position(..) = 0` keeps the blocks that do not contain the substring
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_position_index(&index, "0", b"refused", "eq")
+    );
+
+    // columns not listed in the `ngram_index_columns` option get no n-gram filter
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        index.source_schema.clone(),
+        LatestBloom::VERSION,
+        &blocks_ref,
+        &[],
+    )?
+    .unwrap();
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_like_index(&index, "0", b"%refused%")
+    );
+    Ok(())
+}
+
+fn eval_like_index(index: &BloomIndex, col_name: &str, pattern: &[u8]) -> FilterEvalResult {
+    let expr = check_function(
+        None,
+        "like",
+        &[],
+        &[
+            Expr::ColumnRef {
+                span: None,
+                id: col_name.to_string(),
+                data_type: DataType::String,
+                display_name: col_name.to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(pattern.to_vec()),
+                data_type: DataType::String,
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    assert!(!BloomIndex::find_ngram_columns(&expr).unwrap().is_empty());
+    index.apply(expr, &HashMap::new()).unwrap()
+}
+
+fn eval_position_index(
+    index: &BloomIndex,
+    col_name: &str,
+    substr: &[u8],
+    cmp: &str,
+) -> FilterEvalResult {
+    let position_expr = check_function(
+        None,
+        "position",
+        &[],
+        &[
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(substr.to_vec()),
+                data_type: DataType::String,
+            },
+            Expr::ColumnRef {
+                span: None,
+                id: col_name.to_string(),
+                data_type: DataType::String,
+                display_name: col_name.to_string(),
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+    let expr = check_function(
+        None,
+        cmp,
+        &[],
+        &[position_expr, Expr::Constant {
+            span: None,
+            scalar: Scalar::Number(NumberScalar::UInt64(0)),
+            data_type: DataType::Number(NumberDataType::UInt64),
+        }],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    index.apply(expr, &HashMap::new()).unwrap()
+}
+
 fn eval_index(index: &BloomIndex, col_name: &str, val: Scalar, ty: DataType) -> FilterEvalResult {
     let expr = check_function(
         None,
@@ -0,0 +1,75 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::UInt8Type;
+use common_expression::FromData;
+use storages_common_index::InvertedIndex;
+
+#[test]
+fn test_inverted_index() {
+    let mut index = InvertedIndex::default();
+    index.add_block_column(
+        1,
+        0,
+        &StringType::from_data(vec!["Red apple", "green pear"]),
+    );
+    index.add_block_column(
+        1,
+        1,
+        &StringType::from_data(vec!["red-wine, green tea", "Apple pie"]),
+    );
+    index.add_block_column(1, 2, &StringType::from_data(vec!["coffee"]));
+    // non-string columns are ignored
+    index.add_block_column(2, 0, &UInt8Type::from_data(vec![1, 2]));
+
+    let blocks = |query: &str| index.find_blocks(1, query.as_bytes());
+
+    assert_eq!(blocks("apple"), Some(BTreeSet::from([0, 1])));
+    assert_eq!(blocks("RED Apple"), Some(BTreeSet::from([0, 1])));
+    assert_eq!(blocks("green tea"), Some(BTreeSet::from([1])));
+    assert_eq!(blocks("pear tea"), Some(BTreeSet::new()));
+    assert_eq!(blocks("juice"), Some(BTreeSet::new()));
+    // a query without any term can not be answered by the index
+    assert_eq!(blocks(" , "), None);
+    // unknown column
+    assert_eq!(index.find_blocks(3, b"apple"), None);
+    assert_eq!(
+        index.columns.get(&2).map(|terms| terms.is_empty()),
+        Some(true)
+    );
+}
+
+#[test]
+fn test_inverted_index_data_block() -> Result<()> {
+    let mut index = InvertedIndex::default();
+    index.add_block_column(1, 0, &StringType::from_data(vec!["Red apple"]));
+    index.add_block_column(1, 1, &StringType::from_data(vec!["apple pie"]));
+    index.add_block_column(3, 1, &StringType::from_data(vec!["green tea"]));
+
+    let block = index.to_data_block();
+    assert_eq!(block.num_rows(), 5);
+    assert_eq!(block.num_columns(), InvertedIndex::schema().fields().len());
+    assert_eq!(InvertedIndex::from_data_block(&block)?, index);
+
+    let empty = InvertedIndex::default();
+    assert_eq!(
+        InvertedIndex::from_data_block(&empty.to_data_block())?,
+        empty
+    );
+    Ok(())
+}
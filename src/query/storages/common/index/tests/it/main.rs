@@ -15,3 +15,4 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod filters;
+mod inverted_index;
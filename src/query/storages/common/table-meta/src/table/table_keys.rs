@@ -17,9 +17,13 @@ use std::collections::HashSet;
 use once_cell::sync::Lazy;
 
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
+/// Identity of the user who created the table, the storage of the table is charged to the user
+pub const OPT_KEY_CREATOR: &str = "creator";
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
 pub const OPT_KEY_STORAGE_FORMAT: &str = "storage_format";
 pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
+/// Comma separated names of the string columns that get n-gram bloom filters
+pub const OPT_KEY_NGRAM_INDEX_COLUMNS: &str = "ngram_index_columns";
 
 /// Legacy table snapshot location key
 ///
@@ -37,6 +41,7 @@ pub const OPT_KEY_LEGACY_SNAPSHOT_LOC: &str = "snapshot_loc";
 pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_CREATOR);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r
 });
@@ -46,6 +51,7 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_CREATOR);
     r
 });
 
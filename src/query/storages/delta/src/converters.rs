@@ -0,0 +1,470 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! this module is used for converting delta schemas, partition values and file statistics
+//! to databend
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use serde::Deserialize;
+use serde_json::Value;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+#[derive(Deserialize, Debug)]
+struct DeltaStructType {
+    fields: Vec<DeltaStructField>,
+}
+
+#[derive(Deserialize, Debug)]
+struct DeltaStructField {
+    name: String,
+    #[serde(rename = "type")]
+    data_type: DeltaDataType,
+    nullable: bool,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum DeltaDataType {
+    Primitive(String),
+    Complex(Box<DeltaComplexType>),
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum DeltaComplexType {
+    Struct {
+        fields: Vec<DeltaStructField>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Array {
+        element_type: DeltaDataType,
+        contains_null: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    Map {
+        key_type: DeltaDataType,
+        value_type: DeltaDataType,
+        value_contains_null: bool,
+    },
+}
+
+/// generate databend TableSchema from the `schemaString` of delta table metadata.
+///
+/// The partition columns, which are not stored in the data files, are placed after
+/// the other columns, in the order of `partition_columns`.
+pub(crate) fn schema_delta_to_databend(
+    schema_string: &str,
+    partition_columns: &[String],
+) -> Result<TableSchema> {
+    let schema: DeltaStructType = serde_json::from_str(schema_string).map_err(|e| {
+        ErrorCode::ReadTableDataError(format!("invalid schema of delta table: {e}"))
+    })?;
+    let to_field = |f: &DeltaStructField| {
+        let ty = type_delta_to_databend(&f.data_type, f.nullable)?;
+        Ok(TableField::new(&f.name.to_lowercase(), ty))
+    };
+
+    let mut fields = schema
+        .fields
+        .iter()
+        .filter(|f| !partition_columns.contains(&f.name))
+        .map(to_field)
+        .collect::<Result<Vec<_>>>()?;
+    for name in partition_columns {
+        let field = schema
+            .fields
+            .iter()
+            .find(|f| &f.name == name)
+            .ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!(
+                    "invalid schema of delta table: partition column {name} not found"
+                ))
+            })?;
+        fields.push(to_field(field)?);
+    }
+    Ok(TableSchema::new(fields))
+}
+
+/// Convert the partition values of a data file in the delta log to the values of
+/// the partition columns, which are the last fields of `schema`.
+pub(crate) fn partition_values_delta_to_databend(
+    schema: &TableSchema,
+    partition_columns: &[String],
+    values: &BTreeMap<String, Option<String>>,
+) -> Result<Vec<Scalar>> {
+    let fields = &schema.fields()[schema.num_fields() - partition_columns.len()..];
+    partition_columns
+        .iter()
+        .zip(fields)
+        .map(|(name, field)| match values.get(name) {
+            Some(Some(value)) => {
+                let data_type = field.data_type().remove_nullable();
+                partition_value_to_scalar(value, &data_type).ok_or_else(|| {
+                    ErrorCode::ReadTableDataError(format!(
+                        "invalid value {value} of partition column {name} with type {data_type}"
+                    ))
+                })
+            }
+            // null partition value
+            _ => Ok(Scalar::Null),
+        })
+        .collect()
+}
+
+fn partition_value_to_scalar(value: &str, data_type: &TableDataType) -> Option<Scalar> {
+    let scalar = match data_type {
+        TableDataType::Number(ty) => {
+            let number = match ty {
+                NumberDataType::UInt8 => NumberScalar::UInt8(value.parse().ok()?),
+                NumberDataType::UInt16 => NumberScalar::UInt16(value.parse().ok()?),
+                NumberDataType::UInt32 => NumberScalar::UInt32(value.parse().ok()?),
+                NumberDataType::UInt64 => NumberScalar::UInt64(value.parse().ok()?),
+                NumberDataType::Int8 => NumberScalar::Int8(value.parse().ok()?),
+                NumberDataType::Int16 => NumberScalar::Int16(value.parse().ok()?),
+                NumberDataType::Int32 => NumberScalar::Int32(value.parse().ok()?),
+                NumberDataType::Int64 => NumberScalar::Int64(value.parse().ok()?),
+                NumberDataType::Float32 => {
+                    NumberScalar::Float32(F32::from(value.parse::<f32>().ok()?))
+                }
+                NumberDataType::Float64 => {
+                    NumberScalar::Float64(F64::from(value.parse::<f64>().ok()?))
+                }
+            };
+            Scalar::Number(number)
+        }
+        TableDataType::String => Scalar::String(value.as_bytes().to_vec()),
+        TableDataType::Boolean => Scalar::Boolean(value.parse().ok()?),
+        TableDataType::Date => {
+            let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+            Scalar::Date((date - epoch).num_days().try_into().ok()?)
+        }
+        TableDataType::Timestamp => {
+            // older writers use the format of spark, newer ones use ISO 8601
+            let ts = match NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f") {
+                Ok(ts) => ts.timestamp_micros(),
+                Err(_) => DateTime::parse_from_rfc3339(value).ok()?.timestamp_micros(),
+            };
+            Scalar::Timestamp(ts)
+        }
+        _ => return None,
+    };
+    Some(scalar)
+}
+
+fn type_delta_to_databend(ty: &DeltaDataType, nullable: bool) -> Result<TableDataType> {
+    let ty = match ty {
+        DeltaDataType::Primitive(name) => match name.as_str() {
+            "string" | "binary" => TableDataType::String,
+            "long" => TableDataType::Number(NumberDataType::Int64),
+            "integer" => TableDataType::Number(NumberDataType::Int32),
+            "short" => TableDataType::Number(NumberDataType::Int16),
+            "byte" => TableDataType::Number(NumberDataType::Int8),
+            "float" => TableDataType::Number(NumberDataType::Float32),
+            "double" => TableDataType::Number(NumberDataType::Float64),
+            "boolean" => TableDataType::Boolean,
+            "date" => TableDataType::Date,
+            "timestamp" | "timestamp_ntz" => TableDataType::Timestamp,
+            _ => {
+                let size = name
+                    .strip_prefix("decimal(")
+                    .and_then(|s| s.strip_suffix(')'))
+                    .and_then(|s| s.split_once(','))
+                    .and_then(|(precision, scale)| {
+                        Some(DecimalSize {
+                            precision: precision.trim().parse().ok()?,
+                            scale: scale.trim().parse().ok()?,
+                        })
+                    })
+                    .ok_or_else(|| {
+                        ErrorCode::Unimplemented(format!("unsupported delta data type: {name}"))
+                    })?;
+                TableDataType::Decimal(DecimalDataType::from_size(size)?)
+            }
+        },
+        DeltaDataType::Complex(complex) => match complex.as_ref() {
+            DeltaComplexType::Struct { fields } => {
+                let (fields_name, fields_type) = fields
+                    .iter()
+                    .map(|f| {
+                        let ty = type_delta_to_databend(&f.data_type, f.nullable)?;
+                        Ok((f.name.to_lowercase(), ty))
+                    })
+                    .collect::<Result<Vec<_>>>()?
+                    .into_iter()
+                    .unzip();
+                TableDataType::Tuple {
+                    fields_name,
+                    fields_type,
+                }
+            }
+            DeltaComplexType::Array {
+                element_type,
+                contains_null,
+            } => TableDataType::Array(Box::new(type_delta_to_databend(
+                element_type,
+                *contains_null,
+            )?)),
+            DeltaComplexType::Map {
+                key_type,
+                value_type,
+                value_contains_null,
+            } => TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![
+                    type_delta_to_databend(key_type, false)?,
+                    type_delta_to_databend(value_type, *value_contains_null)?,
+                ],
+            })),
+        },
+    };
+    if nullable {
+        Ok(ty.wrap_nullable())
+    } else {
+        Ok(ty)
+    }
+}
+
+/// Statistics of a data file recorded in the delta log.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct FileStats {
+    num_records: u64,
+    #[serde(default)]
+    min_values: HashMap<String, Value>,
+    #[serde(default)]
+    max_values: HashMap<String, Value>,
+    #[serde(default)]
+    null_count: HashMap<String, Value>,
+}
+
+/// Convert the statistics of a data file in the delta log to [`StatisticsOfColumns`]
+/// of the top level columns in `schema`, for range pruning.
+///
+/// Columns without usable statistics are omitted, which keeps the file.
+pub(crate) fn stats_delta_to_databend(
+    schema: &TableSchema,
+    stats: &str,
+) -> Result<StatisticsOfColumns> {
+    let stats: FileStats = serde_json::from_str(stats).map_err(|e| {
+        ErrorCode::ReadTableDataError(format!("invalid statistics in delta log: {e}"))
+    })?;
+
+    let mut result = StatisticsOfColumns::new();
+    for field in schema.fields() {
+        let data_type = field.data_type().remove_nullable();
+        if matches!(
+            data_type,
+            TableDataType::Tuple { .. } | TableDataType::Array(_) | TableDataType::Map(_)
+        ) {
+            continue;
+        }
+        // the names in the log are case sensitive, while the column names are lowercase
+        let get = |values: &'_ HashMap<String, Value>| -> Option<Value> {
+            values
+                .iter()
+                .find(|(name, _)| name.to_lowercase() == *field.name())
+                .map(|(_, v)| v.clone())
+        };
+
+        let null_count = get(&stats.null_count)
+            .and_then(|v| v.as_u64())
+            .unwrap_or(stats.num_records);
+        let min = get(&stats.min_values).and_then(|v| json_to_scalar(&v, &data_type, false));
+        let max = get(&stats.max_values).and_then(|v| json_to_scalar(&v, &data_type, true));
+        let (min, max) = match (min, max) {
+            (Some(min), Some(max)) => (min, max),
+            // all the values are null
+            _ if stats.num_records > 0 && null_count == stats.num_records => {
+                (Scalar::Null, Scalar::Null)
+            }
+            _ => continue,
+        };
+
+        result.insert(field.column_id(), ColumnStatistics {
+            min,
+            max,
+            null_count,
+            in_memory_size: 0,
+            distinct_of_values: None,
+        });
+    }
+    Ok(result)
+}
+
+/// Delta writers truncate long strings in the statistics
+const STRING_STATS_PREFIX_LENGTH: usize = 32;
+
+fn json_to_scalar(value: &Value, data_type: &TableDataType, is_max: bool) -> Option<Scalar> {
+    let scalar = match data_type {
+        TableDataType::Number(ty) => {
+            let number = match ty {
+                NumberDataType::UInt8 => NumberScalar::UInt8(value.as_u64()?.try_into().ok()?),
+                NumberDataType::UInt16 => NumberScalar::UInt16(value.as_u64()?.try_into().ok()?),
+                NumberDataType::UInt32 => NumberScalar::UInt32(value.as_u64()?.try_into().ok()?),
+                NumberDataType::UInt64 => NumberScalar::UInt64(value.as_u64()?),
+                NumberDataType::Int8 => NumberScalar::Int8(value.as_i64()?.try_into().ok()?),
+                NumberDataType::Int16 => NumberScalar::Int16(value.as_i64()?.try_into().ok()?),
+                NumberDataType::Int32 => NumberScalar::Int32(value.as_i64()?.try_into().ok()?),
+                NumberDataType::Int64 => NumberScalar::Int64(value.as_i64()?),
+                NumberDataType::Float32 => NumberScalar::Float32(F32::from(value.as_f64()? as f32)),
+                NumberDataType::Float64 => NumberScalar::Float64(F64::from(value.as_f64()?)),
+            };
+            Scalar::Number(number)
+        }
+        TableDataType::String => {
+            let s = value.as_str()?;
+            // a truncated max value is not an upper bound
+            if is_max && s.chars().count() >= STRING_STATS_PREFIX_LENGTH {
+                return None;
+            }
+            Scalar::String(s.as_bytes().to_vec())
+        }
+        TableDataType::Date => {
+            let date = NaiveDate::parse_from_str(value.as_str()?, "%Y-%m-%d").ok()?;
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+            Scalar::Date((date - epoch).num_days().try_into().ok()?)
+        }
+        TableDataType::Timestamp => {
+            let ts = DateTime::parse_from_rfc3339(value.as_str()?)
+                .ok()?
+                .timestamp_micros();
+            // the statistics are truncated to milliseconds
+            Scalar::Timestamp(if is_max { ts + 999 } else { ts })
+        }
+        _ => return None,
+    };
+    Some(scalar)
+}
+
+#[cfg(test)]
+mod convert_test {
+    use std::collections::BTreeMap;
+
+    use common_expression::types::number::NumberScalar;
+    use common_expression::types::NumberDataType;
+    use common_expression::Scalar;
+    use common_expression::TableDataType;
+
+    use super::partition_values_delta_to_databend;
+    use super::schema_delta_to_databend;
+    use super::stats_delta_to_databend;
+
+    const SCHEMA: &str = r#"{"type":"struct","fields":[
+        {"name":"Id","type":"long","nullable":false,"metadata":{}},
+        {"name":"name","type":"string","nullable":true,"metadata":{}},
+        {"name":"tags","type":{"type":"array","elementType":"string","containsNull":true},"nullable":true,"metadata":{}},
+        {"name":"day","type":"date","nullable":true,"metadata":{}},
+        {"name":"part","type":"integer","nullable":true,"metadata":{}}
+    ]}"#;
+
+    #[test]
+    fn test_schema_delta_to_databend() {
+        let schema = schema_delta_to_databend(SCHEMA, &["part".to_string()]).unwrap();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["id", "name", "tags", "day", "part"]);
+        assert_eq!(
+            schema.fields()[0].data_type(),
+            &TableDataType::Number(NumberDataType::Int64)
+        );
+        assert_eq!(
+            schema.fields()[2].data_type(),
+            &TableDataType::Nullable(Box::new(TableDataType::Array(Box::new(
+                TableDataType::Nullable(Box::new(TableDataType::String))
+            ))))
+        );
+
+        assert!(
+            schema_delta_to_databend(
+                r#"{"type":"struct","fields":[{"name":"a","type":"void","nullable":true}]}"#,
+                &[]
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_partition_values_delta_to_databend() {
+        let partition_columns = vec!["day".to_string(), "part".to_string()];
+        let schema = schema_delta_to_databend(SCHEMA, &partition_columns).unwrap();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["id", "name", "tags", "day", "part"]);
+
+        let values = BTreeMap::from([
+            ("day".to_string(), Some("2023-01-02".to_string())),
+            ("part".to_string(), None),
+        ]);
+        let scalars =
+            partition_values_delta_to_databend(&schema, &partition_columns, &values).unwrap();
+        assert_eq!(scalars, vec![Scalar::Date(19359), Scalar::Null]);
+
+        let values = BTreeMap::from([("part".to_string(), Some("x".to_string()))]);
+        assert!(partition_values_delta_to_databend(&schema, &partition_columns, &values).is_err());
+
+        // partition column not in the schema
+        assert!(schema_delta_to_databend(SCHEMA, &["other".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_stats_delta_to_databend() {
+        let schema = schema_delta_to_databend(SCHEMA, &[]).unwrap();
+        let stats = r#"{"numRecords":3,
+            "minValues":{"Id":1,"name":"a","day":"2023-01-01"},
+            "maxValues":{"Id":10,"name":"a very long string which is truncated","day":"2023-01-02"},
+            "nullCount":{"Id":0,"name":1,"tags":0,"day":0,"part":3}}"#;
+        let stats = stats_delta_to_databend(&schema, stats).unwrap();
+
+        let id = &stats[&schema.fields()[0].column_id()];
+        assert_eq!(id.min, Scalar::Number(NumberScalar::Int64(1)));
+        assert_eq!(id.max, Scalar::Number(NumberScalar::Int64(10)));
+        assert_eq!(id.null_count, 0);
+        // truncated max value
+        assert!(!stats.contains_key(&schema.fields()[1].column_id()));
+        // nested column
+        assert!(!stats.contains_key(&schema.fields()[2].column_id()));
+        let day = &stats[&schema.fields()[3].column_id()];
+        assert_eq!(day.min, Scalar::Date(19358));
+        assert_eq!(day.max, Scalar::Date(19359));
+        // all null
+        let part = &stats[&schema.fields()[4].column_id()];
+        assert_eq!(part.min, Scalar::Null);
+        assert_eq!(part.null_count, 3);
+    }
+}
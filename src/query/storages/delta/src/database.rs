@@ -0,0 +1,123 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Wrapping of the parent directory containing delta tables
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_catalog::database::Database;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseIdent;
+use common_meta_app::schema::DatabaseInfo;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_storage::DataOperator;
+use futures::TryStreamExt;
+use opendal::Metakey;
+
+use crate::log::DELTA_LOG_DIR;
+use crate::table::DeltaTable;
+
+#[derive(Clone, Debug)]
+pub struct DeltaDatabase {
+    /// catalog this database belongs to
+    ctl_name: String,
+    /// operator pointing to the directory holding delta tables
+    db_root: DataOperator,
+    /// database infomations
+    info: DatabaseInfo,
+}
+
+impl DeltaDatabase {
+    /// create a new database, but from reading
+    pub fn create_database_from_read(ctl_name: &str, db_name: &str, db_root: DataOperator) -> Self {
+        let info = DatabaseInfo {
+            ident: DatabaseIdent { db_id: 0, seq: 0 },
+            name_ident: DatabaseNameIdent {
+                db_name: db_name.to_string(),
+                ..Default::default()
+            },
+            meta: DatabaseMeta {
+                engine: "delta".to_string(),
+                created_on: chrono::Utc::now(),
+                updated_on: chrono::Utc::now(),
+                ..Default::default()
+            },
+        };
+        Self {
+            ctl_name: ctl_name.to_string(),
+            db_root,
+            info,
+        }
+    }
+}
+
+#[async_trait]
+impl Database for DeltaDatabase {
+    fn name(&self) -> &str {
+        &self.info.name_ident.db_name
+    }
+
+    fn get_db_info(&self) -> &DatabaseInfo {
+        &self.info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table(&self, table_name: &str) -> Result<Arc<dyn Table>> {
+        let path = format!("{table_name}/");
+        let op = self.db_root.operator();
+        // a delta table must have the transaction log
+        if !op.is_exist(&format!("{path}{DELTA_LOG_DIR}")).await? {
+            return Err(ErrorCode::UnknownTable(format!(
+                "table {table_name} does not exist or is not a valid delta table"
+            )));
+        }
+
+        let table_sp = self.db_root.params().map_root(|r| format!("{r}{path}"));
+        let tbl_root = DataOperator::try_create(&table_sp).await?;
+
+        let tbl = DeltaTable::try_create_table_from_read(
+            &self.ctl_name,
+            &self.info.name_ident.db_name,
+            table_name,
+            tbl_root,
+        )
+        .await?;
+        Ok(Arc::new(tbl) as Arc<dyn Table>)
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tables(&self) -> Result<Vec<Arc<dyn Table>>> {
+        let mut tables = vec![];
+        let op = self.db_root.operator();
+        let mut lister = op.list("/").await?;
+        while let Some(entry) = lister.try_next().await? {
+            let meta = op.metadata(&entry, Metakey::Mode).await?;
+            if !meta.is_dir() {
+                continue;
+            }
+            let tbl_name = entry.name().trim_end_matches('/');
+            // skip the directories which are not delta tables
+            match self.get_table(tbl_name).await {
+                Ok(table) => tables.push(table),
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(tables)
+    }
+}
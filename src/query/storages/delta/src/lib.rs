@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This is the Delta Lake catalog support for databend.
+//!
+//! Like the Iceberg catalog, databases are the subdirectories of the catalog root,
+//! and tables are the subdirectories of databases containing a `_delta_log` directory.
+//!
+//! For example, accessing a delta catalog on `s3://bkt/path/to/delta`
+//! with following file tree:
+//! ```text
+//! /path/to/delta/
+//! ┝-- /path/to/delta/db0/
+//! |   ┝-- /path/to/delta/db0/tbl0/_delta_log/
+//! |   └-- /path/to/delta/db0/tbl1/_delta_log/
+//! └-- /path/to/delta/db1/    <- empty directory
+//! ```
+//!
+//! with the following SQL:
+//!
+//! ```sql
+//! CREATE CATALOG delta_ctl TYPE=DELTA CONNECTION=( URL='s3://bkt/path/to/delta' ... )
+//! ```
+//!
+//! Users should query tables with:
+//! ```sql
+//! SELECT * FROM delta_ctl.db0.tbl1;
+//! ```
+//!
+//! # Reading
+//!
+//! The latest snapshot of a table is resolved by replaying the transaction log:
+//! the latest complete checkpoint is loaded first, then the JSON commits after it.
+//! The active data files are read with the parquet table, after being pruned
+//! with the min/max values and null counts recorded in the log, and with the
+//! values of the partition columns.
+//!
+//! The schema of the table is taken from the metadata in the log, the partition
+//! columns, which are not stored in the data files, are placed after the other
+//! columns and filled with the partition values of the files in the log.
+//!
+//! # Limitations
+//!
+//! - Tables are read-only.
+//! - Tables requiring reader features beyond protocol version 1 (column mapping,
+//!   deletion vectors, ...) are not supported.
+
+/// the Delta Lake Catalog implementation
+mod catalog;
+/// data converters
+mod converters;
+/// database implementation
+mod database;
+/// replaying the transaction log
+mod log;
+/// table implementation
+mod table;
+
+pub use catalog::DeltaCatalog;
+pub use catalog::DELTA_CATALOG;
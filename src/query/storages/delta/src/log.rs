@@ -0,0 +1,480 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replaying the transaction log of delta tables.
+//!
+//! The log is stored in the `_delta_log` directory of the table:
+//! - `{version:020}.json`: the actions of a commit, one JSON object per line.
+//! - `{version:020}.checkpoint.parquet`
+//!   or `{version:020}.checkpoint.{part:010}.{parts:010}.parquet`:
+//!   the snapshot of the table at the version, one action per row.
+
+use std::collections::BTreeMap;
+use std::io::Cursor;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ListArray;
+use common_arrow::arrow::array::MapArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::array::Utf8Array;
+use common_arrow::arrow::io::parquet::read as pread;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::TryStreamExt;
+use opendal::Operator;
+use serde::Deserialize;
+
+/// directory of the transaction log, relative to the table root
+pub const DELTA_LOG_DIR: &str = "_delta_log/";
+
+/// the highest reader protocol version we are able to read
+const SUPPORTED_READER_VERSION: i32 = 1;
+
+/// a data file added to the table
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddFile {
+    /// uri-encoded path relative to the table root
+    pub path: String,
+    /// values of the partition columns by the column names, null values are `None`
+    #[serde(default)]
+    pub partition_values: BTreeMap<String, Option<String>>,
+    pub size: i64,
+    /// milliseconds since epoch
+    pub modification_time: i64,
+    /// statistics of the file in JSON
+    #[serde(default)]
+    pub stats: Option<String>,
+}
+
+/// a data file removed from the table
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RemoveFile {
+    pub path: String,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    /// schema of the table in JSON
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Protocol {
+    pub min_reader_version: i32,
+}
+
+/// One line of a commit, other actions (`commitInfo`, `txn`, ...) are ignored.
+#[derive(Deserialize, Default, Debug)]
+struct Action {
+    add: Option<AddFile>,
+    remove: Option<RemoveFile>,
+    #[serde(rename = "metaData")]
+    metadata: Option<Metadata>,
+    protocol: Option<Protocol>,
+}
+
+/// State of a delta table at a version.
+#[derive(Clone, Debug, Default)]
+pub struct DeltaSnapshot {
+    /// -1 if no commit is applied
+    pub version: i64,
+    metadata: Option<Metadata>,
+    /// active files by path
+    files: BTreeMap<String, AddFile>,
+}
+
+impl DeltaSnapshot {
+    /// Load the latest snapshot of the table.
+    #[async_backtrace::framed]
+    pub async fn try_load(tbl_root: &Operator) -> Result<DeltaSnapshot> {
+        let (checkpoint, commits) = list_log_files(tbl_root).await?;
+
+        let mut snapshot = DeltaSnapshot {
+            version: -1,
+            ..Default::default()
+        };
+        if let Some((version, parts)) = checkpoint {
+            for part in parts {
+                let data = tbl_root.read(&part).await?;
+                snapshot.apply_checkpoint(data)?;
+            }
+            snapshot.version = version;
+        }
+
+        for (version, path) in commits {
+            if version <= snapshot.version {
+                continue;
+            }
+            if version != snapshot.version + 1 {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "invalid delta log, commit of version {} is missing",
+                    snapshot.version + 1
+                )));
+            }
+            let data = tbl_root.read(&path).await?;
+            snapshot.apply_commit(version, &data)?;
+        }
+
+        // make sure the table is valid
+        snapshot.metadata()?;
+        Ok(snapshot)
+    }
+
+    /// Apply the actions of the commit of `version`.
+    pub fn apply_commit(&mut self, version: i64, data: &[u8]) -> Result<()> {
+        for line in data.split(|c| *c == b'\n') {
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            let action: Action = serde_json::from_slice(line).map_err(|e| {
+                ErrorCode::ReadTableDataError(format!(
+                    "invalid action in delta log of version {version}: {e}"
+                ))
+            })?;
+            self.apply(action)?;
+        }
+        self.version = version;
+        Ok(())
+    }
+
+    /// Apply the actions of a checkpoint file (or one part of it).
+    pub fn apply_checkpoint(&mut self, data: Vec<u8>) -> Result<()> {
+        let mut reader = Cursor::new(data);
+        let meta = pread::read_metadata(&mut reader)?;
+        let schema = pread::infer_schema(&meta)?;
+        let chunks =
+            pread::FileReader::new(reader, meta.row_groups, schema.clone(), None, None, None);
+
+        for chunk in chunks {
+            let chunk = chunk?;
+            let column = |name: &str| {
+                schema
+                    .fields
+                    .iter()
+                    .position(|f| f.name == name)
+                    .and_then(|i| chunk.arrays()[i].as_any().downcast_ref::<StructArray>())
+            };
+            let (add, remove, metadata, protocol) = (
+                column("add"),
+                column("remove"),
+                column("metaData"),
+                column("protocol"),
+            );
+
+            for row in 0..chunk.len() {
+                let action = Action {
+                    add: add.and_then(|add| read_add(add, row)),
+                    remove: remove.and_then(|remove| {
+                        valid(remove, row)?;
+                        Some(RemoveFile {
+                            path: utf8_value(remove, "path", row)?,
+                        })
+                    }),
+                    metadata: metadata.and_then(|metadata| read_metadata(metadata, row)),
+                    protocol: protocol.and_then(|protocol| {
+                        valid(protocol, row)?;
+                        Some(Protocol {
+                            min_reader_version: i32_value(protocol, "minReaderVersion", row)?,
+                        })
+                    }),
+                };
+                self.apply(action)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply(&mut self, action: Action) -> Result<()> {
+        if let Some(protocol) = action.protocol {
+            if protocol.min_reader_version > SUPPORTED_READER_VERSION {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "delta table of reader version {} is not supported",
+                    protocol.min_reader_version
+                )));
+            }
+        }
+        if let Some(metadata) = action.metadata {
+            self.metadata = Some(metadata);
+        }
+        if let Some(remove) = action.remove {
+            self.files.remove(&remove.path);
+        }
+        if let Some(add) = action.add {
+            self.files.insert(add.path.clone(), add);
+        }
+        Ok(())
+    }
+
+    pub fn metadata(&self) -> Result<&Metadata> {
+        self.metadata.as_ref().ok_or_else(|| {
+            ErrorCode::ReadTableDataError("invalid delta log, metadata of the table is missing")
+        })
+    }
+
+    /// Active data files of the table.
+    pub fn files(&self) -> impl Iterator<Item = &AddFile> {
+        self.files.values()
+    }
+}
+
+/// The latest complete checkpoint (version and parts) and all the commits
+/// (version and path) in the log, sorted by version.
+#[allow(clippy::type_complexity)]
+#[async_backtrace::framed]
+async fn list_log_files(
+    tbl_root: &Operator,
+) -> Result<(Option<(i64, Vec<String>)>, Vec<(i64, String)>)> {
+    let mut commits = vec![];
+    // version -> (number of parts, paths of the parts)
+    let mut checkpoints: BTreeMap<i64, (usize, Vec<String>)> = BTreeMap::new();
+
+    let mut lister = tbl_root.list(DELTA_LOG_DIR).await?;
+    while let Some(entry) = lister.try_next().await? {
+        match parse_log_file_name(entry.name()) {
+            Some(LogFile::Commit(version)) => commits.push((version, entry.path().to_string())),
+            Some(LogFile::Checkpoint(version, parts)) => {
+                checkpoints
+                    .entry(version)
+                    .or_insert_with(|| (parts, vec![]))
+                    .1
+                    .push(entry.path().to_string());
+            }
+            None => {}
+        }
+    }
+    commits.sort();
+
+    let checkpoint = checkpoints
+        .into_iter()
+        .rev()
+        .find(|(_, (parts, paths))| *parts == paths.len())
+        .map(|(version, (_, mut paths))| {
+            paths.sort();
+            (version, paths)
+        });
+    Ok((checkpoint, commits))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum LogFile {
+    /// version
+    Commit(i64),
+    /// version, number of parts
+    Checkpoint(i64, usize),
+}
+
+fn parse_log_file_name(name: &str) -> Option<LogFile> {
+    let (version, rest) = name.split_once('.')?;
+    if version.len() != 20 {
+        return None;
+    }
+    let version = version.parse::<i64>().ok()?;
+    match rest {
+        "json" => Some(LogFile::Commit(version)),
+        "checkpoint.parquet" => Some(LogFile::Checkpoint(version, 1)),
+        _ => {
+            let parts = rest
+                .strip_prefix("checkpoint.")?
+                .strip_suffix(".parquet")?
+                .split_once('.')?
+                .1;
+            Some(LogFile::Checkpoint(version, parts.parse().ok()?))
+        }
+    }
+}
+
+fn read_add(add: &StructArray, row: usize) -> Option<AddFile> {
+    valid(add, row)?;
+    Some(AddFile {
+        path: utf8_value(add, "path", row)?,
+        partition_values: map_value(add, "partitionValues", row),
+        size: i64_value(add, "size", row)?,
+        modification_time: i64_value(add, "modificationTime", row).unwrap_or_default(),
+        stats: utf8_value(add, "stats", row),
+    })
+}
+
+fn read_metadata(metadata: &StructArray, row: usize) -> Option<Metadata> {
+    valid(metadata, row)?;
+    let partition_columns = match field(metadata, "partitionColumns")
+        .and_then(|array| array.as_any().downcast_ref::<ListArray<i32>>())
+    {
+        Some(list) if list.is_valid(row) => {
+            let values = list.value(row);
+            match values.as_any().downcast_ref::<Utf8Array<i32>>() {
+                Some(values) => values.values_iter().map(|v| v.to_string()).collect(),
+                None => vec![],
+            }
+        }
+        _ => vec![],
+    };
+    Some(Metadata {
+        schema_string: utf8_value(metadata, "schemaString", row)?,
+        partition_columns,
+    })
+}
+
+fn valid(array: &StructArray, row: usize) -> Option<()> {
+    array.is_valid(row).then_some(())
+}
+
+fn field<'a>(array: &'a StructArray, name: &str) -> Option<&'a dyn Array> {
+    array
+        .fields()
+        .iter()
+        .position(|f| f.name == name)
+        .map(|i| array.values()[i].as_ref())
+}
+
+fn utf8_value(array: &StructArray, name: &str, row: usize) -> Option<String> {
+    let values = field(array, name)?
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()?;
+    values.is_valid(row).then(|| values.value(row).to_string())
+}
+
+fn i64_value(array: &StructArray, name: &str, row: usize) -> Option<i64> {
+    let values = field(array, name)?
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i64>>()?;
+    values.is_valid(row).then(|| values.value(row))
+}
+
+/// The entries of a `map<string, string>`, empty if the map is null.
+fn map_value(array: &StructArray, name: &str, row: usize) -> BTreeMap<String, Option<String>> {
+    let map = match field(array, name).and_then(|array| array.as_any().downcast_ref::<MapArray>()) {
+        Some(map) if map.is_valid(row) => map.value(row),
+        _ => return BTreeMap::new(),
+    };
+    let entries = match map.as_any().downcast_ref::<StructArray>() {
+        Some(entries) => entries,
+        None => return BTreeMap::new(),
+    };
+    let utf8 = |i: usize| {
+        entries
+            .values()
+            .get(i)
+            .and_then(|array| array.as_any().downcast_ref::<Utf8Array<i32>>())
+    };
+    match (utf8(0), utf8(1)) {
+        (Some(keys), Some(values)) => keys
+            .values_iter()
+            .zip(values.iter())
+            .map(|(key, value)| (key.to_string(), value.map(|v| v.to_string())))
+            .collect(),
+        _ => BTreeMap::new(),
+    }
+}
+
+fn i32_value(array: &StructArray, name: &str, row: usize) -> Option<i32> {
+    let values = field(array, name)?
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i32>>()?;
+    values.is_valid(row).then(|| values.value(row))
+}
+
+#[cfg(test)]
+mod log_test {
+    use super::parse_log_file_name;
+    use super::DeltaSnapshot;
+    use super::LogFile;
+
+    #[test]
+    fn test_parse_log_file_name() {
+        assert_eq!(
+            parse_log_file_name("00000000000000000010.json"),
+            Some(LogFile::Commit(10))
+        );
+        assert_eq!(
+            parse_log_file_name("00000000000000000010.checkpoint.parquet"),
+            Some(LogFile::Checkpoint(10, 1))
+        );
+        assert_eq!(
+            parse_log_file_name("00000000000000000010.checkpoint.0000000001.0000000003.parquet"),
+            Some(LogFile::Checkpoint(10, 3))
+        );
+        assert_eq!(parse_log_file_name("_last_checkpoint"), None);
+        assert_eq!(parse_log_file_name("00000000000000000010.crc"), None);
+        assert_eq!(parse_log_file_name("10.json"), None);
+    }
+
+    #[test]
+    fn test_partition_values() {
+        let commit0 = r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}
+{"metaData":{"id":"b1a4","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"id\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}},{\"name\":\"Year\",\"type\":\"integer\",\"nullable\":true,\"metadata\":{}}]}","partitionColumns":["Year"],"configuration":{},"createdTime":1680000000000}}
+{"add":{"path":"Year=2023/part-00000.parquet","partitionValues":{"Year":"2023"},"size":100,"modificationTime":1680000000000,"dataChange":true}}
+{"add":{"path":"Year=__HIVE_DEFAULT_PARTITION__/part-00001.parquet","partitionValues":{"Year":null},"size":100,"modificationTime":1680000000000,"dataChange":true}}
+"#;
+
+        let mut snapshot = DeltaSnapshot {
+            version: -1,
+            ..Default::default()
+        };
+        snapshot.apply_commit(0, commit0.as_bytes()).unwrap();
+        assert_eq!(snapshot.metadata().unwrap().partition_columns, vec![
+            "Year".to_string()
+        ]);
+        let files = snapshot.files().collect::<Vec<_>>();
+        assert_eq!(files.len(), 2);
+        assert_eq!(
+            files[0].partition_values.get("Year"),
+            Some(&Some("2023".to_string()))
+        );
+        assert_eq!(files[1].partition_values.get("Year"), Some(&None));
+    }
+
+    #[test]
+    fn test_replay_commits() {
+        let commit0 = r#"{"commitInfo":{"timestamp":1680000000000,"operation":"CREATE TABLE"}}
+{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}
+{"metaData":{"id":"b1a4","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"id\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}}]}","partitionColumns":[],"configuration":{},"createdTime":1680000000000}}
+{"add":{"path":"part-00000.parquet","partitionValues":{},"size":100,"modificationTime":1680000000000,"dataChange":true,"stats":"{\"numRecords\":1}"}}
+"#;
+        let commit1 = r#"{"add":{"path":"part-00001.parquet","partitionValues":{},"size":200,"modificationTime":1680000000001,"dataChange":true}}
+{"remove":{"path":"part-00000.parquet","deletionTimestamp":1680000000001,"dataChange":true}}
+"#;
+
+        let mut snapshot = DeltaSnapshot {
+            version: -1,
+            ..Default::default()
+        };
+        assert!(snapshot.metadata().is_err());
+
+        snapshot.apply_commit(0, commit0.as_bytes()).unwrap();
+        assert_eq!(snapshot.version, 0);
+        assert!(snapshot.metadata().unwrap().partition_columns.is_empty());
+        let files = snapshot.files().collect::<Vec<_>>();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, "part-00000.parquet");
+        assert_eq!(files[0].stats.as_deref(), Some(r#"{"numRecords":1}"#));
+
+        snapshot.apply_commit(1, commit1.as_bytes()).unwrap();
+        assert_eq!(snapshot.version, 1);
+        let files = snapshot.files().collect::<Vec<_>>();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, "part-00001.parquet");
+        assert_eq!(files[0].size, 200);
+        assert_eq!(files[0].stats, None);
+
+        // reader features are not supported
+        let commit2 = r#"{"protocol":{"minReaderVersion":3,"minWriterVersion":7}}"#;
+        assert!(snapshot.apply_commit(2, commit2.as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,297 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Note:
+//! the snapshot of the table is loaded when the table is created,
+//! and it is not refreshed afterwards.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::TimeZone;
+use chrono::Utc;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_catalog::plan::DataSourceInfo;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::ParquetTableInfo;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::StageInfo;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::Pipeline;
+use common_storage::read_parquet_schema_async;
+use common_storage::DataOperator;
+use common_storage::StageFileInfo;
+use common_storage::StageFileStatus;
+use common_storage::StageFilesInfo;
+use common_storages_parquet::ParquetTable;
+use percent_encoding::percent_decode_str;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnStatistics;
+
+use crate::converters::partition_values_delta_to_databend;
+use crate::converters::schema_delta_to_databend;
+use crate::converters::stats_delta_to_databend;
+use crate::log::AddFile;
+use crate::log::DeltaSnapshot;
+
+/// accessor wrapper as a table
+///
+/// The active data files are read with the parquet table.
+pub struct DeltaTable {
+    /// table information
+    info: TableInfo,
+    /// snapshot of the table replayed from the log
+    snapshot: DeltaSnapshot,
+    /// parquet table over the table root
+    parquet_info: ParquetTableInfo,
+}
+
+impl DeltaTable {
+    /// create a new table on the table directory
+    #[async_backtrace::framed]
+    pub async fn try_create_table_from_read(
+        catalog: &str,
+        database: &str,
+        table_name: &str,
+        tbl_root: DataOperator,
+    ) -> Result<DeltaTable> {
+        let op = tbl_root.operator();
+        let snapshot = DeltaSnapshot::try_load(&op).await?;
+        let metadata = snapshot.metadata()?;
+        let schema =
+            schema_delta_to_databend(&metadata.schema_string, &metadata.partition_columns)?;
+        let num_data_columns = schema.num_fields() - metadata.partition_columns.len();
+
+        // the columns in the data files are read with their physical types, so the
+        // schema to read is resolved from a data file, with the column names in the log.
+        let data_fields = &schema.fields()[..num_data_columns];
+        let arrow_schema = match snapshot.files().next() {
+            Some(file) => {
+                let path = data_file_info(file)?.path;
+                let file_schema = read_parquet_schema_async(&op, &path).await?;
+                if file_schema.fields.len() != num_data_columns {
+                    return Err(ErrorCode::ReadTableDataError(format!(
+                        "columns of data file {path} do not match the schema of the delta table"
+                    )));
+                }
+                ArrowSchema::from(
+                    file_schema
+                        .fields
+                        .into_iter()
+                        .zip(data_fields)
+                        .map(|(f, field)| ArrowField {
+                            name: field.name().clone(),
+                            ..f
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            }
+            None => ArrowSchema::from(data_fields.iter().map(ArrowField::from).collect::<Vec<_>>()),
+        };
+
+        let mut partition_values = BTreeMap::new();
+        if !metadata.partition_columns.is_empty() {
+            for file in snapshot.files() {
+                let values = partition_values_delta_to_databend(
+                    &schema,
+                    &metadata.partition_columns,
+                    &file.partition_values,
+                )?;
+                partition_values.insert(data_file_info(file)?.path, values);
+            }
+        }
+
+        let sp = tbl_root.params();
+
+        // construct table info
+        let info = TableInfo {
+            ident: TableIdent::new(0, 0),
+            desc: format!("DeltaTable: '{database}'.'{table_name}'"),
+            name: table_name.to_string(),
+            meta: TableMeta {
+                schema: Arc::new(schema),
+                catalog: catalog.to_string(),
+                engine: "delta".to_string(),
+                created_on: Utc::now(),
+                storage_params: Some(sp.clone()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let parquet_info = ParquetTableInfo {
+            read_options: ParquetReadOptions::default(),
+            stage_info: StageInfo::new_external_stage(sp, "/"),
+            files_info: StageFilesInfo {
+                path: "/".to_string(),
+                files: None,
+                pattern: None,
+            },
+            table_info: info.clone(),
+            arrow_schema,
+            files_to_read: None,
+            partition_values,
+        };
+
+        Ok(Self {
+            info,
+            snapshot,
+            parquet_info,
+        })
+    }
+
+    /// Active data files which may match the filter, according to the statistics in the log.
+    fn prune_files(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Vec<StageFileInfo>> {
+        let schema = self.info.schema();
+        let filter = push_downs
+            .as_ref()
+            .and_then(|extra| extra.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+        let pruner =
+            RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, filter.as_ref())?;
+
+        let mut files = vec![];
+        for file in self.snapshot.files() {
+            let info = data_file_info(file)?;
+            // invalid statistics should not prevent reading the file
+            let mut stats = file
+                .stats
+                .as_ref()
+                .and_then(|stats| stats_delta_to_databend(&schema, stats).ok())
+                .unwrap_or_default();
+            // the values of partition columns are the same in the whole file
+            if let Some(values) = self.parquet_info.partition_values.get(&info.path) {
+                let num_data_columns = schema.num_fields() - values.len();
+                for (field, value) in schema.fields()[num_data_columns..].iter().zip(values) {
+                    stats.insert(field.column_id(), ColumnStatistics {
+                        min: value.clone(),
+                        max: value.clone(),
+                        null_count: value.is_null() as u64,
+                        in_memory_size: 0,
+                        distinct_of_values: None,
+                    });
+                }
+            }
+            if !stats.is_empty() && !pruner.should_keep(&stats) {
+                continue;
+            }
+            files.push(info);
+        }
+        Ok(files)
+    }
+}
+
+/// `StageFileInfo` of the data file, relative to the table root.
+fn data_file_info(file: &AddFile) -> Result<StageFileInfo> {
+    let path = percent_decode_str(&file.path)
+        .decode_utf8()
+        .map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("invalid data file path {}: {e}", file.path))
+        })?
+        .to_string();
+    if path.contains("://") {
+        return Err(ErrorCode::Unimplemented(format!(
+            "data file {path} outside of the table directory is not supported"
+        )));
+    }
+
+    Ok(StageFileInfo {
+        path,
+        size: file.size as u64,
+        md5: None,
+        last_modified: Utc
+            .timestamp_millis_opt(file.modification_time)
+            .single()
+            .unwrap_or_default(),
+        etag: None,
+        status: StageFileStatus::NeedCopy,
+        creator: None,
+    })
+}
+
+#[async_trait]
+impl Table for DeltaTable {
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    fn name(&self) -> &str {
+        &self.get_table_info().name
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    fn support_prewhere(&self) -> bool {
+        // the partition columns are not in the data files to filter
+        self.parquet_info.read_options.do_prewhere()
+            && self.parquet_info.partition_values.is_empty()
+    }
+
+    fn get_data_source_info(&self) -> DataSourceInfo {
+        DataSourceInfo::ParquetSource(self.parquet_info.clone())
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let files = self.prune_files(&ctx, &push_downs)?;
+        if files.is_empty() {
+            return Ok((PartStatistics::default(), Partitions::default()));
+        }
+
+        let mut parquet_info = self.parquet_info.clone();
+        parquet_info.files_to_read = Some(files);
+        ParquetTable::from_info(&parquet_info)?
+            .read_partitions(ctx, push_downs)
+            .await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        ParquetTable::from_info(&self.parquet_info)?.read_data(ctx, plan, pipeline)
+    }
+}
@@ -63,6 +63,7 @@ use storages_common_table_meta::table::table_storage_prefix;
 use storages_common_table_meta::table::TableCompression;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
@@ -172,6 +173,16 @@ impl FuseTable {
         &self.meta_location_generator
     }
 
+    /// Ids of the columns which have inverted indexes.
+    pub fn inverted_index_column_ids(&self) -> Vec<ColumnId> {
+        self.table_info
+            .meta
+            .indexes
+            .values()
+            .flat_map(|index| index.column_ids.iter().copied())
+            .collect()
+    }
+
     pub fn get_write_settings(&self) -> WriteSettings {
         let default_rows_per_page = if self.operator.info().can_blocking() {
             DEFAULT_ROW_PER_PAGE_FOR_BLOCKING
@@ -187,9 +198,27 @@ impl FuseTable {
             table_compression: self.table_compression,
             max_page_size,
             block_per_seg,
+            ngram_column_ids: self.ngram_index_column_ids(),
         }
     }
 
+    /// Ids of the columns listed in the `ngram_index_columns` option, columns that have
+    /// been dropped since are ignored.
+    pub fn ngram_index_column_ids(&self) -> Vec<ColumnId> {
+        let schema = self.table_info.schema();
+        self.table_info
+            .options()
+            .get(OPT_KEY_NGRAM_INDEX_COLUMNS)
+            .map(|columns| {
+                columns
+                    .split(',')
+                    .filter_map(|name| schema.field_with_name(name.trim()).ok())
+                    .map(|field| field.column_id())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Get max page size.
     /// For native storage format.
     pub fn get_max_page_size(&self) -> Option<usize> {
@@ -508,7 +537,18 @@ impl Table for FuseTable {
         pipeline: &mut Pipeline,
         on_conflict_fields: Vec<TableField>,
     ) -> Result<()> {
-        self.build_replace_pipeline(ctx, on_conflict_fields, pipeline)
+        self.build_replace_pipeline(ctx, on_conflict_fields, false, pipeline)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn merge_into(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        on_conflict_fields: Vec<TableField>,
+    ) -> Result<()> {
+        self.build_replace_pipeline(ctx, on_conflict_fields, true, pipeline)
             .await
     }
 
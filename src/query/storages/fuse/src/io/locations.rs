@@ -16,6 +16,7 @@ use std::marker::PhantomData;
 
 use common_exception::Result;
 use common_expression::DataBlock;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::SnapshotVersion;
@@ -28,6 +29,7 @@ use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::index::filters::BlockFilter;
+use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
@@ -102,6 +104,22 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    /// The inverted index of a segment is named after the uuid of the segment.
+    pub fn segment_inverted_index_location(&self, segment_location: &str) -> String {
+        let file_name = segment_location
+            .rsplit('/')
+            .next()
+            .unwrap_or(segment_location);
+        let segment_uuid = file_name.split('_').next().unwrap_or(file_name);
+        format!(
+            "{}/{}/{}_v{}.parquet",
+            &self.prefix,
+            FUSE_TBL_INVERTED_INDEX_PREFIX,
+            segment_uuid,
+            InvertedIndex::VERSION,
+        )
+    }
+
     pub fn snapshot_location_from_uuid(&self, id: &Uuid, version: u64) -> Result<String> {
         let snapshot_version = SnapshotVersion::try_from(version)?;
         Ok(snapshot_version.create(id, &self.prefix))
@@ -16,6 +16,7 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::write::to_parquet_schema;
 use common_arrow::parquet::metadata::SchemaDescriptor;
 use common_catalog::plan::Projection;
@@ -43,6 +44,9 @@ pub struct BlockReader {
     pub(crate) projected_schema: TableSchemaRef,
     pub(crate) project_indices: BTreeMap<FieldIndex, (ColumnId, Field, DataType)>,
     pub(crate) project_column_nodes: Vec<ColumnNode>,
+    /// For each projected column, the nodes of its legacy columns (most recent first),
+    /// which are read from blocks written before the data type of the column was modified.
+    pub(crate) legacy_column_nodes: Vec<Vec<(ColumnNode, DataType)>>,
     pub(crate) parquet_schema_descriptor: SchemaDescriptor,
     pub(crate) default_vals: Vec<Scalar>,
     pub query_internal_columns: bool,
@@ -115,7 +119,8 @@ impl BlockReader {
         };
 
         let arrow_schema = schema.to_arrow();
-        let parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
+        let mut parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
+        let mut num_leaves = parquet_schema_descriptor.columns().len();
 
         let column_nodes = ColumnNodes::new_from_schema(&arrow_schema, Some(&schema));
 
@@ -124,7 +129,37 @@ impl BlockReader {
             .iter()
             .map(|c| (*c).clone())
             .collect();
-        let project_indices = Self::build_projection_indices(&project_column_nodes);
+        let mut project_indices = Self::build_projection_indices(&project_column_nodes);
+
+        // The legacy columns are appended to the parquet schema as extra leaves,
+        // so that they can be fetched and deserialized like the other columns.
+        let mut legacy_fields = vec![];
+        let mut legacy_column_nodes = Vec::with_capacity(project_column_nodes.len());
+        for field in projected_schema.fields() {
+            let mut nodes = Vec::with_capacity(field.legacy_columns().len());
+            for (column_id, data_type) in field.legacy_columns() {
+                let legacy_field =
+                    TableField::new_from_column_id(field.name(), data_type.clone(), *column_id);
+                let arrow_field = Field::from(&legacy_field);
+                let mut column_node =
+                    ColumnNode::new(arrow_field.clone(), false, vec![num_leaves], None);
+                column_node.leaf_column_ids = vec![*column_id];
+                let data_type = DataType::from(data_type);
+                project_indices.insert(
+                    num_leaves,
+                    (*column_id, arrow_field.clone(), data_type.clone()),
+                );
+                legacy_fields.push(arrow_field);
+                nodes.push((column_node, data_type));
+                num_leaves += 1;
+            }
+            legacy_column_nodes.push(nodes);
+        }
+        if !legacy_fields.is_empty() {
+            let mut arrow_fields = arrow_schema.fields.clone();
+            arrow_fields.extend(legacy_fields);
+            parquet_schema_descriptor = to_parquet_schema(&ArrowSchema::from(arrow_fields))?;
+        }
 
         Ok(Arc::new(BlockReader {
             operator,
@@ -132,6 +167,7 @@ impl BlockReader {
             projected_schema,
             project_indices,
             project_column_nodes,
+            legacy_column_nodes,
             parquet_schema_descriptor,
             default_vals,
             query_internal_columns,
@@ -27,8 +27,16 @@ use common_arrow::parquet::read::PageReader;
 use common_catalog::plan::PartInfoPtr;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_storage::ColumnNode;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::TableDataCacheKey;
@@ -99,6 +107,7 @@ impl BlockReader {
         let mut need_default_vals = Vec::with_capacity(self.project_column_nodes.len());
         let mut need_to_fill_default_val = false;
         let mut deserialized_column_arrays = Vec::with_capacity(self.projection.len());
+        let mut legacy_column_arrays = vec![];
         let field_deserialization_ctx = FieldDeserializationContext {
             column_metas,
             column_chunks: &column_chunks,
@@ -106,11 +115,21 @@ impl BlockReader {
             compression,
             uncompressed_buffer: &uncompressed_buffer,
         };
-        for column_node in &self.project_column_nodes {
+        for (i, column_node) in self.project_column_nodes.iter().enumerate() {
             match self.deserialize_field(&field_deserialization_ctx, column_node)? {
                 None => {
                     need_to_fill_default_val = true;
                     need_default_vals.push(true);
+                    // The column may be written before its data type was modified,
+                    // the default value is replaced by the cast legacy column below.
+                    for (legacy_column_node, data_type) in &self.legacy_column_nodes[i] {
+                        if let Some(v) =
+                            self.deserialize_field(&field_deserialization_ctx, legacy_column_node)?
+                        {
+                            legacy_column_arrays.push((i, v, data_type));
+                            break;
+                        }
+                    }
                 }
                 Some(v) => {
                     deserialized_column_arrays.push(v);
@@ -157,10 +176,28 @@ impl BlockReader {
             )?
         };
 
+        let data_block = if legacy_column_arrays.is_empty() {
+            data_block
+        } else {
+            let mut columns = data_block.columns().to_vec();
+            for (i, array, legacy_data_type) in &legacy_column_arrays {
+                let array = match array {
+                    DeserializedArray::Deserialized((_, array, ..)) => array.as_ref(),
+                    DeserializedArray::NoNeedToCache(array) => array.as_ref(),
+                    DeserializedArray::Cached(sized_column) => sized_column.0.as_ref(),
+                };
+                let column = Column::from_arrow(array, legacy_data_type);
+                let data_type = columns[*i].data_type.clone();
+                columns[*i] = Self::cast_legacy_column(column, &data_type, num_rows)?;
+            }
+            DataBlock::new(columns, num_rows)
+        };
+
         // populate cache if necessary
         if let Some(cache) = CacheManager::instance().get_table_data_array_cache() {
             // populate array cache items
-            for item in deserialized_column_arrays.into_iter() {
+            let legacy_arrays = legacy_column_arrays.into_iter().map(|(_, v, _)| v);
+            for item in deserialized_column_arrays.into_iter().chain(legacy_arrays) {
                 if let DeserializedArray::Deserialized((column_id, array, size)) = item {
                     let key = TableDataCacheKey::new(block_path, column_id);
                     cache.put(key.into(), Arc::new((array, size)))
@@ -170,6 +207,38 @@ impl BlockReader {
         Ok(data_block)
     }
 
+    /// Cast a column read from a legacy column to the current data type of the field.
+    fn cast_legacy_column(
+        column: Column,
+        data_type: &DataType,
+        num_rows: usize,
+    ) -> Result<BlockEntry> {
+        let legacy_data_type = column.data_type();
+        let block = DataBlock::new(
+            vec![BlockEntry {
+                data_type: legacy_data_type.clone(),
+                value: Value::Column(column),
+            }],
+            num_rows,
+        );
+        let expr = Expr::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(Expr::ColumnRef {
+                span: None,
+                id: 0,
+                data_type: legacy_data_type,
+                display_name: "".to_string(),
+            }),
+            dest_type: data_type.clone(),
+        };
+        let evaluator = Evaluator::new(&block, FunctionContext::default(), &BUILTIN_FUNCTIONS);
+        Ok(BlockEntry {
+            data_type: data_type.clone(),
+            value: evaluator.run(&expr)?,
+        })
+    }
+
     fn chunks_to_parquet_array_iter<'a>(
         metas: Vec<&ColumnMeta>,
         chunks: Vec<&'a [u8]>,
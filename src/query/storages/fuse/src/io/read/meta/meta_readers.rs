@@ -12,11 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io::Cursor;
 use std::io::SeekFrom;
 
+use common_arrow::arrow::io::parquet::read::infer_schema;
+use common_arrow::arrow::io::parquet::read::{self as pread};
 use common_arrow::parquet::metadata::ThriftFileMetaData;
+use common_arrow::parquet::read::read_metadata;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
 use common_expression::TableSchemaRef;
 use futures::AsyncRead;
 use futures::AsyncSeek;
@@ -29,6 +35,7 @@ use storages_common_cache::LoadParams;
 use storages_common_cache::Loader;
 use storages_common_cache_manager::CacheManager;
 use storages_common_index::BloomIndexMeta;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::SegmentInfoVersion;
 use storages_common_table_meta::meta::SnapshotVersion;
@@ -42,6 +49,7 @@ use crate::io::read::meta::meta_readers::thrift_file_meta_read::read_thrift_file
 pub type TableSnapshotStatisticsReader =
     InMemoryItemCacheReader<TableSnapshotStatistics, LoaderWrapper<Operator>>;
 pub type BloomIndexMetaReader = InMemoryItemCacheReader<BloomIndexMeta, LoaderWrapper<Operator>>;
+pub type InvertedIndexReader = InMemoryItemCacheReader<InvertedIndex, LoaderWrapper<Operator>>;
 pub type TableSnapshotReader = InMemoryItemCacheReader<TableSnapshot, LoaderWrapper<Operator>>;
 pub type SegmentInfoReader =
     InMemoryItemCacheReader<SegmentInfo, LoaderWrapper<(Operator, TableSchemaRef)>>;
@@ -76,6 +84,13 @@ impl MetaReaders {
             LoaderWrapper(dal),
         )
     }
+
+    pub fn inverted_index_reader(dal: Operator) -> InvertedIndexReader {
+        InvertedIndexReader::new(
+            CacheManager::instance().get_inverted_index_cache(),
+            LoaderWrapper(dal),
+        )
+    }
 }
 
 // workaround for the orphan rules
@@ -132,6 +147,29 @@ impl Loader<BloomIndexMeta> for LoaderWrapper<Operator> {
     }
 }
 
+#[async_trait::async_trait]
+impl Loader<InvertedIndex> for LoaderWrapper<Operator> {
+    #[async_backtrace::framed]
+    async fn load(&self, params: &LoadParams) -> Result<InvertedIndex> {
+        let data = self.0.read(params.location.as_str()).await?;
+        let mut reader = Cursor::new(data);
+        let meta = read_metadata(&mut reader)?;
+        let arrow_schema = infer_schema(&meta)?;
+        let schema = DataSchema::from(InvertedIndex::schema().as_ref());
+
+        let chunks =
+            pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
+        let blocks = chunks
+            .map(|chunk| DataBlock::from_arrow_chunk(&chunk?, &schema))
+            .collect::<Result<Vec<_>>>()?;
+        if blocks.is_empty() {
+            // the index of a segment without any term
+            return Ok(InvertedIndex::default());
+        }
+        InvertedIndex::from_data_block(&DataBlock::concat(&blocks)?)
+    }
+}
+
 async fn bytes_reader(op: &Operator, path: &str, len_hint: Option<u64>) -> Result<Reader> {
     let reader = if let Some(len) = len_hint {
         op.range_reader(path, 0..len).await?
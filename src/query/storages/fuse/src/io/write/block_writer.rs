@@ -115,6 +115,7 @@ impl BlockBuilder {
             self.source_schema.clone(),
             &data_block,
             bloom_index_location,
+            &self.write_settings.ngram_column_ids,
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::ColumnId;
 use storages_common_table_meta::table::TableCompression;
 
 use crate::FuseStorageFormat;
@@ -26,6 +27,9 @@ pub struct WriteSettings {
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+
+    // ids of the string columns that get n-gram filters in the bloom index
+    pub ngram_column_ids: Vec<ColumnId>,
 }
 
 impl Default for WriteSettings {
@@ -35,6 +39,7 @@ impl Default for WriteSettings {
             table_compression: TableCompression::default(),
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            ngram_column_ids: vec![],
         }
     }
 }
@@ -102,6 +102,7 @@ impl FuseTable {
             })?;
         }
 
+        let inverted_index_column_ids = self.inverted_index_column_ids();
         if need_output {
             pipeline.add_transform(|transform_input_port, transform_output_port| {
                 FuseTableSink::try_create(
@@ -113,6 +114,7 @@ impl FuseTable {
                     cluster_stats_gen.clone(),
                     block_compact_thresholds,
                     self.table_info.schema(),
+                    &inverted_index_column_ids,
                     Some(transform_output_port),
                 )
             })?;
@@ -127,6 +129,7 @@ impl FuseTable {
                     cluster_stats_gen.clone(),
                     block_compact_thresholds,
                     self.table_info.schema(),
+                    &inverted_index_column_ids,
                     None,
                 )
             })?;
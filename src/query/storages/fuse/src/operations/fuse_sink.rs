@@ -71,12 +71,16 @@ impl BloomIndexState {
         source_schema: TableSchemaRef,
         block: &DataBlock,
         location: Location,
+        ngram_column_ids: &[ColumnId],
     ) -> Result<Option<Self>> {
         // write index
-        let maybe_bloom_index =
-            BloomIndex::try_create(ctx.get_function_context()?, source_schema, location.1, &[
-                block,
-            ])?;
+        let maybe_bloom_index = BloomIndex::try_create(
+            ctx.get_function_context()?,
+            source_schema,
+            location.1,
+            &[block],
+            ngram_column_ids,
+        )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
             let filter_schema = bloom_index.filter_schema;
@@ -101,6 +105,17 @@ impl BloomIndexState {
     }
 }
 
+fn serialize_inverted_index(inverted_index: &InvertedIndex) -> Result<Vec<u8>> {
+    let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+    blocks_to_parquet(
+        &InvertedIndex::schema(),
+        vec![inverted_index.to_data_block()],
+        &mut data,
+        TableCompression::None,
+    )?;
+    Ok(data)
+}
+
 enum State {
     None,
     NeedSerialize(DataBlock),
@@ -116,6 +131,8 @@ enum State {
         data: Vec<u8>,
         location: String,
         segment: Arc<SegmentInfo>,
+        // location and data of the inverted index of the segment
+        inverted_index: Option<(String, Vec<u8>)>,
     },
     PreCommitSegment {
         location: String,
@@ -134,6 +151,9 @@ pub struct FuseTableSink {
     cluster_stats_gen: ClusterStatsGenerator,
 
     source_schema: TableSchemaRef,
+    // (offset in the block, column id) of the columns which have inverted indexes
+    inverted_index_fields: Vec<(FieldIndex, ColumnId)>,
+    inverted_index: InvertedIndex,
     write_settings: WriteSettings,
     // A dummy output port for distributed insert select to connect Exchange Sink.
     output: Option<Arc<OutputPort>>,
@@ -150,8 +170,16 @@ impl FuseTableSink {
         cluster_stats_gen: ClusterStatsGenerator,
         thresholds: BlockThresholds,
         source_schema: TableSchemaRef,
+        inverted_index_column_ids: &[ColumnId],
         output: Option<Arc<OutputPort>>,
     ) -> Result<ProcessorPtr> {
+        let inverted_index_fields = source_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| inverted_index_column_ids.contains(&field.column_id()))
+            .map(|(offset, field)| (offset, field.column_id()))
+            .collect();
         Ok(ProcessorPtr::create(Box::new(FuseTableSink {
             ctx,
             input,
@@ -162,6 +190,8 @@ impl FuseTableSink {
             write_settings,
             cluster_stats_gen,
             source_schema,
+            inverted_index_fields,
+            inverted_index: InvertedIndex::default(),
             output,
         })))
     }
@@ -221,12 +251,24 @@ impl Processor for FuseTableSink {
 
                 let (block_location, block_id) = self.meta_locations.gen_block_location();
 
+                // the block is appended to the current segment after being written
+                let block_idx = self.accumulator.summary_block_count as u32;
+                for (offset, column_id) in &self.inverted_index_fields {
+                    let entry = block.get_by_offset(*offset);
+                    let column = entry
+                        .value
+                        .convert_to_full_column(&entry.data_type, block.num_rows());
+                    self.inverted_index
+                        .add_block_column(*column_id, block_idx, &column);
+                }
+
                 let location = self.meta_locations.block_bloom_index_location(&block_id);
                 let bloom_index_state = BloomIndexState::try_create(
                     self.ctx.clone(),
                     self.source_schema.clone(),
                     &block,
                     location,
+                    &self.write_settings.ngram_column_ids,
                 )?;
                 let column_distinct_count = bloom_index_state
                     .as_ref()
@@ -270,10 +312,23 @@ impl Processor for FuseTableSink {
                     col_stats,
                 });
 
+                let location = self.meta_locations.gen_segment_info_location();
+                let inverted_index = std::mem::take(&mut self.inverted_index);
+                let inverted_index = if self.inverted_index_fields.is_empty() {
+                    None
+                } else {
+                    Some((
+                        self.meta_locations
+                            .segment_inverted_index_location(&location),
+                        serialize_inverted_index(&inverted_index)?,
+                    ))
+                };
+
                 self.state = State::SerializedSegment {
                     data: serde_json::to_vec(&segment_info)?,
-                    location: self.meta_locations.gen_segment_info_location(),
+                    location,
                     segment: Arc::new(segment_info),
+                    inverted_index,
                 }
             }
             State::PreCommitSegment { location, segment } => {
@@ -369,7 +424,15 @@ impl Processor for FuseTableSink {
                 data,
                 location,
                 segment,
+                inverted_index,
             } => {
+                // write the inverted index before the segment, a segment is never
+                // visible without its index.
+                if let Some((index_location, index_data)) = inverted_index {
+                    self.data_accessor
+                        .write(&index_location, index_data)
+                        .await?;
+                }
                 self.data_accessor.write(&location, data).await?;
 
                 self.state = State::PreCommitSegment { location, segment };
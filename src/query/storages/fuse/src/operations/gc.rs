@@ -27,6 +27,7 @@ use common_exception::Result;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CachedObject;
 use storages_common_index::BloomIndexMeta;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::SnapshotId;
@@ -279,7 +280,23 @@ impl FuseTable {
                     .await?;
                 }
 
-                // 3. Try to purge segment file chunks.
+                // 3. Try to purge inverted index file chunks, there is one per segment.
+                {
+                    let inverted_index_locations_to_be_purged = chunk
+                        .iter()
+                        .map(|loc| {
+                            self.meta_location_generator
+                                .segment_inverted_index_location(&loc.0)
+                        })
+                        .collect::<HashSet<String>>();
+                    self.try_purge_location_files_and_cache::<InvertedIndex>(
+                        ctx.clone(),
+                        inverted_index_locations_to_be_purged,
+                    )
+                    .await?;
+                }
+
+                // 4. Try to purge segment file chunks.
                 {
                     let segment_locations_to_be_purged = HashSet::from_iter(
                         chunk
@@ -420,6 +437,29 @@ impl FuseTable {
         segment_with_refer_index.into_keys().collect()
     }
 
+    /// Remove the inverted index files of the segments of the current snapshot,
+    /// once the table has no inverted index any more.
+    ///
+    /// The files of the segments which are not referenced by the current snapshot
+    /// are removed along with the segments by `do_purge`.
+    #[async_backtrace::framed]
+    pub async fn purge_inverted_index_files(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+        let locations = snapshot
+            .segments
+            .iter()
+            .map(|loc| {
+                self.meta_location_generator
+                    .segment_inverted_index_location(&loc.0)
+            })
+            .collect();
+        self.try_purge_location_files_and_cache::<InvertedIndex>(ctx, locations)
+            .await
+    }
+
     // Purge file by location chunks.
     #[async_backtrace::framed]
     async fn try_purge_location_files(
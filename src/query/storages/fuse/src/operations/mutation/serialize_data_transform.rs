@@ -180,6 +180,7 @@ impl Processor for SerializeDataTransform {
                     self.schema.clone(),
                     &block,
                     location,
+                    &self.write_settings.ngram_column_ids,
                 )?;
                 let column_distinct_count = bloom_index_state
                     .as_ref()
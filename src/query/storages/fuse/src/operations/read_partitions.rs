@@ -27,6 +27,7 @@ use common_catalog::plan::TopK;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
 use common_storage::ColumnNodes;
@@ -148,6 +149,10 @@ impl FuseTable {
                 cluster_keys,
             )?
         };
+        let pruner = pruner.with_inverted_indexes(
+            self.meta_location_generator().clone(),
+            &self.inverted_index_column_ids(),
+        )?;
         let snapshot_loc = self.snapshot_loc().await?;
         let block_metas = pruner
             .pruning(segments_location, snapshot_loc, segment_id_map)
@@ -251,6 +256,7 @@ impl FuseTable {
             Some(extras) => match &extras.projection {
                 None => Self::all_columns_partitions(schema, &block_metas, top_k.clone(), limit),
                 Some(projection) => Self::projection_partitions(
+                    schema,
                     &block_metas,
                     column_nodes,
                     projection,
@@ -315,6 +321,7 @@ impl FuseTable {
     }
 
     fn projection_partitions(
+        schema: Option<&TableSchemaRef>,
         block_metas: &[(Option<BlockMetaIndex>, Arc<BlockMeta>)],
         column_nodes: &ColumnNodes,
         projection: &Projection,
@@ -331,11 +338,25 @@ impl FuseTable {
         let columns = projection.project_column_nodes(column_nodes).unwrap();
         let mut remaining = limit;
 
+        // Blocks written before the data type of a projected column was modified
+        // still store it under one of its legacy column ids.
+        let legacy_column_ids: Vec<ColumnId> = schema
+            .map(|schema| {
+                projection
+                    .project_schema(schema)
+                    .fields()
+                    .iter()
+                    .flat_map(|f| f.legacy_columns().iter().map(|(id, _)| *id))
+                    .collect()
+            })
+            .unwrap_or_default();
+
         for (block_meta_index, block_meta) in block_metas {
             partitions.partitions.push(Self::projection_part(
                 block_meta,
                 block_meta_index,
                 column_nodes,
+                &legacy_column_ids,
                 top_k.clone(),
                 projection,
             ));
@@ -412,6 +433,7 @@ impl FuseTable {
         meta: &BlockMeta,
         block_meta_index: &Option<BlockMetaIndex>,
         column_nodes: &ColumnNodes,
+        legacy_column_ids: &[ColumnId],
         top_k: Option<TopK>,
         projection: &Projection,
     ) -> PartInfoPtr {
@@ -426,6 +448,11 @@ impl FuseTable {
                 }
             }
         }
+        for column_id in legacy_column_ids {
+            if let Some(column_meta) = meta.col_metas.get(column_id) {
+                columns_meta.insert(*column_id, column_meta.clone());
+            }
+        }
 
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
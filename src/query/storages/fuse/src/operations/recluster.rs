@@ -196,6 +196,7 @@ impl FuseTable {
             )?))
         })?;
 
+        let inverted_index_column_ids = self.inverted_index_column_ids();
         pipeline.add_sink(|input| {
             FuseTableSink::try_create(
                 input,
@@ -206,6 +207,7 @@ impl FuseTable {
                 cluster_stats_gen.clone(),
                 block_compact_thresholds,
                 self.table_info.schema(),
+                &inverted_index_column_ids,
                 None,
             )
         })?;
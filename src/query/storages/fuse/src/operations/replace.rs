@@ -96,12 +96,17 @@ impl FuseTable {
     //                      │      ┌───────────────────┐       ┌───────────────────────┐         ┌───────────────────┐
     //                      └─────►│ResizeProcessor(1) ├──────►│TableMutationAggregator├────────►│     CommitSink    │
     //                             └───────────────────┘       └───────────────────────┘         └───────────────────┘
+    //
+    //  - If `with_merge_action` is set, the UpsertSource is the source of MERGE INTO, whose blocks
+    //    are followed by an action column, the ReplaceIntoProcessor only deletes the rows which
+    //    are updated or deleted, and only appends the rows which are updated or inserted.
 
     #[async_backtrace::framed]
     pub async fn build_replace_pipeline<'a>(
         &'a self,
         ctx: Arc<dyn TableContext>,
         on_conflict_field_identifiers: Vec<TableField>,
+        with_merge_action: bool,
         pipeline: &'a mut Pipeline,
     ) -> Result<()> {
         let schema = self.table_info.schema();
@@ -147,8 +152,11 @@ impl FuseTable {
             .unwrap_or_else(|| Arc::new(self.new_empty_snapshot()));
 
         let empty_table = base_snapshot.segments.is_empty();
-        let replace_into_processor =
-            ReplaceIntoProcessor::create(on_conflicts.clone(), empty_table);
+        let replace_into_processor = if with_merge_action {
+            ReplaceIntoProcessor::create_for_merge(on_conflicts.clone(), empty_table)
+        } else {
+            ReplaceIntoProcessor::create(on_conflicts.clone(), empty_table)
+        };
         pipeline.add_pipe(replace_into_processor.into_pipe());
 
         // 3. connect to broadcast processor and append transform
@@ -16,7 +16,13 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt8Type;
+use common_expression::types::ValueType;
 use common_expression::DataBlock;
 use common_pipeline_core::pipe::Pipe;
 use common_pipeline_core::pipe::PipeItem;
@@ -25,7 +31,10 @@ use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::processors::Processor;
+use common_sql::plans::MERGE_INTO_ACTION_DELETE;
+use common_sql::plans::MERGE_INTO_ACTION_INSERT;
 
+use crate::operations::merge_into::mutation_meta::merge_into_operation_meta::MergeIntoOperation;
 use crate::operations::merge_into::OnConflictField;
 use crate::operations::replace_into::mutator_replace_into::ReplaceIntoMutator;
 
@@ -42,6 +51,9 @@ pub struct ReplaceIntoProcessor {
     output_data_append: Option<DataBlock>,
 
     target_table_empty: bool,
+
+    // for MERGE INTO, the input blocks carry a trailing action column
+    with_merge_action: bool,
 }
 
 impl ReplaceIntoProcessor {
@@ -60,9 +72,21 @@ impl ReplaceIntoProcessor {
             output_data_merge_into_action: None,
             output_data_append: None,
             target_table_empty,
+            with_merge_action: false,
         }
     }
 
+    /// The input blocks are followed by the action column of MERGE INTO,
+    /// which decides whether a row should be deleted, appended or both (updated).
+    pub fn create_for_merge(
+        on_conflict_fields: Vec<OnConflictField>,
+        target_table_empty: bool,
+    ) -> Self {
+        let mut processor = Self::create(on_conflict_fields, target_table_empty);
+        processor.with_merge_action = true;
+        processor
+    }
+
     pub fn into_pipe(self) -> Pipe {
         let pipe_item = self.into_pipe_item();
         Pipe::create(1, 2, vec![pipe_item])
@@ -134,6 +158,9 @@ impl Processor for ReplaceIntoProcessor {
 
     fn process(&mut self) -> Result<()> {
         if let Some(data_block) = self.input_data.take() {
+            if self.with_merge_action {
+                return self.process_merge_source(data_block);
+            }
             let merge_into_action = self.replace_into_mutator.process_input_block(&data_block)?;
             if !self.target_table_empty {
                 self.output_data_merge_into_action =
@@ -146,3 +173,40 @@ impl Processor for ReplaceIntoProcessor {
         Ok(())
     }
 }
+
+impl ReplaceIntoProcessor {
+    fn process_merge_source(&mut self, data_block: DataBlock) -> Result<()> {
+        let num_rows = data_block.num_rows();
+        let action_index = data_block.num_columns() - 1;
+        let actions = data_block
+            .get_by_offset(action_index)
+            .value
+            .convert_to_full_column(&DataType::Number(NumberDataType::UInt8), num_rows);
+        let actions = UInt8Type::try_downcast_column(&actions).ok_or_else(|| {
+            ErrorCode::Internal("the action column of merge into must be of type UInt8")
+        })?;
+        let to_delete =
+            MutableBitmap::from_iter(actions.iter().map(|a| *a != MERGE_INTO_ACTION_INSERT));
+        let to_append =
+            MutableBitmap::from_iter(actions.iter().map(|a| *a != MERGE_INTO_ACTION_DELETE));
+
+        let data_block = data_block.pop_columns(1)?.convert_to_full();
+        let deletion_block = data_block.clone().filter_with_bitmap(&to_delete.into())?;
+        let append_block = data_block.filter_with_bitmap(&to_append.into())?;
+
+        if !self.target_table_empty {
+            let merge_into_action = if deletion_block.is_empty() {
+                MergeIntoOperation::None
+            } else {
+                self.replace_into_mutator
+                    .process_input_block(&deletion_block)?
+            };
+            self.output_data_merge_into_action =
+                Some(DataBlock::empty_with_meta(Box::new(merge_into_action)));
+        }
+        if !append_block.is_empty() {
+            self.output_data_append = Some(append_block);
+        }
+        Ok(())
+    }
+}
@@ -47,6 +47,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// n-gram indices that should be loaded from filter block
+    ngram_index_fields: Vec<TableField>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -69,8 +72,9 @@ impl BloomPrunerCreator {
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
             let point_query_cols = BloomIndex::find_eq_columns(expr)?;
+            let substring_query_cols = BloomIndex::find_ngram_columns(expr)?;
 
-            if !point_query_cols.is_empty() {
+            if !point_query_cols.is_empty() || !substring_query_cols.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -84,9 +88,15 @@ impl BloomPrunerCreator {
                     }
                 }
 
+                let ngram_filter_fields = substring_query_cols
+                    .iter()
+                    .filter_map(|col_name| schema.field_with_name(col_name).ok().cloned())
+                    .collect();
+
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_index_fields: ngram_filter_fields,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -109,8 +119,8 @@ impl BloomPrunerCreator {
         let version = index_location.1;
 
         // filter out columns that no longer exist in the indexed block
-        let index_columns = self.index_fields.iter().try_fold(
-            Vec::with_capacity(self.index_fields.len()),
+        let mut index_columns = self.index_fields.iter().try_fold(
+            Vec::with_capacity(self.index_fields.len() + self.ngram_index_fields.len()),
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
                     acc.push(BloomIndex::build_filter_column_name(version, field)?);
@@ -118,6 +128,13 @@ impl BloomPrunerCreator {
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        index_columns.extend(
+            self.ngram_index_fields
+                .iter()
+                .filter(|field| column_ids_of_indexed_block.contains(&field.column_id()))
+                .map(BloomIndex::build_ngram_column_name),
+        );
+
         // load the relevant index columns
         let maybe_filter = index_location
             .read_block_filter(self.dal.clone(), &index_columns, index_length)
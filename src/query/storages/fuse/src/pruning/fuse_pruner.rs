@@ -20,6 +20,7 @@ use common_base::runtime::Runtime;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::RemoteExpr;
 use common_expression::TableSchemaRef;
 use common_functions::BUILTIN_FUNCTIONS;
@@ -38,9 +39,11 @@ use storages_common_table_meta::meta::Location;
 use tracing::warn;
 
 use super::create_segment_location_vector;
+use crate::io::TableMetaLocationGenerator;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentPruner;
 
 pub struct PruningContext {
@@ -61,6 +64,7 @@ pub struct FusePruner {
     pub table_schema: TableSchemaRef,
     pub pruning_ctx: Arc<PruningContext>,
     pub push_down: Option<PushDownInfo>,
+    pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
 }
 
 impl FusePruner {
@@ -156,9 +160,33 @@ impl FusePruner {
             table_schema,
             push_down: push_down.clone(),
             pruning_ctx,
+            inverted_index_pruner: None,
         })
     }
 
+    // Enable the pruning with the inverted indexes of the table.
+    pub fn with_inverted_indexes(
+        mut self,
+        meta_locations: TableMetaLocationGenerator,
+        index_column_ids: &[ColumnId],
+    ) -> Result<Self> {
+        if index_column_ids.is_empty() {
+            return Ok(self);
+        }
+        let filter_expr = self
+            .push_down
+            .as_ref()
+            .and_then(|extra| extra.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+        self.inverted_index_pruner = InvertedIndexPruner::try_create(
+            self.pruning_ctx.dal.clone(),
+            meta_locations,
+            &self.table_schema,
+            index_column_ids,
+            filter_expr.as_ref(),
+        )?;
+        Ok(self)
+    }
+
     // Pruning chain:
     // segment pruner -> block pruner -> topn pruner
     #[async_backtrace::framed]
@@ -172,7 +200,8 @@ impl FusePruner {
             create_segment_location_vector(segment_locs, snapshot_loc, segment_id_map);
         // Segment pruner.
         let segment_pruner =
-            SegmentPruner::create(self.pruning_ctx.clone(), self.table_schema.clone())?;
+            SegmentPruner::create(self.pruning_ctx.clone(), self.table_schema.clone())?
+                .with_inverted_index_pruner(self.inverted_index_pruner.clone());
         let metas = segment_pruner.pruning(segment_locs).await?;
 
         // TopN pruner.
@@ -0,0 +1,146 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::ColumnId;
+use common_expression::Expr;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use opendal::Operator;
+use storages_common_cache::LoadParams;
+use storages_common_index::InvertedIndex;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+
+/// Segment level pruning with the inverted indexes, for the `match(column, 'query')`
+/// conjuncts of the filter on indexed columns.
+pub struct InvertedIndexPruner {
+    dal: Operator,
+    meta_locations: TableMetaLocationGenerator,
+    // (column id, query) of the `match` predicates
+    predicates: Vec<(ColumnId, Vec<u8>)>,
+}
+
+impl InvertedIndexPruner {
+    /// Returns None if there are no `match` predicates on indexed columns.
+    pub fn try_create(
+        dal: Operator,
+        meta_locations: TableMetaLocationGenerator,
+        table_schema: &TableSchemaRef,
+        index_column_ids: &[ColumnId],
+        filter_expr: Option<&Expr<String>>,
+    ) -> Result<Option<Arc<InvertedIndexPruner>>> {
+        let expr = match filter_expr {
+            Some(expr) => expr,
+            None => return Ok(None),
+        };
+
+        let mut predicates = vec![];
+        for (column_name, query) in find_match_conjuncts(expr) {
+            if let Ok(field) = table_schema.field_with_name(&column_name) {
+                if index_column_ids.contains(&field.column_id()) {
+                    predicates.push((field.column_id(), query));
+                }
+            }
+        }
+
+        if predicates.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Arc::new(InvertedIndexPruner {
+            dal,
+            meta_locations,
+            predicates,
+        })))
+    }
+
+    /// Positions of the blocks in the segment which may satisfy the predicates.
+    ///
+    /// Returns None if all the blocks should be kept, e.g. the segment is written
+    /// before the index is created.
+    #[async_backtrace::framed]
+    pub async fn prune(&self, segment_location: &str) -> Result<Option<BTreeSet<u32>>> {
+        let location = self
+            .meta_locations
+            .segment_inverted_index_location(segment_location);
+        let reader = MetaReaders::inverted_index_reader(self.dal.clone());
+        let index = match reader
+            .read(&LoadParams {
+                location,
+                len_hint: None,
+                ver: InvertedIndex::VERSION,
+                put_cache: true,
+            })
+            .await
+        {
+            Ok(index) => index,
+            Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let mut blocks: Option<BTreeSet<u32>> = None;
+        for (column_id, query) in &self.predicates {
+            if let Some(found) = index.find_blocks(*column_id, query) {
+                blocks = Some(match blocks {
+                    None => found,
+                    Some(blocks) => blocks.intersection(&found).copied().collect(),
+                });
+            }
+        }
+        Ok(blocks)
+    }
+}
+
+// Collect `match(Column, <constant>)` from the conjuncts of the filter.
+fn find_match_conjuncts(expr: &Expr<String>) -> Vec<(String, Vec<u8>)> {
+    let mut result = vec![];
+    if let Expr::FunctionCall { function, args, .. } = expr {
+        match (function.signature.name.as_str(), args.as_slice()) {
+            ("and" | "and_filters" | "is_true", _) => {
+                for arg in args {
+                    result.extend(find_match_conjuncts(arg));
+                }
+            }
+            ("match", [column, query]) => {
+                if let (
+                    Expr::ColumnRef { id, data_type, .. },
+                    Expr::Constant {
+                        scalar: Scalar::String(query),
+                        ..
+                    },
+                ) = (column, unwrap_cast(query))
+                {
+                    if data_type.remove_nullable() == DataType::String {
+                        result.push((id.clone(), query.clone()));
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    result
+}
+
+fn unwrap_cast(expr: &Expr<String>) -> &Expr<String> {
+    match expr {
+        Expr::Cast { expr, .. } => unwrap_cast(expr),
+        _ => expr,
+    }
+}
@@ -27,12 +27,14 @@ use super::SegmentLocation;
 use crate::io::MetaReaders;
 use crate::metrics::*;
 use crate::pruning::BlockPruner;
+use crate::pruning::InvertedIndexPruner;
 use crate::pruning::PruningContext;
 
-/// Segment level pruning: range pruning.
+/// Segment level pruning: range pruning and inverted index pruning.
 pub struct SegmentPruner {
     pub pruning_ctx: Arc<PruningContext>,
     pub table_schema: TableSchemaRef,
+    pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
 }
 
 impl SegmentPruner {
@@ -43,9 +45,18 @@ impl SegmentPruner {
         Ok(SegmentPruner {
             pruning_ctx,
             table_schema,
+            inverted_index_pruner: None,
         })
     }
 
+    pub fn with_inverted_index_pruner(
+        mut self,
+        inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
+    ) -> Self {
+        self.inverted_index_pruner = inverted_index_pruner;
+        self
+    }
+
     #[async_backtrace::framed]
     pub async fn pruning(
         &self,
@@ -66,11 +77,13 @@ impl SegmentPruner {
                 segments.next().map(|(segment_idx, segment_location)| {
                     let pruning_ctx = self.pruning_ctx.clone();
                     let table_schema = self.table_schema.clone();
+                    let inverted_index_pruner = self.inverted_index_pruner.clone();
                     move |permit| async move {
                         Self::segment_pruning(
                             pruning_ctx,
                             permit,
                             table_schema,
+                            inverted_index_pruner,
                             segment_idx,
                             segment_location,
                         )
@@ -106,6 +119,7 @@ impl SegmentPruner {
         pruning_ctx: Arc<PruningContext>,
         permit: OwnedSemaphorePermit,
         table_schema: TableSchemaRef,
+        inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
         segment_idx: usize,
         segment_location: SegmentLocation,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
@@ -117,13 +131,19 @@ impl SegmentPruner {
         let (location, ver) = segment_location.location.clone();
         let segment_info = segment_reader
             .read(&LoadParams {
-                location,
+                location: location.clone(),
                 len_hint: None,
                 ver,
                 put_cache: true,
             })
             .await?;
 
+        // Positions of the blocks which may match the full-text predicates.
+        let inverted_index_blocks = match &inverted_index_pruner {
+            Some(pruner) => pruner.prune(&location).await?,
+            None => None,
+        };
+
         // IO job of reading segment done, release the permit, allows more concurrent pruners
         // Note that it is required to explicitly release this permit before pruning blocks, to avoid deadlock.
         drop(permit);
@@ -139,23 +159,35 @@ impl SegmentPruner {
 
         // Segment range pruning.
         let range_pruner = pruning_ctx.range_pruner.clone();
-        let result = if range_pruner.should_keep(&segment_info.summary.col_stats) {
-            // Perf.
-            {
-                metrics_inc_segments_range_pruning_after(1);
-                metrics_inc_bytes_segment_range_pruning_after(total_bytes);
-
-                pruning_stats.set_segments_range_pruning_after(1);
-            }
-
-            // Block pruner.
-            let block_pruner = BlockPruner::create(pruning_ctx)?;
-            block_pruner
-                .pruning(segment_idx, segment_location, &segment_info)
-                .await?
-        } else {
-            vec![]
-        };
+        let inverted_index_keep = inverted_index_blocks
+            .as_ref()
+            .map(|blocks| !blocks.is_empty())
+            .unwrap_or(true);
+        let result =
+            if inverted_index_keep && range_pruner.should_keep(&segment_info.summary.col_stats) {
+                // Perf.
+                {
+                    metrics_inc_segments_range_pruning_after(1);
+                    metrics_inc_bytes_segment_range_pruning_after(total_bytes);
+
+                    pruning_stats.set_segments_range_pruning_after(1);
+                }
+
+                // Block pruner.
+                let block_pruner = BlockPruner::create(pruning_ctx)?;
+                let metas = block_pruner
+                    .pruning(segment_idx, segment_location, &segment_info)
+                    .await?;
+                match inverted_index_blocks {
+                    Some(blocks) => metas
+                        .into_iter()
+                        .filter(|(index, _)| blocks.contains(&(index.block_idx as u32)))
+                        .collect(),
+                    None => metas,
+                }
+            } else {
+                vec![]
+            };
 
         Ok(result)
     }
@@ -35,6 +35,7 @@ use crate::hive_catalog::HIVE_CATALOG;
 use crate::hive_database::HiveDatabase;
 use crate::hive_database::HIVE_DATABASE_ENGIE;
 use crate::hive_table::HIVE_TABLE_ENGIE;
+use crate::hive_table_options::HiveFileFormat;
 use crate::hive_table_options::HiveTableOptions;
 
 /// ! Skeleton of mappers
@@ -81,9 +82,19 @@ pub fn try_into_table_info(
         None
     };
 
+    let file_format = match hms_table
+        .sd
+        .as_ref()
+        .and_then(|storage| storage.input_format.as_ref())
+    {
+        Some(input_format) => HiveFileFormat::from_input_format(input_format)?,
+        None => HiveFileFormat::Parquet,
+    };
+
     let table_options = HiveTableOptions {
         partition_keys,
         location,
+        file_format,
     };
 
     let meta = TableMeta {
@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use chrono::Utc;
 use common_base::base::tokio;
 use common_catalog::catalog::Catalog;
 use common_catalog::catalog::StorageDescription;
@@ -24,6 +26,7 @@ use common_catalog::table_args::TableArgs;
 use common_catalog::table_function::TableFunction;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_hive_meta_store::AddPartitionsRequest;
 use common_hive_meta_store::Partition;
 use common_hive_meta_store::TThriftHiveMetastoreSyncClient;
 use common_hive_meta_store::ThriftHiveMetastoreSyncClient;
@@ -41,6 +44,8 @@ use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SetDatabaseOwnerReply;
+use common_meta_app::schema::SetDatabaseOwnerReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -60,6 +65,7 @@ use thrift::transport::*;
 
 use super::hive_database::HiveDatabase;
 use crate::hive_table::HiveTable;
+use crate::hive_table_options::HiveFileFormat;
 
 pub const HIVE_CATALOG: &str = "hive";
 
@@ -152,6 +158,76 @@ impl HiveCatalog {
             .map_err(from_thrift_error)
     }
 
+    // register the partitions of table, existing partitions are skipped.
+    // partitions are pairs of (name, values), like ('c_region=ASIA/c_nation=CHINA', ['ASIA', 'CHINA'])
+    #[async_backtrace::framed]
+    pub async fn add_partitions(
+        &self,
+        db: String,
+        table: String,
+        partitions: Vec<(String, Vec<String>)>,
+    ) -> Result<()> {
+        let client = self.get_client()?;
+        tokio::task::spawn_blocking(move || Self::do_add_partitions(client, db, table, partitions))
+            .await
+            .unwrap()
+    }
+
+    pub fn do_add_partitions(
+        client: impl TThriftHiveMetastoreSyncClient,
+        db_name: String,
+        tbl_name: String,
+        partitions: Vec<(String, Vec<String>)>,
+    ) -> Result<()> {
+        let mut client = client;
+        let table = client
+            .get_table(db_name.clone(), tbl_name.clone())
+            .map_err(from_thrift_error)?;
+        // partitions share the storage descriptor of table, except the location
+        let table_sd = table.sd.ok_or_else(|| {
+            ErrorCode::TableInfoError(format!(
+                "{}.{}, table storage descriptor is empty",
+                db_name, tbl_name
+            ))
+        })?;
+        let location = table_sd.location.clone().ok_or_else(|| {
+            ErrorCode::TableInfoError(format!("{}.{}, table location is empty", db_name, tbl_name))
+        })?;
+
+        let create_time = Utc::now().timestamp() as i32;
+        let parts = partitions
+            .into_iter()
+            .map(|(name, values)| {
+                let mut sd = table_sd.clone();
+                sd.location = Some(format!("{}/{}", location.trim_end_matches('/'), name));
+                Partition {
+                    values: Some(values),
+                    db_name: Some(db_name.clone()),
+                    table_name: Some(tbl_name.clone()),
+                    create_time: Some(create_time),
+                    last_access_time: Some(0),
+                    sd: Some(sd),
+                    parameters: Some(BTreeMap::new()),
+                    privileges: None,
+                    cat_name: None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let request = AddPartitionsRequest {
+            db_name,
+            tbl_name,
+            parts,
+            if_not_exists: true,
+            need_result: Some(false),
+            cat_name: None,
+        };
+        client
+            .add_partitions_req(request)
+            .map_err(from_thrift_error)?;
+        Ok(())
+    }
+
     fn do_get_table(
         client: impl TThriftHiveMetastoreSyncClient,
         db_name: String,
@@ -177,12 +253,7 @@ impl HiveCatalog {
 
         if let Some(sd) = table_meta.sd.as_ref() {
             if let Some(input_format) = sd.input_format.as_ref() {
-                if input_format != "org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat" {
-                    return Err(ErrorCode::Unimplemented(format!(
-                        "only support parquet, {} not support",
-                        input_format
-                    )));
-                }
+                HiveFileFormat::from_input_format(input_format)?;
             }
         }
 
@@ -269,6 +340,13 @@ impl Catalog for HiveCatalog {
         ))
     }
 
+    #[async_backtrace::framed]
+    async fn set_database_owner(&self, _req: SetDatabaseOwnerReq) -> Result<SetDatabaseOwnerReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot set database owner in HIVE catalog",
+        ))
+    }
+
     fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
         let res: Arc<dyn Table> = Arc::new(HiveTable::try_create(table_info.clone())?);
         Ok(res)
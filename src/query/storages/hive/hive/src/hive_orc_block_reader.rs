@@ -0,0 +1,271 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::io;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::compute::cast::cast;
+use common_arrow::arrow::compute::cast::CastOptions;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::io::orc;
+use common_arrow::arrow::io::orc::format::read::read_metadata;
+use common_arrow::arrow::io::orc::format::read::read_stripe_column;
+use common_arrow::arrow::io::orc::format::read::read_stripe_footer;
+use common_arrow::arrow::io::orc::format::read::FileMetadata;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+
+// bytes read from the end of an orc file, which usually hold the whole file tail
+const ORC_TAIL_SIZE: u64 = 64 * 1024;
+
+// column of orc file to read
+struct OrcColumn {
+    // id of the column in the orc type tree
+    column_id: u32,
+    // type of the column in orc file
+    file_type: ArrowDataType,
+    // type of the column in table schema
+    table_type: ArrowDataType,
+}
+
+fn orc_error(filename: &str, e: impl Debug) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("failed to read orc file {}: {:?}", filename, e))
+}
+
+// hive writes orc files with column names like `_col0` before hive 2.0,
+// columns of these files are matched by position
+fn is_positional(names: &[String]) -> bool {
+    names
+        .iter()
+        .enumerate()
+        .all(|(i, name)| name == &format!("_col{}", i))
+}
+
+// the stripes of an orc file starting in the range of a split,
+// only the bytes of these stripes are read instead of the whole file
+pub struct OrcStripes {
+    metadata: FileMetadata,
+    // offset of `data` in the file
+    offset: u64,
+    data: Vec<u8>,
+}
+
+// read the metadata of the orc file, and the stripes starting in the range
+#[async_backtrace::framed]
+pub async fn read_orc_stripes(
+    op: &Operator,
+    filename: &str,
+    filesize: u64,
+    range: &Range<u64>,
+) -> Result<OrcStripes> {
+    let mut metadata = None;
+    if filesize > ORC_TAIL_SIZE {
+        let tail = op
+            .range_read(filename, filesize - ORC_TAIL_SIZE..filesize)
+            .await?;
+        metadata = read_metadata(&mut Cursor::new(tail)).ok();
+    }
+    let metadata = match metadata {
+        Some(metadata) => metadata,
+        // the tail is larger than read, or the file is small
+        None => {
+            let data = op.read(filename).await?;
+            let metadata =
+                read_metadata(&mut Cursor::new(&data)).map_err(|e| orc_error(filename, e))?;
+            return Ok(OrcStripes {
+                metadata,
+                offset: 0,
+                data,
+            });
+        }
+    };
+
+    // stripes are stored one after another
+    let (start, end) = metadata
+        .footer
+        .stripes
+        .iter()
+        .filter(|info| range.contains(&info.offset()))
+        .map(|info| {
+            let length = info.index_length() + info.data_length() + info.footer_length();
+            (info.offset(), info.offset() + length)
+        })
+        .fold((u64::MAX, 0), |(start, end), (s, e)| {
+            (start.min(s), end.max(e))
+        });
+    let data = match start < end {
+        true => op.range_read(filename, start..end).await?,
+        false => vec![],
+    };
+    Ok(OrcStripes {
+        metadata,
+        offset: start.min(end),
+        data,
+    })
+}
+
+// read the stripes starting in the range,
+// `fields` are pairs of (index in table schema, field in table schema).
+// returns the chunks of stripes and their number of rows
+pub fn read_orc_chunks(
+    filename: &str,
+    stripes: &OrcStripes,
+    fields: &[(usize, Field)],
+    range: &Range<u64>,
+) -> Result<Vec<(Chunk<Box<dyn Array>>, usize)>> {
+    let metadata = &stripes.metadata;
+    let mut reader = OffsetReader {
+        inner: Cursor::new(&stripes.data),
+        offset: stripes.offset,
+    };
+    let schema = orc::read::infer_schema(&metadata.footer)?;
+    let root = metadata
+        .footer
+        .types
+        .first()
+        .ok_or_else(|| orc_error(filename, "missing root type"))?;
+    let names = schema
+        .fields
+        .iter()
+        .map(|f| f.name.to_lowercase())
+        .collect::<Vec<_>>();
+    let positional = is_positional(&names);
+
+    let mut columns = Vec::with_capacity(fields.len());
+    for (index, field) in fields {
+        let position = if positional {
+            Some(*index).filter(|i| *i < names.len())
+        } else {
+            let name = field.name.to_lowercase();
+            names.iter().position(|n| n == &name)
+        };
+        let position = position.ok_or_else(|| {
+            ErrorCode::TableInfoError(format!(
+                "couldn't find column:{} in orc file {}",
+                field.name, filename
+            ))
+        })?;
+        columns.push(OrcColumn {
+            column_id: root.subtypes[position],
+            file_type: schema.fields[position].data_type.clone(),
+            table_type: field.data_type.clone(),
+        });
+    }
+
+    let mut chunks = vec![];
+    let mut scratch = vec![];
+    for (stripe, info) in metadata.footer.stripes.iter().enumerate() {
+        // the stripe belongs to the split where it starts
+        if !range.contains(&info.offset()) {
+            continue;
+        }
+        let num_rows = info.number_of_rows() as usize;
+        let footer = read_stripe_footer(&mut reader, metadata, stripe, &mut scratch)
+            .map_err(|e| orc_error(filename, e))?;
+        let footer = Arc::new(footer);
+
+        let mut arrays = Vec::with_capacity(columns.len());
+        for column in columns.iter() {
+            let data = read_stripe_column(
+                &mut reader,
+                metadata,
+                stripe,
+                footer.clone(),
+                column.column_id,
+                std::mem::take(&mut scratch),
+            )
+            .map_err(|e| orc_error(filename, e))?;
+            let array = orc::read::deserialize(column.file_type.clone(), &data)?;
+            scratch = data.into_inner();
+
+            let array = if array.data_type() == &column.table_type {
+                array
+            } else {
+                cast(array.as_ref(), &column.table_type, CastOptions::default())?
+            };
+            arrays.push(array);
+        }
+        chunks.push((Chunk::new(arrays), num_rows));
+    }
+    Ok(chunks)
+}
+
+// reads a part of the file which starts at `offset`, the positions to seek are of the whole file
+struct OffsetReader<'a> {
+    inner: Cursor<&'a Vec<u8>>,
+    offset: u64,
+}
+
+impl<'a> Read for OffsetReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl<'a> Seek for OffsetReader<'a> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(n) => SeekFrom::Start(n.checked_sub(self.offset).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "seek before the stripes read")
+            })?),
+            pos => pos,
+        };
+        Ok(self.inner.seek(pos)? + self.offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::io::Read;
+    use std::io::Seek;
+    use std::io::SeekFrom;
+
+    use super::is_positional;
+    use super::OffsetReader;
+
+    #[test]
+    fn test_is_positional() {
+        let names = vec!["_col0".to_string(), "_col1".to_string()];
+        assert!(is_positional(&names));
+
+        let names = vec!["_col0".to_string(), "name".to_string()];
+        assert!(!is_positional(&names));
+    }
+
+    #[test]
+    fn test_offset_reader() {
+        let data = vec![10, 11, 12, 13];
+        let mut reader = OffsetReader {
+            inner: Cursor::new(&data),
+            offset: 10,
+        };
+        assert_eq!(reader.seek(SeekFrom::Start(12)).unwrap(), 12);
+        let mut buf = [0; 2];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [12, 13]);
+        assert_eq!(reader.seek(SeekFrom::Current(-3)).unwrap(), 11);
+        assert!(reader.seek(SeekFrom::Start(9)).is_err());
+    }
+}
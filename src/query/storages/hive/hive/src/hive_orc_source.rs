@@ -0,0 +1,143 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::filter_helper::FilterHelpers;
+use common_expression::types::BooleanType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use opendal::Operator;
+
+use crate::hive_orc_block_reader::read_orc_stripes;
+use crate::hive_orc_block_reader::OrcStripes;
+use crate::hive_parquet_block_reader::HiveBlockReader;
+use crate::HivePartInfo;
+
+// source of hive tables stored as orc, a part is the stripes starting in its range.
+// the prewhere filter is evaluated on the prewhere columns before combined with the remain columns
+pub struct HiveOrcSource {
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    prewhere_block_reader: Arc<HiveBlockReader>,
+    remain_reader: Arc<Option<HiveBlockReader>>,
+    prewhere_filter: Arc<Option<Expr>>,
+    generated: Vec<DataBlock>,
+
+    /// The schema before output. Some fields might be removed when outputting.
+    source_schema: DataSchemaRef,
+    /// The final output schema
+    output_schema: DataSchemaRef,
+}
+
+impl HiveOrcSource {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        dal: Operator,
+        output: Arc<OutputPort>,
+        prewhere_block_reader: Arc<HiveBlockReader>,
+        remain_reader: Arc<Option<HiveBlockReader>>,
+        prewhere_filter: Arc<Option<Expr>>,
+        source_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, HiveOrcSource {
+            ctx,
+            dal,
+            prewhere_block_reader,
+            remain_reader,
+            prewhere_filter,
+            generated: vec![],
+            source_schema,
+            output_schema,
+        })
+    }
+
+    fn read_blocks(&self, stripes: &OrcStripes, part: &HivePartInfo) -> Result<Vec<DataBlock>> {
+        let prewhere_blocks = self.prewhere_block_reader.read_orc_blocks(stripes, part)?;
+        let filter = match self.prewhere_filter.as_ref() {
+            Some(filter) => filter,
+            // if no prewhere filter, data are all fetched by the prewhere reader
+            None => return Ok(prewhere_blocks),
+        };
+
+        let remain_blocks = match self.remain_reader.as_ref() {
+            Some(remain_reader) => Some(remain_reader.read_orc_blocks(stripes, part)?),
+            None => None,
+        };
+
+        let func_ctx = self.ctx.get_function_context()?;
+        let mut blocks = Vec::with_capacity(prewhere_blocks.len());
+        for (i, block) in prewhere_blocks.into_iter().enumerate() {
+            let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+            let predicates = evaluator
+                .run(filter)
+                .map_err(|e| e.add_message("eval prewhere filter failed:"))?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            if FilterHelpers::is_all_unset(&predicates) {
+                continue;
+            }
+
+            let mut block = block;
+            if let Some(remain_blocks) = &remain_blocks {
+                for column in remain_blocks[i].columns() {
+                    block.add_column(column.clone());
+                }
+            }
+            let block = DataBlock::filter_boolean_value(block, &predicates)?;
+            if !block.is_empty() {
+                blocks.push(block.resort(&self.source_schema, &self.output_schema)?);
+            }
+        }
+        Ok(blocks)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for HiveOrcSource {
+    const NAME: &'static str = "HiveOrcSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.generated.pop() {
+                return Ok(Some(block));
+            }
+
+            let part = match self.ctx.get_partition() {
+                Some(part) => part,
+                None => return Ok(None),
+            };
+            let part = HivePartInfo::from_part(&part)?;
+            let stripes =
+                read_orc_stripes(&self.dal, &part.filename, part.filesize, &part.range).await?;
+            let mut blocks = self.read_blocks(&stripes, part)?;
+            // blocks are popped from the back
+            blocks.reverse();
+            self.generated = blocks;
+        }
+    }
+}
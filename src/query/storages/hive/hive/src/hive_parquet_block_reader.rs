@@ -36,6 +36,8 @@ use common_expression::TableSchemaRef;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
 
+use crate::hive_orc_block_reader::read_orc_chunks;
+use crate::hive_orc_block_reader::OrcStripes;
 use crate::hive_partition::HivePartInfo;
 use crate::HivePartitionFiller;
 use crate::MetaDataReader;
@@ -313,6 +315,31 @@ impl HiveBlockReader {
             .map_err(|e| e.add_message(format!(" filename of hive part {}", part.filename)))
     }
 
+    // read the stripes of the part from the whole orc file
+    pub fn read_orc_blocks(
+        &self,
+        stripes: &OrcStripes,
+        part: &HivePartInfo,
+    ) -> Result<Vec<DataBlock>> {
+        let fields = self
+            .projection
+            .iter()
+            .map(|index| (*index, self.arrow_schema.fields[*index].clone()))
+            .collect::<Vec<_>>();
+        let chunks = read_orc_chunks(&part.filename, stripes, &fields, &part.range)?;
+
+        let mut blocks = Vec::with_capacity(chunks.len());
+        for (chunk, num_rows) in chunks {
+            let block = DataBlock::from_arrow_chunk(&chunk, &self.projected_schema)?;
+            let block = match &self.hive_partition_filler {
+                Some(filler) => filler.fill_data(block, part, num_rows)?,
+                None => block,
+            };
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+
     pub fn get_all_datablocks(
         &self,
         mut rowgroup_deserializer: DataBlockDeserializer,
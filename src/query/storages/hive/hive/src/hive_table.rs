@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
@@ -26,6 +28,7 @@ use common_catalog::plan::Partitions;
 use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::table::AppendMode;
 use common_catalog::table::Table;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_args::TableArgs;
@@ -58,9 +61,13 @@ use storages_common_index::RangeIndex;
 
 use super::hive_catalog::HiveCatalog;
 use super::hive_partition_pruner::HivePartitionPruner;
+use super::hive_table_options::HiveFileFormat;
 use super::hive_table_options::HiveTableOptions;
 use crate::filter_hive_partition_from_partition_keys;
+use crate::hive_orc_source::HiveOrcSource;
 use crate::hive_parquet_block_reader::HiveBlockReader;
+use crate::hive_table_sink::HiveAppendedFile;
+use crate::hive_table_sink::HiveTableSink;
 use crate::hive_table_source::HiveTableSource;
 use crate::HiveBlockFilter;
 use crate::HiveFileSplitter;
@@ -209,12 +216,11 @@ impl HiveTable {
 
         for index in 0..std::cmp::max(1, max_threads) {
             let output = OutputPort::create();
-            source_builder.add_source(
-                output.clone(),
-                HiveTableSource::create(
+            let source = match self.table_options.file_format {
+                HiveFileFormat::Parquet => HiveTableSource::create(
                     ctx.clone(),
                     self.dal.clone(),
-                    output,
+                    output.clone(),
                     prewhere_reader.clone(),
                     remain_reader.clone(),
                     prewhere_filter.clone(),
@@ -223,7 +229,18 @@ impl HiveTable {
                     src_schema.clone(),
                     output_schema.clone(),
                 )?,
-            );
+                HiveFileFormat::Orc => HiveOrcSource::create(
+                    ctx.clone(),
+                    self.dal.clone(),
+                    output.clone(),
+                    prewhere_reader.clone(),
+                    remain_reader.clone(),
+                    prewhere_filter.clone(),
+                    src_schema.clone(),
+                    output_schema.clone(),
+                )?,
+            };
+            source_builder.add_source(output, source);
         }
 
         pipeline.add_pipe(source_builder.finalize());
@@ -438,6 +455,16 @@ impl HiveTable {
         Ok(res)
     }
 
+    fn get_location(&self) -> Result<&String> {
+        match &self.table_options.location {
+            Some(path) => Ok(path),
+            None => Err(ErrorCode::TableInfoError(format!(
+                "{}, table location is empty",
+                self.table_info.name
+            ))),
+        }
+    }
+
     // return items: (hdfs_location, option<part info>) where part info likes 'c_region=Asia/c_nation=China'
     #[async_backtrace::framed]
     async fn get_query_locations(
@@ -445,15 +472,7 @@ impl HiveTable {
         ctx: Arc<dyn TableContext>,
         push_downs: &Option<PushDownInfo>,
     ) -> Result<Vec<(String, Option<String>)>> {
-        let path = match &self.table_options.location {
-            Some(path) => path,
-            None => {
-                return Err(ErrorCode::TableInfoError(format!(
-                    "{}, table location is empty",
-                    self.table_info.name
-                )));
-            }
-        };
+        let path = self.get_location()?;
 
         if let Some(partition_keys) = &self.table_options.partition_keys {
             if !partition_keys.is_empty() {
@@ -527,6 +546,8 @@ impl HiveTable {
             tracing::trace!("{} hive files: {:?}", all_files.len(), all_files);
         }
 
+        // a parquet row group belongs to the split holding its middle,
+        // an orc stripe belongs to the split where it starts
         let splitter = HiveFileSplitter::create(128 * 1024 * 1024_u64);
         let partitions = splitter.get_splits(all_files);
 
@@ -543,6 +564,59 @@ impl HiveTable {
     }
 }
 
+impl HiveTable {
+    // the written files are visible once they are in the directories,
+    // the new partitions are registered to hive meta store.
+    //
+    // INSERT OVERWRITE only overwrites the written partitions, the other partitions are kept.
+    #[async_backtrace::framed]
+    async fn do_commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        files: Vec<HiveAppendedFile>,
+        overwrite: bool,
+    ) -> Result<()> {
+        let location = convert_hdfs_path(self.get_location()?, true);
+        let is_partitioned = !self.get_partition_key_sets().is_empty();
+
+        let mut partitions = BTreeMap::new();
+        let mut dirs = BTreeSet::new();
+        let mut new_files = HashSet::new();
+        if !is_partitioned {
+            dirs.insert(location.clone());
+        }
+        for file in files {
+            if let Some(name) = &file.partition_name {
+                dirs.insert(format!("{}{}/", location, name));
+                partitions
+                    .entry(name.clone())
+                    .or_insert_with(|| file.partition_values.clone());
+            }
+            new_files.insert(file.path);
+        }
+
+        if overwrite {
+            for dir in dirs.iter() {
+                remove_files_from_dir(&self.dal, dir, &new_files).await?;
+            }
+        }
+
+        if !partitions.is_empty() {
+            let hive_catalog = ctx.get_catalog(CATALOG_HIVE)?;
+            let hive_catalog = hive_catalog.as_any().downcast_ref::<HiveCatalog>().unwrap();
+            let table_info = self.table_info.desc.split('.').collect::<Vec<&str>>();
+            hive_catalog
+                .add_partitions(
+                    table_info[0].to_string(),
+                    table_info[1].to_string(),
+                    partitions.into_iter().collect(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
 #[async_trait::async_trait]
 impl Table for HiveTable {
     fn is_local(&self) -> bool {
@@ -587,19 +661,52 @@ impl Table for HiveTable {
         self.do_read2(ctx, plan, pipeline)
     }
 
+    fn append_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _: AppendMode,
+        _: bool,
+    ) -> Result<()> {
+        if self.table_options.file_format != HiveFileFormat::Parquet {
+            return Err(ErrorCode::Unimplemented(format!(
+                "insert into hive table {} stored as {} is not supported",
+                self.name(),
+                self.table_options.file_format
+            )));
+        }
+
+        let location = convert_hdfs_path(self.get_location()?, true);
+        let partition_keys = self
+            .table_options
+            .partition_keys
+            .clone()
+            .unwrap_or_default();
+        pipeline.add_sink(|input| {
+            HiveTableSink::create(
+                ctx.clone(),
+                input,
+                self.dal.clone(),
+                location.clone(),
+                self.table_info.schema(),
+                &partition_keys,
+            )
+        })
+    }
+
     #[async_backtrace::framed]
     async fn commit_insertion(
         &self,
-        _ctx: Arc<dyn TableContext>,
-        _operations: Vec<DataBlock>,
+        ctx: Arc<dyn TableContext>,
+        operations: Vec<DataBlock>,
         _copied_files: Option<UpsertTableCopiedFileReq>,
-        _overwrite: bool,
+        overwrite: bool,
     ) -> Result<()> {
-        Err(ErrorCode::Unimplemented(format!(
-            "commit_insertion operation for table {} is not implemented, table engine is {}",
-            self.name(),
-            self.get_table_info().meta.engine
-        )))
+        let files = operations
+            .iter()
+            .map(HiveAppendedFile::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        self.do_commit_insertion(ctx, files, overwrite).await
     }
 
     #[async_backtrace::framed]
@@ -774,6 +881,26 @@ async fn list_files_from_dir(
     Ok(all_files)
 }
 
+// remove the data files in the dir, except the kept ones. sub directories are not touched
+async fn remove_files_from_dir(
+    operator: &Operator,
+    dir: &str,
+    kept: &HashSet<String>,
+) -> Result<()> {
+    let (files, _) = do_list_files_from_dir(
+        operator.clone(),
+        dir.to_string(),
+        Arc::new(Semaphore::new(1)),
+    )
+    .await?;
+    for file in files {
+        if !kept.contains(&file.filename) {
+            operator.delete(&file.filename).await?;
+        }
+    }
+    Ok(())
+}
+
 async fn do_list_files_from_dir(
     operator: Operator,
     location: String,
@@ -14,21 +14,74 @@
 
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
+use std::fmt::Display;
+use std::fmt::Formatter;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
 
 pub const PARTITION_KEYS: &str = "partition_keys";
 pub const LOCATION: &str = "location";
+pub const FILE_FORMAT: &str = "file_format";
+
+const PARQUET_INPUT_FORMAT: &str = "org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat";
+const ORC_INPUT_FORMAT: &str = "org.apache.hadoop.hive.ql.io.orc.OrcInputFormat";
+
+// the format of the data files of hive table
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HiveFileFormat {
+    #[default]
+    Parquet,
+    Orc,
+}
+
+impl HiveFileFormat {
+    // the format of the input format class of hive table storage descriptor
+    pub fn from_input_format(input_format: &str) -> Result<HiveFileFormat> {
+        match input_format {
+            PARQUET_INPUT_FORMAT => Ok(HiveFileFormat::Parquet),
+            ORC_INPUT_FORMAT => Ok(HiveFileFormat::Orc),
+            _ => Err(ErrorCode::Unimplemented(format!(
+                "only support parquet and orc, {} not support",
+                input_format
+            ))),
+        }
+    }
+}
+
+impl Display for HiveFileFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HiveFileFormat::Parquet => write!(f, "parquet"),
+            HiveFileFormat::Orc => write!(f, "orc"),
+        }
+    }
+}
+
+impl TryFrom<&str> for HiveFileFormat {
+    type Error = ErrorCode;
+    fn try_from(value: &str) -> Result<HiveFileFormat> {
+        match value {
+            "parquet" => Ok(HiveFileFormat::Parquet),
+            "orc" => Ok(HiveFileFormat::Orc),
+            _ => Err(ErrorCode::Internal(format!(
+                "unknown hive file format {}",
+                value
+            ))),
+        }
+    }
+}
 
 // represents hive table schema info
 //
 // partition_keys,  hive partition keys, such as:  "p_date", "p_hour"
 // location,  hive table location, such as: hdfs://namenode:8020/user/hive/warehouse/a.db/b.table/
+// file_format,  format of the data files, parquet or orc
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HiveTableOptions {
     pub partition_keys: Option<Vec<String>>,
     pub location: Option<String>,
+    pub file_format: HiveFileFormat,
 }
 
 impl From<HiveTableOptions> for BTreeMap<String, String> {
@@ -42,6 +95,7 @@ impl From<HiveTableOptions> for BTreeMap<String, String> {
         options
             .location
             .map(|v| map.insert(LOCATION.to_string(), v));
+        map.insert(FILE_FORMAT.to_string(), options.file_format.to_string());
         map
     }
 }
@@ -61,9 +115,15 @@ impl TryFrom<&BTreeMap<String, String>> for HiveTableOptions {
             .get(LOCATION)
             .ok_or_else(|| ErrorCode::Internal("Hive engine table missing location key"))?
             .clone();
+        // tables without the key are created before orc is supported
+        let file_format = match options.get(FILE_FORMAT) {
+            Some(file_format) => HiveFileFormat::try_from(file_format.as_str())?,
+            None => HiveFileFormat::Parquet,
+        };
         let options = HiveTableOptions {
             partition_keys,
             location: Some(location),
+            file_format,
         };
         Ok(options)
     }
@@ -73,6 +133,7 @@ impl TryFrom<&BTreeMap<String, String>> for HiveTableOptions {
 mod tests {
     use std::collections::BTreeMap;
 
+    use super::HiveFileFormat;
     use super::HiveTableOptions;
 
     fn do_test_hive_table_options(hive_table_options: HiveTableOptions) {
@@ -86,6 +147,7 @@ mod tests {
         let hive_table_options = HiveTableOptions {
             partition_keys: Some(vec!["a".to_string(), "b".to_string()]),
             location: Some("test".to_string()),
+            file_format: HiveFileFormat::Parquet,
         };
 
         do_test_hive_table_options(hive_table_options);
@@ -93,7 +155,15 @@ mod tests {
         let empty = HiveTableOptions {
             partition_keys: None,
             location: Some("test".to_string()),
+            file_format: HiveFileFormat::Orc,
         };
         do_test_hive_table_options(empty);
+
+        let input_format = "org.apache.hadoop.hive.ql.io.orc.OrcInputFormat";
+        assert_eq!(
+            HiveFileFormat::from_input_format(input_format).unwrap(),
+            HiveFileFormat::Orc
+        );
+        assert!(HiveFileFormat::from_input_format("TextInputFormat").is_err());
     }
 }
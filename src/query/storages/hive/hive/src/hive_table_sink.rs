@@ -0,0 +1,345 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockMetaInfo;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sinks::AsyncSinker;
+use opendal::Operator;
+use storages_common_blocks::blocks_to_parquet;
+use storages_common_table_meta::table::TableCompression;
+use uuid::Uuid;
+
+use crate::hive_table::HIVE_DEFAULT_PARTITION;
+
+// blocks of a partition are buffered until the size, to avoid writing tiny files
+const DATA_FILE_BUFFER_SIZE: usize = 64 * 1024 * 1024;
+
+// a data file written by the insertion, committed by the table later
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct HiveAppendedFile {
+    // path of the file, relative to the storage root
+    pub path: String,
+    // partition of the file, like 'c_region=ASIA/c_nation=CHINA'
+    pub partition_name: Option<String>,
+    // values of the partition keys, like ['ASIA', 'CHINA']
+    pub partition_values: Vec<String>,
+}
+
+impl From<HiveAppendedFile> for DataBlock {
+    fn from(value: HiveAppendedFile) -> Self {
+        DataBlock::new_with_meta(vec![], 0, Some(Box::new(value)))
+    }
+}
+
+impl TryFrom<&DataBlock> for HiveAppendedFile {
+    type Error = ErrorCode;
+    fn try_from(block: &DataBlock) -> Result<Self, Self::Error> {
+        block
+            .get_meta()
+            .and_then(HiveAppendedFile::downcast_ref_from)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "invalid data block meta of hive insertion, {:?}",
+                    block.get_meta()
+                ))
+            })
+    }
+}
+
+#[typetag::serde(name = "hive_appended_file")]
+impl BlockMetaInfo for HiveAppendedFile {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        match HiveAppendedFile::downcast_ref_from(info) {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+// escape the characters not allowed in path names, the way of hive `FileUtils.escapePathName`
+pub fn escape_path_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '\u{01}'..='\u{1F}'
+            | '"'
+            | '#'
+            | '%'
+            | '\''
+            | '*'
+            | '/'
+            | ':'
+            | '='
+            | '?'
+            | '\\'
+            | '\u{7F}'
+            | '{'
+            | '['
+            | ']'
+            | '^' => escaped.push_str(&format!("%{:02X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// partition name like 'c_region=ASIA/c_nation=CHINA'
+pub fn partition_name(keys: &[String], values: &[String]) -> String {
+    keys.iter()
+        .zip(values.iter())
+        .map(|(k, v)| format!("{}={}", escape_path_name(k), escape_path_name(v)))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+// value of partition key in the partition name, only strings and numbers are supported,
+// the same as the partition keys can be read.
+fn partition_value(value: ScalarRef) -> Result<String> {
+    match value {
+        ScalarRef::Null => Ok(HIVE_DEFAULT_PARTITION.to_string()),
+        ScalarRef::String(v) if v.is_empty() => Ok(HIVE_DEFAULT_PARTITION.to_string()),
+        ScalarRef::String(v) => Ok(String::from_utf8_lossy(v).to_string()),
+        ScalarRef::Number(v) => Ok(v.to_string()),
+        v => Err(ErrorCode::Unimplemented(format!(
+            "hive partition value {} is not supported",
+            v
+        ))),
+    }
+}
+
+#[derive(Default)]
+struct PartitionBuffer {
+    blocks: Vec<DataBlock>,
+    bytes: usize,
+}
+
+// writes the blocks into parquet files under the directories of partitions
+pub struct HiveTableSink {
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    // directory of the table, ends with '/'
+    location: String,
+    partition_keys: Vec<String>,
+    // offsets of the partition columns in the blocks
+    partition_columns: Vec<usize>,
+    // offsets of the other columns, they are written into files
+    data_columns: Vec<usize>,
+    data_schema: TableSchemaRef,
+    buffers: HashMap<Vec<String>, PartitionBuffer>,
+}
+
+impl HiveTableSink {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        input: Arc<InputPort>,
+        dal: Operator,
+        location: String,
+        schema: TableSchemaRef,
+        partition_keys: &[String],
+    ) -> Result<ProcessorPtr> {
+        let mut partition_columns = Vec::with_capacity(partition_keys.len());
+        for key in partition_keys {
+            partition_columns.push(schema.index_of(key)?);
+        }
+        let data_columns = (0..schema.num_fields())
+            .filter(|i| !partition_columns.contains(i))
+            .collect::<Vec<_>>();
+        let data_schema = Arc::new(TableSchema::new(
+            data_columns
+                .iter()
+                .map(|i| schema.field(*i).clone())
+                .collect(),
+        ));
+
+        Ok(ProcessorPtr::create(AsyncSinker::create(
+            input,
+            HiveTableSink {
+                ctx,
+                dal,
+                location,
+                partition_keys: partition_keys.to_vec(),
+                partition_columns,
+                data_columns,
+                data_schema,
+                buffers: HashMap::new(),
+            },
+        )))
+    }
+
+    // split the block by the values of partition keys, the partition columns are removed
+    fn split_block(&self, block: DataBlock) -> Result<Vec<(Vec<String>, DataBlock)>> {
+        let num_rows = block.num_rows();
+        let data_block = DataBlock::new(
+            self.data_columns
+                .iter()
+                .map(|i| block.get_by_offset(*i).clone())
+                .collect(),
+            num_rows,
+        );
+
+        // static partition values are constant
+        let is_constant = self
+            .partition_columns
+            .iter()
+            .all(|i| matches!(block.get_by_offset(*i).value, Value::Scalar(_)));
+        if is_constant {
+            let values = self
+                .partition_columns
+                .iter()
+                .map(|i| partition_value(block.get_by_offset(*i).value.index(0).unwrap()))
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(vec![(values, data_block)]);
+        }
+
+        let mut groups: Vec<(Vec<String>, Vec<u32>)> = vec![];
+        let mut group_index: HashMap<Vec<String>, usize> = HashMap::new();
+        for row in 0..num_rows {
+            let values = self
+                .partition_columns
+                .iter()
+                .map(|i| partition_value(block.get_by_offset(*i).value.index(row).unwrap()))
+                .collect::<Result<Vec<_>>>()?;
+            match group_index.get(&values) {
+                Some(index) => groups[*index].1.push(row as u32),
+                None => {
+                    group_index.insert(values.clone(), groups.len());
+                    groups.push((values, vec![row as u32]));
+                }
+            }
+        }
+
+        if groups.len() == 1 {
+            let (values, _) = groups.pop().unwrap();
+            return Ok(vec![(values, data_block)]);
+        }
+        groups
+            .into_iter()
+            .map(|(values, rows)| Ok((values, data_block.take(&rows)?)))
+            .collect()
+    }
+
+    // write the buffered blocks of the partition as a file
+    #[async_backtrace::framed]
+    async fn flush(&mut self, values: Vec<String>) -> Result<()> {
+        let buffer = match self.buffers.remove(&values) {
+            Some(buffer) if !buffer.blocks.is_empty() => buffer,
+            _ => return Ok(()),
+        };
+
+        let partition_name = if self.partition_keys.is_empty() {
+            None
+        } else {
+            Some(partition_name(&self.partition_keys, &values))
+        };
+        let dir = match &partition_name {
+            Some(name) => format!("{}{}/", self.location, name),
+            None => self.location.clone(),
+        };
+        let path = format!("{}{}.parquet", dir, Uuid::new_v4().simple());
+
+        let mut data = Vec::with_capacity(buffer.bytes);
+        blocks_to_parquet(
+            &self.data_schema,
+            buffer.blocks,
+            &mut data,
+            TableCompression::Snappy,
+        )?;
+        self.dal.write(&path, data).await?;
+
+        let file = HiveAppendedFile {
+            path,
+            partition_name,
+            partition_values: values,
+        };
+        self.ctx.push_precommit_block(file.into());
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncSink for HiveTableSink {
+    const NAME: &'static str = "HiveTableSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        let partitions = self.buffers.keys().cloned().collect::<Vec<_>>();
+        for values in partitions {
+            self.flush(values).await?;
+        }
+        Ok(())
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if data_block.num_rows() == 0 {
+            return Ok(false);
+        }
+
+        for (values, block) in self.split_block(data_block)? {
+            let buffer = self.buffers.entry(values.clone()).or_default();
+            buffer.bytes += block.memory_size();
+            buffer.blocks.push(block);
+            if buffer.bytes >= DATA_FILE_BUFFER_SIZE {
+                self.flush(values).await?;
+            }
+        }
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::escape_path_name;
+    use super::partition_name;
+
+    #[test]
+    fn test_partition_name() {
+        assert_eq!(escape_path_name("ASIA"), "ASIA");
+        assert_eq!(escape_path_name("a/b=c:d%"), "a%2Fb%3Dc%3Ad%25");
+
+        let keys = vec!["c_region".to_string(), "c_date".to_string()];
+        let values = vec!["ASIA".to_string(), "2023-01-01 10:00".to_string()];
+        assert_eq!(
+            partition_name(&keys, &values),
+            "c_region=ASIA/c_date=2023-01-01 10%3A00"
+        );
+    }
+}
@@ -38,6 +38,8 @@ use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::SetDatabaseOwnerReply;
+use common_meta_app::schema::SetDatabaseOwnerReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -51,12 +53,16 @@ use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_app::storage::StorageParams;
 use common_meta_types::MetaId;
 use common_storage::DataOperator;
 use futures::TryStreamExt;
 use opendal::Metakey;
 
 use crate::database::IcebergDatabase;
+use crate::meta_writer::new_table_metadata;
+use crate::meta_writer::write_metadata;
+use crate::table::IcebergTable;
 
 pub const ICEBERG_CATALOG: &str = "iceberg";
 
@@ -128,24 +134,18 @@ impl IcebergCatalog {
         }
         Ok(dbs)
     }
-}
 
-#[async_trait]
-impl Catalog for IcebergCatalog {
-    #[tracing::instrument(level = "debug", skip(self))]
+    /// operator pointing to the directory of the database
     #[async_backtrace::framed]
-    async fn get_database(&self, _tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
+    async fn database_root(&self, db_name: &str) -> Result<DataOperator> {
         if self.flatten {
-            // is flatten catalog, must return `default` catalog
+            // is flatten catalog, must be `default` catalog
             if db_name != "default" {
                 return Err(ErrorCode::UnknownDatabase(format!(
                     "Database {db_name} does not exist"
                 )));
             }
-            let tbl: Arc<dyn Database> = Arc::new(
-                IcebergDatabase::create_database_omitted_default(&self.name, self.operator.clone()),
-            );
-            return Ok(tbl);
+            return Ok(self.operator.clone());
         }
 
         let rel_path = format!("{db_name}/");
@@ -162,7 +162,38 @@ impl Catalog for IcebergCatalog {
             .operator
             .params()
             .map_root(|root| format!("{root}{rel_path}"));
-        let db_root = DataOperator::try_create(&db_sp).await?;
+        DataOperator::try_create(&db_sp).await
+    }
+}
+
+/// absolute location of the table written in the metadata
+fn table_location(sp: &StorageParams) -> Result<String> {
+    let location = match sp {
+        StorageParams::Fs(cfg) => cfg.root.clone(),
+        StorageParams::S3(cfg) => format!("s3://{}{}", cfg.bucket, cfg.root),
+        StorageParams::Gcs(cfg) => format!("gs://{}{}", cfg.bucket, cfg.root),
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "creating iceberg tables on storage {sp} is not supported"
+            )));
+        }
+    };
+    Ok(location.trim_end_matches('/').to_string())
+}
+
+#[async_trait]
+impl Catalog for IcebergCatalog {
+    #[tracing::instrument(level = "debug", skip(self))]
+    #[async_backtrace::framed]
+    async fn get_database(&self, _tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
+        let db_root = self.database_root(db_name).await?;
+        if self.flatten {
+            // is flatten catalog, return `default` catalog
+            let tbl: Arc<dyn Database> = Arc::new(
+                IcebergDatabase::create_database_omitted_default(&self.name, db_root),
+            );
+            return Ok(tbl);
+        }
 
         Ok(Arc::new(IcebergDatabase::create_database_from_read(
             &self.name, db_name, db_root,
@@ -194,10 +225,16 @@ impl Catalog for IcebergCatalog {
         unimplemented!()
     }
 
-    fn get_table_by_info(&self, _table_info: &TableInfo) -> Result<Arc<dyn Table>> {
+    #[async_backtrace::framed]
+    async fn set_database_owner(&self, _req: SetDatabaseOwnerReq) -> Result<SetDatabaseOwnerReply> {
         unimplemented!()
     }
 
+    fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
+        let table = IcebergTable::try_create(table_info.clone())?;
+        Ok(Arc::new(table))
+    }
+
     #[async_backtrace::framed]
     async fn get_table_meta_by_id(
         &self,
@@ -233,9 +270,27 @@ impl Catalog for IcebergCatalog {
         unimplemented!()
     }
 
+    /// create an unpartitioned table of format v2 without snapshots
     #[async_backtrace::framed]
-    async fn create_table(&self, _req: CreateTableReq) -> Result<()> {
-        unimplemented!()
+    async fn create_table(&self, req: CreateTableReq) -> Result<()> {
+        let db_root = self.database_root(&req.name_ident.db_name).await?;
+        let table_name = &req.name_ident.table_name;
+        let rel_path = format!("{table_name}/");
+        if db_root.operator().is_exist(&rel_path).await? {
+            return if req.if_not_exists {
+                Ok(())
+            } else {
+                Err(ErrorCode::TableAlreadyExists(format!(
+                    "Table '{}'.'{table_name}' already exists",
+                    req.name_ident.db_name
+                )))
+            };
+        }
+
+        let table_sp = db_root.params().map_root(|r| format!("{r}{rel_path}"));
+        let metadata = new_table_metadata(&table_location(&table_sp)?, &req.table_meta.schema)?;
+        let tbl_root = DataOperator::try_create(&table_sp).await?;
+        write_metadata(&tbl_root.operator(), 1, &metadata).await
     }
 
     #[async_backtrace::framed]
@@ -329,7 +384,13 @@ impl Catalog for IcebergCatalog {
     }
 
     // Get table engines
+    //
+    // Tables are created with the default engine in SQL, which are created as iceberg tables.
     fn get_table_engines(&self) -> Vec<StorageDescription> {
-        unimplemented!()
+        vec![StorageDescription {
+            engine_name: "FUSE".to_string(),
+            comment: "ICEBERG tables in the iceberg catalog".to_string(),
+            support_cluster_key: false,
+        }]
     }
 }
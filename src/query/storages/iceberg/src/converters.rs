@@ -15,10 +15,17 @@
 //! this module is used for converting iceberg data types, shemas and other metadata
 //! to databend
 
+use apache_avro::types::Value as AvroValue;
 use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
 use common_expression::types::DecimalDataType;
 use common_expression::types::NumberDataType;
+use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
@@ -30,16 +37,22 @@ use iceberg_rs::model::schema::SchemaV2;
 use iceberg_rs::model::schema::StructField;
 use iceberg_rs::model::table::TableMetadata;
 use itertools::Itertools;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::manifest::unwrap_union;
 
 /// generate TableMeta from Iceberg table meta
+///
+/// The schema at `schema_index` is taken, or the last one if not found.
 pub(crate) fn meta_iceberg_to_databend(
     catalog: &str,
     storage_params: &StorageParams,
     meta: &TableMetadata,
+    schema_index: Option<usize>,
 ) -> TableMeta {
-    let meta = meta.clone().to_latest();
-    let schema = match meta.schemas.last() {
-        Some(scm) => schema_iceberg_to_databend(scm),
+    let schema = match iceberg_schema(meta, schema_index) {
+        Some(scm) => schema_iceberg_to_databend(&scm),
         // empty schema
         None => TableSchema::empty(),
     }
@@ -55,6 +68,29 @@ pub(crate) fn meta_iceberg_to_databend(
     }
 }
 
+/// the iceberg schema at `schema_index`, or the last one if not found
+pub(crate) fn iceberg_schema(
+    meta: &TableMetadata,
+    schema_index: Option<usize>,
+) -> Option<SchemaV2> {
+    let mut schemas = meta.clone().to_latest().schemas;
+    match schema_index {
+        Some(i) if i < schemas.len() => Some(schemas.swap_remove(i)),
+        _ => schemas.pop(),
+    }
+}
+
+/// ids of the top level fields, in the order of the converted schema
+pub(crate) fn field_ids_iceberg(schema: &SchemaV2) -> Vec<i32> {
+    schema
+        .struct_fields
+        .fields
+        .iter()
+        .map(|f| f.id)
+        .sorted()
+        .collect()
+}
+
 /// generate databend DataSchema from Iceberg
 pub(super) fn schema_iceberg_to_databend(schema: &SchemaV2) -> TableSchema {
     let fields = schema
@@ -84,7 +120,7 @@ fn primitive_iceberg_to_databend(prim: &AllType) -> TableDataType {
         iceberg_rs::model::schema::AllType::Primitive(p) => match p {
             iceberg_rs::model::schema::PrimitiveType::Boolean => TableDataType::Boolean,
             iceberg_rs::model::schema::PrimitiveType::Int => {
-                TableDataType::Number(NumberDataType::UInt32)
+                TableDataType::Number(NumberDataType::Int32)
             }
             iceberg_rs::model::schema::PrimitiveType::Long => {
                 TableDataType::Number(NumberDataType::Int64)
@@ -157,13 +193,164 @@ fn primitive_iceberg_to_databend(prim: &AllType) -> TableDataType {
     }
 }
 
+/// generate the iceberg schema of tables created in the catalog
+///
+/// Field ids are assigned from 1 in the order of the columns,
+/// only the columns of primitive types are supported.
+pub(crate) fn schema_databend_to_iceberg(schema: &TableSchema) -> Result<JsonValue> {
+    let fields = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let ty = primitive_databend_to_iceberg(field.data_type()).ok_or_else(|| {
+                ErrorCode::Unimplemented(format!(
+                    "column {} of type {} is not supported by iceberg tables",
+                    field.name(),
+                    field.data_type()
+                ))
+            })?;
+            Ok(json!({
+                "id": i + 1,
+                "name": field.name(),
+                "required": !field.is_nullable(),
+                "type": ty,
+            }))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(json!({
+        "type": "struct",
+        "schema-id": 0,
+        "fields": fields,
+    }))
+}
+
+fn primitive_databend_to_iceberg(data_type: &TableDataType) -> Option<String> {
+    let ty = match data_type.remove_nullable() {
+        TableDataType::Boolean => "boolean".to_string(),
+        TableDataType::Number(num) => match num {
+            NumberDataType::Int8
+            | NumberDataType::Int16
+            | NumberDataType::Int32
+            | NumberDataType::UInt8
+            | NumberDataType::UInt16 => "int".to_string(),
+            NumberDataType::Int64 | NumberDataType::UInt32 => "long".to_string(),
+            NumberDataType::Float32 => "float".to_string(),
+            NumberDataType::Float64 => "double".to_string(),
+            // out of the range of long
+            NumberDataType::UInt64 => return None,
+        },
+        TableDataType::Decimal(decimal) => {
+            let size = decimal.size();
+            format!("decimal({}, {})", size.precision, size.scale)
+        }
+        TableDataType::Date => "date".to_string(),
+        TableDataType::Timestamp => "timestamp".to_string(),
+        TableDataType::String => "string".to_string(),
+        _ => return None,
+    };
+    Some(ty)
+}
+
+/// encode a lower or upper bound in the single-value binary serialization of iceberg
+///
+/// The reverse of `bound_iceberg_to_scalar`, `None` is returned for the other types.
+pub(crate) fn scalar_to_bound_iceberg(scalar: &Scalar) -> Option<Vec<u8>> {
+    let bound = match scalar {
+        Scalar::Number(NumberScalar::Int32(v)) => v.to_le_bytes().to_vec(),
+        Scalar::Number(NumberScalar::Int64(v)) => v.to_le_bytes().to_vec(),
+        Scalar::Number(NumberScalar::Float32(v)) if !v.is_nan() => v.to_le_bytes().to_vec(),
+        Scalar::Number(NumberScalar::Float64(v)) if !v.is_nan() => v.to_le_bytes().to_vec(),
+        Scalar::Date(v) => v.to_le_bytes().to_vec(),
+        Scalar::Timestamp(v) => v.to_le_bytes().to_vec(),
+        Scalar::String(v) => v.clone(),
+        _ => return None,
+    };
+    Some(bound)
+}
+
+/// decode a lower or upper bound in the single-value binary serialization of iceberg
+///
+/// `None` is returned for the types not used for pruning.
+pub(crate) fn bound_iceberg_to_scalar(bound: &[u8], data_type: &TableDataType) -> Option<Scalar> {
+    let scalar = match data_type.remove_nullable() {
+        TableDataType::Number(NumberDataType::Int32) => Scalar::Number(NumberScalar::Int32(
+            i32::from_le_bytes(bound.try_into().ok()?),
+        )),
+        TableDataType::Number(NumberDataType::Int64) => Scalar::Number(NumberScalar::Int64(
+            i64::from_le_bytes(bound.try_into().ok()?),
+        )),
+        TableDataType::Number(NumberDataType::Float32) => Scalar::Number(NumberScalar::Float32(
+            F32::from(f32::from_le_bytes(bound.try_into().ok()?)),
+        )),
+        TableDataType::Number(NumberDataType::Float64) => Scalar::Number(NumberScalar::Float64(
+            F64::from(f64::from_le_bytes(bound.try_into().ok()?)),
+        )),
+        // days since epoch
+        TableDataType::Date => Scalar::Date(i32::from_le_bytes(bound.try_into().ok()?)),
+        // microseconds since epoch
+        TableDataType::Timestamp => Scalar::Timestamp(i64::from_le_bytes(bound.try_into().ok()?)),
+        TableDataType::String => Scalar::String(bound.to_vec()),
+        _ => return None,
+    };
+    Some(scalar)
+}
+
+/// convert a partition value of identity transform
+///
+/// `None` is returned for the types not used for pruning.
+pub(crate) fn partition_iceberg_to_scalar(
+    value: &AvroValue,
+    data_type: &TableDataType,
+) -> Option<Scalar> {
+    let scalar = match (unwrap_union(value), data_type.remove_nullable()) {
+        (AvroValue::Null, _) if data_type.is_nullable() => Scalar::Null,
+        (AvroValue::Int(v), TableDataType::Number(NumberDataType::Int32)) => {
+            Scalar::Number(NumberScalar::Int32(*v))
+        }
+        (AvroValue::Long(v), TableDataType::Number(NumberDataType::Int64)) => {
+            Scalar::Number(NumberScalar::Int64(*v))
+        }
+        (AvroValue::Float(v), TableDataType::Number(NumberDataType::Float32)) => {
+            Scalar::Number(NumberScalar::Float32(F32::from(*v)))
+        }
+        (AvroValue::Double(v), TableDataType::Number(NumberDataType::Float64)) => {
+            Scalar::Number(NumberScalar::Float64(F64::from(*v)))
+        }
+        (AvroValue::Int(v) | AvroValue::Date(v), TableDataType::Date) => Scalar::Date(*v),
+        (AvroValue::Long(v) | AvroValue::TimestampMicros(v), TableDataType::Timestamp) => {
+            Scalar::Timestamp(*v)
+        }
+        (AvroValue::TimestampMillis(v), TableDataType::Timestamp) => Scalar::Timestamp(*v * 1000),
+        (AvroValue::String(v), TableDataType::String) => Scalar::String(v.as_bytes().to_vec()),
+        (AvroValue::Bytes(v) | AvroValue::Fixed(_, v), TableDataType::String) => {
+            Scalar::String(v.clone())
+        }
+        _ => return None,
+    };
+    Some(scalar)
+}
+
 #[cfg(test)]
 mod convert_test {
+    use apache_avro::types::Value as AvroValue;
+    use common_expression::types::number::NumberScalar;
+    use common_expression::types::number::F64;
+    use common_expression::types::NumberDataType;
+    use common_expression::Scalar;
+    use common_expression::TableDataType;
+    use common_expression::TableField;
+    use common_expression::TableSchema;
     use common_meta_app::storage::StorageFsConfig;
     use common_meta_app::storage::StorageParams;
     use iceberg_rs::model::table::TableMetadata;
 
+    use super::bound_iceberg_to_scalar;
     use super::meta_iceberg_to_databend;
+    use super::partition_iceberg_to_scalar;
+    use super::scalar_to_bound_iceberg;
+    use super::schema_databend_to_iceberg;
 
     /// example metadata file
     const METADATA_FILE: &str = r#"
@@ -229,9 +416,115 @@ mod convert_test {
             root: "/".to_string(),
         });
 
-        let converted = meta_iceberg_to_databend("ctl", &mock_sp, &metadata);
+        let converted = meta_iceberg_to_databend("ctl", &mock_sp, &metadata, None);
 
         assert_eq!(converted.engine, "iceberg");
         assert_eq!(converted.catalog, "ctl");
     }
+
+    #[test]
+    fn test_decode_bounds() {
+        let int = TableDataType::Number(NumberDataType::Int32);
+        assert_eq!(
+            bound_iceberg_to_scalar(&[1, 1, 0, 0], &int),
+            Some(Scalar::Number(NumberScalar::Int32(257)))
+        );
+        // malformed bound
+        assert_eq!(bound_iceberg_to_scalar(&[1, 1, 0], &int), None);
+
+        let long = TableDataType::Number(NumberDataType::Int64).wrap_nullable();
+        assert_eq!(
+            bound_iceberg_to_scalar(&(-2i64).to_le_bytes(), &long),
+            Some(Scalar::Number(NumberScalar::Int64(-2)))
+        );
+        assert_eq!(
+            bound_iceberg_to_scalar(
+                &1.5f64.to_le_bytes(),
+                &TableDataType::Number(NumberDataType::Float64)
+            ),
+            Some(Scalar::Number(NumberScalar::Float64(F64::from(1.5))))
+        );
+        assert_eq!(
+            bound_iceberg_to_scalar(&19000i32.to_le_bytes(), &TableDataType::Date),
+            Some(Scalar::Date(19000))
+        );
+        assert_eq!(
+            bound_iceberg_to_scalar(b"abc", &TableDataType::String),
+            Some(Scalar::String(b"abc".to_vec()))
+        );
+        assert_eq!(bound_iceberg_to_scalar(&[1], &TableDataType::Boolean), None);
+    }
+
+    #[test]
+    fn test_encode_bounds() {
+        let scalars = [
+            (
+                Scalar::Number(NumberScalar::Int32(-3)),
+                TableDataType::Number(NumberDataType::Int32),
+            ),
+            (
+                Scalar::Number(NumberScalar::Float64(F64::from(2.5))),
+                TableDataType::Number(NumberDataType::Float64),
+            ),
+            (Scalar::Timestamp(1_000_000), TableDataType::Timestamp),
+            (Scalar::String(b"abc".to_vec()), TableDataType::String),
+        ];
+        for (scalar, data_type) in scalars {
+            let bound = scalar_to_bound_iceberg(&scalar).unwrap();
+            assert_eq!(bound_iceberg_to_scalar(&bound, &data_type), Some(scalar));
+        }
+
+        assert_eq!(
+            scalar_to_bound_iceberg(&Scalar::Number(NumberScalar::Float64(F64::from(f64::NAN)))),
+            None
+        );
+        assert_eq!(scalar_to_bound_iceberg(&Scalar::Boolean(true)), None);
+    }
+
+    #[test]
+    fn test_convert_schema_to_iceberg() {
+        let schema = TableSchema::new(vec![
+            TableField::new("a", TableDataType::Number(NumberDataType::Int16)),
+            TableField::new("b", TableDataType::String.wrap_nullable()),
+        ]);
+        let iceberg = schema_databend_to_iceberg(&schema).unwrap();
+        assert_eq!(iceberg["fields"][0]["id"], 1);
+        assert_eq!(iceberg["fields"][0]["type"], "int");
+        assert_eq!(iceberg["fields"][0]["required"], true);
+        assert_eq!(iceberg["fields"][1]["name"], "b");
+        assert_eq!(iceberg["fields"][1]["type"], "string");
+        assert_eq!(iceberg["fields"][1]["required"], false);
+
+        let schema = TableSchema::new(vec![TableField::new(
+            "c",
+            TableDataType::Number(NumberDataType::UInt64),
+        )]);
+        assert!(schema_databend_to_iceberg(&schema).is_err());
+    }
+
+    #[test]
+    fn test_convert_partition_values() {
+        let int = TableDataType::Number(NumberDataType::Int32);
+        assert_eq!(
+            partition_iceberg_to_scalar(&AvroValue::Int(3), &int),
+            Some(Scalar::Number(NumberScalar::Int32(3)))
+        );
+        assert_eq!(
+            partition_iceberg_to_scalar(
+                &AvroValue::Union(0, Box::new(AvroValue::Null)),
+                &int.wrap_nullable()
+            ),
+            Some(Scalar::Null)
+        );
+        // null is not a valid value for not null columns
+        assert_eq!(partition_iceberg_to_scalar(&AvroValue::Null, &int), None);
+        assert_eq!(
+            partition_iceberg_to_scalar(&AvroValue::Date(10), &TableDataType::Date),
+            Some(Scalar::Date(10))
+        );
+        assert_eq!(
+            partition_iceberg_to_scalar(&AvroValue::TimestampMillis(2), &TableDataType::Timestamp),
+            Some(Scalar::Timestamp(2000))
+        );
+    }
 }
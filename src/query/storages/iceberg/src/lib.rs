@@ -86,6 +86,31 @@
 //! ```sql
 //! SELECT * FROM icb_ctl.default.icbg_tbl_0;
 //! ```
+//!
+//! ## Snapshots
+//!
+//! The current snapshot is read by default, earlier snapshots can be read with time travel:
+//! ```sql
+//! SELECT * FROM icb_ctl.db0.tbl1 AT (SNAPSHOT => '3051729675574597004');
+//! SELECT * FROM icb_ctl.db0.tbl1 AT (TIMESTAMP => '2023-01-01 00:00:00'::TIMESTAMP);
+//! ```
+//!
+//! Manifests and data files are pruned with the partition summaries of identity partitions
+//! and the column statistics, and the position and equality delete files of format v2
+//! are applied when reading the data files. Only parquet files are supported.
+//!
+//! ## Writing
+//!
+//! Tables can be created in the catalog, and written into by `INSERT` or `CREATE TABLE ... AS SELECT`:
+//! ```sql
+//! CREATE TABLE icb_ctl.db0.tbl2 AS SELECT * FROM icb_ctl.db0.tbl1;
+//! INSERT INTO icb_ctl.db0.tbl2 VALUES (1, 'a');
+//! INSERT OVERWRITE icb_ctl.db0.tbl2 VALUES (2, 'b');
+//! ```
+//!
+//! The data is written as parquet files, and committed as a new snapshot the way of
+//! filesystem (Hadoop) catalogs, by writing `metadata/v{version}.metadata.json` and
+//! `metadata/version-hint.text`. Partitioned tables can't be written into yet.
 
 /// the Iceberg Catalog implementation
 mod catalog;
@@ -94,11 +119,19 @@ mod converters;
 /// database implementation
 mod database;
 /// reading manifestlist and manifest files
-#[allow(unused)]
 mod manifest;
 /// table metadata reader
-#[allow(unused)]
 mod meta_reader;
+/// table metadata writer
+mod meta_writer;
+/// parts of the data files to read
+mod partition;
+/// pruning manifests and data files
+mod pruning;
+/// writing data files
+mod sink;
+/// reading data files with the deletes applied
+mod source;
 /// table implementation
 mod table;
 
@@ -12,56 +12,858 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Manifest lists and manifests are avro files,
+//! only the fields used for planning the scan are read.
+//!
+//! The files are written in the format of v2 with unpartitioned data files,
+//! which can be read as v1 as well.
+
 use std::collections::HashMap;
 
-use serde::Deserialize;
+use apache_avro::types::Value;
+use apache_avro::Reader;
+use apache_avro::Schema;
+use apache_avro::Writer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+
+/// `content` of manifests and data files holding data
+pub(crate) const CONTENT_DATA: i32 = 0;
+/// `content` of data files holding position deletes
+pub(crate) const CONTENT_POSITION_DELETES: i32 = 1;
+/// `content` of data files holding equality deletes
+pub(crate) const CONTENT_EQUALITY_DELETES: i32 = 2;
+
+/// `status` of manifest entries added in the snapshot
+pub(crate) const STATUS_ADDED: i32 = 1;
+/// `status` of manifest entries removed in the snapshot
+pub(crate) const STATUS_DELETED: i32 = 2;
+
+/// schema of the manifest list files written
+const MANIFEST_LIST_SCHEMA: &str = r#"
+{
+    "type": "record",
+    "name": "manifest_file",
+    "fields": [
+        { "name": "manifest_path", "type": "string", "field-id": 500 },
+        { "name": "manifest_length", "type": "long", "field-id": 501 },
+        { "name": "partition_spec_id", "type": "int", "field-id": 502 },
+        { "name": "content", "type": "int", "field-id": 517 },
+        { "name": "sequence_number", "type": "long", "field-id": 515 },
+        { "name": "min_sequence_number", "type": "long", "field-id": 516 },
+        { "name": "added_snapshot_id", "type": "long", "field-id": 503 },
+        { "name": "added_files_count", "type": "int", "field-id": 504 },
+        { "name": "existing_files_count", "type": "int", "field-id": 505 },
+        { "name": "deleted_files_count", "type": "int", "field-id": 506 },
+        { "name": "added_rows_count", "type": "long", "field-id": 512 },
+        { "name": "existing_rows_count", "type": "long", "field-id": 513 },
+        { "name": "deleted_rows_count", "type": "long", "field-id": 514 },
+        {
+            "name": "partitions",
+            "type": [
+                "null",
+                {
+                    "type": "array",
+                    "items": {
+                        "type": "record",
+                        "name": "r508",
+                        "fields": [
+                            { "name": "contains_null", "type": "boolean", "field-id": 509 },
+                            { "name": "lower_bound", "type": ["null", "bytes"], "default": null, "field-id": 510 },
+                            { "name": "upper_bound", "type": ["null", "bytes"], "default": null, "field-id": 511 }
+                        ]
+                    },
+                    "element-id": 508
+                }
+            ],
+            "default": null,
+            "field-id": 507
+        }
+    ]
+}
+"#;
+
+/// schema of the manifest files written, with an empty partition tuple
+const MANIFEST_SCHEMA: &str = r#"
+{
+    "type": "record",
+    "name": "manifest_entry",
+    "fields": [
+        { "name": "status", "type": "int", "field-id": 0 },
+        { "name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1 },
+        { "name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3 },
+        { "name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4 },
+        {
+            "name": "data_file",
+            "type": {
+                "type": "record",
+                "name": "r2",
+                "fields": [
+                    { "name": "content", "type": "int", "field-id": 134 },
+                    { "name": "file_path", "type": "string", "field-id": 100 },
+                    { "name": "file_format", "type": "string", "field-id": 101 },
+                    {
+                        "name": "partition",
+                        "type": { "type": "record", "name": "r102", "fields": [] },
+                        "field-id": 102
+                    },
+                    { "name": "record_count", "type": "long", "field-id": 103 },
+                    { "name": "file_size_in_bytes", "type": "long", "field-id": 104 },
+                    {
+                        "name": "null_value_counts",
+                        "type": [
+                            "null",
+                            {
+                                "type": "array",
+                                "items": {
+                                    "type": "record",
+                                    "name": "k121_v122",
+                                    "fields": [
+                                        { "name": "key", "type": "int", "field-id": 121 },
+                                        { "name": "value", "type": "long", "field-id": 122 }
+                                    ]
+                                },
+                                "logicalType": "map"
+                            }
+                        ],
+                        "default": null,
+                        "field-id": 110
+                    },
+                    {
+                        "name": "lower_bounds",
+                        "type": [
+                            "null",
+                            {
+                                "type": "array",
+                                "items": {
+                                    "type": "record",
+                                    "name": "k126_v127",
+                                    "fields": [
+                                        { "name": "key", "type": "int", "field-id": 126 },
+                                        { "name": "value", "type": "bytes", "field-id": 127 }
+                                    ]
+                                },
+                                "logicalType": "map"
+                            }
+                        ],
+                        "default": null,
+                        "field-id": 125
+                    },
+                    {
+                        "name": "upper_bounds",
+                        "type": [
+                            "null",
+                            {
+                                "type": "array",
+                                "items": {
+                                    "type": "record",
+                                    "name": "k129_v130",
+                                    "fields": [
+                                        { "name": "key", "type": "int", "field-id": 129 },
+                                        { "name": "value", "type": "bytes", "field-id": 130 }
+                                    ]
+                                },
+                                "logicalType": "map"
+                            }
+                        ],
+                        "default": null,
+                        "field-id": 128
+                    }
+                ]
+            },
+            "field-id": 2
+        }
+    ]
+}
+"#;
 
 /// item in manifest list file
-/// read manifest file by this struct
-#[derive(Clone, Debug, Deserialize)]
-pub(crate) struct ManifestPtr {
+#[derive(Clone, Debug)]
+pub(crate) struct ManifestFile {
     pub manifest_path: String,
-    pub manifest_length: usize,
-    pub partition_spec_id: usize,
-    pub added_snapshot_id: usize,
-    pub existing_data_files_count: usize,
-    pub deleted_data_files_count: usize,
-    pub partitions: Vec<ManiPart>,
-    pub added_rows_count: usize,
-    pub existing_rows_count: usize,
-    pub deleted_rows_count: usize,
-}
-
-/// item of manifest spec in `ManifestPtr`
-#[derive(Clone, Debug, Deserialize)]
-pub(crate) struct ManiPart {
+    pub partition_spec_id: i32,
+    /// data or deletes, always data in v1
+    pub content: i32,
+    /// sequence number when the manifest was added, always 0 in v1
+    pub sequence_number: i64,
+    /// summaries of the partition fields, in the order of the partition spec
+    pub partitions: Vec<FieldSummary>,
+    // the fields below are only kept to be written to the manifest lists of later snapshots
+    pub manifest_length: i64,
+    pub min_sequence_number: i64,
+    pub added_snapshot_id: i64,
+    pub added_files_count: i32,
+    pub existing_files_count: i32,
+    pub deleted_files_count: i32,
+    pub added_rows_count: i64,
+    pub existing_rows_count: i64,
+    pub deleted_rows_count: i64,
+}
+
+/// summary of a partition field in `ManifestFile`
+#[derive(Clone, Debug)]
+pub(crate) struct FieldSummary {
     pub contains_null: bool,
-    pub lower_bound: String,
-    pub upper_bound: String,
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
 }
 
-/// manifest file
-#[derive(Clone, Debug, Deserialize)]
-pub(crate) struct Manifest {
-    pub status: isize,
-    pub snapshot_id: i64,
+/// item in manifest file
+#[derive(Clone, Debug)]
+pub(crate) struct ManifestEntry {
+    pub status: i32,
+    /// `None` if inherited from the manifest
+    pub sequence_number: Option<i64>,
     pub data_file: DataFile,
 }
 
-/// data file
-#[derive(Clone, Debug, Deserialize)]
+/// data file, or delete file in v2
+#[derive(Clone, Debug)]
 pub(crate) struct DataFile {
+    pub content: i32,
     pub file_path: String,
     pub file_format: String,
-    pub partition: HashMap<String, u64>,
-    pub record_count: usize,
-    pub file_size_in_bytes: usize,
-    pub block_size_in_bytes: usize,
-    pub column_sizes: HashMap<u64, u64>,
-    pub value_counts: HashMap<u64, u64>,
-    pub null_value_counts: HashMap<u64, u64>,
-    pub lower_bounds: HashMap<u64, String>,
-    pub upper_bounds: HashMap<u64, String>,
-    pub key_metadata: Option<String>,
-    pub split_offsets: Vec<u64>,
+    /// partition values, in the order of the partition spec
+    pub partition: Vec<Value>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    /// keyed by field id
+    pub null_value_counts: HashMap<i32, i64>,
+    /// keyed by field id
+    pub lower_bounds: HashMap<i32, Vec<u8>>,
+    /// keyed by field id
+    pub upper_bounds: HashMap<i32, Vec<u8>>,
+    /// field ids used for equality deletes
+    pub equality_ids: Vec<i32>,
+}
+
+impl ManifestFile {
+    /// manifest of v1 snapshots without manifest list
+    pub fn from_path(manifest_path: &str) -> Self {
+        Self {
+            manifest_path: manifest_path.to_string(),
+            partition_spec_id: 0,
+            content: CONTENT_DATA,
+            sequence_number: 0,
+            partitions: vec![],
+            manifest_length: 0,
+            min_sequence_number: 0,
+            added_snapshot_id: 0,
+            added_files_count: 0,
+            existing_files_count: 0,
+            deleted_files_count: 0,
+            added_rows_count: 0,
+            existing_rows_count: 0,
+            deleted_rows_count: 0,
+        }
+    }
+
+    /// manifest of the data files added in the snapshot
+    pub fn from_added(
+        manifest_path: &str,
+        manifest_length: i64,
+        snapshot_id: i64,
+        data_files: &[DataFile],
+    ) -> Self {
+        Self {
+            manifest_path: manifest_path.to_string(),
+            manifest_length,
+            added_snapshot_id: snapshot_id,
+            added_files_count: data_files.len() as i32,
+            added_rows_count: data_files.iter().map(|f| f.record_count).sum(),
+            ..Self::from_path(manifest_path)
+        }
+    }
+
+    fn try_from_value(value: &Value) -> Result<Self> {
+        let partitions = match field(value, "partitions") {
+            Some(Value::Array(summaries)) => summaries
+                .iter()
+                .map(|summary| FieldSummary {
+                    contains_null: field(summary, "contains_null")
+                        .and_then(as_bool)
+                        .unwrap_or(true),
+                    lower_bound: field(summary, "lower_bound").and_then(as_bytes),
+                    upper_bound: field(summary, "upper_bound").and_then(as_bytes),
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        let sequence_number = optional_i64(value, "sequence_number").unwrap_or(0);
+        let count = |name| optional_i64(value, name).unwrap_or(0);
+        Ok(Self {
+            manifest_path: required(value, "manifest_path", as_string)?,
+            partition_spec_id: required(value, "partition_spec_id", as_i64)? as i32,
+            content: count("content") as i32,
+            sequence_number,
+            partitions,
+            manifest_length: count("manifest_length"),
+            min_sequence_number: optional_i64(value, "min_sequence_number")
+                .unwrap_or(sequence_number),
+            added_snapshot_id: count("added_snapshot_id"),
+            // named `added_data_files_count` and so on in v1
+            added_files_count: optional_i64(value, "added_files_count")
+                .unwrap_or_else(|| count("added_data_files_count"))
+                as i32,
+            existing_files_count: optional_i64(value, "existing_files_count")
+                .unwrap_or_else(|| count("existing_data_files_count"))
+                as i32,
+            deleted_files_count: optional_i64(value, "deleted_files_count")
+                .unwrap_or_else(|| count("deleted_data_files_count"))
+                as i32,
+            added_rows_count: count("added_rows_count"),
+            existing_rows_count: count("existing_rows_count"),
+            deleted_rows_count: count("deleted_rows_count"),
+        })
+    }
+
+    fn to_value(&self) -> Value {
+        let partitions = self
+            .partitions
+            .iter()
+            .map(|summary| {
+                Value::Record(vec![
+                    (
+                        "contains_null".to_string(),
+                        Value::Boolean(summary.contains_null),
+                    ),
+                    (
+                        "lower_bound".to_string(),
+                        nullable(summary.lower_bound.clone().map(Value::Bytes)),
+                    ),
+                    (
+                        "upper_bound".to_string(),
+                        nullable(summary.upper_bound.clone().map(Value::Bytes)),
+                    ),
+                ])
+            })
+            .collect();
+
+        Value::Record(vec![
+            (
+                "manifest_path".to_string(),
+                Value::String(self.manifest_path.clone()),
+            ),
+            (
+                "manifest_length".to_string(),
+                Value::Long(self.manifest_length),
+            ),
+            (
+                "partition_spec_id".to_string(),
+                Value::Int(self.partition_spec_id),
+            ),
+            ("content".to_string(), Value::Int(self.content)),
+            (
+                "sequence_number".to_string(),
+                Value::Long(self.sequence_number),
+            ),
+            (
+                "min_sequence_number".to_string(),
+                Value::Long(self.min_sequence_number),
+            ),
+            (
+                "added_snapshot_id".to_string(),
+                Value::Long(self.added_snapshot_id),
+            ),
+            (
+                "added_files_count".to_string(),
+                Value::Int(self.added_files_count),
+            ),
+            (
+                "existing_files_count".to_string(),
+                Value::Int(self.existing_files_count),
+            ),
+            (
+                "deleted_files_count".to_string(),
+                Value::Int(self.deleted_files_count),
+            ),
+            (
+                "added_rows_count".to_string(),
+                Value::Long(self.added_rows_count),
+            ),
+            (
+                "existing_rows_count".to_string(),
+                Value::Long(self.existing_rows_count),
+            ),
+            (
+                "deleted_rows_count".to_string(),
+                Value::Long(self.deleted_rows_count),
+            ),
+            (
+                "partitions".to_string(),
+                nullable(Some(Value::Array(partitions))),
+            ),
+        ])
+    }
+}
+
+impl ManifestEntry {
+    fn try_from_value(value: &Value) -> Result<Self> {
+        let data_file = field(value, "data_file")
+            .ok_or_else(|| invalid("data_file"))
+            .and_then(DataFile::try_from_value)?;
+
+        Ok(Self {
+            status: required(value, "status", as_i64)? as i32,
+            sequence_number: optional_i64(value, "sequence_number"),
+            data_file,
+        })
+    }
+
+    /// entry of a data file added in the snapshot, the sequence number is inherited
+    fn added_value(snapshot_id: i64, data_file: &DataFile) -> Value {
+        Value::Record(vec![
+            ("status".to_string(), Value::Int(STATUS_ADDED)),
+            (
+                "snapshot_id".to_string(),
+                nullable(Some(Value::Long(snapshot_id))),
+            ),
+            ("sequence_number".to_string(), nullable(None)),
+            ("file_sequence_number".to_string(), nullable(None)),
+            ("data_file".to_string(), data_file.to_value()),
+        ])
+    }
+}
+
+impl DataFile {
+    fn try_from_value(value: &Value) -> Result<Self> {
+        let partition = match field(value, "partition") {
+            Some(Value::Record(fields)) => fields.iter().map(|(_, v)| v.clone()).collect(),
+            _ => vec![],
+        };
+        let equality_ids = match field(value, "equality_ids") {
+            Some(Value::Array(ids)) => ids
+                .iter()
+                .filter_map(|id| as_i64(id).map(|id| id as i32))
+                .collect(),
+            _ => vec![],
+        };
+
+        Ok(Self {
+            content: optional_i64(value, "content").unwrap_or(0) as i32,
+            file_path: required(value, "file_path", as_string)?,
+            file_format: required(value, "file_format", as_string)?,
+            partition,
+            record_count: required(value, "record_count", as_i64)?,
+            file_size_in_bytes: required(value, "file_size_in_bytes", as_i64)?,
+            null_value_counts: int_map(value, "null_value_counts", as_i64),
+            lower_bounds: int_map(value, "lower_bounds", as_bytes),
+            upper_bounds: int_map(value, "upper_bounds", as_bytes),
+            equality_ids,
+        })
+    }
+
+    /// only unpartitioned data files are written
+    fn to_value(&self) -> Value {
+        fn map(values: impl Iterator<Item = (i32, Value)>) -> Value {
+            let items = values
+                .map(|(key, value)| {
+                    Value::Record(vec![
+                        ("key".to_string(), Value::Int(key)),
+                        ("value".to_string(), value),
+                    ])
+                })
+                .collect();
+            nullable(Some(Value::Array(items)))
+        }
+
+        Value::Record(vec![
+            ("content".to_string(), Value::Int(self.content)),
+            (
+                "file_path".to_string(),
+                Value::String(self.file_path.clone()),
+            ),
+            (
+                "file_format".to_string(),
+                Value::String(self.file_format.clone()),
+            ),
+            ("partition".to_string(), Value::Record(vec![])),
+            ("record_count".to_string(), Value::Long(self.record_count)),
+            (
+                "file_size_in_bytes".to_string(),
+                Value::Long(self.file_size_in_bytes),
+            ),
+            (
+                "null_value_counts".to_string(),
+                map(sorted(&self.null_value_counts).map(|(k, v)| (k, Value::Long(*v)))),
+            ),
+            (
+                "lower_bounds".to_string(),
+                map(sorted(&self.lower_bounds).map(|(k, v)| (k, Value::Bytes(v.clone())))),
+            ),
+            (
+                "upper_bounds".to_string(),
+                map(sorted(&self.upper_bounds).map(|(k, v)| (k, Value::Bytes(v.clone())))),
+            ),
+        ])
+    }
+}
+
+/// read the manifest list file of a snapshot
+#[async_backtrace::framed]
+pub(crate) async fn read_manifest_list(op: &Operator, path: &str) -> Result<Vec<ManifestFile>> {
+    read_avro(op, path)
+        .await?
+        .iter()
+        .map(ManifestFile::try_from_value)
+        .collect()
+}
+
+/// read the entries of a manifest file
+#[async_backtrace::framed]
+pub(crate) async fn read_manifest(op: &Operator, path: &str) -> Result<Vec<ManifestEntry>> {
+    read_avro(op, path)
+        .await?
+        .iter()
+        .map(ManifestEntry::try_from_value)
+        .collect()
+}
+
+/// write the manifest of the data files added in the snapshot, returns the length of the file
+///
+/// The schema of the table and the partition spec are kept in the metadata of the file.
+#[async_backtrace::framed]
+pub(crate) async fn write_manifest(
+    op: &Operator,
+    path: &str,
+    snapshot_id: i64,
+    data_files: &[DataFile],
+    metadata: &[(&str, String)],
+) -> Result<i64> {
+    let values = data_files
+        .iter()
+        .map(|file| ManifestEntry::added_value(snapshot_id, file))
+        .collect();
+    let data = encode_avro(path, MANIFEST_SCHEMA, values, metadata)?;
+    let length = data.len() as i64;
+    op.write(path, data).await?;
+    Ok(length)
+}
+
+/// write the manifest list file of a snapshot
+#[async_backtrace::framed]
+pub(crate) async fn write_manifest_list(
+    op: &Operator,
+    path: &str,
+    manifests: &[ManifestFile],
+    metadata: &[(&str, String)],
+) -> Result<()> {
+    let values = manifests.iter().map(ManifestFile::to_value).collect();
+    let data = encode_avro(path, MANIFEST_LIST_SCHEMA, values, metadata)?;
+    op.write(path, data).await?;
+    Ok(())
+}
+
+fn encode_avro(
+    path: &str,
+    schema: &str,
+    values: Vec<Value>,
+    metadata: &[(&str, String)],
+) -> Result<Vec<u8>> {
+    let err = |e: apache_avro::Error| {
+        ErrorCode::Internal(format!("failed to write avro file {path}: {e}"))
+    };
+    let schema = Schema::parse_str(schema).map_err(err)?;
+    let mut writer = Writer::new(&schema, Vec::new());
+    for (key, value) in metadata {
+        writer
+            .add_user_metadata(key.to_string(), value)
+            .map_err(err)?;
+    }
+    for value in values {
+        writer.append(value).map_err(err)?;
+    }
+    writer.into_inner().map_err(err)
+}
+
+async fn read_avro(op: &Operator, path: &str) -> Result<Vec<Value>> {
+    let data = op.read(path).await?;
+    decode_avro(path, &data)
+}
+
+fn decode_avro(path: &str, data: &[u8]) -> Result<Vec<Value>> {
+    let reader = Reader::new(data)
+        .map_err(|e| ErrorCode::ReadTableDataError(format!("invalid avro file {path}: {e}")))?;
+    reader
+        .map(|value| {
+            value.map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("invalid avro file {path}: {e}"))
+            })
+        })
+        .collect()
+}
+
+fn invalid(name: &str) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("invalid iceberg manifest: missing {name}"))
+}
+
+/// field of a record, nulls are taken as missing
+fn field<'a>(record: &'a Value, name: &str) -> Option<&'a Value> {
+    match record {
+        Value::Record(fields) => fields
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| unwrap_union(v))
+            .filter(|v| !matches!(v, Value::Null)),
+        Value::Union(_, inner) => field(inner, name),
+        _ => None,
+    }
+}
+
+fn required<T>(record: &Value, name: &str, f: impl Fn(&Value) -> Option<T>) -> Result<T> {
+    field(record, name).and_then(f).ok_or_else(|| invalid(name))
+}
+
+fn optional_i64(record: &Value, name: &str) -> Option<i64> {
+    field(record, name).and_then(as_i64)
+}
+
+/// maps with int keys are written as arrays of key-value records
+fn int_map<T>(record: &Value, name: &str, f: impl Fn(&Value) -> Option<T>) -> HashMap<i32, T> {
+    match field(record, name) {
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(|kv| {
+                let key = field(kv, "key").and_then(as_i64)?;
+                let value = field(kv, "value").and_then(&f)?;
+                Some((key as i32, value))
+            })
+            .collect(),
+        Some(Value::Map(items)) => items
+            .iter()
+            .filter_map(|(k, v)| Some((k.parse().ok()?, f(unwrap_union(v))?)))
+            .collect(),
+        _ => HashMap::new(),
+    }
+}
+
+/// value of the union with null, null is always the first in the written schemas
+fn nullable(value: Option<Value>) -> Value {
+    match value {
+        Some(v) => Value::Union(1, Box::new(v)),
+        None => Value::Union(0, Box::new(Value::Null)),
+    }
+}
+
+/// maps are written in the order of the keys
+fn sorted<T>(map: &HashMap<i32, T>) -> impl Iterator<Item = (i32, &T)> {
+    let mut items = map.iter().map(|(k, v)| (*k, v)).collect::<Vec<_>>();
+    items.sort_by_key(|(k, _)| *k);
+    items.into_iter()
+}
+
+pub(crate) fn unwrap_union(value: &Value) -> &Value {
+    match value {
+        Value::Union(_, inner) => unwrap_union(inner),
+        v => v,
+    }
+}
+
+fn as_i64(value: &Value) -> Option<i64> {
+    match unwrap_union(value) {
+        Value::Int(v) => Some(*v as i64),
+        Value::Long(v) => Some(*v),
+        _ => None,
+    }
+}
+
+fn as_bool(value: &Value) -> Option<bool> {
+    match unwrap_union(value) {
+        Value::Boolean(v) => Some(*v),
+        _ => None,
+    }
+}
+
+fn as_string(value: &Value) -> Option<String> {
+    match unwrap_union(value) {
+        Value::String(v) | Value::Enum(_, v) => Some(v.clone()),
+        _ => None,
+    }
+}
+
+fn as_bytes(value: &Value) -> Option<Vec<u8>> {
+    match unwrap_union(value) {
+        Value::Bytes(v) | Value::Fixed(_, v) => Some(v.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod manifest_test {
+    use std::collections::HashMap;
+
+    use apache_avro::types::Value;
+
+    use super::decode_avro;
+    use super::encode_avro;
+    use super::DataFile;
+    use super::ManifestEntry;
+    use super::ManifestFile;
+    use super::CONTENT_DATA;
+    use super::MANIFEST_LIST_SCHEMA;
+    use super::MANIFEST_SCHEMA;
+    use super::STATUS_ADDED;
+
+    fn kv(key: i32, value: Value) -> Value {
+        Value::Record(vec![
+            ("key".to_string(), Value::Int(key)),
+            ("value".to_string(), value),
+        ])
+    }
+
+    #[test]
+    fn test_manifest_file() {
+        let value = Value::Record(vec![
+            (
+                "manifest_path".to_string(),
+                Value::String("s3://b/tbl/metadata/m0.avro".to_string()),
+            ),
+            ("partition_spec_id".to_string(), Value::Int(1)),
+            ("content".to_string(), Value::Int(1)),
+            ("sequence_number".to_string(), Value::Long(3)),
+            (
+                "partitions".to_string(),
+                Value::Union(
+                    1,
+                    Box::new(Value::Array(vec![Value::Record(vec![
+                        ("contains_null".to_string(), Value::Boolean(false)),
+                        (
+                            "lower_bound".to_string(),
+                            Value::Union(1, Box::new(Value::Bytes(vec![1, 0, 0, 0]))),
+                        ),
+                        (
+                            "upper_bound".to_string(),
+                            Value::Union(0, Box::new(Value::Null)),
+                        ),
+                    ])])),
+                ),
+            ),
+        ]);
+
+        let manifest = ManifestFile::try_from_value(&value).unwrap();
+        assert_eq!(manifest.partition_spec_id, 1);
+        assert_eq!(manifest.content, 1);
+        assert_eq!(manifest.sequence_number, 3);
+        assert_eq!(manifest.partitions.len(), 1);
+        assert!(!manifest.partitions[0].contains_null);
+        assert_eq!(manifest.partitions[0].lower_bound, Some(vec![1, 0, 0, 0]));
+        assert_eq!(manifest.partitions[0].upper_bound, None);
+
+        // v1 manifest lists have neither content nor sequence number
+        let value = Value::Record(vec![
+            (
+                "manifest_path".to_string(),
+                Value::String("m0.avro".to_string()),
+            ),
+            ("partition_spec_id".to_string(), Value::Int(0)),
+        ]);
+        let manifest = ManifestFile::try_from_value(&value).unwrap();
+        assert_eq!(manifest.content, 0);
+        assert_eq!(manifest.sequence_number, 0);
+        assert!(manifest.partitions.is_empty());
+    }
+
+    #[test]
+    fn test_data_file() {
+        let value = Value::Record(vec![
+            ("content".to_string(), Value::Int(2)),
+            (
+                "file_path".to_string(),
+                Value::String("data/d0.parquet".to_string()),
+            ),
+            (
+                "file_format".to_string(),
+                Value::String("PARQUET".to_string()),
+            ),
+            (
+                "partition".to_string(),
+                Value::Record(vec![("id".to_string(), Value::Int(7))]),
+            ),
+            ("record_count".to_string(), Value::Long(10)),
+            ("file_size_in_bytes".to_string(), Value::Long(100)),
+            (
+                "null_value_counts".to_string(),
+                Value::Array(vec![kv(1, Value::Long(0))]),
+            ),
+            (
+                "lower_bounds".to_string(),
+                Value::Array(vec![kv(1, Value::Bytes(vec![1, 0, 0, 0]))]),
+            ),
+            (
+                "equality_ids".to_string(),
+                Value::Union(1, Box::new(Value::Array(vec![Value::Int(1)]))),
+            ),
+        ]);
+
+        let file = DataFile::try_from_value(&value).unwrap();
+        assert_eq!(file.content, 2);
+        assert_eq!(file.partition, vec![Value::Int(7)]);
+        assert_eq!(file.record_count, 10);
+        assert_eq!(file.null_value_counts.get(&1), Some(&0));
+        assert_eq!(file.lower_bounds.get(&1), Some(&vec![1, 0, 0, 0]));
+        assert!(file.upper_bounds.is_empty());
+        assert_eq!(file.equality_ids, vec![1]);
+
+        // the file path is required
+        let value = Value::Record(vec![("record_count".to_string(), Value::Long(10))]);
+        assert!(DataFile::try_from_value(&value).is_err());
+    }
+
+    #[test]
+    fn test_write_manifest() {
+        let data_file = DataFile {
+            content: CONTENT_DATA,
+            file_path: "s3://b/tbl/data/d0.parquet".to_string(),
+            file_format: "PARQUET".to_string(),
+            partition: vec![],
+            record_count: 10,
+            file_size_in_bytes: 100,
+            null_value_counts: HashMap::from([(1, 0), (2, 3)]),
+            lower_bounds: HashMap::from([(1, vec![1, 0, 0, 0])]),
+            upper_bounds: HashMap::from([(1, vec![9, 0, 0, 0])]),
+            equality_ids: vec![],
+        };
+        let values = vec![ManifestEntry::added_value(7, &data_file)];
+        let metadata = [("format-version", "2".to_string())];
+        let data = encode_avro("m0.avro", MANIFEST_SCHEMA, values, &metadata).unwrap();
+
+        let entries = decode_avro("m0.avro", &data)
+            .unwrap()
+            .iter()
+            .map(ManifestEntry::try_from_value)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].status, STATUS_ADDED);
+        // inherited from the manifest
+        assert_eq!(entries[0].sequence_number, None);
+        let file = &entries[0].data_file;
+        assert_eq!(file.file_path, data_file.file_path);
+        assert_eq!(file.record_count, 10);
+        assert!(file.partition.is_empty());
+        assert_eq!(file.null_value_counts, data_file.null_value_counts);
+        assert_eq!(file.lower_bounds, data_file.lower_bounds);
+        assert_eq!(file.upper_bounds, data_file.upper_bounds);
+
+        let data_files = vec![data_file];
+        let mut manifest =
+            ManifestFile::from_added("s3://b/tbl/metadata/m0.avro", 120, 7, &data_files);
+        manifest.sequence_number = 3;
+        manifest.min_sequence_number = 3;
+        let values = vec![manifest.to_value()];
+        let data = encode_avro("snap.avro", MANIFEST_LIST_SCHEMA, values, &[]).unwrap();
+
+        let manifests = decode_avro("snap.avro", &data).unwrap();
+        let read = ManifestFile::try_from_value(&manifests[0]).unwrap();
+        assert_eq!(read.manifest_path, manifest.manifest_path);
+        assert_eq!(read.manifest_length, 120);
+        assert_eq!(read.content, CONTENT_DATA);
+        assert_eq!(read.sequence_number, 3);
+        assert_eq!(read.added_snapshot_id, 7);
+        assert_eq!(read.added_files_count, 1);
+        assert_eq!(read.added_rows_count, 10);
+        assert!(read.partitions.is_empty());
+    }
 }
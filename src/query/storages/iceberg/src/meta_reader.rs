@@ -13,3 +13,275 @@
 // limitations under the License.
 
 //! this module contains metadata reader utilities for table metadata
+//!
+//! The schemas are converted from the metadata parsed by `iceberg_rs`,
+//! the snapshots and partition specs are read here.
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+
+/// snapshot related part of the table metadata file
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct SnapshotMetadata {
+    #[serde(default = "default_format_version")]
+    pub format_version: i32,
+    /// absolute location of the table,
+    /// used to resolve the absolute paths in manifests
+    pub location: String,
+    /// always 0 in v1
+    #[serde(default)]
+    pub last_sequence_number: i64,
+    #[serde(default)]
+    pub current_schema_id: Option<i32>,
+    #[serde(default)]
+    pub schemas: Vec<SchemaId>,
+    #[serde(default)]
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+    /// spec of the data files written
+    #[serde(default)]
+    pub default_spec_id: i32,
+    /// partition fields of the only partition spec in some v1 tables
+    #[serde(default)]
+    pub partition_spec: Vec<PartitionField>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct SchemaId {
+    #[serde(default)]
+    pub schema_id: Option<i32>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct Snapshot {
+    pub snapshot_id: i64,
+    /// always 0 in v1
+    #[serde(default)]
+    pub sequence_number: i64,
+    pub timestamp_ms: i64,
+    /// manifest list file, always present in v2
+    #[serde(default)]
+    pub manifest_list: Option<String>,
+    /// manifest files, in v1 tables without manifest list
+    #[serde(default)]
+    pub manifests: Vec<String>,
+    /// schema when the snapshot was created
+    #[serde(default)]
+    pub schema_id: Option<i32>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct PartitionField {
+    pub source_id: i32,
+    pub transform: String,
+}
+
+fn default_format_version() -> i32 {
+    1
+}
+
+impl SnapshotMetadata {
+    pub fn try_from_slice(meta_json: &[u8]) -> Result<Self> {
+        serde_json::from_slice(meta_json).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("invalid iceberg table metadata: {e}"))
+        })
+    }
+
+    /// the current snapshot, `None` for tables without any data
+    pub fn current_snapshot(&self) -> Option<&Snapshot> {
+        // `-1` is written by some writers for no current snapshot
+        let id = self.current_snapshot_id.filter(|id| *id >= 0)?;
+        self.snapshots.iter().find(|s| s.snapshot_id == id)
+    }
+
+    pub fn snapshot_by_id(&self, snapshot_id: i64) -> Option<&Snapshot> {
+        self.snapshots.iter().find(|s| s.snapshot_id == snapshot_id)
+    }
+
+    /// the latest snapshot created at or before the time point
+    pub fn snapshot_at(&self, time_point: &DateTime<Utc>) -> Option<&Snapshot> {
+        let ts = time_point.timestamp_millis();
+        self.snapshots
+            .iter()
+            .filter(|s| s.timestamp_ms <= ts)
+            .max_by_key(|s| s.timestamp_ms)
+    }
+
+    pub fn partition_spec(&self, spec_id: i32) -> Option<&[PartitionField]> {
+        match self.partition_specs.iter().find(|s| s.spec_id == spec_id) {
+            Some(spec) => Some(&spec.fields),
+            None if spec_id == 0 && self.partition_specs.is_empty() => Some(&self.partition_spec),
+            None => None,
+        }
+    }
+
+    /// position of the schema in the `schemas` list
+    ///
+    /// The schema of the snapshot is taken if specified, or the current schema.
+    pub fn schema_index(&self, snapshot: Option<&Snapshot>) -> Option<usize> {
+        let schema_id = snapshot
+            .and_then(|s| s.schema_id)
+            .or(self.current_schema_id)?;
+        self.schemas
+            .iter()
+            .position(|s| s.schema_id == Some(schema_id))
+    }
+
+    /// absolute path of a file relative to the table root, to be written in manifests
+    pub fn absolute_path(&self, relative: &str) -> String {
+        format!("{}/{relative}", self.location.trim_end_matches('/'))
+    }
+
+    /// path of a file relative to the table root
+    pub fn relative_path(&self, path: &str) -> Result<String> {
+        let location = self.location.trim_end_matches('/');
+        match path
+            .strip_prefix(location)
+            .and_then(|p| p.strip_prefix('/'))
+        {
+            Some(relative) => Ok(relative.to_string()),
+            None if !path.contains("://") && !path.starts_with('/') => Ok(path.to_string()),
+            None => Err(ErrorCode::Unimplemented(format!(
+                "file {path} outside of the table location {location} is not supported"
+            ))),
+        }
+    }
+}
+
+/// version of a metadata file, by the name of `v{version}.metadata.json`
+/// written by filesystem catalogs, or `{version}-{uuid}.metadata.json` by the others
+pub(crate) fn metadata_version(path: &str) -> Option<u64> {
+    let name = path.rsplit('/').next()?.strip_suffix(".metadata.json")?;
+    match name.strip_prefix('v') {
+        Some(version) => version.parse().ok(),
+        None => name.split('-').next()?.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod meta_reader_test {
+    use chrono::TimeZone;
+    use chrono::Utc;
+
+    use super::metadata_version;
+    use super::SnapshotMetadata;
+
+    const METADATA_FILE: &str = r#"
+    {
+        "format-version" : 2,
+        "location": "s3://b/wh/data.db/table",
+        "last-sequence-number" : 2,
+        "current-schema-id" : 1,
+        "schemas": [
+            { "schema-id" : 0, "type" : "struct", "fields" : [] },
+            { "schema-id" : 1, "type" : "struct", "fields" : [] }
+        ],
+        "current-snapshot-id" : 3,
+        "snapshots" : [
+            {
+                "snapshot-id" : 1,
+                "timestamp-ms" : 1000,
+                "manifest-list" : "s3://b/wh/data.db/table/metadata/snap-1.avro",
+                "schema-id" : 0
+            },
+            {
+                "snapshot-id" : 3,
+                "sequence-number" : 2,
+                "timestamp-ms" : 3000,
+                "manifest-list" : "s3://b/wh/data.db/table/metadata/snap-3.avro",
+                "schema-id" : 1
+            }
+        ],
+        "partition-specs": [
+            {
+                "spec-id": 0,
+                "fields": [
+                    {
+                        "source-id": 4,
+                        "field-id": 1000,
+                        "name": "ts_day",
+                        "transform": "day"
+                    }
+                ]
+            }
+        ]
+    }
+"#;
+
+    #[test]
+    fn test_select_snapshot() {
+        let meta = SnapshotMetadata::try_from_slice(METADATA_FILE.as_bytes()).unwrap();
+
+        assert_eq!(meta.current_snapshot().unwrap().snapshot_id, 3);
+        assert_eq!(meta.snapshot_by_id(1).unwrap().snapshot_id, 1);
+        assert!(meta.snapshot_by_id(2).is_none());
+
+        let at = |ms| meta.snapshot_at(&Utc.timestamp_millis_opt(ms).unwrap());
+        assert!(at(999).is_none());
+        assert_eq!(at(1000).unwrap().snapshot_id, 1);
+        assert_eq!(at(2999).unwrap().snapshot_id, 1);
+        assert_eq!(at(5000).unwrap().snapshot_id, 3);
+
+        assert_eq!(meta.schema_index(None), Some(1));
+        assert_eq!(meta.schema_index(meta.snapshot_by_id(1)), Some(0));
+        assert_eq!(meta.partition_spec(0).unwrap()[0].transform, "day");
+
+        assert_eq!(meta.format_version, 2);
+        assert_eq!(meta.last_sequence_number, 2);
+        assert_eq!(meta.current_snapshot().unwrap().sequence_number, 2);
+        assert_eq!(meta.snapshot_by_id(1).unwrap().sequence_number, 0);
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let meta = SnapshotMetadata::try_from_slice(METADATA_FILE.as_bytes()).unwrap();
+
+        assert_eq!(
+            meta.relative_path("s3://b/wh/data.db/table/data/00000.parquet")
+                .unwrap(),
+            "data/00000.parquet"
+        );
+        assert_eq!(
+            meta.relative_path("data/00000.parquet").unwrap(),
+            "data/00000.parquet"
+        );
+        assert!(meta.relative_path("s3://other/00000.parquet").is_err());
+        assert!(
+            meta.relative_path("s3://b/wh/data.db/table2/00000.parquet")
+                .is_err()
+        );
+
+        assert_eq!(
+            meta.absolute_path("data/00000.parquet"),
+            "s3://b/wh/data.db/table/data/00000.parquet"
+        );
+    }
+
+    #[test]
+    fn test_metadata_version() {
+        assert_eq!(metadata_version("metadata/v12.metadata.json"), Some(12));
+        assert_eq!(
+            metadata_version("metadata/00004-624c12b5-ca17-4804-91ae-40f8622b406c.metadata.json"),
+            Some(4)
+        );
+        assert_eq!(metadata_version("metadata/snap-1.avro"), None);
+        assert_eq!(metadata_version("metadata/vx.metadata.json"), None);
+    }
+}
@@ -0,0 +1,348 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! this module writes the table metadata files, the way of filesystem (Hadoop) catalogs
+//!
+//! Every commit writes a new `metadata/v{version}.metadata.json` and points
+//! `metadata/version-hint.text` to it. The metadata is updated as json,
+//! so the fields not known by databend are kept as is.
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchema;
+use futures::TryStreamExt;
+use opendal::Operator;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use crate::converters::schema_databend_to_iceberg;
+
+/// file marking the current version of metadata file, written by filesystem catalogs
+pub(crate) const VERSION_HINT: &str = "metadata/version-hint.text";
+
+/// a new snapshot to commit
+pub(crate) struct NewSnapshot {
+    pub snapshot_id: i64,
+    pub parent_snapshot_id: Option<i64>,
+    /// ignored in v1
+    pub sequence_number: i64,
+    pub timestamp_ms: i64,
+    /// absolute path of the manifest list
+    pub manifest_list: String,
+    pub schema_id: Option<i32>,
+    /// `append` or `overwrite`
+    pub operation: &'static str,
+    pub added_data_files: usize,
+    pub added_records: u64,
+    pub added_files_size: u64,
+}
+
+/// random positive id of a new snapshot
+pub(crate) fn new_snapshot_id() -> i64 {
+    let (high, low) = Uuid::new_v4().as_u64_pair();
+    ((high ^ low) & i64::MAX as u64) as i64
+}
+
+/// metadata of a new table in format v2, without any snapshot
+pub(crate) fn new_table_metadata(location: &str, schema: &TableSchema) -> Result<JsonValue> {
+    let iceberg_schema = schema_databend_to_iceberg(schema)?;
+    // data files are written without field ids, they are mapped by name
+    let name_mapping = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| json!({ "field-id": i + 1, "names": [field.name()] }))
+        .collect::<Vec<_>>();
+
+    Ok(json!({
+        "format-version": 2,
+        "table-uuid": Uuid::new_v4().to_string(),
+        "location": location.trim_end_matches('/'),
+        "last-sequence-number": 0,
+        "last-updated-ms": Utc::now().timestamp_millis(),
+        "last-column-id": schema.num_fields(),
+        "current-schema-id": 0,
+        "schemas": [iceberg_schema],
+        "default-spec-id": 0,
+        "partition-specs": [{ "spec-id": 0, "fields": [] }],
+        "last-partition-id": 999,
+        "default-sort-order-id": 0,
+        "sort-orders": [{ "order-id": 0, "fields": [] }],
+        "properties": {
+            "write.format.default": "parquet",
+            "schema.name-mapping.default": JsonValue::Array(name_mapping).to_string(),
+        },
+        "current-snapshot-id": -1,
+        "refs": {},
+        "snapshots": [],
+        "snapshot-log": [],
+        "metadata-log": [],
+    }))
+}
+
+/// add the snapshot to the metadata, and make it current
+///
+/// `metadata_file` is the absolute path of the metadata file being replaced.
+pub(crate) fn add_snapshot(
+    metadata: &mut JsonValue,
+    snapshot: &NewSnapshot,
+    metadata_file: &str,
+) -> Result<()> {
+    let format_version = metadata["format-version"].as_i64().unwrap_or(1);
+    let previous_updated_ms = metadata["last-updated-ms"].clone();
+    let meta = metadata.as_object_mut().ok_or_else(|| {
+        ErrorCode::ReadTableDataError("invalid iceberg table metadata: not an object")
+    })?;
+
+    let mut new_snapshot = json!({
+        "snapshot-id": snapshot.snapshot_id,
+        "timestamp-ms": snapshot.timestamp_ms,
+        "summary": {
+            "operation": snapshot.operation,
+            "added-data-files": snapshot.added_data_files.to_string(),
+            "added-records": snapshot.added_records.to_string(),
+            "added-files-size": snapshot.added_files_size.to_string(),
+        },
+        "manifest-list": snapshot.manifest_list,
+    });
+    if let Some(parent) = snapshot.parent_snapshot_id {
+        new_snapshot["parent-snapshot-id"] = json!(parent);
+    }
+    if let Some(schema_id) = snapshot.schema_id {
+        new_snapshot["schema-id"] = json!(schema_id);
+    }
+    if format_version >= 2 {
+        new_snapshot["sequence-number"] = json!(snapshot.sequence_number);
+        meta.insert(
+            "last-sequence-number".to_string(),
+            json!(snapshot.sequence_number),
+        );
+    }
+
+    meta.insert("last-updated-ms".to_string(), json!(snapshot.timestamp_ms));
+    meta.insert(
+        "current-snapshot-id".to_string(),
+        json!(snapshot.snapshot_id),
+    );
+    meta.insert(
+        "refs".to_string(),
+        json!({ "main": { "snapshot-id": snapshot.snapshot_id, "type": "branch" } }),
+    );
+    push(meta, "snapshots", new_snapshot);
+    push(
+        meta,
+        "snapshot-log",
+        json!({ "timestamp-ms": snapshot.timestamp_ms, "snapshot-id": snapshot.snapshot_id }),
+    );
+    push(
+        meta,
+        "metadata-log",
+        json!({ "timestamp-ms": previous_updated_ms, "metadata-file": metadata_file }),
+    );
+    Ok(())
+}
+
+fn push(meta: &mut serde_json::Map<String, JsonValue>, key: &str, value: JsonValue) {
+    match meta.get_mut(key) {
+        Some(JsonValue::Array(values)) => values.push(value),
+        _ => {
+            meta.insert(key.to_string(), JsonValue::Array(vec![value]));
+        }
+    }
+}
+
+/// claims of versions older than this are left by the writers failed to finish, and ignored
+const CLAIM_EXPIRE_MS: i64 = 60_000;
+
+/// write the metadata file of the version, and point the version hint to it
+///
+/// Object storages can not create a file only if not existing, so the version is claimed
+/// first with a file named uniquely, and only the writer seeing no other claims of the
+/// version writes it. Of two writers, at least one sees the claim or the metadata file
+/// written by the other.
+///
+/// Fails with `TableVersionMismatched` if the version has been written or claimed by others.
+#[async_backtrace::framed]
+pub(crate) async fn write_metadata(
+    op: &Operator,
+    version: u64,
+    metadata: &JsonValue,
+) -> Result<()> {
+    let path = format!("metadata/v{version}.metadata.json");
+    let data = serde_json::to_vec_pretty(metadata).map_err(|e| {
+        ErrorCode::Internal(format!(
+            "failed to write iceberg table metadata {path}: {e}"
+        ))
+    })?;
+
+    let now = Utc::now().timestamp_millis();
+    let claim = format!("v{version}.{now}-{}.claim", Uuid::new_v4().simple());
+    op.write(&format!("metadata/{claim}"), vec![]).await?;
+    let res = write_claimed(op, version, &claim, &path, data).await;
+    // released after the metadata file is written, later writers see the file instead
+    if let Err(e) = op.delete(&format!("metadata/{claim}")).await {
+        tracing::warn!("failed to remove the claim {claim} of iceberg table metadata: {e}");
+    }
+    res
+}
+
+async fn write_claimed(
+    op: &Operator,
+    version: u64,
+    claim: &str,
+    path: &str,
+    data: Vec<u8>,
+) -> Result<()> {
+    let entries: Vec<_> = op.list("metadata/").await?.try_collect().await?;
+    let now = Utc::now().timestamp_millis();
+    let claimed = entries.iter().any(|entry| {
+        entry.name() != claim
+            && claim_time(entry.name(), version).map_or(false, |ts| now - ts < CLAIM_EXPIRE_MS)
+    });
+    if claimed || op.is_exist(path).await? {
+        return Err(ErrorCode::TableVersionMismatched(format!(
+            "iceberg table metadata {path} has been committed by others"
+        )));
+    }
+
+    op.write(path, data).await?;
+    op.write(VERSION_HINT, version.to_string()).await?;
+    Ok(())
+}
+
+/// time of the claim of the version, by the name of `v{version}.{timestamp_ms}-{uuid}.claim`
+fn claim_time(name: &str, version: u64) -> Option<i64> {
+    let (ts, _) = name
+        .strip_prefix(&format!("v{version}."))?
+        .strip_suffix(".claim")?
+        .split_once('-')?;
+    ts.parse().ok()
+}
+
+#[cfg(test)]
+mod meta_writer_test {
+    use chrono::Utc;
+    use common_exception::ErrorCode;
+    use common_exception::Result;
+    use common_expression::types::NumberDataType;
+    use common_expression::TableDataType;
+    use common_expression::TableField;
+    use common_expression::TableSchema;
+    use opendal::services::Memory;
+    use opendal::Operator;
+
+    use super::add_snapshot;
+    use super::claim_time;
+    use super::new_snapshot_id;
+    use super::new_table_metadata;
+    use super::write_metadata;
+    use super::NewSnapshot;
+    use super::VERSION_HINT;
+    use crate::meta_reader::SnapshotMetadata;
+
+    #[test]
+    fn test_new_table_metadata() {
+        let schema = TableSchema::new(vec![
+            TableField::new("id", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("name", TableDataType::String.wrap_nullable()),
+        ]);
+        let mut metadata = new_table_metadata("/tmp/ice/db/t/", &schema).unwrap();
+        assert_eq!(metadata["location"], "/tmp/ice/db/t");
+        assert_eq!(metadata["last-column-id"], 2);
+
+        let bytes = serde_json::to_vec(&metadata).unwrap();
+        let snapshots = SnapshotMetadata::try_from_slice(&bytes).unwrap();
+        assert_eq!(snapshots.format_version, 2);
+        assert!(snapshots.current_snapshot().is_none());
+        assert!(
+            snapshots
+                .partition_spec(snapshots.default_spec_id)
+                .unwrap()
+                .is_empty()
+        );
+
+        let snapshot_id = new_snapshot_id();
+        assert!(snapshot_id >= 0);
+        let snapshot = NewSnapshot {
+            snapshot_id,
+            parent_snapshot_id: None,
+            sequence_number: 1,
+            timestamp_ms: 1000,
+            manifest_list: "/tmp/ice/db/t/metadata/snap.avro".to_string(),
+            schema_id: Some(0),
+            operation: "append",
+            added_data_files: 1,
+            added_records: 10,
+            added_files_size: 100,
+        };
+        add_snapshot(
+            &mut metadata,
+            &snapshot,
+            "/tmp/ice/db/t/metadata/v1.metadata.json",
+        )
+        .unwrap();
+        assert_eq!(metadata["refs"]["main"]["snapshot-id"], snapshot_id);
+        assert_eq!(metadata["snapshot-log"][0]["timestamp-ms"], 1000);
+        assert_eq!(
+            metadata["metadata-log"][0]["metadata-file"],
+            "/tmp/ice/db/t/metadata/v1.metadata.json"
+        );
+
+        let bytes = serde_json::to_vec(&metadata).unwrap();
+        let snapshots = SnapshotMetadata::try_from_slice(&bytes).unwrap();
+        assert_eq!(snapshots.last_sequence_number, 1);
+        let current = snapshots.current_snapshot().unwrap();
+        assert_eq!(current.snapshot_id, snapshot_id);
+        assert_eq!(current.sequence_number, 1);
+        assert_eq!(
+            current.manifest_list.as_deref(),
+            Some("/tmp/ice/db/t/metadata/snap.avro")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_metadata_claimed() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        let schema = TableSchema::new(vec![TableField::new(
+            "id",
+            TableDataType::Number(NumberDataType::Int32),
+        )]);
+        let metadata = new_table_metadata("/tmp/ice/db/t", &schema)?;
+
+        write_metadata(&op, 1, &metadata).await?;
+        assert_eq!(op.read(VERSION_HINT).await?, b"1");
+        let err = write_metadata(&op, 1, &metadata).await.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::TABLE_VERSION_MISMATCHED);
+
+        // claimed by another writer
+        let now = Utc::now().timestamp_millis();
+        op.write(&format!("metadata/v2.{now}-a.claim"), vec![])
+            .await?;
+        let err = write_metadata(&op, 2, &metadata).await.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::TABLE_VERSION_MISMATCHED);
+        assert!(!op.is_exist("metadata/v2.metadata.json").await?);
+
+        // left by a writer failed long ago
+        op.write("metadata/v3.1000-a.claim", vec![]).await?;
+        write_metadata(&op, 3, &metadata).await?;
+        assert_eq!(op.read(VERSION_HINT).await?, b"3");
+
+        assert_eq!(claim_time("v2.1000-a.claim", 2), Some(1000));
+        assert_eq!(claim_time("v2.1000-a.claim", 20), None);
+        assert_eq!(claim_time("v2.metadata.json", 2), None);
+        Ok(())
+    }
+}
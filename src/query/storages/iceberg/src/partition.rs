@@ -0,0 +1,83 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// a data file to read, with the delete files applied to it
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct IcebergPartInfo {
+    /// path relative to the table root
+    pub path: String,
+    /// path as written in the manifest,
+    /// which is referred by the position delete files
+    pub file_path: String,
+    pub size: u64,
+    pub record_count: u64,
+    /// position delete files, relative to the table root
+    pub position_deletes: Vec<String>,
+    pub equality_deletes: Vec<EqualityDeletes>,
+}
+
+/// an equality delete file
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct EqualityDeletes {
+    /// path relative to the table root
+    pub path: String,
+    /// names of the columns compared
+    pub columns: Vec<String>,
+}
+
+#[typetag::serde(name = "iceberg")]
+impl PartInfo for IcebergPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<IcebergPartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.path.hash(&mut s);
+        s.finish()
+    }
+}
+
+impl IcebergPartInfo {
+    pub fn convert_to_part_info(self) -> PartInfoPtr {
+        Arc::new(Box::new(self))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&IcebergPartInfo> {
+        match info.as_any().downcast_ref::<IcebergPartInfo>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to IcebergPartInfo.",
+            )),
+        }
+    }
+}
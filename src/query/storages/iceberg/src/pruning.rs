@@ -0,0 +1,189 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pruning of manifests and data files with the partition summaries
+//! in manifest lists, and the partition values and column statistics in manifests.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::converters::bound_iceberg_to_scalar;
+use crate::converters::partition_iceberg_to_scalar;
+use crate::manifest::DataFile;
+use crate::manifest::ManifestFile;
+use crate::meta_reader::PartitionField;
+
+/// the only partition transform that keeps the values of the source column
+const TRANSFORM_IDENTITY: &str = "identity";
+
+/// top level column of the table
+struct PruningColumn {
+    column_id: ColumnId,
+    field_id: i32,
+    data_type: TableDataType,
+}
+
+pub(crate) struct IcebergPruner {
+    range_pruner: Arc<dyn RangePruner + Send + Sync>,
+    columns: Vec<PruningColumn>,
+}
+
+impl IcebergPruner {
+    /// `field_ids` are the iceberg field ids of the top level fields in `schema`
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: &TableSchemaRef,
+        field_ids: &[i32],
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Self> {
+        let filter = push_downs
+            .as_ref()
+            .and_then(|extra| extra.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+        let range_pruner = RangePrunerCreator::try_create(func_ctx, schema, filter.as_ref())?;
+
+        let columns = schema
+            .fields()
+            .iter()
+            .zip(field_ids)
+            .filter(|(field, _)| {
+                // nested columns have no statistics
+                !matches!(
+                    field.data_type().remove_nullable(),
+                    TableDataType::Tuple { .. } | TableDataType::Array(_) | TableDataType::Map(_)
+                )
+            })
+            .map(|(field, field_id)| PruningColumn {
+                column_id: field.column_id(),
+                field_id: *field_id,
+                data_type: field.data_type().clone(),
+            })
+            .collect();
+
+        Ok(Self {
+            range_pruner,
+            columns,
+        })
+    }
+
+    fn column(&self, field_id: i32) -> Option<&PruningColumn> {
+        self.columns.iter().find(|c| c.field_id == field_id)
+    }
+
+    /// check the partition summaries of a data manifest
+    pub fn should_keep_manifest(&self, manifest: &ManifestFile, spec: &[PartitionField]) -> bool {
+        let mut stats = StatisticsOfColumns::new();
+        for (partition_field, summary) in spec.iter().zip(&manifest.partitions) {
+            if partition_field.transform != TRANSFORM_IDENTITY {
+                continue;
+            }
+            let column = match self.column(partition_field.source_id) {
+                Some(column) => column,
+                None => continue,
+            };
+
+            let (min, max) = match (&summary.lower_bound, &summary.upper_bound) {
+                (Some(lower), Some(upper)) => match (
+                    bound_iceberg_to_scalar(lower, &column.data_type),
+                    bound_iceberg_to_scalar(upper, &column.data_type),
+                ) {
+                    (Some(min), Some(max)) => (min, max),
+                    _ => continue,
+                },
+                // the bounds are missing if all the values are null
+                (None, None) if summary.contains_null && column.data_type.is_nullable() => {
+                    (Scalar::Null, Scalar::Null)
+                }
+                _ => continue,
+            };
+            stats.insert(column.column_id, ColumnStatistics {
+                min,
+                max,
+                null_count: summary.contains_null as u64,
+                in_memory_size: 0,
+                distinct_of_values: None,
+            });
+        }
+        self.range_pruner.should_keep(&stats)
+    }
+
+    /// check the partition values and column statistics of a data file
+    pub fn should_keep_data_file(&self, file: &DataFile, spec: &[PartitionField]) -> bool {
+        let record_count = file.record_count.max(0) as u64;
+        let mut stats = StatisticsOfColumns::new();
+        for column in &self.columns {
+            let null_count = file.null_value_counts.get(&column.field_id).copied();
+            let bound = |bounds: &HashMap<i32, Vec<u8>>| {
+                bounds
+                    .get(&column.field_id)
+                    .and_then(|b| bound_iceberg_to_scalar(b, &column.data_type))
+            };
+            let (min, max) = match (bound(&file.lower_bounds), bound(&file.upper_bounds)) {
+                (Some(min), Some(max)) => (min, max),
+                _ if record_count > 0
+                    && null_count == Some(record_count as i64)
+                    && column.data_type.is_nullable() =>
+                {
+                    (Scalar::Null, Scalar::Null)
+                }
+                _ => continue,
+            };
+            stats.insert(column.column_id, ColumnStatistics {
+                min,
+                max,
+                // unknown null counts are taken as having nulls
+                null_count: null_count.map_or(1, |n| n.max(0) as u64),
+                in_memory_size: 0,
+                distinct_of_values: None,
+            });
+        }
+
+        // the values of identity partition fields are exact
+        for (partition_field, value) in spec.iter().zip(&file.partition) {
+            if partition_field.transform != TRANSFORM_IDENTITY {
+                continue;
+            }
+            let column = match self.column(partition_field.source_id) {
+                Some(column) => column,
+                None => continue,
+            };
+            let value = match partition_iceberg_to_scalar(value, &column.data_type) {
+                Some(value) => value,
+                None => continue,
+            };
+            let null_count = if value.is_null() { record_count } else { 0 };
+            stats.insert(column.column_id, ColumnStatistics {
+                min: value.clone(),
+                max: value,
+                null_count,
+                in_memory_size: 0,
+                distinct_of_values: None,
+            });
+        }
+
+        self.range_pruner.should_keep(&stats)
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Writing the data files of insertions, they are committed by the table later.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::BlockMetaInfo;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableSchemaRef;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sinks::AsyncSinker;
+use opendal::Operator;
+use storages_common_blocks::blocks_to_parquet;
+use storages_common_table_meta::table::TableCompression;
+use uuid::Uuid;
+
+use crate::converters::scalar_to_bound_iceberg;
+
+/// blocks are buffered until the size, to avoid writing tiny data files
+const DATA_FILE_BUFFER_SIZE: usize = 64 * 1024 * 1024;
+
+/// strings longer than it are not kept as bounds
+const MAX_STRING_BOUND_LEN: usize = 16;
+
+/// a data file written, to be added to the table by the commit
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AppendedDataFile {
+    /// path relative to the table root
+    pub path: String,
+    pub record_count: u64,
+    pub file_size: u64,
+    /// statistics of the columns, in the order of the table schema
+    pub null_value_counts: Vec<u64>,
+    pub lower_bounds: Vec<Option<Vec<u8>>>,
+    pub upper_bounds: Vec<Option<Vec<u8>>>,
+}
+
+impl From<AppendedDataFile> for DataBlock {
+    fn from(value: AppendedDataFile) -> Self {
+        DataBlock::new_with_meta(vec![], 0, Some(Box::new(value)))
+    }
+}
+
+impl TryFrom<&DataBlock> for AppendedDataFile {
+    type Error = ErrorCode;
+    fn try_from(block: &DataBlock) -> Result<Self, Self::Error> {
+        block
+            .get_meta()
+            .and_then(AppendedDataFile::downcast_ref_from)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "invalid data block meta of iceberg insertion, {:?}",
+                    block.get_meta()
+                ))
+            })
+    }
+}
+
+#[typetag::serde(name = "iceberg_appended_data_file")]
+impl BlockMetaInfo for AppendedDataFile {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        match AppendedDataFile::downcast_ref_from(info) {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// statistics of a column in a data file
+#[derive(Default)]
+struct ColumnStatistics {
+    null_count: u64,
+    min: Option<Scalar>,
+    max: Option<Scalar>,
+}
+
+impl ColumnStatistics {
+    fn update(&mut self, value: ScalarRef, keep_bounds: bool) {
+        match value {
+            ScalarRef::Null => self.null_count += 1,
+            // NaN is not a valid bound
+            ScalarRef::Number(NumberScalar::Float32(v)) if v.is_nan() => {}
+            ScalarRef::Number(NumberScalar::Float64(v)) if v.is_nan() => {}
+            v if keep_bounds => {
+                if self.min.as_ref().map_or(true, |min| v < min.as_ref()) {
+                    self.min = Some(v.to_owned());
+                }
+                if self.max.as_ref().map_or(true, |max| v > max.as_ref()) {
+                    self.max = Some(v.to_owned());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn bound(scalar: &Option<Scalar>) -> Option<Vec<u8>> {
+        match scalar {
+            Some(Scalar::String(v)) if v.len() > MAX_STRING_BOUND_LEN => None,
+            Some(scalar) => scalar_to_bound_iceberg(scalar),
+            None => None,
+        }
+    }
+}
+
+/// the types of bounds can be decoded for pruning
+fn keep_bounds(data_type: &TableDataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        TableDataType::Number(NumberDataType::Int32)
+            | TableDataType::Number(NumberDataType::Int64)
+            | TableDataType::Number(NumberDataType::Float32)
+            | TableDataType::Number(NumberDataType::Float64)
+            | TableDataType::Date
+            | TableDataType::Timestamp
+            | TableDataType::String
+    )
+}
+
+/// writes the blocks into parquet data files of the table
+pub struct IcebergTableSink {
+    ctx: Arc<dyn TableContext>,
+    /// operator pointing to the table root
+    op: Operator,
+    schema: TableSchemaRef,
+    blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+}
+
+impl IcebergTableSink {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        input: Arc<InputPort>,
+        op: Operator,
+        schema: TableSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(AsyncSinker::create(
+            input,
+            IcebergTableSink {
+                ctx,
+                op,
+                schema,
+                blocks: vec![],
+                buffered_bytes: 0,
+            },
+        )))
+    }
+
+    /// write the buffered blocks as a data file
+    #[async_backtrace::framed]
+    async fn flush(&mut self) -> Result<()> {
+        if self.blocks.is_empty() {
+            return Ok(());
+        }
+        let blocks = std::mem::take(&mut self.blocks);
+        self.buffered_bytes = 0;
+
+        let fields = self.schema.fields();
+        let mut statistics = fields
+            .iter()
+            .map(|_| ColumnStatistics::default())
+            .collect::<Vec<_>>();
+        let mut record_count = 0;
+        for block in &blocks {
+            let num_rows = block.num_rows();
+            record_count += num_rows as u64;
+            for ((entry, field), stats) in block
+                .columns()
+                .iter()
+                .zip(fields.iter())
+                .zip(statistics.iter_mut())
+            {
+                let keep_bounds = keep_bounds(field.data_type());
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows);
+                for value in column.iter() {
+                    stats.update(value, keep_bounds);
+                }
+            }
+        }
+
+        let mut data = Vec::with_capacity(DATA_FILE_BUFFER_SIZE);
+        blocks_to_parquet(&self.schema, blocks, &mut data, TableCompression::Zstd)?;
+        let file_size = data.len() as u64;
+        let path = format!("data/{}.parquet", Uuid::new_v4().simple());
+        self.op.write(&path, data).await?;
+
+        let file = AppendedDataFile {
+            path,
+            record_count,
+            file_size,
+            null_value_counts: statistics.iter().map(|s| s.null_count).collect(),
+            lower_bounds: statistics
+                .iter()
+                .map(|s| ColumnStatistics::bound(&s.min))
+                .collect(),
+            upper_bounds: statistics
+                .iter()
+                .map(|s| ColumnStatistics::bound(&s.max))
+                .collect(),
+        };
+        self.ctx.push_precommit_block(file.into());
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncSink for IcebergTableSink {
+    const NAME: &'static str = "IcebergTableSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        self.flush().await
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if data_block.num_rows() == 0 {
+            return Ok(false);
+        }
+        self.buffered_bytes += data_block.memory_size();
+        self.blocks.push(data_block);
+        if self.buffered_bytes >= DATA_FILE_BUFFER_SIZE {
+            self.flush().await?;
+        }
+        Ok(false)
+    }
+}
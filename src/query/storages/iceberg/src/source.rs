@@ -0,0 +1,388 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reading the data files, with the delete files applied.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::read as pread;
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use opendal::Operator;
+
+use crate::partition::IcebergPartInfo;
+
+/// columns of position delete files
+const POSITION_DELETE_FILE_PATH: &str = "file_path";
+const POSITION_DELETE_POS: &str = "pos";
+
+pub struct IcebergTableSource {
+    ctx: Arc<dyn TableContext>,
+    reader: Arc<DataFileReader>,
+    /// blocks of the data file being read
+    blocks: VecDeque<DataBlock>,
+}
+
+impl IcebergTableSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        reader: Arc<DataFileReader>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, IcebergTableSource {
+            ctx,
+            reader,
+            blocks: VecDeque::new(),
+        })
+    }
+}
+
+/// reader of the data files, with the delete files applied
+pub struct DataFileReader {
+    /// operator pointing to the table root
+    op: Operator,
+    /// all the columns of the table
+    schema: TableSchemaRef,
+    /// the columns to output
+    projection: Projection,
+}
+
+impl DataFileReader {
+    pub fn create(op: Operator, schema: TableSchemaRef, projection: Projection) -> Self {
+        Self {
+            op,
+            schema,
+            projection,
+        }
+    }
+
+    /// read a data file, and remove the deleted rows
+    ///
+    /// Only the projected columns are read, along with the columns of the equality deletes.
+    #[async_backtrace::framed]
+    pub async fn read_part(&self, part: &IcebergPartInfo) -> Result<Vec<DataBlock>> {
+        // indices of the top level columns to read
+        let mut indices = match &self.projection {
+            Projection::Columns(indices) => indices.clone(),
+            Projection::InnerColumns(path_indices) => {
+                path_indices.values().map(|path| path[0]).collect()
+            }
+        };
+        for deletes in &part.equality_deletes {
+            for name in &deletes.columns {
+                indices.push(self.schema.index_of(name)?);
+            }
+        }
+        indices.sort_unstable();
+        indices.dedup();
+        let schema = self.schema.project(&indices);
+
+        let blocks = read_parquet(&self.op, &part.path, &schema).await?;
+        let blocks = self.apply_deletes(part, &schema, blocks).await?;
+        blocks
+            .into_iter()
+            .map(|block| self.project(&indices, block))
+            .collect()
+    }
+
+    /// output the projected columns of the block read with the columns of `indices`
+    fn project(&self, indices: &[usize], block: DataBlock) -> Result<DataBlock> {
+        let offset = |index: &usize| indices.binary_search(index).unwrap();
+        let num_rows = block.num_rows();
+        let columns = match &self.projection {
+            Projection::Columns(projected) => projected
+                .iter()
+                .map(|index| block.get_by_offset(offset(index)).clone())
+                .collect(),
+            Projection::InnerColumns(path_indices) => path_indices
+                .values()
+                .map(|path| {
+                    let entry = block.get_by_offset(offset(&path[0])).clone();
+                    inner_column(entry, &path[1..])
+                })
+                .collect::<Result<Vec<_>>>()?,
+        };
+        Ok(DataBlock::new(columns, num_rows))
+    }
+
+    /// remove the deleted rows from the blocks of `schema`
+    #[async_backtrace::framed]
+    async fn apply_deletes(
+        &self,
+        part: &IcebergPartInfo,
+        schema: &TableSchema,
+        blocks: Vec<DataBlock>,
+    ) -> Result<Vec<DataBlock>> {
+        if part.position_deletes.is_empty() && part.equality_deletes.is_empty() {
+            return Ok(blocks);
+        }
+
+        let positions = self.read_position_deletes(part).await?;
+        let mut equality_deletes = Vec::with_capacity(part.equality_deletes.len());
+        for deletes in &part.equality_deletes {
+            let fields = deletes
+                .columns
+                .iter()
+                .map(|name| schema.field_with_name(name).cloned())
+                .collect::<Result<Vec<_>>>()?;
+            let indices = deletes
+                .columns
+                .iter()
+                .map(|name| schema.index_of(name))
+                .collect::<Result<Vec<_>>>()?;
+
+            let mut keys = HashSet::new();
+            let schema = TableSchema::new(fields);
+            for block in read_parquet(&self.op, &deletes.path, &schema).await? {
+                let columns = (0..block.num_columns()).collect::<Vec<_>>();
+                for row in 0..block.num_rows() {
+                    keys.insert(row_key(&block, &columns, row));
+                }
+            }
+            equality_deletes.push((indices, keys));
+        }
+
+        let mut offset = 0;
+        let mut result = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            let num_rows = block.num_rows();
+            let mut bitmap = MutableBitmap::with_capacity(num_rows);
+            for row in 0..num_rows {
+                let deleted = positions.contains(&((offset + row) as i64))
+                    || equality_deletes
+                        .iter()
+                        .any(|(indices, keys)| keys.contains(&row_key(&block, indices, row)));
+                bitmap.push(!deleted);
+            }
+            offset += num_rows;
+
+            let bitmap: Bitmap = bitmap.into();
+            let block = match bitmap.unset_bits() {
+                0 => block,
+                n if n == num_rows => continue,
+                _ => block.filter_with_bitmap(&bitmap)?,
+            };
+            result.push(block);
+        }
+        Ok(result)
+    }
+
+    /// positions of the deleted rows in the data file
+    #[async_backtrace::framed]
+    async fn read_position_deletes(&self, part: &IcebergPartInfo) -> Result<HashSet<i64>> {
+        let schema = TableSchema::new(vec![
+            TableField::new(POSITION_DELETE_FILE_PATH, TableDataType::String),
+            TableField::new(
+                POSITION_DELETE_POS,
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+        ]);
+
+        let mut positions = HashSet::new();
+        for path in &part.position_deletes {
+            for block in read_parquet(&self.op, path, &schema).await? {
+                let file_paths = &block.get_by_offset(0).value;
+                let pos = &block.get_by_offset(1).value;
+                for row in 0..block.num_rows() {
+                    // a delete file may refer to several data files
+                    if let (
+                        Some(ScalarRef::String(file_path)),
+                        Some(ScalarRef::Number(NumberScalar::Int64(pos))),
+                    ) = (file_paths.index(row), pos.index(row))
+                    {
+                        if file_path == part.file_path.as_bytes() {
+                            positions.insert(pos);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(positions)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for IcebergTableSource {
+    const NAME: &'static str = "IcebergTableSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.blocks.pop_front() {
+                return Ok(Some(block));
+            }
+            match self.ctx.get_partition() {
+                None => return Ok(None),
+                Some(part) => {
+                    let part = IcebergPartInfo::from_part(&part)?;
+                    self.blocks = self.reader.read_part(part).await?.into();
+                }
+            }
+        }
+    }
+}
+
+/// the inner column of a tuple column by the path of field indices
+fn inner_column(entry: BlockEntry, path: &[usize]) -> Result<BlockEntry> {
+    match path.split_first() {
+        None => Ok(entry),
+        Some((index, path)) => match (entry.data_type, entry.value) {
+            (DataType::Tuple(types), Value::Column(Column::Tuple(columns))) => {
+                let entry = BlockEntry {
+                    data_type: types[*index].clone(),
+                    value: Value::Column(columns[*index].clone()),
+                };
+                inner_column(entry, path)
+            }
+            (DataType::Tuple(types), Value::Scalar(Scalar::Tuple(values))) => {
+                let entry = BlockEntry {
+                    data_type: types[*index].clone(),
+                    value: Value::Scalar(values[*index].clone()),
+                };
+                inner_column(entry, path)
+            }
+            (data_type, _) => Err(ErrorCode::BadArguments(format!(
+                "unable to get inner column of type {data_type}"
+            ))),
+        },
+    }
+}
+
+/// values of the columns at the row, compared for equality deletes
+fn row_key(block: &DataBlock, columns: &[usize], row: usize) -> Vec<Scalar> {
+    columns
+        .iter()
+        .map(|i| {
+            block
+                .get_by_offset(*i)
+                .value
+                .index(row)
+                .map_or(Scalar::Null, |v| v.to_owned())
+        })
+        .collect()
+}
+
+/// read a parquet file as blocks of `schema`
+///
+/// The columns are matched by name, the ones not in the file are filled with nulls.
+#[async_backtrace::framed]
+pub(crate) async fn read_parquet(
+    op: &Operator,
+    path: &str,
+    schema: &TableSchema,
+) -> Result<Vec<DataBlock>> {
+    let data = op.read(path).await?;
+    let mut reader = Cursor::new(data);
+    let meta = pread::read_metadata(&mut reader)?;
+    let file_schema = pread::infer_schema(&meta)?;
+
+    // position of the columns in the projected schema
+    let mut projected = vec![];
+    let mut positions = Vec::with_capacity(schema.num_fields());
+    for field in schema.fields() {
+        let found = file_schema
+            .fields
+            .iter()
+            .find(|f| f.name == *field.name())
+            .or_else(|| {
+                file_schema
+                    .fields
+                    .iter()
+                    .find(|f| f.name.eq_ignore_ascii_case(field.name()))
+            });
+        match found {
+            Some(f) => {
+                positions.push(Some(projected.len()));
+                projected.push(f.clone());
+            }
+            None if field.data_type().is_nullable() => positions.push(None),
+            None => {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "column {} not found in data file {path}",
+                    field.name()
+                )));
+            }
+        }
+    }
+
+    let build_block = |arrays: &[Box<dyn common_arrow::arrow::array::Array>], num_rows| {
+        let entries = schema
+            .fields()
+            .iter()
+            .zip(&positions)
+            .map(|(field, position)| {
+                let data_type = DataType::from(field.data_type());
+                let value = match position {
+                    Some(i) => Value::Column(Column::from_arrow(arrays[*i].as_ref(), &data_type)),
+                    None => Value::Scalar(Scalar::Null),
+                };
+                BlockEntry { data_type, value }
+            })
+            .collect();
+        DataBlock::new(entries, num_rows)
+    };
+
+    // none of the columns are in the file, only the number of rows matters
+    if projected.is_empty() {
+        let num_rows = meta.num_rows;
+        return Ok(if num_rows == 0 {
+            vec![]
+        } else {
+            vec![build_block(&[], num_rows)]
+        });
+    }
+
+    let chunks = pread::FileReader::new(
+        reader,
+        meta.row_groups,
+        ArrowSchema::from(projected),
+        None,
+        None,
+        None,
+    );
+    let mut blocks = vec![];
+    for chunk in chunks {
+        let chunk = chunk?;
+        if !chunk.is_empty() {
+            blocks.push(build_block(chunk.arrays(), chunk.len()));
+        }
+    }
+    Ok(blocks)
+}
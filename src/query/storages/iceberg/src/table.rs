@@ -15,45 +15,109 @@
 //! 2022-11-25:
 //! TODO: support synchronize with remote
 //! Note:
-//! currently, we only care about immutable tables
-//! once the table created we don't update it.
+//! the table is loaded once per query, insertions are committed
+//! on top of the latest metadata when the query finishes.
 
 use std::any::Any;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::table::AppendMode;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataBlock;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpsertTableCopiedFileReq;
+use common_meta_app::storage::StorageParams;
+use common_pipeline_core::Pipeline;
+use common_storage::init_operator;
 use common_storage::DataOperator;
 use futures::StreamExt;
 use iceberg_rs::model::table::TableMetadata;
 use opendal::Operator;
+use uuid::Uuid;
 
+use crate::converters::field_ids_iceberg;
+use crate::converters::iceberg_schema;
 use crate::converters::meta_iceberg_to_databend;
+use crate::manifest::read_manifest;
+use crate::manifest::read_manifest_list;
+use crate::manifest::write_manifest;
+use crate::manifest::write_manifest_list;
+use crate::manifest::DataFile;
+use crate::manifest::ManifestFile;
+use crate::manifest::CONTENT_DATA;
+use crate::manifest::CONTENT_EQUALITY_DELETES;
+use crate::manifest::CONTENT_POSITION_DELETES;
+use crate::manifest::STATUS_DELETED;
+use crate::meta_reader::metadata_version;
+use crate::meta_reader::Snapshot;
+use crate::meta_reader::SnapshotMetadata;
+use crate::meta_writer::add_snapshot;
+use crate::meta_writer::new_snapshot_id;
+use crate::meta_writer::write_metadata;
+use crate::meta_writer::NewSnapshot;
+use crate::meta_writer::VERSION_HINT;
+use crate::partition::EqualityDeletes;
+use crate::partition::IcebergPartInfo;
+use crate::pruning::IcebergPruner;
+use crate::sink::AppendedDataFile;
+use crate::sink::IcebergTableSink;
+use crate::source::DataFileReader;
+use crate::source::IcebergTableSource;
 
-/// file marking the current version of metadata file
+/// file marking the current version of metadata file, written by Dremio
 const META_PTR: &str = "metadata/version_hint.text";
 
-/// accessor wrapper as a table
-#[allow(unused)]
-pub struct IcebergTable {
+/// the only file format supported for data and delete files
+const FILE_FORMAT_PARQUET: &str = "parquet";
+
+/// times of committing on top of the latest metadata, when conflicting with others
+const MAX_COMMIT_ATTEMPTS: usize = 5;
+
+/// reserved field id of the `file_path` column in position delete files
+const POSITION_DELETE_FILE_PATH_ID: i32 = 2147483546;
+
+/// metadata of the table, parsed from the latest metadata file
+struct IcebergMetadata {
     /// database that belongs to
     database: String,
-    /// name of the current table
-    name: String,
-    /// root of the table
-    tbl_root: DataOperator,
-    /// table metadata
-    manifests: TableMetadata,
+    /// table metadata, for schemas
+    table: TableMetadata,
+    /// snapshots and partition specs
+    snapshots: SnapshotMetadata,
+}
+
+/// a data or delete file alive in the snapshot
+struct SnapshotFile {
+    /// data sequence number
+    sequence_number: i64,
+    partition_spec_id: i32,
+    file: DataFile,
+}
+
+/// accessor wrapper as a table
+pub struct IcebergTable {
     /// table information
     info: TableInfo,
+    /// operator pointing to the table root
+    op: Operator,
+    /// table metadata, only available for the tables read from the catalog,
+    /// not the ones restored from `TableInfo` to read data
+    metadata: Option<Arc<IcebergMetadata>>,
+    /// the snapshot to read, `None` for tables without data
+    snapshot_id: Option<i64>,
+    /// iceberg field ids of the top level columns in the schema of the snapshot
+    field_ids: Vec<i32>,
 }
 
 impl IcebergTable {
@@ -66,63 +130,482 @@ impl IcebergTable {
         tbl_root: DataOperator,
     ) -> Result<IcebergTable> {
         let op = tbl_root.operator();
-        // detect the latest manifest file
-        let latest_manifest = Self::version_detect(&op).await?;
-        // get table metadata from metadata file
-        let meta_json = op.read(&latest_manifest).await.map_err(|e| {
-            ErrorCode::ReadTableDataError(format!(
-                "invalid metadata in {}: {:?}",
-                &latest_manifest, e
-            ))
-        })?;
-        let metadata: TableMetadata =
+        let (latest_manifest, meta_json) = Self::read_latest_metadata(&op).await?;
+        let table: TableMetadata =
             serde_json::de::from_slice(meta_json.as_slice()).map_err(|e| {
                 ErrorCode::ReadTableDataError(format!(
                     "invalid metadata in {}: {:?}",
                     &latest_manifest, e
                 ))
             })?;
+        let snapshots = SnapshotMetadata::try_from_slice(meta_json.as_slice())?;
 
-        let sp = tbl_root.params();
+        let metadata = Arc::new(IcebergMetadata {
+            database: database.to_string(),
+            table,
+            snapshots,
+        });
+        let snapshot = metadata.snapshots.current_snapshot().cloned();
+        Ok(Self::create_at_snapshot(
+            catalog,
+            table_name,
+            op,
+            tbl_root.params(),
+            metadata,
+            snapshot.as_ref(),
+        ))
+    }
+
+    /// create a table reading the snapshot
+    fn create_at_snapshot(
+        catalog: &str,
+        table_name: &str,
+        op: Operator,
+        sp: StorageParams,
+        metadata: Arc<IcebergMetadata>,
+        snapshot: Option<&Snapshot>,
+    ) -> IcebergTable {
+        let database = &metadata.database;
+        // the schema when the snapshot was created
+        let schema_index = metadata.snapshots.schema_index(snapshot);
+        let field_ids = iceberg_schema(&metadata.table, schema_index)
+            .map(|schema| field_ids_iceberg(&schema))
+            .unwrap_or_default();
 
         // construct table info
         let info = TableInfo {
             ident: TableIdent::new(0, 0),
             desc: format!("IcebergTable: '{database}'.'{table_name}'"),
             name: table_name.to_string(),
-            meta: meta_iceberg_to_databend(catalog, &sp, &metadata),
+            meta: meta_iceberg_to_databend(catalog, &sp, &metadata.table, schema_index),
             ..Default::default()
         };
 
         // finish making table
+        Self {
+            info,
+            op,
+            snapshot_id: snapshot.map(|s| s.snapshot_id),
+            metadata: Some(metadata),
+            field_ids,
+        }
+    }
+
+    /// create the table to read data with the parts planned by the table read from the catalog
+    pub fn try_create(info: TableInfo) -> Result<IcebergTable> {
+        let op = match &info.meta.storage_params {
+            Some(sp) => init_operator(sp)?,
+            None => {
+                return Err(ErrorCode::Internal(format!(
+                    "missing storage params of iceberg table {}",
+                    info.name
+                )));
+            }
+        };
         Ok(Self {
-            database: database.to_string(),
-            name: table_name.to_string(),
-            tbl_root,
-            manifests: metadata,
             info,
+            op,
+            metadata: None,
+            snapshot_id: None,
+            field_ids: vec![],
+        })
+    }
+
+    fn metadata(&self) -> Result<&Arc<IcebergMetadata>> {
+        self.metadata.as_ref().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "metadata of iceberg table {} is not loaded",
+                self.info.name
+            ))
+        })
+    }
+
+    /// alive data files and delete files of the snapshot, data files are pruned with the filter
+    ///
+    /// Returns the number of data files before pruning as well.
+    #[async_backtrace::framed]
+    async fn snapshot_files(
+        &self,
+        pruner: &IcebergPruner,
+        snapshot: &Snapshot,
+    ) -> Result<(Vec<SnapshotFile>, Vec<SnapshotFile>, usize)> {
+        let snapshots = &self.metadata()?.snapshots;
+        let manifests = match &snapshot.manifest_list {
+            Some(path) => read_manifest_list(&self.op, &snapshots.relative_path(path)?).await?,
+            None => snapshot
+                .manifests
+                .iter()
+                .map(|path| ManifestFile::from_path(path))
+                .collect(),
+        };
+
+        let mut data_files = vec![];
+        let mut delete_files = vec![];
+        let mut total = 0;
+        for manifest in manifests {
+            let spec = snapshots
+                .partition_spec(manifest.partition_spec_id)
+                .unwrap_or_default();
+            // the summaries in manifests of deletes are not for the data
+            let prune_data = manifest.content == CONTENT_DATA;
+            if prune_data && !pruner.should_keep_manifest(&manifest, spec) {
+                // the pruned data files are not counted
+                continue;
+            }
+
+            let path = snapshots.relative_path(&manifest.manifest_path)?;
+            for entry in read_manifest(&self.op, &path).await? {
+                if entry.status == STATUS_DELETED {
+                    continue;
+                }
+                let file = SnapshotFile {
+                    // inherited from the manifest if not set
+                    sequence_number: entry.sequence_number.unwrap_or(manifest.sequence_number),
+                    partition_spec_id: manifest.partition_spec_id,
+                    file: entry.data_file,
+                };
+                if file.file.content == CONTENT_DATA {
+                    total += 1;
+                    if pruner.should_keep_data_file(&file.file, spec) {
+                        data_files.push(file);
+                    }
+                } else {
+                    delete_files.push(file);
+                }
+            }
+        }
+        Ok((data_files, delete_files, total))
+    }
+
+    /// the part reading the data file, with the delete files applying to it
+    fn data_file_part(
+        &self,
+        data: &SnapshotFile,
+        delete_files: &[SnapshotFile],
+    ) -> Result<IcebergPartInfo> {
+        let snapshots = &self.metadata()?.snapshots;
+        let same_partition = |delete: &SnapshotFile| {
+            delete.partition_spec_id == data.partition_spec_id
+                && delete.file.partition == data.file.partition
+        };
+
+        let mut position_deletes = vec![];
+        let mut equality_deletes = vec![];
+        for delete in delete_files {
+            match delete.file.content {
+                // position deletes apply to the data files in the same partition,
+                // with a sequence number less than or equal to the delete file
+                CONTENT_POSITION_DELETES
+                    if delete.sequence_number >= data.sequence_number
+                        && same_partition(delete)
+                        && may_refer_to(&delete.file, &data.file.file_path) =>
+                {
+                    check_file_format(&delete.file)?;
+                    position_deletes.push(snapshots.relative_path(&delete.file.file_path)?);
+                }
+                // equality deletes apply to the data files in the same partition
+                // or all the data files if unpartitioned,
+                // with a sequence number strictly less than the delete file
+                CONTENT_EQUALITY_DELETES
+                    if delete.sequence_number > data.sequence_number
+                        && (same_partition(delete)
+                            || snapshots
+                                .partition_spec(delete.partition_spec_id)
+                                .map_or(false, |spec| spec.is_empty())) =>
+                {
+                    check_file_format(&delete.file)?;
+                    let columns = delete
+                        .file
+                        .equality_ids
+                        .iter()
+                        .map(|id| {
+                            self.field_ids
+                                .iter()
+                                .position(|field_id| field_id == id)
+                                .map(|i| self.info.meta.schema.field(i).name().clone())
+                                .ok_or_else(|| {
+                                    ErrorCode::Unimplemented(format!(
+                                        "equality deletes on field {id} of {} are not supported",
+                                        delete.file.file_path
+                                    ))
+                                })
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    equality_deletes.push(EqualityDeletes {
+                        path: snapshots.relative_path(&delete.file.file_path)?,
+                        columns,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        check_file_format(&data.file)?;
+        Ok(IcebergPartInfo {
+            path: snapshots.relative_path(&data.file.file_path)?,
+            file_path: data.file.file_path.clone(),
+            size: data.file.file_size_in_bytes.max(0) as u64,
+            record_count: data.file.record_count.max(0) as u64,
+            position_deletes,
+            equality_deletes,
         })
     }
 
+    /// path and content of the latest metadata file
+    #[async_backtrace::framed]
+    async fn read_latest_metadata(op: &Operator) -> Result<(String, Vec<u8>)> {
+        // detect the latest manifest file
+        let latest_manifest = Self::version_detect(op).await?;
+        // get table metadata from metadata file
+        let meta_json = op.read(&latest_manifest).await.map_err(|e| {
+            ErrorCode::ReadTableDataError(format!(
+                "invalid metadata in {}: {:?}",
+                &latest_manifest, e
+            ))
+        })?;
+        Ok((latest_manifest, meta_json))
+    }
+
+    /// data files can only be added to unpartitioned tables of format v1 and v2,
+    /// with the schema the insertion was planned with
+    fn check_writable(&self, snapshots: &SnapshotMetadata, field_ids: &[i32]) -> Result<()> {
+        if snapshots.format_version > 2 {
+            return Err(ErrorCode::Unimplemented(format!(
+                "writing into iceberg table {} of format version {} is not supported",
+                self.info.name, snapshots.format_version
+            )));
+        }
+        if !snapshots
+            .partition_spec(snapshots.default_spec_id)
+            .map_or(false, |spec| spec.is_empty())
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "writing into partitioned iceberg table {} is not supported",
+                self.info.name
+            )));
+        }
+        if field_ids != self.field_ids {
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "schema of iceberg table {} has been changed since the current snapshot",
+                self.info.name
+            )));
+        }
+        Ok(())
+    }
+
+    /// add the data files as a new snapshot, replacing all the data files if overwrite
+    ///
+    /// The manifest of the data files is written once, the manifest list and the metadata
+    /// are written on top of the latest metadata until no other commits conflict.
+    #[async_backtrace::framed]
+    async fn do_commit(&self, files: Vec<AppendedDataFile>, overwrite: bool) -> Result<()> {
+        if files.is_empty() && !overwrite {
+            return Ok(());
+        }
+
+        let loaded = &self.metadata()?.snapshots;
+        let snapshot_id = new_snapshot_id();
+        let data_files = files
+            .iter()
+            .map(|file| self.data_file(loaded, file))
+            .collect::<Vec<_>>();
+        let added_records = data_files.iter().map(|f| f.record_count as u64).sum();
+        let added_files_size = data_files.iter().map(|f| f.file_size_in_bytes as u64).sum();
+
+        let commit_uuid = Uuid::new_v4();
+        let mut added_manifest = None;
+        for attempt in 0..MAX_COMMIT_ATTEMPTS {
+            let (metadata_path, meta_json) = Self::read_latest_metadata(&self.op).await?;
+            let version = metadata_version(&metadata_path).ok_or_else(|| {
+                ErrorCode::Unimplemented(format!(
+                    "metadata file {metadata_path} of iceberg table {} is not writable",
+                    self.info.name
+                ))
+            })?;
+            let snapshots = SnapshotMetadata::try_from_slice(&meta_json)?;
+            let mut metadata: serde_json::Value =
+                serde_json::from_slice(&meta_json).map_err(|e| {
+                    ErrorCode::ReadTableDataError(format!(
+                        "invalid metadata in {metadata_path}: {e}"
+                    ))
+                })?;
+            let table: TableMetadata = serde_json::from_value(metadata.clone()).map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("invalid metadata in {metadata_path}: {e}"))
+            })?;
+            let schema_index = snapshots.schema_index(None);
+            let field_ids = iceberg_schema(&table, schema_index)
+                .map(|schema| field_ids_iceberg(&schema))
+                .unwrap_or_default();
+            self.check_writable(&snapshots, &field_ids)?;
+
+            if added_manifest.is_none() && !data_files.is_empty() {
+                let path = format!("metadata/{commit_uuid}-m0.avro");
+                let schema = schema_index
+                    .map(|i| metadata["schemas"][i].to_string())
+                    .unwrap_or_else(|| metadata["schema"].to_string());
+                let manifest_metadata = [
+                    ("schema", schema),
+                    ("partition-spec", "[]".to_string()),
+                    ("partition-spec-id", snapshots.default_spec_id.to_string()),
+                    ("format-version", snapshots.format_version.to_string()),
+                    ("content", "data".to_string()),
+                ];
+                let length = write_manifest(
+                    &self.op,
+                    &path,
+                    snapshot_id,
+                    &data_files,
+                    &manifest_metadata,
+                )
+                .await?;
+                added_manifest = Some(ManifestFile::from_added(
+                    &loaded.absolute_path(&path),
+                    length,
+                    snapshot_id,
+                    &data_files,
+                ));
+            }
+
+            let sequence_number = match snapshots.format_version {
+                1 => 0,
+                _ => snapshots.last_sequence_number + 1,
+            };
+            let parent = snapshots.current_snapshot();
+            let mut manifests = match (parent, overwrite) {
+                (Some(parent), false) => self.snapshot_manifests(&snapshots, parent).await?,
+                _ => vec![],
+            };
+            if let Some(manifest) = &added_manifest {
+                let mut manifest = manifest.clone();
+                manifest.sequence_number = sequence_number;
+                manifest.min_sequence_number = sequence_number;
+                manifests.insert(0, manifest);
+            }
+
+            let manifest_list = format!(
+                "metadata/snap-{snapshot_id}-{}-{commit_uuid}.avro",
+                attempt + 1
+            );
+            let list_metadata = [
+                ("snapshot-id", snapshot_id.to_string()),
+                (
+                    "parent-snapshot-id",
+                    parent.map_or("null".to_string(), |p| p.snapshot_id.to_string()),
+                ),
+                ("sequence-number", sequence_number.to_string()),
+                ("format-version", snapshots.format_version.to_string()),
+            ];
+            write_manifest_list(&self.op, &manifest_list, &manifests, &list_metadata).await?;
+
+            let snapshot = NewSnapshot {
+                snapshot_id,
+                parent_snapshot_id: parent.map(|p| p.snapshot_id),
+                sequence_number,
+                timestamp_ms: chrono::Utc::now().timestamp_millis(),
+                manifest_list: snapshots.absolute_path(&manifest_list),
+                schema_id: snapshots.current_schema_id,
+                operation: if overwrite { "overwrite" } else { "append" },
+                added_data_files: data_files.len(),
+                added_records,
+                added_files_size,
+            };
+            add_snapshot(
+                &mut metadata,
+                &snapshot,
+                &snapshots.absolute_path(&metadata_path),
+            )?;
+            match write_metadata(&self.op, version + 1, &metadata).await {
+                Ok(()) => return Ok(()),
+                Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {
+                    tracing::warn!(
+                        "conflict when committing into iceberg table {}, retrying: {e}",
+                        self.info.name
+                    );
+                    continue;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(ErrorCode::TableVersionMismatched(format!(
+            "failed to commit into iceberg table {} after {MAX_COMMIT_ATTEMPTS} attempts",
+            self.info.name
+        )))
+    }
+
+    /// all the manifests of the snapshot, to be kept in the next snapshot
+    #[async_backtrace::framed]
+    async fn snapshot_manifests(
+        &self,
+        snapshots: &SnapshotMetadata,
+        snapshot: &Snapshot,
+    ) -> Result<Vec<ManifestFile>> {
+        if let Some(path) = &snapshot.manifest_list {
+            return read_manifest_list(&self.op, &snapshots.relative_path(path)?).await;
+        }
+
+        // the lengths are required in manifest lists
+        let mut manifests = Vec::with_capacity(snapshot.manifests.len());
+        for path in &snapshot.manifests {
+            let mut manifest = ManifestFile::from_path(path);
+            let meta = self.op.stat(&snapshots.relative_path(path)?).await?;
+            manifest.manifest_length = meta.content_length() as i64;
+            manifest.added_snapshot_id = snapshot.snapshot_id;
+            manifests.push(manifest);
+        }
+        Ok(manifests)
+    }
+
+    /// the data file to write in the manifest, with the statistics keyed by field ids
+    fn data_file(&self, snapshots: &SnapshotMetadata, file: &AppendedDataFile) -> DataFile {
+        let by_field_id = |values: &[Option<Vec<u8>>]| {
+            self.field_ids
+                .iter()
+                .zip(values)
+                .filter_map(|(id, value)| Some((*id, value.clone()?)))
+                .collect()
+        };
+        DataFile {
+            content: CONTENT_DATA,
+            file_path: snapshots.absolute_path(&file.path),
+            file_format: FILE_FORMAT_PARQUET.to_uppercase(),
+            partition: vec![],
+            record_count: file.record_count as i64,
+            file_size_in_bytes: file.file_size as i64,
+            null_value_counts: self
+                .field_ids
+                .iter()
+                .zip(&file.null_value_counts)
+                .map(|(id, count)| (*id, *count as i64))
+                .collect(),
+            lower_bounds: by_field_id(&file.lower_bounds),
+            upper_bounds: by_field_id(&file.upper_bounds),
+            equality_ids: vec![],
+        }
+    }
+
     /// version_detect figures out the manifest list version of the table
     /// and gives the relative path from table root directory
     /// to latest metadata json file
     #[async_backtrace::framed]
     async fn version_detect(tbl_root: &Operator) -> Result<String> {
-        // try Dremio's way
-        // Dremio has an `version_hint.txt` file
+        // try the way of filesystem catalogs and Dremio's way
+        // they have an `version-hint.text` or `version_hint.text` file
         // recording the latest snapshot version number
         // and stores metadata
-        if let Ok(version_hint) = tbl_root.read(META_PTR).await {
-            if let Ok(version_str) = String::from_utf8(version_hint) {
-                if let Ok(version) = version_str.trim().parse::<u64>() {
-                    return Ok(format!("metadata/v{version}.metadata.json"));
+        for hint in [VERSION_HINT, META_PTR] {
+            if let Ok(version_hint) = tbl_root.read(hint).await {
+                if let Ok(version_str) = String::from_utf8(version_hint) {
+                    if let Ok(version) = version_str.trim().parse::<u64>() {
+                        return Ok(format!("metadata/v{version}.metadata.json"));
+                    }
                 }
             }
         }
         // try Spark's way
         // Spark will arange all files with a sequential number
-        // in such case, we just need to find the file with largest alphabetical name.
+        // in such case, we just need to find the file with the largest version,
+        // or the largest alphabetical name.
         let files = tbl_root.list("metadata/").await.map_err(|e| {
             ErrorCode::ReadTableDataError(format!("Cannot list metadata directory: {e:?}"))
         })?;
@@ -141,7 +624,7 @@ impl IcebergTable {
             .collect::<Vec<String>>()
             .await
             .into_iter()
-            .max()
+            .max_by_key(|name| (metadata_version(name), name.clone()))
             .map(|s| format!("metadata/{s}"))
             .ok_or_else(|| ErrorCode::ReadTableDataError("Cannot get the latest manifest file"))
     }
@@ -165,12 +648,764 @@ impl Table for IcebergTable {
         &self.get_table_info().name
     }
 
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
-        _ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
-        todo!()
+        let snapshot = match self
+            .snapshot_id
+            .and_then(|id| self.metadata().ok()?.snapshots.snapshot_by_id(id))
+        {
+            Some(snapshot) => snapshot,
+            // table without data
+            None => return Ok((PartStatistics::default(), Partitions::default())),
+        };
+
+        let pruner = IcebergPruner::try_create(
+            ctx.get_function_context()?,
+            &self.info.schema(),
+            &self.field_ids,
+            &push_downs,
+        )?;
+        let (data_files, delete_files, total) = self.snapshot_files(&pruner, snapshot).await?;
+
+        let mut read_rows = 0;
+        let mut read_bytes = 0;
+        let mut parts = Vec::with_capacity(data_files.len());
+        for data in &data_files {
+            let part = self.data_file_part(data, &delete_files)?;
+            read_rows += part.record_count as usize;
+            read_bytes += part.size as usize;
+            parts.push(part.convert_to_part_info());
+        }
+
+        Ok((
+            PartStatistics::new_estimated(read_rows, read_bytes, parts.len(), total),
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(plan.parts.len(), max_threads);
+
+        let schema = self.info.schema();
+        let projection = PushDownInfo::projection_of_push_downs(&schema, &plan.push_downs);
+        let reader = Arc::new(DataFileReader::create(self.op.clone(), schema, projection));
+        pipeline.add_source(
+            |output| IcebergTableSource::create(ctx.clone(), output, reader.clone()),
+            std::cmp::max(1, max_threads),
+        )
+    }
+
+    fn append_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _: AppendMode,
+        _: bool,
+    ) -> Result<()> {
+        let metadata = self.metadata()?;
+        let schema_index = metadata.snapshots.schema_index(None);
+        let field_ids = iceberg_schema(&metadata.table, schema_index)
+            .map(|schema| field_ids_iceberg(&schema))
+            .unwrap_or_default();
+        self.check_writable(&metadata.snapshots, &field_ids)?;
+
+        pipeline.add_sink(|input| {
+            IcebergTableSink::create(ctx.clone(), input, self.op.clone(), self.info.schema())
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn commit_insertion(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        operations: Vec<DataBlock>,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        overwrite: bool,
+    ) -> Result<()> {
+        let files = operations
+            .iter()
+            .map(AppendedDataFile::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        self.do_commit(files, overwrite).await
+    }
+
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let metadata = self.metadata()?;
+        let snapshot = match point {
+            NavigationPoint::SnapshotID(id) => id
+                .parse::<i64>()
+                .ok()
+                .and_then(|id| metadata.snapshots.snapshot_by_id(id)),
+            NavigationPoint::TimePoint(time_point) => metadata.snapshots.snapshot_at(time_point),
+        }
+        .ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data found at given point {point:?} of iceberg table {}",
+                self.info.name
+            ))
+        })?;
+
+        let sp = self.info.meta.storage_params.clone().unwrap_or_default();
+        Ok(Arc::new(Self::create_at_snapshot(
+            &self.info.meta.catalog,
+            &self.info.name,
+            self.op.clone(),
+            sp,
+            metadata.clone(),
+            Some(snapshot),
+        )))
+    }
+}
+
+/// only parquet files can be read for now
+fn check_file_format(file: &DataFile) -> Result<()> {
+    if file.file_format.eq_ignore_ascii_case(FILE_FORMAT_PARQUET) {
+        Ok(())
+    } else {
+        Err(ErrorCode::Unimplemented(format!(
+            "file format {} of {} is not supported",
+            file.file_format, file.file_path
+        )))
+    }
+}
+
+/// whether the position delete file may refer to the data file
+///
+/// The bounds of `file_path` are usually equal when the deletes refer to a single data file.
+fn may_refer_to(delete: &DataFile, file_path: &str) -> bool {
+    match (
+        delete.lower_bounds.get(&POSITION_DELETE_FILE_PATH_ID),
+        delete.upper_bounds.get(&POSITION_DELETE_FILE_PATH_ID),
+    ) {
+        (Some(lower), Some(upper)) => {
+            lower.as_slice() <= file_path.as_bytes() && file_path.as_bytes() <= upper.as_slice()
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod table_test {
+    use std::sync::Arc;
+
+    use apache_avro::types::Value;
+    use apache_avro::Schema;
+    use apache_avro::Writer;
+    use chrono::TimeZone;
+    use chrono::Utc;
+    use common_catalog::plan::Projection;
+    use common_catalog::plan::PushDownInfo;
+    use common_catalog::table::NavigationPoint;
+    use common_exception::ErrorCode;
+    use common_exception::Result;
+    use common_expression::type_check::check_function;
+    use common_expression::types::number::NumberScalar;
+    use common_expression::types::DataType;
+    use common_expression::types::Int32Type;
+    use common_expression::types::Int64Type;
+    use common_expression::types::NumberDataType;
+    use common_expression::types::StringType;
+    use common_expression::DataBlock;
+    use common_expression::Expr;
+    use common_expression::FromData;
+    use common_expression::FunctionContext;
+    use common_expression::Scalar;
+    use common_expression::ScalarRef;
+    use common_expression::TableDataType;
+    use common_expression::TableField;
+    use common_expression::TableSchema;
+    use common_functions::BUILTIN_FUNCTIONS;
+    use common_meta_app::storage::StorageParams;
+    use futures::TryStreamExt;
+    use iceberg_rs::model::table::TableMetadata;
+    use opendal::services::Memory;
+    use opendal::Operator;
+    use serde_json::json;
+    use storages_common_blocks::blocks_to_parquet;
+    use storages_common_table_meta::table::TableCompression;
+
+    use super::IcebergMetadata;
+    use super::IcebergTable;
+    use crate::manifest::read_manifest;
+    use crate::manifest::read_manifest_list;
+    use crate::manifest::write_manifest_list;
+    use crate::manifest::FieldSummary;
+    use crate::manifest::ManifestFile;
+    use crate::manifest::CONTENT_DATA;
+    use crate::manifest::CONTENT_EQUALITY_DELETES;
+    use crate::manifest::CONTENT_POSITION_DELETES;
+    use crate::manifest::STATUS_ADDED;
+    use crate::meta_reader::SnapshotMetadata;
+    use crate::meta_writer::add_snapshot;
+    use crate::meta_writer::new_table_metadata;
+    use crate::meta_writer::write_metadata;
+    use crate::meta_writer::NewSnapshot;
+    use crate::pruning::IcebergPruner;
+    use crate::sink::AppendedDataFile;
+    use crate::source::DataFileReader;
+
+    const LOCATION: &str = "s3://bkt/ice/db/tbl";
+
+    /// `content` of manifests holding delete files
+    const MANIFEST_CONTENT_DELETES: i32 = 1;
+
+    /// schema of the manifests written by spark for tables partitioned by `part`
+    const MANIFEST_SCHEMA: &str = r#"
+    {
+        "type": "record",
+        "name": "manifest_entry",
+        "fields": [
+            { "name": "status", "type": "int", "field-id": 0 },
+            { "name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1 },
+            { "name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3 },
+            { "name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4 },
+            {
+                "name": "data_file",
+                "type": {
+                    "type": "record",
+                    "name": "r2",
+                    "fields": [
+                        { "name": "content", "type": "int", "field-id": 134 },
+                        { "name": "file_path", "type": "string", "field-id": 100 },
+                        { "name": "file_format", "type": "string", "field-id": 101 },
+                        {
+                            "name": "partition",
+                            "type": {
+                                "type": "record",
+                                "name": "r102",
+                                "fields": [
+                                    { "name": "part", "type": ["null", "int"], "default": null, "field-id": 1000 }
+                                ]
+                            },
+                            "field-id": 102
+                        },
+                        { "name": "record_count", "type": "long", "field-id": 103 },
+                        { "name": "file_size_in_bytes", "type": "long", "field-id": 104 },
+                        {
+                            "name": "equality_ids",
+                            "type": ["null", { "type": "array", "items": "int", "element-id": 136 }],
+                            "default": null,
+                            "field-id": 135
+                        }
+                    ]
+                },
+                "field-id": 2
+            }
+        ]
+    }
+    "#;
+
+    /// a file written into the table
+    struct File {
+        content: i32,
+        path: &'static str,
+        part: i32,
+        block: DataBlock,
+        equality_ids: Vec<i32>,
+    }
+
+    fn data_file(path: &'static str, part: i32, ids: Vec<i32>, names: Vec<&str>) -> File {
+        let num_rows = ids.len();
+        File {
+            content: CONTENT_DATA,
+            path,
+            part,
+            block: DataBlock::new_from_columns(vec![
+                Int32Type::from_data(ids),
+                StringType::from_data(names),
+                Int32Type::from_data(vec![part; num_rows]),
+            ]),
+            equality_ids: vec![],
+        }
+    }
+
+    fn int_bound(value: i32) -> Option<Vec<u8>> {
+        Some(value.to_le_bytes().to_vec())
+    }
+
+    fn null() -> Value {
+        Value::Union(0, Box::new(Value::Null))
+    }
+
+    /// write the files and their manifest, returns the manifest in the manifest list
+    async fn write_manifest(
+        op: &Operator,
+        path: &str,
+        snapshot_id: i64,
+        sequence_number: i64,
+        files: &[File],
+    ) -> Result<ManifestFile> {
+        let mut entries = vec![];
+        for file in files {
+            let schema = match file.content {
+                CONTENT_DATA => TableSchema::new(vec![
+                    TableField::new("id", TableDataType::Number(NumberDataType::Int32)),
+                    TableField::new("name", TableDataType::String),
+                    TableField::new("part", TableDataType::Number(NumberDataType::Int32)),
+                ]),
+                CONTENT_POSITION_DELETES => TableSchema::new(vec![
+                    TableField::new("file_path", TableDataType::String),
+                    TableField::new("pos", TableDataType::Number(NumberDataType::Int64)),
+                ]),
+                _ => TableSchema::new(vec![TableField::new(
+                    "id",
+                    TableDataType::Number(NumberDataType::Int32),
+                )]),
+            };
+            let mut data = vec![];
+            blocks_to_parquet(
+                &schema,
+                vec![file.block.clone()],
+                &mut data,
+                TableCompression::Snappy,
+            )?;
+            let file_size = data.len() as i64;
+            op.write(file.path, data).await?;
+
+            let equality_ids = match file.equality_ids.is_empty() {
+                true => null(),
+                false => Value::Union(
+                    1,
+                    Box::new(Value::Array(
+                        file.equality_ids.iter().map(|id| Value::Int(*id)).collect(),
+                    )),
+                ),
+            };
+            entries.push(Value::Record(vec![
+                ("status".to_string(), Value::Int(STATUS_ADDED)),
+                (
+                    "snapshot_id".to_string(),
+                    Value::Union(1, Box::new(Value::Long(snapshot_id))),
+                ),
+                ("sequence_number".to_string(), null()),
+                ("file_sequence_number".to_string(), null()),
+                (
+                    "data_file".to_string(),
+                    Value::Record(vec![
+                        ("content".to_string(), Value::Int(file.content)),
+                        (
+                            "file_path".to_string(),
+                            Value::String(format!("{LOCATION}/{}", file.path)),
+                        ),
+                        (
+                            "file_format".to_string(),
+                            Value::String("PARQUET".to_string()),
+                        ),
+                        (
+                            "partition".to_string(),
+                            Value::Record(vec![(
+                                "part".to_string(),
+                                Value::Union(1, Box::new(Value::Int(file.part))),
+                            )]),
+                        ),
+                        (
+                            "record_count".to_string(),
+                            Value::Long(file.block.num_rows() as i64),
+                        ),
+                        ("file_size_in_bytes".to_string(), Value::Long(file_size)),
+                        ("equality_ids".to_string(), equality_ids),
+                    ]),
+                ),
+            ]));
+        }
+
+        let schema = Schema::parse_str(MANIFEST_SCHEMA).unwrap();
+        let mut writer = Writer::new(&schema, Vec::new());
+        for entry in entries {
+            writer.append(entry).unwrap();
+        }
+        let data = writer.into_inner().unwrap();
+        let length = data.len() as i64;
+        op.write(path, data).await?;
+
+        let parts = files.iter().map(|f| f.part);
+        let mut manifest =
+            ManifestFile::from_added(&format!("{LOCATION}/{path}"), length, snapshot_id, &[]);
+        manifest.content = match files[0].content {
+            CONTENT_DATA => CONTENT_DATA,
+            _ => MANIFEST_CONTENT_DELETES,
+        };
+        manifest.sequence_number = sequence_number;
+        manifest.min_sequence_number = sequence_number;
+        manifest.partitions = vec![FieldSummary {
+            contains_null: false,
+            lower_bound: int_bound(parts.clone().min().unwrap()),
+            upper_bound: int_bound(parts.max().unwrap()),
+        }];
+        Ok(manifest)
+    }
+
+    /// schema of the table, the columns are nullable as written by spark
+    fn table_schema() -> TableSchema {
+        TableSchema::new(vec![
+            TableField::new(
+                "id",
+                TableDataType::Number(NumberDataType::Int32).wrap_nullable(),
+            ),
+            TableField::new("name", TableDataType::String.wrap_nullable()),
+            TableField::new(
+                "part",
+                TableDataType::Number(NumberDataType::Int32).wrap_nullable(),
+            ),
+        ])
+    }
+
+    /// a table of format v2 partitioned by `part`, written the way of spark
+    ///
+    /// - snapshot 1 at 1000 ms: adds the data files of `part=1` (id 1 to 4) in a manifest,
+    ///   and the ones of `part=2` (id 5 to 8) and `part=3` (id 9 and 10) in another.
+    /// - snapshot 2 at 2000 ms: deletes the first row (id 1) of `part=1` by position,
+    ///   and the row of id 6 in `part=2` by equality.
+    async fn create_table(op: &Operator) -> Result<Arc<IcebergMetadata>> {
+        let m1 = write_manifest(op, "metadata/m1.avro", 1, 1, &[data_file(
+            "data/part=1/00000-0-d1.parquet",
+            1,
+            vec![1, 2, 3, 4],
+            vec!["a", "b", "c", "d"],
+        )])
+        .await?;
+        let m2 = write_manifest(op, "metadata/m2.avro", 1, 1, &[
+            data_file("data/part=2/00000-0-d2.parquet", 2, vec![5, 6, 7, 8], vec![
+                "e", "f", "g", "h",
+            ]),
+            data_file("data/part=3/00000-0-d3.parquet", 3, vec![9, 10], vec![
+                "i", "j",
+            ]),
+        ])
+        .await?;
+        let m3 = write_manifest(op, "metadata/m3.avro", 2, 2, &[
+            File {
+                content: CONTENT_POSITION_DELETES,
+                path: "data/part=1/00000-1-deletes.parquet",
+                part: 1,
+                block: DataBlock::new_from_columns(vec![
+                    StringType::from_data(vec![format!(
+                        "{LOCATION}/data/part=1/00000-0-d1.parquet"
+                    )]),
+                    Int64Type::from_data(vec![0]),
+                ]),
+                equality_ids: vec![],
+            },
+            File {
+                content: CONTENT_EQUALITY_DELETES,
+                path: "data/part=2/00000-1-deletes.parquet",
+                part: 2,
+                block: DataBlock::new_from_columns(vec![Int32Type::from_data(vec![6])]),
+                equality_ids: vec![1],
+            },
+        ])
+        .await?;
+        write_manifest_list(op, "metadata/snap-1.avro", &[m1.clone(), m2.clone()], &[]).await?;
+        write_manifest_list(op, "metadata/snap-2.avro", &[m1, m2, m3], &[]).await?;
+
+        let mut metadata = new_table_metadata(LOCATION, &table_schema())?;
+        metadata["partition-specs"] = json!([{
+            "spec-id": 0,
+            "fields": [{ "source-id": 3, "field-id": 1000, "name": "part", "transform": "identity" }],
+        }]);
+        metadata["last-partition-id"] = json!(1000);
+        for (snapshot_id, operation) in [(1, "append"), (2, "delete")] {
+            let snapshot = NewSnapshot {
+                snapshot_id,
+                parent_snapshot_id: Some(snapshot_id - 1).filter(|id| *id > 0),
+                sequence_number: snapshot_id,
+                timestamp_ms: snapshot_id * 1000,
+                manifest_list: format!("{LOCATION}/metadata/snap-{snapshot_id}.avro"),
+                schema_id: Some(0),
+                operation,
+                added_data_files: 0,
+                added_records: 0,
+                added_files_size: 0,
+            };
+            let metadata_file = format!("{LOCATION}/metadata/v{snapshot_id}.metadata.json");
+            add_snapshot(&mut metadata, &snapshot, &metadata_file)?;
+        }
+
+        Ok(Arc::new(IcebergMetadata {
+            database: "db".to_string(),
+            table: serde_json::from_value::<TableMetadata>(metadata.clone()).unwrap(),
+            snapshots: SnapshotMetadata::try_from_slice(&serde_json::to_vec(&metadata).unwrap())?,
+        }))
+    }
+
+    async fn open_table(op: &Operator) -> Result<IcebergTable> {
+        let metadata = create_table(op).await?;
+        let snapshot = metadata.snapshots.current_snapshot().cloned();
+        Ok(IcebergTable::create_at_snapshot(
+            "ctl",
+            "tbl",
+            op.clone(),
+            StorageParams::default(),
+            metadata,
+            snapshot.as_ref(),
+        ))
+    }
+
+    /// `part = {part}`
+    fn filter_by_part(part: i32) -> Option<PushDownInfo> {
+        let expr = check_function(
+            None,
+            "eq",
+            &[],
+            &[
+                Expr::ColumnRef {
+                    span: None,
+                    id: "part".to_string(),
+                    data_type: DataType::Number(NumberDataType::Int32).wrap_nullable(),
+                    display_name: "part".to_string(),
+                },
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::Number(NumberScalar::Int32(part)),
+                    data_type: DataType::Number(NumberDataType::Int32),
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap();
+        Some(PushDownInfo {
+            filter: Some(expr.as_remote_expr()),
+            ..Default::default()
+        })
+    }
+
+    /// ids of the rows read from the snapshot of the table, and the number of data files
+    /// before pruning by the data files
+    async fn read_ids(
+        table: &IcebergTable,
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<(Vec<i32>, usize)> {
+        let snapshots = &table.metadata()?.snapshots;
+        let snapshot = snapshots
+            .snapshot_by_id(table.snapshot_id.unwrap())
+            .unwrap();
+        let schema = table.info.schema();
+        let pruner = IcebergPruner::try_create(
+            FunctionContext::default(),
+            &schema,
+            &table.field_ids,
+            push_downs,
+        )?;
+        let (data_files, delete_files, total) = table.snapshot_files(&pruner, snapshot).await?;
+
+        let reader = DataFileReader::create(table.op.clone(), schema, Projection::Columns(vec![0]));
+        let mut ids = vec![];
+        for data in &data_files {
+            let part = table.data_file_part(data, &delete_files)?;
+            for block in reader.read_part(&part).await? {
+                let column = &block.get_by_offset(0).value;
+                for row in 0..block.num_rows() {
+                    if let Some(ScalarRef::Number(NumberScalar::Int32(id))) = column.index(row) {
+                        ids.push(id);
+                    }
+                }
+            }
+        }
+        ids.sort();
+        Ok((ids, total))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_read_deletes() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        let table = open_table(&op).await?;
+
+        let (ids, total) = read_ids(&table, &None).await?;
+        assert_eq!(ids, vec![2, 3, 4, 5, 7, 8, 9, 10]);
+        assert_eq!(total, 3);
+
+        // the delete files apply to the data files in the same partition
+        let snapshots = &table.metadata()?.snapshots;
+        let snapshot = snapshots.current_snapshot().unwrap();
+        let pruner = IcebergPruner::try_create(
+            FunctionContext::default(),
+            &table.info.schema(),
+            &table.field_ids,
+            &None,
+        )?;
+        let (data_files, delete_files, _) = table.snapshot_files(&pruner, snapshot).await?;
+        assert_eq!(delete_files.len(), 2);
+        let parts = data_files
+            .iter()
+            .map(|data| table.data_file_part(data, &delete_files))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(parts[0].path, "data/part=1/00000-0-d1.parquet");
+        assert_eq!(parts[0].position_deletes, vec![
+            "data/part=1/00000-1-deletes.parquet".to_string()
+        ]);
+        assert!(parts[0].equality_deletes.is_empty());
+        assert!(parts[1].position_deletes.is_empty());
+        assert_eq!(parts[1].equality_deletes[0].columns, vec!["id".to_string()]);
+        assert!(parts[2].position_deletes.is_empty());
+        assert!(parts[2].equality_deletes.is_empty());
+
+        // the columns of equality deletes are read even if not projected
+        let reader = DataFileReader::create(
+            op.clone(),
+            table.info.schema(),
+            Projection::Columns(vec![1]),
+        );
+        let blocks = reader.read_part(&parts[1]).await?;
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].num_columns(), 1);
+        let names = &blocks[0].get_by_offset(0).value;
+        let names = (0..blocks[0].num_rows())
+            .map(|row| names.index(row).unwrap().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec![
+            Scalar::String(b"e".to_vec()),
+            Scalar::String(b"g".to_vec()),
+            Scalar::String(b"h".to_vec()),
+        ]);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_prune_by_partitions() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        let table = open_table(&op).await?;
+
+        // the manifest of `part=2` and `part=3` is pruned by its partition summary
+        let (ids, total) = read_ids(&table, &filter_by_part(1)).await?;
+        assert_eq!(ids, vec![2, 3, 4]);
+        assert_eq!(total, 1);
+
+        // the data file of `part=2` is pruned by its partition value
+        let (ids, total) = read_ids(&table, &filter_by_part(3)).await?;
+        assert_eq!(ids, vec![9, 10]);
+        assert_eq!(total, 2);
+
+        let (ids, total) = read_ids(&table, &filter_by_part(4)).await?;
+        assert!(ids.is_empty());
+        assert_eq!(total, 0);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_navigate_to_snapshot() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        let table = open_table(&op).await?;
+        assert_eq!(table.snapshot_id, Some(2));
+
+        // AT (SNAPSHOT => '1')
+        let point = NavigationPoint::SnapshotID("1".to_string());
+        let navigated = table.navigate_to(&point).await?;
+        let navigated = navigated.as_any().downcast_ref::<IcebergTable>().unwrap();
+        assert_eq!(navigated.snapshot_id, Some(1));
+        // the rows are not deleted yet
+        let (ids, _) = read_ids(navigated, &None).await?;
+        assert_eq!(ids, (1..=10).collect::<Vec<_>>());
+
+        // AT (TIMESTAMP => ...)
+        let time_point = Utc.timestamp_millis_opt(1500).unwrap();
+        let navigated = table
+            .navigate_to(&NavigationPoint::TimePoint(time_point))
+            .await?;
+        let navigated = navigated.as_any().downcast_ref::<IcebergTable>().unwrap();
+        assert_eq!(navigated.snapshot_id, Some(1));
+
+        let time_point = Utc.timestamp_millis_opt(2000).unwrap();
+        let navigated = table
+            .navigate_to(&NavigationPoint::TimePoint(time_point))
+            .await?;
+        let navigated = navigated.as_any().downcast_ref::<IcebergTable>().unwrap();
+        assert_eq!(navigated.snapshot_id, Some(2));
+
+        // before the first snapshot, or unknown snapshot
+        let time_point = Utc.timestamp_millis_opt(500).unwrap();
+        for point in [
+            NavigationPoint::TimePoint(time_point),
+            NavigationPoint::SnapshotID("3".to_string()),
+        ] {
+            let err = table.navigate_to(&point).await.err().unwrap();
+            assert_eq!(err.code(), ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND);
+        }
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_concurrent_commits() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        let metadata = new_table_metadata(LOCATION, &table_schema())?;
+        write_metadata(&op, 1, &metadata).await?;
+        let table = IcebergTable::create_at_snapshot(
+            "ctl",
+            "tbl",
+            op.clone(),
+            StorageParams::default(),
+            Arc::new(IcebergMetadata {
+                database: "db".to_string(),
+                table: serde_json::from_value::<TableMetadata>(metadata.clone()).unwrap(),
+                snapshots: SnapshotMetadata::try_from_slice(
+                    &serde_json::to_vec(&metadata).unwrap(),
+                )?,
+            }),
+            None,
+        );
+
+        let appended = |path: &str| AppendedDataFile {
+            path: path.to_string(),
+            record_count: 1,
+            file_size: 100,
+            null_value_counts: vec![0; 3],
+            lower_bounds: vec![None; 3],
+            upper_bounds: vec![None; 3],
+        };
+        // both of the commits are written on top of the same version at first
+        let (r1, r2) = futures::join!(
+            table.do_commit(vec![appended("data/a.parquet")], false),
+            table.do_commit(vec![appended("data/b.parquet")], false),
+        );
+        r1?;
+        r2?;
+
+        let (metadata_path, meta_json) = IcebergTable::read_latest_metadata(&op).await?;
+        assert_eq!(metadata_path, "metadata/v3.metadata.json");
+        let snapshots = SnapshotMetadata::try_from_slice(&meta_json)?;
+        assert_eq!(snapshots.snapshots.len(), 2);
+        assert_eq!(snapshots.last_sequence_number, 2);
+        // the latter is committed on top of the former
+        let metadata: serde_json::Value = serde_json::from_slice(&meta_json).unwrap();
+        assert_eq!(
+            metadata["snapshots"][1]["parent-snapshot-id"],
+            metadata["snapshots"][0]["snapshot-id"]
+        );
+
+        // none of the data files are lost
+        let current = snapshots.current_snapshot().unwrap();
+        let manifest_list = snapshots.relative_path(current.manifest_list.as_ref().unwrap())?;
+        let mut data_files = vec![];
+        for manifest in read_manifest_list(&op, &manifest_list).await? {
+            let path = snapshots.relative_path(&manifest.manifest_path)?;
+            for entry in read_manifest(&op, &path).await? {
+                data_files.push(snapshots.relative_path(&entry.data_file.file_path)?);
+            }
+        }
+        data_files.sort();
+        assert_eq!(data_files, vec!["data/a.parquet", "data/b.parquet"]);
+
+        // the claims of versions are removed
+        let entries: Vec<_> = op.list("metadata/").await?.try_collect().await?;
+        assert!(
+            entries
+                .iter()
+                .all(|entry| !entry.name().ends_with(".claim"))
+        );
+        Ok(())
     }
 }
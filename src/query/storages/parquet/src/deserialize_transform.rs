@@ -48,6 +48,7 @@ use crate::parquet_part::ParquetRowGroupPart;
 use crate::parquet_reader::IndexedReaders;
 use crate::parquet_reader::ParquetReader;
 use crate::parquet_source::ParquetSourceMeta;
+use crate::partition_filler::PartitionFiller;
 
 #[derive(Clone)]
 pub struct ParquetPrewhereInfo {
@@ -79,6 +80,9 @@ pub struct ParquetDeserializeTransform {
     remain_reader: Arc<ParquetReader>,
     // Used for top k optimization
     top_k_finished: bool,
+
+    // Used for filling the partition columns, which are not in the data files.
+    partition_filler: Option<Arc<PartitionFiller>>,
 }
 
 impl ParquetDeserializeTransform {
@@ -90,6 +94,7 @@ impl ParquetDeserializeTransform {
         output_schema: DataSchemaRef,
         prewhere_info: Option<ParquetPrewhereInfo>,
         remain_reader: Arc<ParquetReader>,
+        partition_filler: Option<Arc<PartitionFiller>>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
 
@@ -110,6 +115,8 @@ impl ParquetDeserializeTransform {
                 remain_reader,
 
                 top_k_finished: false,
+
+                partition_filler,
             },
         )))
     }
@@ -225,7 +232,10 @@ impl Processor for ParquetDeserializeTransform {
 
             // this means it's empty projection
             if readers.is_empty() {
-                let data_block = DataBlock::new(vec![], part.num_rows);
+                let mut data_block = DataBlock::new(vec![], part.num_rows);
+                if let Some(filler) = &self.partition_filler {
+                    data_block = filler.fill(&part.location, &self.output_schema, data_block)?;
+                }
                 self.add_block(data_block)?;
                 return Ok(());
             }
@@ -341,7 +351,15 @@ impl Processor for ParquetDeserializeTransform {
                 }
                 None => {
                     let chunks = self.remain_reader.read_from_readers(&mut readers)?;
-                    self.remain_reader.deserialize(part, chunks, row_selection)
+                    let data_block = self
+                        .remain_reader
+                        .deserialize(part, chunks, row_selection)?;
+                    match &self.partition_filler {
+                        Some(filler) => {
+                            filler.fill(&part.location, &self.output_schema, data_block)
+                        }
+                        None => Ok(data_block),
+                    }
                 }
             }?;
 
@@ -20,7 +20,9 @@ mod parquet_part;
 mod parquet_reader;
 mod parquet_source;
 mod parquet_table;
+mod partition_filler;
 mod pruning;
 mod statistics;
 
+pub use parquet_table::arrow_to_table_schema;
 pub use parquet_table::ParquetTable;
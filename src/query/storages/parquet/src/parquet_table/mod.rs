@@ -18,5 +18,5 @@ mod partition;
 mod read;
 mod table;
 
-pub(crate) use table::arrow_to_table_schema;
+pub use table::arrow_to_table_schema;
 pub use table::ParquetTable;
@@ -64,6 +64,7 @@ impl ParquetTable {
             stage_info,
             files_info,
             files_to_read,
+            partition_values: Default::default(),
         }))
     }
 
@@ -45,14 +45,16 @@ impl ParquetTable {
             ..
         }) = &push_down
         {
-            prj.clone()
+            self.data_projection(prj)
         } else {
             let indices = (0..self.arrow_schema.fields.len()).collect::<Vec<usize>>();
             Projection::Columns(indices)
         };
 
+        // The columns of the top k are looked up in the data files.
         let top_k = push_down
             .as_ref()
+            .filter(|_| !self.has_partition_columns())
             .map(|p| p.top_k(&self.table_info.schema(), None, RangeIndex::supported_type))
             .unwrap_or_default();
 
@@ -73,9 +75,12 @@ impl ParquetTable {
             ParquetReader::do_projection(&self.arrow_schema, &projection)?;
         let schema = Arc::new(arrow_to_table_schema(projected_arrow_schema));
 
+        // The data files are already pruned with the partition columns by the owner of
+        // the table, the filter is only used here if it is on the columns in the files.
         let filter = push_down
             .as_ref()
-            .and_then(|extra| extra.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)));
+            .and_then(|extra| extra.filter.as_ref().map(|f| f.as_expr(&BUILTIN_FUNCTIONS)))
+            .filter(|filter| self.refers_data_columns_only(filter));
 
         let top_k = top_k.map(|top_k| {
             let offset = projected_column_nodes
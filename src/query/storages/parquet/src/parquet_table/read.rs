@@ -37,6 +37,7 @@ use crate::deserialize_transform::ParquetPrewhereInfo;
 use crate::parquet_reader::ParquetReader;
 use crate::parquet_source::AsyncParquetSource;
 use crate::parquet_source::SyncParquetSource;
+use crate::partition_filler::PartitionFiller;
 
 impl ParquetTable {
     pub fn create_reader(&self, projection: Projection) -> Result<Arc<ParquetReader>> {
@@ -57,8 +58,10 @@ impl ParquetTable {
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let table_schema: TableSchemaRef = self.table_info.schema();
-        let source_projection =
-            PushDownInfo::projection_of_push_downs(&table_schema, &plan.push_downs);
+        let source_projection = self.data_projection(&PushDownInfo::projection_of_push_downs(
+            &table_schema,
+            &plan.push_downs,
+        ));
 
         // The front of the src_fields are prewhere columns (if exist).
         // The back of the src_fields are remain columns.
@@ -78,6 +81,7 @@ impl ParquetTable {
         let top_k = plan
             .push_downs
             .as_ref()
+            .filter(|_| !self.has_partition_columns())
             .map(|p| p.top_k(&table_schema, None, RangeIndex::supported_type))
             .unwrap_or_default();
 
@@ -140,6 +144,20 @@ impl ParquetTable {
         src_fields.extend_from_slice(remain_reader.output_schema.fields());
         let src_schema = DataSchemaRefExt::create(src_fields);
 
+        // Prewhere is not supported by the tables with partition columns,
+        // so the blocks to fill are the ones read by the remain reader.
+        let partition_filler = if self.has_partition_columns() {
+            Some(Arc::new(PartitionFiller::create(
+                &table_schema,
+                self.arrow_schema.fields.len(),
+                &remain_reader.output_schema,
+                &output_schema,
+                self.partition_values.clone(),
+            )?))
+        } else {
+            None
+        };
+
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
 
         // Add source pipe.
@@ -169,6 +187,7 @@ impl ParquetTable {
                 output_schema.clone(),
                 prewhere_info.clone(),
                 remain_reader.clone(),
+                partition_filler.clone(),
             )
         })
     }
@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use chrono::NaiveDateTime;
@@ -27,10 +28,13 @@ use common_catalog::plan::ParquetReadOptions;
 use common_catalog::plan::ParquetTableInfo;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
+use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::Expr;
+use common_expression::Scalar;
 use common_expression::TableSchema;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableIdent;
@@ -52,6 +56,8 @@ pub struct ParquetTable {
     pub(super) table_info: TableInfo,
     pub(super) arrow_schema: ArrowSchema,
     pub(super) files_to_read: Option<Vec<StageFileInfo>>,
+    /// see [`ParquetTableInfo::partition_values`]
+    pub(super) partition_values: Arc<BTreeMap<String, Vec<Scalar>>>,
 }
 
 impl ParquetTable {
@@ -66,8 +72,44 @@ impl ParquetTable {
             stage_info: info.stage_info.clone(),
             files_info: info.files_info.clone(),
             files_to_read: info.files_to_read.clone(),
+            partition_values: Arc::new(info.partition_values.clone()),
         }))
     }
+
+    /// Whether the table has partition columns, which are not stored in the data files.
+    pub(super) fn has_partition_columns(&self) -> bool {
+        self.table_info.schema().num_fields() > self.arrow_schema.fields.len()
+    }
+
+    /// The part of the projection on the columns stored in the data files.
+    pub(super) fn data_projection(&self, projection: &Projection) -> Projection {
+        let num_data_columns = self.arrow_schema.fields.len();
+        match projection {
+            Projection::Columns(indices) => Projection::Columns(
+                indices
+                    .iter()
+                    .copied()
+                    .filter(|index| *index < num_data_columns)
+                    .collect(),
+            ),
+            Projection::InnerColumns(path_indices) => Projection::InnerColumns(
+                path_indices
+                    .iter()
+                    .filter(|(_, path)| path[0] < num_data_columns)
+                    .map(|(index, path)| (*index, path.clone()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Whether the expression only refers to the columns stored in the data files.
+    pub(super) fn refers_data_columns_only(&self, expr: &Expr<String>) -> bool {
+        let schema = self.table_info.schema();
+        let partition_fields = &schema.fields()[self.arrow_schema.fields.len()..];
+        expr.column_refs()
+            .keys()
+            .all(|name| partition_fields.iter().all(|field| field.name() != name))
+    }
 }
 
 #[async_trait::async_trait]
@@ -104,6 +146,7 @@ impl Table for ParquetTable {
             stage_info: self.stage_info.clone(),
             files_info: self.files_info.clone(),
             files_to_read: self.files_to_read.clone(),
+            partition_values: self.partition_values.as_ref().clone(),
         })
     }
 
@@ -145,7 +188,8 @@ fn lower_field_name(field: &mut ArrowField) {
     }
 }
 
-pub(crate) fn arrow_to_table_schema(mut schema: ArrowSchema) -> TableSchema {
+/// Convert the arrow schema of parquet files to table schema, with lowercase field names.
+pub fn arrow_to_table_schema(mut schema: ArrowSchema) -> TableSchema {
     schema.fields.iter_mut().for_each(|f| {
         lower_field_name(f);
     });
@@ -0,0 +1,96 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Scalar;
+use common_expression::TableSchema;
+use common_expression::Value;
+
+enum FieldSource {
+    /// The offset of the column in the block read from the data file.
+    Data(usize),
+    /// The index of the value in the partition values of the data file.
+    Partition(usize),
+}
+
+/// Fills the values of the partition columns, which are not stored in the data files,
+/// into the blocks read from the data files.
+pub struct PartitionFiller {
+    sources: Vec<FieldSource>,
+    partition_values: Arc<BTreeMap<String, Vec<Scalar>>>,
+}
+
+impl PartitionFiller {
+    /// `table_schema` is the schema of the table, the partition columns are the fields
+    /// after the first `num_data_columns` ones.
+    pub fn create(
+        table_schema: &TableSchema,
+        num_data_columns: usize,
+        data_schema: &DataSchema,
+        output_schema: &DataSchema,
+        partition_values: Arc<BTreeMap<String, Vec<Scalar>>>,
+    ) -> Result<Self> {
+        let sources = output_schema
+            .fields()
+            .iter()
+            .map(|field| match table_schema.index_of(field.name()) {
+                Ok(index) if index >= num_data_columns => {
+                    Ok(FieldSource::Partition(index - num_data_columns))
+                }
+                // The inner columns of nested fields are not in the table schema.
+                _ => Ok(FieldSource::Data(data_schema.index_of(field.name())?)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            sources,
+            partition_values,
+        })
+    }
+
+    /// Build the output block of the data file at `location` from the block read from it.
+    pub fn fill(
+        &self,
+        location: &str,
+        output_schema: &DataSchema,
+        block: DataBlock,
+    ) -> Result<DataBlock> {
+        let values = self.partition_values.get(location).ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "partition values of data file {} not found",
+                location
+            ))
+        })?;
+        let num_rows = block.num_rows();
+        let columns = self
+            .sources
+            .iter()
+            .zip(output_schema.fields())
+            .map(|(source, field)| match source {
+                FieldSource::Data(offset) => block.get_by_offset(*offset).clone(),
+                FieldSource::Partition(index) => BlockEntry {
+                    data_type: field.data_type().clone(),
+                    value: Value::Scalar(values[*index].clone()),
+                },
+            })
+            .collect();
+        Ok(DataBlock::new(columns, num_rows))
+    }
+}
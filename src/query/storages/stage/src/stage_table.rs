@@ -111,6 +111,14 @@ impl Table for StageTable {
         DataSourceInfo::StageSource(self.table_info.clone())
     }
 
+    fn benefit_column_prune(&self) -> bool {
+        self.table_info
+            .stage_info
+            .file_format_options
+            .format
+            .has_inner_schema()
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
@@ -171,7 +179,12 @@ impl Table for StageTable {
 
         //  Build copy pipeline.
         let settings = ctx.get_settings();
-        let schema = stage_table_info.schema.clone();
+        // formats with inner schema read the projected columns only, others are read by position
+        let schema = if self.benefit_column_prune() {
+            plan.schema()
+        } else {
+            stage_table_info.schema.clone()
+        };
         let stage_info = stage_table_info.stage_info.clone();
         let operator = StageTable::get_op(&stage_table_info.stage_info)?;
         let compact_threshold = self.get_block_compact_thresholds_with_default();
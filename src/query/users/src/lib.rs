@@ -17,7 +17,9 @@
 extern crate core;
 
 mod jwt;
+mod masking_policy;
 mod role_mgr;
+mod row_access_policy;
 mod user;
 mod user_api;
 mod user_mgr;
@@ -19,10 +19,14 @@ use common_exception::Result;
 use common_grpc::RpcClientConf;
 use common_management::FileFormatApi;
 use common_management::FileFormatMgr;
+use common_management::MaskingPolicyApi;
+use common_management::MaskingPolicyMgr;
 use common_management::QuotaApi;
 use common_management::QuotaMgr;
 use common_management::RoleApi;
 use common_management::RoleMgr;
+use common_management::RowAccessPolicyApi;
+use common_management::RowAccessPolicyMgr;
 use common_management::SettingApi;
 use common_management::SettingMgr;
 use common_management::StageApi;
@@ -121,6 +125,23 @@ impl UserApiProvider {
         Ok(Arc::new(UdfMgr::create(self.client.clone(), tenant)?))
     }
 
+    pub fn get_row_access_policy_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<dyn RowAccessPolicyApi>> {
+        Ok(Arc::new(RowAccessPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_masking_policy_api_client(&self, tenant: &str) -> Result<Arc<dyn MaskingPolicyApi>> {
+        Ok(Arc::new(MaskingPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_tenant_quota_api_client(&self, tenant: &str) -> Result<Arc<dyn QuotaApi>> {
         Ok(Arc::new(QuotaMgr::create(self.client.clone(), tenant)?))
     }
@@ -21,6 +21,7 @@ use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserQuota;
 use common_meta_types::MatchSeq;
 
 use crate::role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
@@ -245,6 +246,21 @@ impl UserApiProvider {
         }
     }
 
+    // Update an user's quota
+    #[async_backtrace::framed]
+    pub async fn update_user_quota(
+        &self,
+        tenant: &str,
+        user: UserIdentity,
+        quota: UserQuota,
+    ) -> Result<Option<u64>> {
+        let client = self.get_user_api_client(tenant)?;
+        client
+            .update_user_with(user, MatchSeq::GE(1), |ui: &mut UserInfo| ui.quota = quota)
+            .await
+            .map_err(|e| e.add_message_back("(while alter user quota)."))
+    }
+
     // Update an user's default role
     #[async_backtrace::framed]
     pub async fn update_user_default_role(
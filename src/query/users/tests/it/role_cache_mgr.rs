@@ -56,15 +56,19 @@ async fn test_find_all_related_roles() -> Result<()> {
         RoleInfo::new("role3"),
         RoleInfo::new("role4"),
         RoleInfo::new("role5"),
+        RoleInfo::new("role6"),
+        RoleInfo::new("account_admin"),
     ];
     // role1 -> role2 -> role4 -> role5
     //    <- -> role3
+    // role6 -> account_admin
     let role_grants = vec![
         ("role1".to_string(), "role2".to_string()),
         ("role1".to_string(), "role3".to_string()),
         ("role2".to_string(), "role4".to_string()),
         ("role3".to_string(), "role1".to_string()),
         ("role4".to_string(), "role5".to_string()),
+        ("role6".to_string(), "account_admin".to_string()),
     ];
     let tests = vec![
         (vec!["role1".to_string()], vec![
@@ -73,6 +77,7 @@ async fn test_find_all_related_roles() -> Result<()> {
         (vec!["role3".to_string()], vec![
             "role1", "role2", "role3", "role4", "role5",
         ]),
+        (vec!["role6".to_string()], vec!["role6", "account_admin"]),
     ];
     let mut cached: HashMap<String, RoleInfo> = roles
         .into_iter()
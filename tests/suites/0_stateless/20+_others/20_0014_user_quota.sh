@@ -0,0 +1,39 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=quota_password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-quota\npassword=${TEST_USER_PASSWORD}" >> quota_password.out
+
+## create user with storage quota
+echo "drop user if exists 'test-quota'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "create user 'test-quota'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD' WITH QUOTA (MAX_STORAGE_IN_BYTES = 1)" | $MYSQL_CLIENT_CONNECT
+echo "GRANT ALL ON default.* TO 'test-quota'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+
+## the first insertion is allowed, the table created by the user is empty
+echo "create table t20_0014(c int)" | $TEST_USER_CONNECT
+echo "insert into t20_0014 values(1),(2)" | $TEST_USER_CONNECT
+
+## the storage quota is used up
+echo "insert into t20_0014 values(3)" | $TEST_USER_CONNECT 2>&1 | grep -o "Code: 2904"
+
+## the table is not created by root, root is not limited
+echo "insert into t20_0014 values(3)" | $MYSQL_CLIENT_CONNECT
+
+## remove the storage quota
+echo "alter user 'test-quota'@'$QUERY_MYSQL_HANDLER_HOST' WITH QUOTA (MAX_STORAGE_IN_BYTES = 0)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0014 values(4)" | $TEST_USER_CONNECT
+echo "select count(*) from t20_0014" | $TEST_USER_CONNECT
+
+## limit the cpu and memory of the user
+echo "alter user 'test-quota'@'$QUERY_MYSQL_HANDLER_HOST' WITH QUOTA (MAX_CPU = 1, MAX_MEMORY_IN_BYTES = 1073741824)" | $MYSQL_CLIENT_CONNECT
+echo "select sum(c) from t20_0014" | $TEST_USER_CONNECT
+
+## Drop table.
+echo "drop table default.t20_0014 all" | $MYSQL_CLIENT_CONNECT
+
+## Drop user
+echo "drop user 'test-quota'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+rm -rf quota_password.out
@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=column_password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-column\npassword=${TEST_USER_PASSWORD}" >> column_password.out
+
+echo "drop table if exists t20_0015" | $MYSQL_CLIENT_CONNECT
+echo "create table t20_0015(a int, b string)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t20_0015 values(1, 'x'),(2, 'y')" | $MYSQL_CLIENT_CONNECT
+
+## create user with column privileges
+echo "drop user if exists 'test-column'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "create user 'test-column'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT(a) ON default.t20_0015 TO 'test-column'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT(c) ON default.t20_0015 TO 'test-column'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -o "Code: 1058"
+echo "show grants for 'test-column'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+
+## the columns which are not granted are hidden
+echo "select * from t20_0015 order by a" | $TEST_USER_CONNECT
+echo "select b from t20_0015" | $TEST_USER_CONNECT 2>&1 | grep -o "Code: 1065"
+
+## revoke the column privilege
+echo "REVOKE SELECT(a) ON default.t20_0015 FROM 'test-column'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select a from t20_0015" | $TEST_USER_CONNECT 2>&1 | grep -o "Code: 1063"
+
+## Drop table.
+echo "drop table default.t20_0015 all" | $MYSQL_CLIENT_CONNECT
+
+## Drop user
+echo "drop user 'test-column'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+rm -rf column_password.out
@@ -0,0 +1,63 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export OWNER_USER_CONNECT="mysql --defaults-extra-file=owner_password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+export PUBLIC_USER_CONNECT="mysql --defaults-extra-file=public_password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+export NESTED_USER_CONNECT="mysql --defaults-extra-file=nested_password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-owner\npassword=${TEST_USER_PASSWORD}" >> owner_password.out
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-public\npassword=${TEST_USER_PASSWORD}" >> public_password.out
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-nested\npassword=${TEST_USER_PASSWORD}" >> nested_password.out
+
+echo "drop table if exists t20_0016" | $MYSQL_CLIENT_CONNECT
+echo "drop user if exists 'test-owner'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "drop user if exists 'test-public'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "drop user if exists 'test-nested'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "drop role if exists 'test-owner-role'" | $MYSQL_CLIENT_CONNECT
+echo "drop role if exists 'test-nested-role'" | $MYSQL_CLIENT_CONNECT
+
+## the nested role is granted with the owner role
+echo "create role 'test-owner-role'" | $MYSQL_CLIENT_CONNECT
+echo "create role 'test-nested-role'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT ROLE 'test-owner-role' TO ROLE 'test-nested-role'" | $MYSQL_CLIENT_CONNECT
+
+## create users with the owner role, the nested role and only the PUBLIC role
+echo "create user 'test-owner'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD' WITH DEFAULT_ROLE = 'test-owner-role'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT ROLE 'test-owner-role' TO 'test-owner'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "create user 'test-nested'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD' WITH DEFAULT_ROLE = 'test-nested-role'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT ROLE 'test-nested-role' TO 'test-nested'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "create user 'test-public'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $MYSQL_CLIENT_CONNECT
+
+echo "create table t20_0016(c int)" | $MYSQL_CLIENT_CONNECT
+echo "GRANT OWNERSHIP ON default.t20_0016 TO ROLE 'test-owner-role'" | $MYSQL_CLIENT_CONNECT
+
+## the owner role has all the privileges on the table
+echo "insert into t20_0016 values(1)" | $OWNER_USER_CONNECT
+echo "select c from t20_0016 order by c" | $OWNER_USER_CONNECT
+
+## the ownership is inherited through the nested role
+echo "insert into t20_0016 values(2)" | $NESTED_USER_CONNECT
+echo "select c from t20_0016 order by c" | $NESTED_USER_CONNECT
+
+## the user with only the PUBLIC role is denied
+echo "select c from t20_0016" | $PUBLIC_USER_CONNECT 2>&1 | grep -o "Code: 1063"
+echo "insert into t20_0016 values(3)" | $PUBLIC_USER_CONNECT 2>&1 | grep -o "Code: 1063"
+echo "GRANT SELECT ON default.t20_0016 TO 'test-public'@'$QUERY_MYSQL_HANDLER_HOST'" | $PUBLIC_USER_CONNECT 2>&1 | grep -o "Code: 1063"
+echo "GRANT OWNERSHIP ON default.t20_0016 TO ROLE 'public'" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -o "Code: 1061"
+
+## the owner can grant privileges on the table
+echo "GRANT SELECT ON default.t20_0016 TO 'test-public'@'$QUERY_MYSQL_HANDLER_HOST'" | $OWNER_USER_CONNECT
+echo "select count(*) from t20_0016" | $PUBLIC_USER_CONNECT
+
+## Drop table.
+echo "drop table default.t20_0016 all" | $MYSQL_CLIENT_CONNECT
+
+## Drop users and roles
+echo "drop user 'test-owner'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "drop user 'test-public'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "drop user 'test-nested'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "drop role 'test-owner-role'" | $MYSQL_CLIENT_CONNECT
+echo "drop role 'test-nested-role'" | $MYSQL_CLIENT_CONNECT
+rm -rf owner_password.out public_password.out nested_password.out
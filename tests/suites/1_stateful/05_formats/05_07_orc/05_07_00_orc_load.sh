@@ -0,0 +1,38 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+echo "drop table if exists test_orc" | $MYSQL_CLIENT_CONNECT
+echo "create table test_orc (id int, name string)" | $MYSQL_CLIENT_CONNECT
+
+DATADIR_PATH="/tmp/data_05_07_00"
+rm -rf ${DATADIR_PATH}
+mkdir ${DATADIR_PATH}
+DATADIR="fs://$DATADIR_PATH/"
+cp "$CURDIR"/../../../../data/sample.orc ${DATADIR_PATH}/
+
+echo "drop stage if exists stage_05_07_00;" | $MYSQL_CLIENT_CONNECT
+echo "create stage stage_05_07_00 url = '${DATADIR}' FILE_FORMAT = (TYPE = ORC);" | $MYSQL_CLIENT_CONNECT
+
+echo "---copy into table"
+echo "copy into test_orc from @stage_05_07_00 pattern = '.*orc';" | $MYSQL_CLIENT_CONNECT
+echo "select * from test_orc order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "---select stage"
+echo "select name from @stage_05_07_00 (pattern => '.*orc', FILE_FORMAT => 'orc') order by name" | $MYSQL_CLIENT_CONNECT
+echo "select count(*) from '${DATADIR}' (pattern => '.*orc', FILE_FORMAT => 'orc') where id > 1" | $MYSQL_CLIENT_CONNECT
+
+echo "---infer_schema"
+echo "select * from infer_schema(location => '@stage_05_07_00', pattern => '.*orc', FILE_FORMAT => 'orc')" | $MYSQL_CLIENT_CONNECT
+
+echo "---streaming load"
+echo "truncate table test_orc" | $MYSQL_CLIENT_CONNECT
+curl -sH "insert_sql:insert into test_orc file_format = (type = ORC)" \
+	-F "upload=@${DATADIR_PATH}/sample.orc" \
+	-u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" | grep -c "SUCCESS"
+echo "select * from test_orc order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table test_orc" | $MYSQL_CLIENT_CONNECT
+echo "drop stage stage_05_07_00" | $MYSQL_CLIENT_CONNECT
+rm -rf ${DATADIR_PATH}
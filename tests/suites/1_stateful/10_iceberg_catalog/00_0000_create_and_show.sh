@@ -20,3 +20,6 @@ EOF
 echo "SHOW DATABASES IN iceberg_ctl;" | $MYSQL_CLIENT_CONNECT
 
 echo "SHOW TABLES IN iceberg_ctl.iceberg_db;" | $MYSQL_CLIENT_CONNECT
+
+## The table has no snapshot yet
+echo "SELECT count(*) FROM iceberg_ctl.iceberg_db.iceberg_tbl;" | $MYSQL_CLIENT_CONNECT
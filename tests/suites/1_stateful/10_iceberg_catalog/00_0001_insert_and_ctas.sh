@@ -0,0 +1,44 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ICEBERG_ROOT=/tmp/databend_iceberg_write
+
+echo "DROP CATALOG IF EXISTS iceberg_write_ctl" | $MYSQL_CLIENT_CONNECT
+
+rm -rf ${ICEBERG_ROOT} && mkdir -p ${ICEBERG_ROOT}/iceberg_db
+
+## Create iceberg catalog on the local file system
+cat <<SQL | $MYSQL_CLIENT_CONNECT
+CREATE CATALOG iceberg_write_ctl
+TYPE=ICEBERG
+CONNECTION=(
+    URL='fs://${ICEBERG_ROOT}/'
+);
+SQL
+
+echo "CREATE TABLE iceberg_write_ctl.iceberg_db.t AS SELECT number::INT AS id, to_string(number) AS name FROM numbers(5);" | $MYSQL_CLIENT_CONNECT
+
+echo "SHOW TABLES IN iceberg_write_ctl.iceberg_db;" | $MYSQL_CLIENT_CONNECT
+
+echo "SELECT count(*), sum(id) FROM iceberg_write_ctl.iceberg_db.t;" | $MYSQL_CLIENT_CONNECT
+
+echo "INSERT INTO iceberg_write_ctl.iceberg_db.t VALUES (10, 'x'), (11, 'y');" | $MYSQL_CLIENT_CONNECT
+
+echo "SELECT count(*), sum(id) FROM iceberg_write_ctl.iceberg_db.t;" | $MYSQL_CLIENT_CONNECT
+
+## Pruned by the bounds written in the manifest
+echo "SELECT id, name FROM iceberg_write_ctl.iceberg_db.t WHERE id > 9 ORDER BY id;" | $MYSQL_CLIENT_CONNECT
+
+echo "INSERT OVERWRITE iceberg_write_ctl.iceberg_db.t VALUES (20, 'z');" | $MYSQL_CLIENT_CONNECT
+
+echo "SELECT id, name FROM iceberg_write_ctl.iceberg_db.t;" | $MYSQL_CLIENT_CONNECT
+
+## Each commit writes a new version of metadata
+cat ${ICEBERG_ROOT}/iceberg_db/t/metadata/version-hint.text
+echo
+
+echo "DROP CATALOG iceberg_write_ctl" | $MYSQL_CLIENT_CONNECT
+
+rm -rf ${ICEBERG_ROOT}
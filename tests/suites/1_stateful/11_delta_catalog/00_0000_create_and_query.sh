@@ -0,0 +1,42 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "DROP CATALOG IF EXISTS delta_ctl" | $MYSQL_CLIENT_CONNECT
+
+## Create delta catalog
+cat <<EOF | $MYSQL_CLIENT_CONNECT
+CREATE CATALOG delta_ctl
+TYPE=DELTA
+CONNECTION=(
+    URL='s3://testbucket/delta_data/delta_ctl/'
+    AWS_KEY_ID='minioadmin'
+    AWS_SECRET_KEY='minioadmin'
+    ENDPOINT_URL='${STORAGE_S3_ENDPOINT_URL}'
+);
+EOF
+
+echo "SHOW DATABASES IN delta_ctl;" | $MYSQL_CLIENT_CONNECT
+
+echo "SHOW TABLES IN delta_ctl.delta_db;" | $MYSQL_CLIENT_CONNECT
+
+## Only the file added by the latest commit is active
+echo "SELECT count(*) FROM delta_ctl.delta_db.delta_tbl;" | $MYSQL_CLIENT_CONNECT
+
+echo "SELECT id FROM delta_ctl.delta_db.delta_tbl WHERE id > 5 ORDER BY id;" | $MYSQL_CLIENT_CONNECT
+
+## Pruned by the statistics in the log
+echo "SELECT count(*) FROM delta_ctl.delta_db.delta_tbl WHERE id > 100;" | $MYSQL_CLIENT_CONNECT
+
+## Partition columns are filled with the values in the log
+echo "SELECT count(*) FROM delta_ctl.delta_db.delta_part_tbl;" | $MYSQL_CLIENT_CONNECT
+
+echo "SELECT year, count(*) FROM delta_ctl.delta_db.delta_part_tbl GROUP BY year ORDER BY year;" | $MYSQL_CLIENT_CONNECT
+
+## Pruned by the partition values in the log
+echo "SELECT count(*) FROM delta_ctl.delta_db.delta_part_tbl WHERE year = 2023 AND id > 5;" | $MYSQL_CLIENT_CONNECT
+
+echo "SELECT id, year FROM delta_ctl.delta_db.delta_part_tbl WHERE year = 2022 ORDER BY id LIMIT 2;" | $MYSQL_CLIENT_CONNECT
+
+echo "DROP CATALOG delta_ctl" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,3 @@
+select * from hive.default.t_orc order by id;
+select id, t_double from hive.default.t_orc where t_int > 1;
+select count(*) from hive.default.t_orc where t_date is null;
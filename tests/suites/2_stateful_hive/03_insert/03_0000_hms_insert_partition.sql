@@ -0,0 +1,6 @@
+insert into hive.default.t_write partition (p_date = '20230101') values ('a', 1), ('b', 2);
+insert into hive.default.t_write partition (p_date = '20230102') select 'c', 3;
+select * from hive.default.t_write order by id;
+select id from hive.default.t_write where p_date = '20230102';
+insert overwrite hive.default.t_write partition (p_date = '20230101') values ('d', 4);
+select * from hive.default.t_write order by id;